@@ -1,33 +1,51 @@
 //! Main application coordinator
 
 use anyhow::Result;
+use bollard::auth::DockerCredentials;
+use bollard::models::{CreateImageInfo, PushImageInfo};
 use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
 use crossterm::terminal::{self, EnterAlternateScreen, LeaveAlternateScreen};
 
 use ratatui::backend::CrosstermBackend;
 use ratatui::Terminal;
 use std::io;
+use std::path::{Path, PathBuf};
 use std::pin::Pin;
 use std::time::Duration;
 use tracing::{debug, error, info, warn};
 
 use crate::config::Config;
 use crate::core::{
-    ConnectionInfo, ContainerSummary, ImageSummary, NetworkSummary, NotificationLevel,
-    Result as ContuiResult, VolumeSummary,
+    ConfirmAction, ConnectionInfo, ContainerSummary, ContuiError, DockerError, ImageSummary,
+    NetworkSummary, NotificationLevel, Result as ContuiResult, VolumeSummary,
 };
+use crate::docker::archive;
+use crate::docker::build::{self, BuildLine, BuildOptions};
 use crate::docker::exec::ExecStart;
-use crate::docker::{select_exec_command, DockerClient, LogEntry, SystemDiskUsage};
-use crate::exec::spinner;
-use crate::state::AppState;
+use crate::docker::layers::{self, ImageAnalysis};
+use crate::docker::volume_backup;
+use crate::docker::{
+    format_bytes_size, parse_aliases, parse_volume_options, plan_cleanup, select_exec_command,
+    BackupManifest, DockerClient, ExitInfo, LogEntry, NetworkOptions, RestoreOutcome,
+    SystemDiskUsage, TransferProgress, VolumeEntry, VolumeOptions,
+};
+use crate::exec::asciicast::{self, CastPlayer, CastRecorder};
+use crate::exec::scrollback::ScrollbackParser;
+use crate::exec::{clipboard, spinner};
+use crate::registry::{self, DigestCache, ImageRef, ManifestInfo, RegistryClient};
+use crate::scan;
+use crate::state::restart_tracker::needs_exit_check;
+use crate::state::{AppState, ExitCheckBaseline, FormDialogState, FormKind, RegistryLevel};
 use crate::ui::{UiAction, UiApp};
 use futures::StreamExt;
 use tokio::io::AsyncWriteExt;
 use tokio::sync::mpsc;
 
+/// Log lines shown before the exit in the "why did it die" panel
+const DEATH_REPORT_LOG_LINES: usize = 50;
+
 /// Main application struct
 pub struct App {
-    #[allow(dead_code)]
     config: Config,
     state: AppState,
     docker_client: Option<DockerClient>,
@@ -53,6 +71,36 @@ pub struct App {
     exec_start_pending: Option<ExecStartPending>,
     /// Track last terminal size for exec resize
     last_terminal_size: Option<(u16, u16)>,
+    /// Recording being replayed in the exec pane
+    playback: Option<CastPlayer>,
+    /// Last time playback advanced
+    last_playback_tick: std::time::Instant,
+    /// Most recently saved recording (default for the replay dialog)
+    last_recording: Option<PathBuf>,
+    /// Channel receiver for a running recreate: (container name, new ID)
+    recreate_rx: Option<mpsc::Receiver<(String, ContuiResult<String>)>>,
+    /// Channel receiver for a running pull; dropping it cancels the pull
+    transfer_rx: Option<mpsc::Receiver<TransferEvent>>,
+    /// Channel receiver for a running build; dropping it cancels the build
+    build_rx: Option<mpsc::Receiver<BuildEvent>>,
+    /// Background job ID and channel receiver for a running save or load
+    archive_rx: Option<(u64, mpsc::Receiver<ArchiveEvent>)>,
+    /// Background job ID and channel receiver for a running layer analysis;
+    /// dropping it cancels the export
+    layers_rx: Option<(u64, mpsc::Receiver<LayerEvent>)>,
+    /// Runtime of the open volume browser
+    volume_browser: Option<VolumeBrowserRuntime>,
+    /// Background job ID and channel receiver for a running volume backup
+    /// or restore
+    volume_backup_rx: Option<(u64, mpsc::Receiver<VolumeBackupEvent>)>,
+    /// Clients for the registries shown in the registry browser
+    registry_clients: Vec<RegistryClient>,
+    /// Channel receiver for the latest registry browser request
+    registry_rx: Option<mpsc::Receiver<RegistryEvent>>,
+    /// Channel receiver for running registry digest lookups: (tag, digest)
+    freshness_rx: Option<mpsc::Receiver<(String, Result<String, String>)>>,
+    /// Background job ID and channel receiver for "pull all outdated"
+    outdated_pull_rx: Option<(u64, mpsc::Receiver<OutdatedPullEvent>)>,
 }
 
 enum ExecOutput {
@@ -64,9 +112,102 @@ struct ExecRuntime {
     exec_id: String,
     container_id: String,
     input: Pin<Box<dyn tokio::io::AsyncWrite + Send>>,
-    parser: vt100::Parser,
+    parser: ScrollbackParser,
     output_rx: mpsc::Receiver<ExecOutput>,
     size: (u16, u16),
+    recorder: Option<CastRecorder>,
+    /// Debug sidecar the session runs in, removed when the pane closes
+    sidecar_id: Option<String>,
+}
+
+enum TransferEvent {
+    Pull(CreateImageInfo),
+    Push(PushImageInfo),
+    Finished(ContuiResult<()>),
+}
+
+enum BuildEvent {
+    Output(Vec<BuildLine>),
+    /// The built image ID
+    Finished(ContuiResult<String>),
+}
+
+enum ArchiveEvent {
+    /// Bytes written (save) or read (load) so far
+    Progress(u64),
+    /// Archive path and total bytes written
+    Saved(String, ContuiResult<u64>),
+    /// Archive path and the references that appeared
+    Loaded(String, ContuiResult<Vec<String>>),
+}
+
+enum LayerEvent {
+    /// Bytes exported so far
+    Progress(u64),
+    Finished(ContuiResult<ImageAnalysis>),
+}
+
+enum VolumeEvent {
+    /// Archive bytes read so far
+    Progress(u64),
+    /// Helper container ID, as soon as it exists
+    HelperCreated(String),
+    /// The volume's entries
+    Listed(ContuiResult<Vec<VolumeEntry>>),
+    /// Download job ID and the local path
+    Downloaded(u64, ContuiResult<PathBuf>),
+}
+
+enum VolumeBackupEvent {
+    /// Archive bytes read (backup) or sent (restore) so far
+    Progress(u64),
+    /// Archive path and the manifest written with it
+    BackedUp(String, ContuiResult<BackupManifest>),
+    /// Archive path, volume, and how the restore went
+    Restored(String, String, ContuiResult<RestoreOutcome>),
+}
+
+/// Helper container and channel behind the open volume browser
+struct VolumeBrowserRuntime {
+    /// Set once the helper is created; removed when the browser closes
+    helper_id: Option<String>,
+    /// Background job of the listing while it runs
+    listing_job: Option<u64>,
+    /// Background jobs of running downloads
+    download_jobs: Vec<u64>,
+    tx: mpsc::Sender<VolumeEvent>,
+    /// Closing it cancels the listing and any download
+    rx: mpsc::Receiver<VolumeEvent>,
+}
+
+impl VolumeBrowserRuntime {
+    /// Stop taking events, picking up a helper created in the meantime.
+    /// Closing first means every later send fails, so the listing task
+    /// removes any helper it creates after this.
+    fn take_pending_helper(&mut self) {
+        self.rx.close();
+        while let Ok(event) = self.rx.try_recv() {
+            if let VolumeEvent::HelperCreated(helper_id) = event {
+                self.helper_id = Some(helper_id);
+            }
+        }
+    }
+}
+
+enum RegistryEvent {
+    Repositories(ContuiResult<Vec<String>>),
+    Tags(ContuiResult<Vec<String>>),
+    /// Tag and its resolved manifest
+    Manifest(String, ContuiResult<ManifestInfo>),
+}
+
+enum OutdatedPullEvent {
+    /// Tag being pulled, its position in the batch and the batch size
+    Started(String, usize, usize),
+    /// Bytes transferred and expected for the current tag
+    Progress(u64, u64),
+    /// Tags that failed to pull
+    Finished(Vec<String>),
 }
 
 struct ExecStartPending {
@@ -80,6 +221,7 @@ enum ExecStartResult {
         container_id: String,
         container_name: String,
         size: (u16, u16),
+        sidecar_id: Option<String>,
     },
     Failed {
         container_id: String,
@@ -123,6 +265,7 @@ struct DataRefreshHandle {
 #[derive(Debug, Default)]
 struct DataRefreshData {
     containers: Option<Vec<ContainerSummary>>,
+    exit_infos: Option<Vec<ExitInfo>>,
     images: Option<Vec<ImageSummary>>,
     volumes: Option<Vec<VolumeSummary>>,
     networks: Option<Vec<NetworkSummary>>,
@@ -130,7 +273,7 @@ struct DataRefreshData {
 }
 
 impl DataRefreshData {
-    async fn fetch(client: &DockerClient) -> Self {
+    async fn fetch(client: &DockerClient, baseline: &ExitCheckBaseline) -> Self {
         let containers = match client.list_containers(true).await {
             Ok(containers) => {
                 debug!("Loaded {} containers", containers.len());
@@ -142,6 +285,20 @@ impl DataRefreshData {
             }
         };
 
+        // List results carry no restart count, so inspect for crash tracking,
+        // skipping running containers that didn't change since last time
+        let exit_infos = match &containers {
+            Some(containers) => {
+                let ids: Vec<String> = containers
+                    .iter()
+                    .filter(|c| needs_exit_check(c, baseline))
+                    .map(|c| c.id.clone())
+                    .collect();
+                Some(client.exit_infos(&ids).await)
+            }
+            None => None,
+        };
+
         let images = match client.list_images(true).await {
             Ok(images) => {
                 debug!("Loaded {} images", images.len());
@@ -188,6 +345,7 @@ impl DataRefreshData {
 
         Self {
             containers,
+            exit_infos,
             images,
             volumes,
             networks,
@@ -199,6 +357,9 @@ impl DataRefreshData {
         if let Some(containers) = self.containers {
             state.update_containers(containers);
         }
+        if let Some(exit_infos) = self.exit_infos {
+            state.update_exit_infos(exit_infos);
+        }
         if let Some(images) = self.images {
             state.update_images(images);
         }
@@ -221,7 +382,11 @@ struct DataRefreshResult {
 }
 
 impl DataRefreshResult {
-    async fn gather(client: Option<DockerClient>, config: Config) -> Self {
+    async fn gather(
+        client: Option<DockerClient>,
+        config: Config,
+        baseline: ExitCheckBaseline,
+    ) -> Self {
         if let Some(client) = client {
             debug!("Refreshing data from Docker");
             if let Err(e) = client.ping().await {
@@ -229,7 +394,7 @@ impl DataRefreshResult {
                 return Self::disconnected();
             }
 
-            let data = DataRefreshData::fetch(&client).await;
+            let data = DataRefreshData::fetch(&client, &baseline).await;
             let info = client.connection_info().clone();
             return Self {
                 client: Some(client),
@@ -241,7 +406,7 @@ impl DataRefreshResult {
         info!("No Docker client, attempting to connect...");
         match App::connect_docker(&config).await {
             Ok((client, info)) => {
-                let data = DataRefreshData::fetch(&client).await;
+                let data = DataRefreshData::fetch(&client, &baseline).await;
                 Self {
                     client: Some(client),
                     info: Some(info),
@@ -270,6 +435,20 @@ impl App {
         info!("Creating new App instance");
 
         let mut state = AppState::new();
+        state.remote_digests =
+            DigestCache::new(Duration::from_secs(config.images.update_check_ttl_seconds));
+        for path in &config.images.scan_reports {
+            let (reports, errors) = scan::load_reports(path);
+            for error in errors {
+                warn!("Skipping scan report {}", error);
+            }
+            info!(
+                "Loaded {} scan report(s) from {}",
+                reports.len(),
+                path.display()
+            );
+            state.add_scan_reports(reports);
+        }
 
         // Try to connect to Docker
         let docker_client = match Self::connect_docker(&config).await {
@@ -299,6 +478,20 @@ impl App {
             exec_start_rx: None,
             exec_start_pending: None,
             last_terminal_size: None,
+            playback: None,
+            last_playback_tick: std::time::Instant::now(),
+            last_recording: None,
+            recreate_rx: None,
+            transfer_rx: None,
+            build_rx: None,
+            archive_rx: None,
+            layers_rx: None,
+            volume_browser: None,
+            volume_backup_rx: None,
+            registry_clients: Vec::new(),
+            registry_rx: None,
+            freshness_rx: None,
+            outdated_pull_rx: None,
         })
     }
 
@@ -375,6 +568,8 @@ impl App {
                 // Check if should quit
                 if should_quit {
                     info!("Quit signal received, exiting event loop");
+                    self.remove_exec_sidecar_on_exit().await;
+                    self.remove_volume_helper_on_exit().await;
                     break;
                 }
             }
@@ -390,6 +585,39 @@ impl App {
                 // Check for completed data refreshes
                 self.check_data_refresh().await;
 
+                // Check for a finished recreate
+                self.check_recreate();
+
+                // Apply image pull/push progress
+                self.check_transfer();
+
+                // Apply image build output
+                self.check_build();
+
+                // Apply image save/load progress
+                self.check_archive();
+
+                // Apply layer analysis progress
+                self.check_layers();
+
+                // Apply volume browser listings and downloads
+                self.check_volume_browser();
+
+                // Apply volume backup/restore progress
+                self.check_volume_backup();
+
+                // Apply registry browser responses
+                self.check_registry();
+
+                // Apply registry digests and check tags that are due
+                self.check_freshness();
+                if self.config.images.check_updates && self.freshness_rx.is_none() {
+                    self.start_freshness_checks();
+                }
+
+                // Apply "pull all outdated" progress
+                self.check_outdated_pull();
+
                 // Auto-refresh logs when in follow mode (every 2 seconds)
                 if let Some(ref log_view) = self.state.log_view {
                     if log_view.follow && self.log_fetch_rx.is_none() {
@@ -456,6 +684,9 @@ impl App {
                 // Check for exec output
                 self.check_exec_output().await;
 
+                // Advance recording playback
+                self.tick_playback();
+
                 // Resize exec session if terminal size changed
                 if self.state.exec_view.is_some() {
                     let current = self.state.terminal_size;
@@ -574,16 +805,112 @@ impl App {
                     .add_notification("Loading details...", NotificationLevel::Info);
                 self.fetch_container_details(id).await;
             }
+            UiAction::OpenRecreateForm(id) => self.open_recreate_form(id).await,
+            UiAction::ShowDeathReport(id) => {
+                let name = self
+                    .state
+                    .containers
+                    .iter()
+                    .find(|c| c.id == id)
+                    .and_then(|c| c.names.first())
+                    .cloned()
+                    .unwrap_or_else(|| id.chars().take(12).collect::<String>());
+
+                self.state.open_death_report(id.clone(), name);
+                self.fetch_death_report(id).await;
+            }
+            UiAction::GenerateRunSpec(id) => {
+                self.generate_run_spec(&id).await;
+            }
             UiAction::ExecContainer(id) => {
-                self.start_exec_for_container(&id).await;
+                self.start_exec_for_container(&id, None).await;
             }
             UiAction::StartContainerAndExec(id) => {
                 self.start_container(&id).await;
-                self.start_exec_for_container(&id).await;
+                self.start_exec_for_container(&id, None).await;
+            }
+            UiAction::DebugContainer(id) => {
+                let image = self.config.exec.debug_image.clone();
+                self.start_exec_for_container(&id, Some(image)).await;
             }
             UiAction::ExecInput(bytes) => {
                 self.write_exec_input(bytes).await;
             }
+            UiAction::EnterExecCopyMode => {
+                self.enter_exec_copy_mode();
+            }
+            UiAction::CopyToClipboard(text) => {
+                self.copy_to_clipboard(&text);
+            }
+            UiAction::ToggleExecRecording => {
+                self.toggle_exec_recording();
+            }
+            UiAction::OpenPlayRecordingDialog => {
+                self.open_play_recording_dialog();
+            }
+            UiAction::TogglePlaybackPause => {
+                if let Some(player) = &mut self.playback {
+                    player.toggle_pause();
+                }
+                self.update_playback_status();
+            }
+            UiAction::StopPlayback => {
+                self.playback = None;
+                self.state.close_exec_view();
+            }
+            UiAction::SubmitForm => {
+                self.submit_form().await;
+            }
+            UiAction::CancelTransfer => {
+                self.cancel_transfer();
+            }
+            UiAction::CancelBuild => {
+                self.cancel_build();
+            }
+            UiAction::OpenRegistryBrowser => {
+                self.open_registry_browser();
+            }
+            UiAction::BrowseRegistry(index) => {
+                self.browse_registry(index);
+            }
+            UiAction::BrowseRepository(index, repository) => {
+                self.browse_repository(index, repository);
+            }
+            UiAction::InspectRegistryTag(index, repository, tag) => {
+                self.inspect_registry_tag(index, repository, tag);
+            }
+            UiAction::PullRegistryTag(index, repository, tag) => {
+                if let Some(client) = self.registry_clients.get(index) {
+                    let reference = client.reference(&repository, &tag);
+                    self.start_pull(&reference);
+                }
+            }
+            UiAction::CheckImageUpdates => {
+                self.state.remote_digests.expire();
+                self.state
+                    .add_notification("Checking images for updates", NotificationLevel::Info);
+                if self.freshness_rx.is_none() {
+                    self.start_freshness_checks();
+                }
+            }
+            UiAction::PullOutdated(tags) => {
+                self.start_outdated_pull(tags);
+            }
+            UiAction::OpenCleanupPlanner => {
+                self.open_cleanup_planner().await;
+            }
+            UiAction::RunCleanup(index) => {
+                self.run_cleanup(index).await;
+            }
+            UiAction::CompareImages(left, right) => {
+                self.compare_images(left, right).await;
+            }
+            UiAction::ExploreLayers(id) => {
+                self.start_layer_analysis(&id);
+            }
+            UiAction::CancelLayerAnalysis => {
+                self.cancel_layer_analysis();
+            }
             UiAction::RemoveImage(id) => {
                 self.remove_image(&id).await;
             }
@@ -605,12 +932,35 @@ impl App {
                     .add_notification("Loading image details...", NotificationLevel::Info);
                 self.fetch_image_details(id).await;
             }
+            UiAction::ShowVolumeDetails(name) => {
+                self.state.open_volume_detail_view(name.clone());
+                self.fetch_volume_details(name).await;
+            }
+            UiAction::ShowNetworkDetails(id, name) => {
+                self.state.open_network_detail_view(id.clone(), name);
+                self.fetch_network_details(id).await;
+            }
             UiAction::RemoveVolume(name) => {
                 self.remove_volume(&name).await;
             }
+            UiAction::RemoveVolumes(names) => {
+                self.remove_volumes(names).await;
+            }
             UiAction::PruneVolumes => {
                 self.prune_volumes().await;
             }
+            UiAction::BrowseVolume(name) => {
+                self.browse_volume(name);
+            }
+            UiAction::PreviewVolumeFile(path) => {
+                self.preview_volume_file(&path).await;
+            }
+            UiAction::CloseVolumeBrowser => {
+                self.close_volume_browser();
+            }
+            UiAction::RestoreVolume(path, volume) => {
+                self.start_volume_restore(path, volume, true);
+            }
             UiAction::RemoveNetwork(id) => {
                 self.remove_network(&id).await;
             }
@@ -654,110 +1004,989 @@ impl App {
         }
     }
 
-    /// Stop a container
-    async fn stop_container(&mut self, id: &str) {
-        if let Some(client) = &self.docker_client {
-            info!("Stopping container {}", id);
-            match client.stop_container(id, Some(10)).await {
-                Ok(_) => {
-                    info!("Container {} stopped", id);
-                    self.state
-                        .add_notification("Container stopped", NotificationLevel::Success);
-                    self.request_data_refresh();
-                }
-                Err(e) => {
-                    error!("Failed to stop container {}: {}", id, e);
-                    self.state.add_notification(
-                        format!("Failed to stop: {}", e),
-                        NotificationLevel::Error,
-                    );
-                }
+    /// Open the recreate form, pre-filled with the image the container was
+    /// created from
+    async fn open_recreate_form(&mut self, id: String) {
+        let Some(client) = &self.docker_client else {
+            self.state
+                .add_notification("Docker not connected", NotificationLevel::Error);
+            return;
+        };
+        let image = match client.recreate_image(&id).await {
+            Ok(image) => image,
+            Err(e) => {
+                self.state
+                    .add_notification(format!("Cannot recreate: {}", e), NotificationLevel::Error);
+                return;
             }
+        };
+        let name = self
+            .state
+            .containers
+            .iter()
+            .find(|c| c.id == id)
+            .and_then(|c| c.names.first())
+            .cloned()
+            .unwrap_or_else(|| id.chars().take(12).collect::<String>());
+        self.state.open_form_dialog(
+            FormDialogState::new(
+                FormKind::RecreateContainer(id),
+                format!("Recreate {}", name),
+            )
+            .with_text("image", "Image", image, "")
+            .with_toggle("pull", "Pull image first", true),
+        );
+    }
+
+    /// Recreate a container in the background
+    fn recreate_container(&mut self, id: &str, image: &str, pull: bool) {
+        if self.recreate_rx.is_some() {
+            self.state.add_notification(
+                "A recreate is already in progress",
+                NotificationLevel::Warning,
+            );
+            return;
+        }
+        let Some(client) = self.docker_client.clone() else {
+            self.state
+                .add_notification("Docker not connected", NotificationLevel::Error);
+            return;
+        };
+
+        let name = self
+            .state
+            .containers
+            .iter()
+            .find(|c| c.id == id)
+            .and_then(|c| c.names.first())
+            .cloned()
+            .unwrap_or_else(|| id.chars().take(12).collect::<String>());
+        self.state.add_notification(
+            if pull {
+                format!("Pulling {} and recreating {}...", image, name)
+            } else {
+                format!("Recreating {}...", name)
+            },
+            NotificationLevel::Info,
+        );
+
+        let credentials = if pull {
+            registry::credentials_for(image, &self.config.registries)
         } else {
+            None
+        };
+        let (tx, rx) = mpsc::channel(1);
+        self.recreate_rx = Some(rx);
+        let id = id.to_string();
+        let image = image.to_string();
+        tokio::spawn(async move {
+            let result = client
+                .recreate_container(&id, Some(image.as_str()), pull, credentials)
+                .await;
+            let _ = tx.send((name, result)).await;
+        });
+    }
+
+    /// Start pulling an image in the background, using credentials from the
+    /// configured registries when one matches
+    fn start_pull(&mut self, image: &str) {
+        let Some((client, credentials)) = self.prepare_transfer(image) else {
+            return;
+        };
+        self.state.open_transfer_view(image.to_string(), false);
+        let (tx, rx) = mpsc::channel(64);
+        self.transfer_rx = Some(rx);
+        let stream = client.pull_image_progress(image, credentials);
+        tokio::spawn(forward_transfer(stream, tx, TransferEvent::Pull));
+    }
+
+    /// Start pushing a tag in the background, authenticating with the
+    /// configured registry when one matches
+    fn start_push(&mut self, reference: &str) {
+        let Some((client, credentials)) = self.prepare_transfer(reference) else {
+            return;
+        };
+        self.state.open_transfer_view(reference.to_string(), true);
+        let (tx, rx) = mpsc::channel(64);
+        self.transfer_rx = Some(rx);
+        let stream = client.push_image_progress(reference, credentials);
+        tokio::spawn(forward_transfer(stream, tx, TransferEvent::Push));
+    }
+
+    /// Check a pull or push can start and look up registry credentials
+    fn prepare_transfer(
+        &mut self,
+        image: &str,
+    ) -> Option<(DockerClient, Option<DockerCredentials>)> {
+        if image.is_empty() {
+            return None;
+        }
+        if self.transfer_rx.is_some() {
+            self.state.add_notification(
+                "A pull or push is already in progress",
+                NotificationLevel::Warning,
+            );
+            return None;
+        }
+        let Some(client) = self.docker_client.clone() else {
             self.state
                 .add_notification("Docker not connected", NotificationLevel::Error);
+            return None;
+        };
+        let credentials = registry::credentials_for(image, &self.config.registries);
+        if credentials.is_some() {
+            debug!("Using configured credentials for {}", image);
         }
+        Some((client, credentials))
     }
 
-    /// Restart a container
-    async fn restart_container(&mut self, id: &str) {
-        if let Some(client) = &self.docker_client {
-            info!("Restarting container {}", id);
-            match client.restart_container(id, Some(10)).await {
-                Ok(_) => {
-                    info!("Container {} restarted", id);
-                    self.state
-                        .add_notification("Container restarted", NotificationLevel::Success);
-                    self.request_data_refresh();
-                }
-                Err(e) => {
-                    error!("Failed to restart container {}: {}", id, e);
-                    self.state.add_notification(
-                        format!("Failed to restart: {}", e),
-                        NotificationLevel::Error,
-                    );
-                }
+    /// Stop a running pull or push and close its dialog
+    fn cancel_transfer(&mut self) {
+        if self.transfer_rx.take().is_some() {
+            if let Some(view) = &self.state.transfer_view {
+                info!(
+                    "Cancelled {} of {}",
+                    if view.push { "push" } else { "pull" },
+                    view.image
+                );
             }
-        } else {
             self.state
-                .add_notification("Docker not connected", NotificationLevel::Error);
+                .add_notification("Transfer cancelled", NotificationLevel::Warning);
         }
+        self.state.close_transfer_view();
     }
 
-    /// Pause a container
-    async fn pause_container(&mut self, id: &str) {
-        if let Some(client) = &self.docker_client {
-            info!("Pausing container {}", id);
-            match client.pause_container(id).await {
-                Ok(_) => {
-                    info!("Container {} paused", id);
-                    self.state
-                        .add_notification("Container paused", NotificationLevel::Success);
+    /// Apply pull or push progress received since the last tick
+    fn check_transfer(&mut self) {
+        let Some(rx) = &mut self.transfer_rx else {
+            return;
+        };
+        loop {
+            match rx.try_recv() {
+                Ok(TransferEvent::Pull(info)) => {
+                    if let Some(view) = &mut self.state.transfer_view {
+                        view.progress.apply(&info);
+                    }
+                }
+                Ok(TransferEvent::Push(info)) => {
+                    if let Some(view) = &mut self.state.transfer_view {
+                        view.progress.apply_push(&info);
+                    }
+                }
+                Ok(TransferEvent::Finished(result)) => {
+                    self.transfer_rx = None;
+                    let push = self.state.transfer_view.as_ref().is_some_and(|v| v.push);
+                    match result {
+                        Ok(()) => {
+                            if let Some(view) = &mut self.state.transfer_view {
+                                if push {
+                                    view.progress.complete_push();
+                                }
+                            }
+                            self.state.finish_transfer(None);
+                            self.state.add_notification(
+                                if push { "Image pushed" } else { "Image pulled" },
+                                NotificationLevel::Success,
+                            );
+                        }
+                        Err(e) => {
+                            error!("Transfer failed: {}", e);
+                            self.state.finish_transfer(Some(e.to_string()));
+                        }
+                    }
                     self.request_data_refresh();
+                    return;
                 }
-                Err(e) => {
-                    error!("Failed to pause container {}: {}", id, e);
-                    self.state.add_notification(
-                        format!("Failed to pause: {}", e),
-                        NotificationLevel::Error,
-                    );
+                Err(mpsc::error::TryRecvError::Empty) => return,
+                Err(mpsc::error::TryRecvError::Disconnected) => {
+                    self.transfer_rx = None;
+                    return;
                 }
             }
-        } else {
-            self.state
-                .add_notification("Docker not connected", NotificationLevel::Error);
         }
     }
 
-    /// Unpause a container
-    async fn unpause_container(&mut self, id: &str) {
-        if let Some(client) = &self.docker_client {
-            info!("Unpausing container {}", id);
-            match client.unpause_container(id).await {
-                Ok(_) => {
-                    info!("Container {} unpaused", id);
-                    self.state
-                        .add_notification("Container unpaused", NotificationLevel::Success);
-                    self.request_data_refresh();
-                }
-                Err(e) => {
-                    error!("Failed to unpause container {}: {}", id, e);
-                    self.state.add_notification(
-                        format!("Failed to unpause: {}", e),
-                        NotificationLevel::Error,
-                    );
-                }
+    /// Add a tag to an image
+    async fn tag_image(&mut self, id: &str, reference: &str) {
+        let Some(client) = &self.docker_client else {
+            return;
+        };
+        match client.tag_image(id, reference).await {
+            Ok(()) => {
+                self.state
+                    .add_notification(format!("Tagged {}", reference), NotificationLevel::Success);
+                self.request_data_refresh();
+            }
+            Err(e) => {
+                error!("Failed to tag {} as {}: {}", id, reference, e);
+                self.state
+                    .add_notification(e.to_string(), NotificationLevel::Error);
             }
-        } else {
-            self.state
-                .add_notification("Docker not connected", NotificationLevel::Error);
         }
     }
 
-    /// Kill a container
-    async fn kill_container(&mut self, id: &str) {
-        if let Some(client) = &self.docker_client {
-            info!("Killing container {}", id);
+    /// Remove one tag from an image, refusing to remove its last tag (that
+    /// would delete the image)
+    async fn untag_image(&mut self, id: &str, reference: &str) {
+        let Some(image) = self.state.images.iter().find(|i| i.id == id) else {
+            return;
+        };
+        if !image.repo_tags.iter().any(|t| t == reference) {
+            self.state.add_notification(
+                format!("{} is not a tag of this image", reference),
+                NotificationLevel::Warning,
+            );
+            return;
+        }
+        if image.repo_tags.len() < 2 {
+            self.state.add_notification(
+                "This is the image's only tag; use delete to remove the image",
+                NotificationLevel::Warning,
+            );
+            return;
+        }
+        let Some(client) = &self.docker_client else {
+            return;
+        };
+        match client.untag_image(reference).await {
+            Ok(()) => {
+                self.state.add_notification(
+                    format!("Removed tag {}", reference),
+                    NotificationLevel::Success,
+                );
+                self.request_data_refresh();
+            }
+            Err(e) => {
+                error!("Failed to untag {}: {}", reference, e);
+                self.state
+                    .add_notification(e.to_string(), NotificationLevel::Error);
+            }
+        }
+    }
+
+    /// Package the context and build an image in the background
+    fn start_build(&mut self, options: BuildOptions) {
+        if self.build_rx.is_some() {
+            self.state
+                .add_notification("A build is already in progress", NotificationLevel::Warning);
+            return;
+        }
+        if !options.context_dir.is_dir() {
+            self.state.add_notification(
+                format!(
+                    "Build context {} is not a directory",
+                    options.context_dir.display()
+                ),
+                NotificationLevel::Error,
+            );
+            return;
+        }
+        let Some(client) = self.docker_client.clone() else {
+            self.state
+                .add_notification("Docker not connected", NotificationLevel::Error);
+            return;
+        };
+
+        let title = options
+            .tags
+            .first()
+            .cloned()
+            .unwrap_or_else(|| options.context_dir.display().to_string());
+        self.state.open_build_view(title);
+
+        let (tx, rx) = mpsc::channel(64);
+        self.build_rx = Some(rx);
+        tokio::spawn(async move {
+            let result = run_build(&client, &options, &tx).await;
+            let _ = tx.send(BuildEvent::Finished(result)).await;
+        });
+    }
+
+    /// Stop a running build and close its pane
+    fn cancel_build(&mut self) {
+        if self.build_rx.take().is_some() {
+            info!("Cancelled image build");
+            self.state
+                .add_notification("Build cancelled", NotificationLevel::Warning);
+        }
+        self.state.close_build_view();
+    }
+
+    /// Apply build output received since the last tick
+    fn check_build(&mut self) {
+        let Some(rx) = &mut self.build_rx else {
+            return;
+        };
+        loop {
+            match rx.try_recv() {
+                Ok(BuildEvent::Output(lines)) => self.state.push_build_lines(lines),
+                Ok(BuildEvent::Finished(result)) => {
+                    self.build_rx = None;
+                    match result {
+                        Ok(image_id) => {
+                            info!("Built image {}", image_id);
+                            self.state.finish_build(None);
+                            self.state.pending_image_select = Some(image_id);
+                            self.state
+                                .add_notification("Image built", NotificationLevel::Success);
+                        }
+                        Err(e) => {
+                            error!("Build failed: {}", e);
+                            self.state.finish_build(Some(e.to_string()));
+                        }
+                    }
+                    self.request_data_refresh();
+                    return;
+                }
+                Err(mpsc::error::TryRecvError::Empty) => return,
+                Err(mpsc::error::TryRecvError::Disconnected) => {
+                    self.build_rx = None;
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Save images to a tar archive in the background
+    fn start_save(&mut self, names: Vec<String>, path: &str, gzip: bool) {
+        let Some(client) = self.prepare_archive(path) else {
+            return;
+        };
+        // The image sizes approximate the archive size (shared layers are
+        // only written once, so this overestimates)
+        let estimate: i64 = self
+            .state
+            .images
+            .iter()
+            .filter(|i| names.contains(&i.id) || i.repo_tags.iter().any(|t| names.contains(t)))
+            .map(|i| i.size)
+            .sum();
+        let gzip = gzip || archive::is_gzip_path(path);
+        let job = self
+            .state
+            .start_job(format!("Saving {}", path), Some(estimate.max(0) as u64));
+        let (tx, rx) = mpsc::channel(16);
+        self.archive_rx = Some((job, rx));
+
+        let path = path.to_string();
+        tokio::spawn(async move {
+            let progress = tx.clone();
+            let result = client
+                .save_images(&names, std::path::Path::new(&path), gzip, |written| {
+                    let _ = progress.try_send(ArchiveEvent::Progress(written));
+                })
+                .await;
+            let _ = tx.send(ArchiveEvent::Saved(path, result)).await;
+        });
+    }
+
+    /// Load images from a tar archive in the background
+    fn start_load(&mut self, path: &str) {
+        let Some(client) = self.prepare_archive(path) else {
+            return;
+        };
+        let size = match std::fs::metadata(path) {
+            Ok(metadata) => metadata.len(),
+            Err(e) => {
+                self.state.add_notification(
+                    format!("Cannot read {}: {}", path, e),
+                    NotificationLevel::Error,
+                );
+                return;
+            }
+        };
+        let job = self
+            .state
+            .start_job(format!("Loading {}", path), Some(size));
+        let (tx, rx) = mpsc::channel(16);
+        self.archive_rx = Some((job, rx));
+
+        let path = path.to_string();
+        tokio::spawn(async move {
+            let progress = tx.clone();
+            let mut read = 0u64;
+            let result = client
+                .load_images(std::path::Path::new(&path), move |chunk| {
+                    read += chunk;
+                    let _ = progress.try_send(ArchiveEvent::Progress(read));
+                })
+                .await;
+            let _ = tx.send(ArchiveEvent::Loaded(path, result)).await;
+        });
+    }
+
+    /// Check a save or load can start
+    fn prepare_archive(&mut self, path: &str) -> Option<DockerClient> {
+        if path.is_empty() {
+            return None;
+        }
+        if self.archive_rx.is_some() {
+            self.state.add_notification(
+                "An image save or load is already in progress",
+                NotificationLevel::Warning,
+            );
+            return None;
+        }
+        let client = self.docker_client.clone();
+        if client.is_none() {
+            self.state
+                .add_notification("Docker not connected", NotificationLevel::Error);
+        }
+        client
+    }
+
+    /// Apply save/load progress received since the last tick
+    fn check_archive(&mut self) {
+        let Some((job, rx)) = &mut self.archive_rx else {
+            return;
+        };
+        let job = *job;
+        loop {
+            match rx.try_recv() {
+                Ok(ArchiveEvent::Progress(bytes)) => self.state.update_job(job, bytes),
+                Ok(ArchiveEvent::Saved(path, result)) => {
+                    self.archive_rx = None;
+                    self.state.finish_job(job);
+                    match result {
+                        Ok(written) => {
+                            self.state.marked_images.clear();
+                            self.state.add_notification(
+                                format!(
+                                    "Saved images to {} ({})",
+                                    path,
+                                    format_bytes_size(written as i64)
+                                ),
+                                NotificationLevel::Success,
+                            );
+                        }
+                        Err(e) => {
+                            error!("Failed to save images to {}: {}", path, e);
+                            self.state
+                                .add_notification(e.to_string(), NotificationLevel::Error);
+                        }
+                    }
+                    return;
+                }
+                Ok(ArchiveEvent::Loaded(path, result)) => {
+                    self.archive_rx = None;
+                    self.state.finish_job(job);
+                    match result {
+                        Ok(references) if references.is_empty() => {
+                            self.state.add_notification(
+                                format!("No images found in {}", path),
+                                NotificationLevel::Warning,
+                            );
+                        }
+                        Ok(references) => {
+                            self.state.pending_image_select = references.first().cloned();
+                            self.state.add_notification(
+                                format!("Loaded {}", references.join(", ")),
+                                NotificationLevel::Success,
+                            );
+                        }
+                        Err(e) => {
+                            error!("Failed to load images from {}: {}", path, e);
+                            self.state
+                                .add_notification(e.to_string(), NotificationLevel::Error);
+                        }
+                    }
+                    self.request_data_refresh();
+                    return;
+                }
+                Err(mpsc::error::TryRecvError::Empty) => return,
+                Err(mpsc::error::TryRecvError::Disconnected) => {
+                    self.archive_rx = None;
+                    self.state.finish_job(job);
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Open the registry browser on the configured registries, plus Docker
+    /// Hub when it isn't configured
+    fn open_registry_browser(&mut self) {
+        let mut names = Vec::new();
+        self.registry_clients.clear();
+        for registry in &self.config.registries {
+            names.push(registry.name.clone());
+            self.registry_clients.push(RegistryClient::new(registry));
+        }
+        if !self.registry_clients.iter().any(|c| c.is_docker_hub()) {
+            names.push("Docker Hub".to_string());
+            self.registry_clients
+                .push(RegistryClient::new(&crate::config::Registry {
+                    name: "Docker Hub".to_string(),
+                    url: registry::DOCKER_HUB.to_string(),
+                    auth: None,
+                    is_default: false,
+                }));
+        }
+        self.registry_rx = None;
+        self.state.open_registry_browser(names);
+    }
+
+    /// Run a registry request in the background, replacing any running one
+    fn spawn_registry_request<F>(&mut self, request: F)
+    where
+        F: std::future::Future<Output = RegistryEvent> + Send + 'static,
+    {
+        let (tx, rx) = mpsc::channel(1);
+        self.registry_rx = Some(rx);
+        tokio::spawn(async move {
+            let _ = tx.send(request.await).await;
+        });
+    }
+
+    /// List the repositories of a registry
+    fn browse_registry(&mut self, index: usize) {
+        let Some(client) = self.registry_clients.get(index).cloned() else {
+            return;
+        };
+        self.state
+            .registry_browser_enter(RegistryLevel::Repositories, None);
+        if client.is_docker_hub() {
+            self.registry_rx = None;
+            self.state.set_registry_error(
+                "Docker Hub has no catalog; press / to open a repository".to_string(),
+            );
+            return;
+        }
+        self.spawn_registry_request(
+            async move { RegistryEvent::Repositories(client.catalog().await) },
+        );
+    }
+
+    /// List the tags of a repository
+    fn browse_repository(&mut self, index: usize, repository: String) {
+        let Some(client) = self.registry_clients.get(index).cloned() else {
+            return;
+        };
+        if repository.is_empty() {
+            return;
+        }
+        self.state
+            .registry_browser_enter(RegistryLevel::Tags, Some(repository.clone()));
+        self.spawn_registry_request(
+            async move { RegistryEvent::Tags(client.tags(&repository).await) },
+        );
+    }
+
+    /// Resolve a tag's digest and platforms
+    fn inspect_registry_tag(&mut self, index: usize, repository: String, tag: String) {
+        let Some(client) = self.registry_clients.get(index).cloned() else {
+            return;
+        };
+        self.state.set_registry_loading();
+        self.spawn_registry_request(async move {
+            let manifest = client.manifest(&repository, &tag).await;
+            RegistryEvent::Manifest(tag, manifest)
+        });
+    }
+
+    /// Apply a registry response received since the last tick
+    fn check_registry(&mut self) {
+        let Some(rx) = &mut self.registry_rx else {
+            return;
+        };
+        let event = match rx.try_recv() {
+            Ok(event) => event,
+            Err(mpsc::error::TryRecvError::Empty) => return,
+            Err(mpsc::error::TryRecvError::Disconnected) => {
+                self.registry_rx = None;
+                return;
+            }
+        };
+        self.registry_rx = None;
+        let result = match event {
+            RegistryEvent::Repositories(result) => {
+                result.map(|repositories| self.state.set_registry_repositories(repositories))
+            }
+            RegistryEvent::Tags(result) => result.map(|tags| self.state.set_registry_tags(tags)),
+            RegistryEvent::Manifest(tag, result) => {
+                result.map(|manifest| self.state.set_registry_manifest(tag, manifest))
+            }
+        };
+        if let Err(e) = result {
+            warn!("Registry request failed: {}", e);
+            self.state.set_registry_error(e.to_string());
+        }
+    }
+
+    /// Look up the registry digest of every tag that is due for a check
+    fn start_freshness_checks(&mut self) {
+        if self.docker_client.is_none() {
+            return;
+        }
+        let tags = self.state.digest_checks_due(std::time::Instant::now());
+        if tags.is_empty() {
+            return;
+        }
+        debug!("Checking {} tag(s) against their registries", tags.len());
+        let registries = self.config.registries.clone();
+        let (tx, rx) = mpsc::channel(16);
+        self.freshness_rx = Some(rx);
+        tokio::spawn(async move {
+            // One client per registry so tokens are reused across tags
+            let mut clients: Vec<(String, RegistryClient)> = Vec::new();
+            for tag in tags {
+                let Some(reference) = ImageRef::parse(&tag) else {
+                    continue;
+                };
+                let client = match clients.iter().find(|(host, _)| *host == reference.host) {
+                    Some((_, client)) => client.clone(),
+                    None => {
+                        let client = RegistryClient::for_image(&tag, &registries);
+                        clients.push((reference.host.clone(), client.clone()));
+                        client
+                    }
+                };
+                let result = client
+                    .tag_digest(&reference.repository, &reference.tag)
+                    .await
+                    .map_err(|e| e.to_string());
+                if let Err(e) = &result {
+                    debug!("No registry digest for {}: {}", tag, e);
+                }
+                if tx.send((tag, result)).await.is_err() {
+                    return;
+                }
+            }
+        });
+    }
+
+    /// Record registry digests received since the last tick
+    fn check_freshness(&mut self) {
+        let Some(rx) = &mut self.freshness_rx else {
+            return;
+        };
+        loop {
+            match rx.try_recv() {
+                Ok((tag, result)) => {
+                    self.state
+                        .set_remote_digest(&tag, result, std::time::Instant::now());
+                }
+                Err(mpsc::error::TryRecvError::Empty) => return,
+                Err(mpsc::error::TryRecvError::Disconnected) => {
+                    self.freshness_rx = None;
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Pull outdated tags one after another in the background
+    fn start_outdated_pull(&mut self, tags: Vec<String>) {
+        if tags.is_empty() {
+            return;
+        }
+        if self.outdated_pull_rx.is_some() {
+            self.state.add_notification(
+                "Outdated images are already being pulled",
+                NotificationLevel::Warning,
+            );
+            return;
+        }
+        let Some(client) = self.docker_client.clone() else {
+            self.state
+                .add_notification("Docker not connected", NotificationLevel::Error);
+            return;
+        };
+        let job = self.state.start_job(format!("Pulling {}", tags[0]), None);
+        let (tx, rx) = mpsc::channel(16);
+        self.outdated_pull_rx = Some((job, rx));
+        tokio::spawn(pull_outdated(
+            client,
+            tags,
+            self.config.registries.clone(),
+            tx,
+        ));
+    }
+
+    /// Apply "pull all outdated" progress received since the last tick
+    fn check_outdated_pull(&mut self) {
+        let Some((job, rx)) = &mut self.outdated_pull_rx else {
+            return;
+        };
+        let job = *job;
+        loop {
+            match rx.try_recv() {
+                Ok(OutdatedPullEvent::Started(tag, index, count)) => {
+                    self.state.relabel_job(
+                        job,
+                        format!("Pulling {} ({}/{})", tag, index + 1, count),
+                        None,
+                    );
+                }
+                Ok(OutdatedPullEvent::Progress(done, total)) => {
+                    if let Some(entry) = self.state.jobs.iter_mut().find(|j| j.id == job) {
+                        entry.total_bytes = Some(total);
+                    }
+                    self.state.update_job(job, done);
+                }
+                Ok(OutdatedPullEvent::Finished(failed)) => {
+                    self.outdated_pull_rx = None;
+                    self.state.finish_job(job);
+                    if failed.is_empty() {
+                        self.state
+                            .add_notification("Outdated images pulled", NotificationLevel::Success);
+                    } else {
+                        self.state.add_notification(
+                            format!("Failed to pull {}", failed.join(", ")),
+                            NotificationLevel::Error,
+                        );
+                    }
+                    self.request_data_refresh();
+                    return;
+                }
+                Err(mpsc::error::TryRecvError::Empty) => return,
+                Err(mpsc::error::TryRecvError::Disconnected) => {
+                    self.outdated_pull_rx = None;
+                    self.state.finish_job(job);
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Export an image and analyze its layers in the background
+    fn start_layer_analysis(&mut self, id: &str) {
+        if self.layers_rx.is_some() {
+            self.state.add_notification(
+                "A layer analysis is already in progress",
+                NotificationLevel::Warning,
+            );
+            return;
+        }
+        let Some(client) = self.docker_client.clone() else {
+            self.state
+                .add_notification("Docker not connected", NotificationLevel::Error);
+            return;
+        };
+        let name = self
+            .state
+            .image_detail_view
+            .as_ref()
+            .map(|v| v.image_name.clone())
+            .unwrap_or_else(|| id.chars().take(12).collect());
+        let size = self
+            .state
+            .images
+            .iter()
+            .find(|i| i.id == id)
+            .map(|i| i.size.max(0) as u64);
+        self.state.open_layer_explorer(name.clone());
+        let job = self.state.start_job(format!("Exporting {}", name), size);
+        let (tx, rx) = mpsc::channel(16);
+        self.layers_rx = Some((job, rx));
+
+        let id = id.to_string();
+        tokio::spawn(async move {
+            let path =
+                std::env::temp_dir().join(format!("contui-layers-{}.tar", uuid::Uuid::new_v4()));
+            let progress = tx.clone();
+            let analysis = async {
+                client
+                    .save_images(&[id], &path, false, |written| {
+                        let _ = progress.try_send(LayerEvent::Progress(written));
+                    })
+                    .await?;
+                let archive = path.clone();
+                tokio::task::spawn_blocking(move || layers::analyze_archive(&archive))
+                    .await
+                    .map_err(|e| DockerError::Image(format!("Layer analysis failed: {}", e)))?
+            };
+            // Stop exporting as soon as the explorer is closed
+            let result = tokio::select! {
+                result = analysis => Some(result),
+                _ = tx.closed() => None,
+            };
+            let _ = tokio::fs::remove_file(&path).await;
+            if let Some(result) = result {
+                let _ = tx.send(LayerEvent::Finished(result)).await;
+            }
+        });
+    }
+
+    /// Stop a running layer analysis and close the explorer
+    fn cancel_layer_analysis(&mut self) {
+        if let Some((job, _)) = self.layers_rx.take() {
+            info!("Cancelled layer analysis");
+            self.state.finish_job(job);
+        }
+        self.state.close_layer_explorer();
+    }
+
+    /// Apply layer analysis progress received since the last tick
+    fn check_layers(&mut self) {
+        let Some((job, rx)) = &mut self.layers_rx else {
+            return;
+        };
+        let job = *job;
+        loop {
+            match rx.try_recv() {
+                Ok(LayerEvent::Progress(bytes)) => self.state.update_job(job, bytes),
+                Ok(LayerEvent::Finished(result)) => {
+                    self.layers_rx = None;
+                    self.state.finish_job(job);
+                    match result {
+                        Ok(analysis) => self.state.set_layer_analysis(analysis),
+                        Err(e) => {
+                            error!("Layer analysis failed: {}", e);
+                            self.state
+                                .add_notification(e.to_string(), NotificationLevel::Error);
+                            self.state.close_layer_explorer();
+                        }
+                    }
+                    return;
+                }
+                Err(mpsc::error::TryRecvError::Empty) => return,
+                Err(mpsc::error::TryRecvError::Disconnected) => {
+                    self.layers_rx = None;
+                    self.state.finish_job(job);
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Check for a finished recreate
+    fn check_recreate(&mut self) {
+        let Some(rx) = &mut self.recreate_rx else {
+            return;
+        };
+        match rx.try_recv() {
+            Ok((name, result)) => {
+                self.recreate_rx = None;
+                match result {
+                    Ok(new_id) => {
+                        info!("Recreated {} as {}", name, new_id);
+                        self.state.add_notification(
+                            format!("Container {} recreated", name),
+                            NotificationLevel::Success,
+                        );
+                    }
+                    Err(e) => {
+                        error!("Failed to recreate {}: {}", name, e);
+                        self.state.add_notification(
+                            format!("Recreate failed: {}", e),
+                            NotificationLevel::Error,
+                        );
+                    }
+                }
+                self.request_data_refresh();
+            }
+            Err(mpsc::error::TryRecvError::Empty) => {}
+            Err(mpsc::error::TryRecvError::Disconnected) => {
+                self.recreate_rx = None;
+            }
+        }
+    }
+
+    /// Stop a container
+    async fn stop_container(&mut self, id: &str) {
+        if let Some(client) = &self.docker_client {
+            info!("Stopping container {}", id);
+            match client.stop_container(id, Some(10)).await {
+                Ok(_) => {
+                    info!("Container {} stopped", id);
+                    self.state
+                        .add_notification("Container stopped", NotificationLevel::Success);
+                    self.request_data_refresh();
+                }
+                Err(e) => {
+                    error!("Failed to stop container {}: {}", id, e);
+                    self.state.add_notification(
+                        format!("Failed to stop: {}", e),
+                        NotificationLevel::Error,
+                    );
+                }
+            }
+        } else {
+            self.state
+                .add_notification("Docker not connected", NotificationLevel::Error);
+        }
+    }
+
+    /// Restart a container
+    async fn restart_container(&mut self, id: &str) {
+        if let Some(client) = &self.docker_client {
+            info!("Restarting container {}", id);
+            match client.restart_container(id, Some(10)).await {
+                Ok(_) => {
+                    info!("Container {} restarted", id);
+                    self.state
+                        .add_notification("Container restarted", NotificationLevel::Success);
+                    self.request_data_refresh();
+                }
+                Err(e) => {
+                    error!("Failed to restart container {}: {}", id, e);
+                    self.state.add_notification(
+                        format!("Failed to restart: {}", e),
+                        NotificationLevel::Error,
+                    );
+                }
+            }
+        } else {
+            self.state
+                .add_notification("Docker not connected", NotificationLevel::Error);
+        }
+    }
+
+    /// Pause a container
+    async fn pause_container(&mut self, id: &str) {
+        if let Some(client) = &self.docker_client {
+            info!("Pausing container {}", id);
+            match client.pause_container(id).await {
+                Ok(_) => {
+                    info!("Container {} paused", id);
+                    self.state
+                        .add_notification("Container paused", NotificationLevel::Success);
+                    self.request_data_refresh();
+                }
+                Err(e) => {
+                    error!("Failed to pause container {}: {}", id, e);
+                    self.state.add_notification(
+                        format!("Failed to pause: {}", e),
+                        NotificationLevel::Error,
+                    );
+                }
+            }
+        } else {
+            self.state
+                .add_notification("Docker not connected", NotificationLevel::Error);
+        }
+    }
+
+    /// Unpause a container
+    async fn unpause_container(&mut self, id: &str) {
+        if let Some(client) = &self.docker_client {
+            info!("Unpausing container {}", id);
+            match client.unpause_container(id).await {
+                Ok(_) => {
+                    info!("Container {} unpaused", id);
+                    self.state
+                        .add_notification("Container unpaused", NotificationLevel::Success);
+                    self.request_data_refresh();
+                }
+                Err(e) => {
+                    error!("Failed to unpause container {}: {}", id, e);
+                    self.state.add_notification(
+                        format!("Failed to unpause: {}", e),
+                        NotificationLevel::Error,
+                    );
+                }
+            }
+        } else {
+            self.state
+                .add_notification("Docker not connected", NotificationLevel::Error);
+        }
+    }
+
+    /// Kill a container
+    async fn kill_container(&mut self, id: &str) {
+        if let Some(client) = &self.docker_client {
+            info!("Killing container {}", id);
             match client.kill_container(id, None).await {
                 Ok(_) => {
                     info!("Container {} killed", id);
@@ -807,8 +2036,12 @@ impl App {
     /// Refresh all data from Docker (blocking, used for initial load)
     async fn refresh_data_once(&mut self) {
         let had_client = self.docker_client.is_some();
-        let result =
-            DataRefreshResult::gather(self.docker_client.clone(), self.config.clone()).await;
+        let result = DataRefreshResult::gather(
+            self.docker_client.clone(),
+            self.config.clone(),
+            self.state.exit_check_baseline(),
+        )
+        .await;
         self.apply_refresh_result(result, had_client);
         self.last_data_refresh = std::time::Instant::now();
     }
@@ -826,6 +2059,7 @@ impl App {
 
         let client = self.docker_client.clone();
         let config = self.config.clone();
+        let baseline = self.state.exit_check_baseline();
 
         std::thread::spawn(move || {
             let rt = tokio::runtime::Builder::new_current_thread()
@@ -834,7 +2068,7 @@ impl App {
                 .expect("Failed to create runtime");
 
             rt.block_on(async move {
-                let result = DataRefreshResult::gather(client, config).await;
+                let result = DataRefreshResult::gather(client, config, baseline).await;
                 let _ = tx.send(result).await;
             });
         });
@@ -943,6 +2177,445 @@ impl App {
         }
     }
 
+    /// Create a volume and select it once the list refreshes
+    async fn create_volume(&mut self, options: VolumeOptions) {
+        let Some(client) = &self.docker_client else {
+            return;
+        };
+        match client.create_volume(&options).await {
+            Ok(volume) => {
+                self.state.add_notification(
+                    format!("Created volume {}", volume.name),
+                    NotificationLevel::Success,
+                );
+                self.state.pending_volume_select = Some(volume.name);
+                self.request_data_refresh();
+            }
+            Err(e) => {
+                error!("Failed to create volume {}: {}", options.name, e);
+                self.state
+                    .add_notification(e.to_string(), NotificationLevel::Error);
+            }
+        }
+    }
+
+    async fn create_network(&mut self, options: NetworkOptions) {
+        let Some(client) = &self.docker_client else {
+            return;
+        };
+        match client.create_network(&options).await {
+            Ok(_) => {
+                self.state.add_notification(
+                    format!("Created network {}", options.name),
+                    NotificationLevel::Success,
+                );
+                self.request_data_refresh();
+            }
+            Err(e) => {
+                error!("Failed to create network {}: {}", options.name, e);
+                self.state
+                    .add_notification(e.to_string(), NotificationLevel::Error);
+            }
+        }
+    }
+
+    async fn connect_network(
+        &mut self,
+        network: &str,
+        container: &str,
+        aliases: &[String],
+        ip: &str,
+    ) {
+        let Some(client) = &self.docker_client else {
+            return;
+        };
+        match client
+            .connect_network(network, container, aliases, ip)
+            .await
+        {
+            Ok(()) => {
+                self.state.add_notification(
+                    format!("Connected {} to {}", container, network),
+                    NotificationLevel::Success,
+                );
+                self.after_network_change().await;
+            }
+            Err(e) => {
+                error!("{}", e);
+                self.state
+                    .add_notification(e.to_string(), NotificationLevel::Error);
+            }
+        }
+    }
+
+    async fn disconnect_network(&mut self, network: &str, container: &str, force: bool) {
+        let Some(client) = &self.docker_client else {
+            return;
+        };
+        match client.disconnect_network(network, container, force).await {
+            Ok(()) => {
+                self.state.add_notification(
+                    format!("Disconnected {} from {}", container, network),
+                    NotificationLevel::Success,
+                );
+                self.after_network_change().await;
+            }
+            Err(e) => {
+                error!("{}", e);
+                self.state
+                    .add_notification(e.to_string(), NotificationLevel::Error);
+            }
+        }
+    }
+
+    /// Refresh the lists and the open container detail view, whose network
+    /// list just changed
+    async fn after_network_change(&mut self) {
+        self.request_data_refresh();
+        if let Some(view) = &self.state.detail_view {
+            let id = view.container_id.clone();
+            self.fetch_container_details(id).await;
+        }
+    }
+
+    /// Open the volume browser and list the volume through a helper
+    /// container in the background
+    fn browse_volume(&mut self, name: String) {
+        let Some(client) = self.docker_client.clone() else {
+            self.state
+                .add_notification("Docker not connected", NotificationLevel::Error);
+            return;
+        };
+        self.close_volume_browser();
+        self.state.open_volume_browser(name.clone());
+        let job = self
+            .state
+            .start_job(format!("Reading volume {}", name), None);
+        let (tx, rx) = mpsc::channel(16);
+        self.volume_browser = Some(VolumeBrowserRuntime {
+            helper_id: None,
+            listing_job: Some(job),
+            download_jobs: Vec::new(),
+            tx: tx.clone(),
+            rx,
+        });
+
+        let image = self.config.volumes.helper_image.clone();
+        tokio::spawn(async move {
+            let helper_id = match client.create_volume_helper(&name, &image, true).await {
+                Ok(id) => id,
+                Err(e) => {
+                    let _ = tx.send(VolumeEvent::Listed(Err(e))).await;
+                    return;
+                }
+            };
+            // From here the browser owns the helper and removes it on close
+            // or exit; if it is already gone, clean up here
+            if tx
+                .send(VolumeEvent::HelperCreated(helper_id.clone()))
+                .await
+                .is_err()
+            {
+                if let Err(e) = client.remove_volume_helper(&helper_id).await {
+                    warn!("Failed to remove volume helper {}: {}", helper_id, e);
+                }
+                return;
+            }
+            let progress = tx.clone();
+            let listing = client.list_volume(&helper_id, |read| {
+                let _ = progress.try_send(VolumeEvent::Progress(read));
+            });
+            // Stop reading as soon as the browser is closed
+            let result = tokio::select! {
+                result = listing => result,
+                _ = tx.closed() => return,
+            };
+            let _ = tx.send(VolumeEvent::Listed(result)).await;
+        });
+    }
+
+    /// Apply volume listing progress and finished downloads
+    fn check_volume_browser(&mut self) {
+        let Some(runtime) = &mut self.volume_browser else {
+            return;
+        };
+        while let Ok(event) = runtime.rx.try_recv() {
+            match event {
+                VolumeEvent::Progress(bytes) => {
+                    if let Some(job) = runtime.listing_job {
+                        self.state.update_job(job, bytes);
+                    }
+                }
+                VolumeEvent::HelperCreated(helper_id) => {
+                    runtime.helper_id = Some(helper_id);
+                }
+                VolumeEvent::Listed(result) => {
+                    if let Some(job) = runtime.listing_job.take() {
+                        self.state.finish_job(job);
+                    }
+                    match result {
+                        Ok(entries) => self.state.set_volume_entries(entries),
+                        Err(e) => {
+                            error!("Failed to read volume: {}", e);
+                            self.state.add_notification(
+                                format!("Failed to read volume: {}", e),
+                                NotificationLevel::Error,
+                            );
+                            self.close_volume_browser();
+                            return;
+                        }
+                    }
+                }
+                VolumeEvent::Downloaded(job, result) => {
+                    runtime.download_jobs.retain(|&j| j != job);
+                    self.state.finish_job(job);
+                    match result {
+                        Ok(path) => self.state.add_notification(
+                            format!("Downloaded to {}", path.display()),
+                            NotificationLevel::Success,
+                        ),
+                        Err(e) => {
+                            error!("Volume download failed: {}", e);
+                            self.state
+                                .add_notification(e.to_string(), NotificationLevel::Error);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Preview the start of a file in the browsed volume
+    async fn preview_volume_file(&mut self, path: &str) {
+        let (Some(client), Some(helper_id)) = (
+            &self.docker_client,
+            self.volume_browser
+                .as_ref()
+                .and_then(|r| r.helper_id.as_deref()),
+        ) else {
+            return;
+        };
+        match client.preview_volume_file(helper_id, path).await {
+            Ok(preview) => self.state.set_volume_preview(preview),
+            Err(e) => {
+                error!("Failed to preview {}: {}", path, e);
+                self.state
+                    .add_notification(e.to_string(), NotificationLevel::Error);
+            }
+        }
+    }
+
+    /// Copy a file or directory out of the browsed volume in the background
+    fn download_volume_entry(&mut self, path: String, directory: PathBuf) {
+        let (Some(client), Some(runtime)) = (self.docker_client.clone(), &mut self.volume_browser)
+        else {
+            return;
+        };
+        let Some(helper_id) = runtime.helper_id.clone() else {
+            return;
+        };
+        let tx = runtime.tx.clone();
+        let job = self.state.start_job(format!("Downloading {}", path), None);
+        runtime.download_jobs.push(job);
+        tokio::spawn(async move {
+            let result = client
+                .download_volume_entry(&helper_id, &path, &directory)
+                .await;
+            let _ = tx.send(VolumeEvent::Downloaded(job, result)).await;
+        });
+    }
+
+    /// Close the volume browser and remove its helper container
+    fn close_volume_browser(&mut self) {
+        self.state.close_volume_browser();
+        let Some(mut runtime) = self.volume_browser.take() else {
+            return;
+        };
+        runtime.take_pending_helper();
+        if let Some(job) = runtime.listing_job {
+            info!("Cancelled volume listing");
+            self.state.finish_job(job);
+        }
+        // Downloads still running fail once the helper is gone
+        for job in runtime.download_jobs {
+            self.state.finish_job(job);
+        }
+        if let (Some(client), Some(helper_id)) = (self.docker_client.clone(), runtime.helper_id) {
+            tokio::spawn(async move {
+                if let Err(e) = client.remove_volume_helper(&helper_id).await {
+                    warn!("Failed to remove volume helper {}: {}", helper_id, e);
+                }
+            });
+        }
+    }
+
+    async fn remove_volume_helper_on_exit(&mut self) {
+        let helper_id = self.volume_browser.as_mut().and_then(|runtime| {
+            runtime.take_pending_helper();
+            runtime.helper_id.clone()
+        });
+        if let (Some(client), Some(helper_id)) = (&self.docker_client, helper_id) {
+            if let Err(e) = client.remove_volume_helper(&helper_id).await {
+                warn!("Failed to remove volume helper {}: {}", helper_id, e);
+            }
+        }
+    }
+
+    fn prepare_volume_backup(&mut self, path: &str) -> Option<DockerClient> {
+        if path.is_empty() {
+            return None;
+        }
+        if self.volume_backup_rx.is_some() {
+            self.state.add_notification(
+                "A volume backup or restore is already in progress",
+                NotificationLevel::Warning,
+            );
+            return None;
+        }
+        let client = self.docker_client.clone();
+        if client.is_none() {
+            self.state
+                .add_notification("Docker not connected", NotificationLevel::Error);
+        }
+        client
+    }
+
+    /// Back up a volume to a tar archive in the background
+    fn start_volume_backup(&mut self, volume: String, path: String) {
+        let Some(client) = self.prepare_volume_backup(&path) else {
+            return;
+        };
+        let job = self.state.start_job(format!("Backing up {}", volume), None);
+        let (tx, rx) = mpsc::channel(16);
+        self.volume_backup_rx = Some((job, rx));
+
+        let image = self.config.volumes.helper_image.clone();
+        tokio::spawn(async move {
+            let progress = tx.clone();
+            let result = client
+                .backup_volume(&volume, Path::new(&path), &image, |read| {
+                    let _ = progress.try_send(VolumeBackupEvent::Progress(read));
+                })
+                .await;
+            let _ = tx.send(VolumeBackupEvent::BackedUp(path, result)).await;
+        });
+    }
+
+    /// Restore a backup into a volume in the background. Without
+    /// `overwrite`, a volume that already has files asks first.
+    fn start_volume_restore(&mut self, path: String, volume: String, overwrite: bool) {
+        let Some(client) = self.prepare_volume_backup(&path) else {
+            return;
+        };
+        if volume.is_empty() {
+            return;
+        }
+        let size = match std::fs::metadata(&path) {
+            Ok(metadata) => metadata.len(),
+            Err(e) => {
+                self.state.add_notification(
+                    format!("Cannot read {}: {}", path, e),
+                    NotificationLevel::Error,
+                );
+                return;
+            }
+        };
+        let job = self
+            .state
+            .start_job(format!("Restoring {}", volume), Some(size));
+        let (tx, rx) = mpsc::channel(16);
+        self.volume_backup_rx = Some((job, rx));
+
+        let image = self.config.volumes.helper_image.clone();
+        tokio::spawn(async move {
+            let progress = tx.clone();
+            let result = client
+                .restore_volume(Path::new(&path), &volume, &image, overwrite, move |sent| {
+                    let _ = progress.try_send(VolumeBackupEvent::Progress(sent));
+                })
+                .await;
+            let _ = tx
+                .send(VolumeBackupEvent::Restored(path, volume, result))
+                .await;
+        });
+    }
+
+    /// Apply backup/restore progress received since the last tick
+    fn check_volume_backup(&mut self) {
+        let Some((job, rx)) = &mut self.volume_backup_rx else {
+            return;
+        };
+        let job = *job;
+        loop {
+            match rx.try_recv() {
+                Ok(VolumeBackupEvent::Progress(bytes)) => self.state.update_job(job, bytes),
+                Ok(VolumeBackupEvent::BackedUp(path, result)) => {
+                    self.volume_backup_rx = None;
+                    self.state.finish_job(job);
+                    match result {
+                        Ok(manifest) => self.state.add_notification(
+                            format!(
+                                "Backed up {} to {} ({})",
+                                manifest.volume,
+                                path,
+                                format_bytes_size(manifest.size as i64)
+                            ),
+                            NotificationLevel::Success,
+                        ),
+                        Err(e) => {
+                            error!("Failed to back up to {}: {}", path, e);
+                            self.state
+                                .add_notification(e.to_string(), NotificationLevel::Error);
+                        }
+                    }
+                    return;
+                }
+                Ok(VolumeBackupEvent::Restored(path, volume, result)) => {
+                    self.volume_backup_rx = None;
+                    self.state.finish_job(job);
+                    match result {
+                        Ok(RestoreOutcome::NotEmpty) => {
+                            self.state.confirm_dialog = Some(ConfirmAction {
+                                message: format!(
+                                    "Volume '{}' already has files. Restore {} into it and overwrite files with the same names?",
+                                    volume, path
+                                ),
+                                action: UiAction::RestoreVolume(path, volume),
+                            });
+                        }
+                        Ok(RestoreOutcome::Restored { created, .. }) => {
+                            let target = if created {
+                                format!("new volume {}", volume)
+                            } else {
+                                volume.clone()
+                            };
+                            self.state.add_notification(
+                                format!("Restored {} into {}", path, target),
+                                NotificationLevel::Success,
+                            );
+                            self.state.pending_volume_select = Some(volume);
+                            self.request_data_refresh();
+                        }
+                        Err(e) => {
+                            error!("Failed to restore {} into {}: {}", path, volume, e);
+                            self.state
+                                .add_notification(e.to_string(), NotificationLevel::Error);
+                            // A volume created for the restore shows up anyway
+                            self.request_data_refresh();
+                        }
+                    }
+                    return;
+                }
+                Err(mpsc::error::TryRecvError::Empty) => return,
+                Err(mpsc::error::TryRecvError::Disconnected) => {
+                    self.volume_backup_rx = None;
+                    self.state.finish_job(job);
+                    return;
+                }
+            }
+        }
+    }
+
     /// Remove a volume
     async fn remove_volume(&mut self, name: &str) {
         if let Some(client) = &self.docker_client {
@@ -965,6 +2638,40 @@ impl App {
         }
     }
 
+    /// Remove several volumes, reporting how many succeeded
+    async fn remove_volumes(&mut self, names: Vec<String>) {
+        let Some(client) = &self.docker_client else {
+            return;
+        };
+        let mut failed = Vec::new();
+        for name in &names {
+            info!("Removing volume {}", name);
+            if let Err(e) = client.remove_volume(name, false).await {
+                error!("Failed to remove volume {}: {}", name, e);
+                failed.push(format!("{}: {}", name, e));
+            }
+        }
+        let removed = names.len() - failed.len();
+        if failed.is_empty() {
+            self.state.add_notification(
+                format!("Removed {} volumes", removed),
+                NotificationLevel::Success,
+            );
+        } else {
+            self.state.add_notification(
+                format!(
+                    "Removed {} of {} volumes; failed: {}",
+                    removed,
+                    names.len(),
+                    failed.join("; ")
+                ),
+                NotificationLevel::Error,
+            );
+        }
+        // The refresh drops the marks of removed volumes, failed ones stay marked
+        self.request_data_refresh();
+    }
+
     /// Prune unused volumes
     async fn prune_volumes(&mut self) {
         if let Some(client) = &self.docker_client {
@@ -1238,6 +2945,44 @@ impl App {
         }
     }
 
+    /// Fetch a container's exit information and last logs for the death report
+    async fn fetch_death_report(&mut self, container_id: String) {
+        let Some(client) = &self.docker_client else {
+            return;
+        };
+
+        let info = match client.exit_info(&container_id).await {
+            Ok(info) => info,
+            Err(e) => {
+                self.state.close_death_report();
+                self.state.add_notification(
+                    format!("Failed to inspect container: {}", e),
+                    NotificationLevel::Error,
+                );
+                return;
+            }
+        };
+
+        let logs = match info.finished_at {
+            Some(finished_at) => {
+                client
+                    .fetch_logs_until(&container_id, finished_at, DEATH_REPORT_LOG_LINES)
+                    .await
+            }
+            None => {
+                client
+                    .fetch_logs(&container_id, DEATH_REPORT_LOG_LINES)
+                    .await
+            }
+        };
+        let logs = logs.unwrap_or_else(|e| {
+            warn!("Failed to fetch logs for death report: {}", e);
+            Vec::new()
+        });
+
+        self.state.set_death_report_content(info, logs);
+    }
+
     /// Fetch container details
     async fn fetch_container_details(&mut self, container_id: String) {
         if let Some(client) = &self.docker_client {
@@ -1260,14 +3005,128 @@ impl App {
                     self.state.close_detail_view();
                 }
             }
-        } else {
+        } else {
+            self.state
+                .add_notification("Not connected to Docker", NotificationLevel::Error);
+            self.state.close_detail_view();
+        }
+    }
+
+    /// Fetch image details
+    /// Plan every configured cleanup policy against the current images
+    async fn open_cleanup_planner(&mut self) {
+        let policies = &self.config.images.cleanup_policies;
+        if policies.is_empty() {
+            self.state.add_notification(
+                "No cleanup policies configured (add [[images.cleanup_policies]] to the config)",
+                NotificationLevel::Info,
+            );
+            return;
+        }
+        let now = chrono::Utc::now();
+        let last_tagged = match &self.docker_client {
+            Some(client) => {
+                client
+                    .last_tag_times(&self.state.images, policies, now)
+                    .await
+            }
+            None => std::collections::HashMap::new(),
+        };
+        let plans = policies
+            .iter()
+            .map(|policy| {
+                plan_cleanup(
+                    policy,
+                    &self.state.images,
+                    &self.state.all_containers,
+                    &last_tagged,
+                    now,
+                )
+            })
+            .collect();
+        self.state.open_cleanup_view(plans);
+    }
+
+    /// Delete exactly the images the planner showed for a policy
+    async fn run_cleanup(&mut self, index: usize) {
+        let Some(plan) = self
+            .state
+            .cleanup_view
+            .as_ref()
+            .and_then(|view| view.plans.get(index))
+            .cloned()
+        else {
+            return;
+        };
+        let Some(client) = &self.docker_client else {
+            self.state
+                .add_notification("Not connected to Docker", NotificationLevel::Error);
+            return;
+        };
+        self.state.close_cleanup_view();
+
+        let result = client.run_cleanup(&plan).await;
+        let mut message = format!(
+            "Cleanup '{}': removed {} image(s), reclaimed {}",
+            plan.policy,
+            result.removed,
+            format_bytes_size(result.reclaimed)
+        );
+        let level = match result.failed.first() {
+            Some((name, error)) => {
+                for (name, error) in &result.failed {
+                    warn!("Cleanup could not remove {}: {}", name, error);
+                }
+                message.push_str(&format!(
+                    "; {} failed ({}: {})",
+                    result.failed.len(),
+                    name,
+                    error
+                ));
+                NotificationLevel::Warning
+            }
+            None => NotificationLevel::Success,
+        };
+        self.state.add_notification(message, level);
+        self.request_data_refresh();
+    }
+
+    /// Inspect two images and show what changed between them
+    async fn compare_images(&mut self, left: String, right: String) {
+        let Some(client) = self.docker_client.clone() else {
             self.state
                 .add_notification("Not connected to Docker", NotificationLevel::Error);
-            self.state.close_detail_view();
+            return;
+        };
+        let name = |id: &str| {
+            self.state
+                .images
+                .iter()
+                .find(|i| i.id == id)
+                .map(crate::state::image_tree::image_name)
+                .unwrap_or_else(|| id.chars().take(12).collect())
+        };
+        let (left_name, right_name) = (name(&left), name(&right));
+        self.state.open_image_diff(left_name, right_name);
+
+        info!("Comparing images '{}' and '{}'", left, right);
+        let (left, right) = tokio::join!(client.inspect_image(&left), client.inspect_image(&right));
+        match left.and_then(|left| right.map(|right| (left, right))) {
+            Ok((left, right)) => {
+                self.state
+                    .set_image_diff(crate::docker::diff_images(&left, &right));
+            }
+            Err(e) => {
+                error!("Failed to compare images: {}", e);
+                self.state.add_notification(
+                    format!("Failed to compare images: {}", e),
+                    NotificationLevel::Error,
+                );
+                self.state.close_image_diff();
+            }
         }
     }
 
-    /// Fetch image details
     async fn fetch_image_details(&mut self, image_id: String) {
         if let Some(client) = &self.docker_client {
             info!("Fetching details for image '{}'", image_id);
@@ -1293,6 +3152,46 @@ impl App {
         }
     }
 
+    async fn fetch_volume_details(&mut self, name: String) {
+        let Some(client) = &self.docker_client else {
+            self.state
+                .add_notification("Not connected to Docker", NotificationLevel::Error);
+            self.state.close_volume_detail_view();
+            return;
+        };
+        match client.inspect_volume(&name).await {
+            Ok(details) => self.state.set_volume_detail_view_content(details),
+            Err(e) => {
+                error!("Failed to fetch details for volume '{}': {}", name, e);
+                self.state.add_notification(
+                    format!("Failed to fetch volume details: {}", e),
+                    NotificationLevel::Error,
+                );
+                self.state.close_volume_detail_view();
+            }
+        }
+    }
+
+    async fn fetch_network_details(&mut self, id: String) {
+        let Some(client) = &self.docker_client else {
+            self.state
+                .add_notification("Not connected to Docker", NotificationLevel::Error);
+            self.state.close_network_detail_view();
+            return;
+        };
+        match client.inspect_network(&id).await {
+            Ok(details) => self.state.set_network_detail_view_content(details),
+            Err(e) => {
+                error!("Failed to fetch details for network '{}': {}", id, e);
+                self.state.add_notification(
+                    format!("Failed to fetch network details: {}", e),
+                    NotificationLevel::Error,
+                );
+                self.state.close_network_detail_view();
+            }
+        }
+    }
+
     /// Export currently visible logs to file
     fn export_logs(&mut self) {
         use crate::state::LogLevelFilter;
@@ -1345,13 +3244,10 @@ impl App {
             if log_view.level_filter != LogLevelFilter::All {
                 content.push_str(&format!("# Level filter: {:?}\n", log_view.level_filter));
             }
-            if log_view.time_filter.is_some() {
+            if let Some(time_filter) = log_view.time_filter {
                 content.push_str(&format!(
                     "# Time filter: logs after {}\n",
-                    log_view
-                        .time_filter
-                        .unwrap()
-                        .format("%Y-%m-%d %H:%M:%S UTC")
+                    time_filter.format("%Y-%m-%d %H:%M:%S UTC")
                 ));
             }
             content.push_str("#\n");
@@ -1480,11 +3376,10 @@ impl App {
 
     // ==================== Exec Handling ====================
 
-    async fn start_exec_for_container(&mut self, id: &str) {
+    async fn start_exec_for_container(&mut self, id: &str, debug_image: Option<String>) {
         if let Some(runtime) = &self.exec_runtime {
             if runtime.container_id == id {
-                self.state.close_exec_view();
-                self.exec_runtime = None;
+                self.close_exec_session();
                 return;
             }
             self.state.add_notification(
@@ -1517,7 +3412,10 @@ impl App {
             }
         };
 
-        let container_name = self
+        // A live session replaces any replay in the pane
+        self.playback = None;
+
+        let mut container_name = self
             .state
             .containers
             .iter()
@@ -1525,6 +3423,9 @@ impl App {
             .and_then(|c| c.names.first())
             .cloned()
             .unwrap_or_else(|| id.chars().take(12).collect::<String>());
+        if debug_image.is_some() {
+            container_name = debug_pane_name(&container_name);
+        }
         let (cols, rows) =
             compute_exec_pane_size(self.state.terminal_size.0, self.state.terminal_size.1);
 
@@ -1566,13 +3467,52 @@ impl App {
                 return;
             }
 
-            let cmd = select_exec_command(&defaults.entrypoint, &defaults.cmd);
+            // In debug mode the shell runs in a toolbox sidecar that shares
+            // the target's namespaces; the pane still belongs to the target.
+            let (exec_target, exec_defaults) = match &debug_image {
+                Some(image) => {
+                    let sidecar_id = match client
+                        .create_debug_sidecar(&defaults.container_id, image)
+                        .await
+                    {
+                        Ok(id) => id,
+                        Err(e) => {
+                            let _ = tx
+                                .send(ExecStartResult::Failed {
+                                    container_id: defaults.container_id,
+                                    message: format!("Failed to start debug sidecar: {e}"),
+                                })
+                                .await;
+                            return;
+                        }
+                    };
+                    match client.exec_defaults(&sidecar_id).await {
+                        Ok(d) => (Some(sidecar_id), d),
+                        Err(e) => {
+                            let _ = client.remove_debug_sidecar(&sidecar_id).await;
+                            let _ = tx
+                                .send(ExecStartResult::Failed {
+                                    container_id: defaults.container_id,
+                                    message: format!("Failed to inspect debug sidecar: {e}"),
+                                })
+                                .await;
+                            return;
+                        }
+                    }
+                }
+                None => (None, defaults.clone()),
+            };
+
+            let cmd = select_exec_command(&exec_defaults.entrypoint, &exec_defaults.cmd);
             let exec = match client
-                .start_exec_session(&defaults.container_id, cmd, cols, rows)
+                .start_exec_session(&exec_defaults.container_id, cmd, cols, rows)
                 .await
             {
                 Ok(exec) => exec,
                 Err(e) => {
+                    if let Some(sidecar_id) = &exec_target {
+                        let _ = client.remove_debug_sidecar(sidecar_id).await;
+                    }
                     let _ = tx
                         .send(ExecStartResult::Failed {
                             container_id: defaults.container_id,
@@ -1583,20 +3523,27 @@ impl App {
                 }
             };
 
-            let container_name = if defaults.container_name.is_empty() {
+            let container_name = if defaults.container_name.is_empty() || exec_target.is_some() {
                 container_name
             } else {
                 defaults.container_name
             };
 
-            let _ = tx
-                .send(ExecStartResult::Started {
-                    exec,
-                    container_id: defaults.container_id,
-                    container_name,
-                    size: (cols, rows),
-                })
-                .await;
+            let result = ExecStartResult::Started {
+                exec,
+                container_id: defaults.container_id,
+                container_name,
+                size: (cols, rows),
+                sidecar_id: exec_target,
+            };
+            // Nobody is waiting any more (start canceled): don't leak the sidecar
+            if let Err(mpsc::error::SendError(ExecStartResult::Started {
+                sidecar_id: Some(sidecar_id),
+                ..
+            })) = tx.send(result).await
+            {
+                let _ = client.remove_debug_sidecar(&sidecar_id).await;
+            }
         });
     }
 
@@ -1620,6 +3567,7 @@ impl App {
                             container_id,
                             container_name,
                             size,
+                            sidecar_id,
                         } => {
                             let matches_pending = self
                                 .exec_start_pending
@@ -1627,6 +3575,9 @@ impl App {
                                 .map(|p| p.container_id == container_id)
                                 .unwrap_or(false);
                             if !matches_pending {
+                                if let Some(sidecar_id) = sidecar_id {
+                                    self.remove_debug_sidecar(sidecar_id);
+                                }
                                 return;
                             }
                             self.exec_start_pending = None;
@@ -1664,7 +3615,11 @@ impl App {
                                 let _ = tx.send(ExecOutput::End).await;
                             });
 
-                            let parser = vt100::Parser::new(size.1, size.0, 0);
+                            let parser = ScrollbackParser::new(
+                                size.1,
+                                size.0,
+                                self.config.exec.scrollback_lines,
+                            );
                             if let Some(exec_view) = &mut self.state.exec_view {
                                 exec_view.container_id = container_id.clone();
                                 exec_view.container_name = container_name;
@@ -1680,6 +3635,8 @@ impl App {
                                 parser,
                                 output_rx: rx,
                                 size,
+                                recorder: None,
+                                sidecar_id,
                             });
                         }
                         ExecStartResult::Failed {
@@ -1731,10 +3688,18 @@ impl App {
         let mut cursor: Option<Option<(u16, u16)>> = None;
         let mut end = false;
 
+        let mut record_error = None;
+
         if let Some(runtime) = &mut self.exec_runtime {
             while let Ok(msg) = runtime.output_rx.try_recv() {
                 match msg {
                     ExecOutput::Bytes(bytes) => {
+                        if let Some(recorder) = &mut runtime.recorder {
+                            if let Err(e) = recorder.write_output(&bytes) {
+                                runtime.recorder = None;
+                                record_error = Some(e);
+                            }
+                        }
                         runtime.parser.process(&bytes);
                         let screen = runtime.parser.screen();
                         let contents = screen.contents();
@@ -1760,14 +3725,406 @@ impl App {
             self.state.set_exec_cursor(cursor);
         }
 
+        if let Some(e) = record_error {
+            self.state.set_exec_recording(false);
+            self.state
+                .add_notification(format!("Recording stopped: {e}"), NotificationLevel::Error);
+        }
+
         if end {
-            self.state.close_exec_view();
-            self.exec_runtime = None;
+            self.close_exec_session();
             self.state
                 .add_notification("Exec ended", NotificationLevel::Info);
         }
     }
 
+    /// Close the exec pane and tear down the live session
+    fn close_exec_session(&mut self) {
+        self.stop_exec_recording();
+        self.state.close_exec_view();
+        if let Some(sidecar_id) = self.exec_runtime.take().and_then(|r| r.sidecar_id) {
+            self.remove_debug_sidecar(sidecar_id);
+        }
+    }
+
+    async fn remove_exec_sidecar_on_exit(&mut self) {
+        let sidecar_id = self
+            .exec_runtime
+            .as_ref()
+            .and_then(|r| r.sidecar_id.clone());
+        if let (Some(client), Some(sidecar_id)) = (&self.docker_client, sidecar_id) {
+            if let Err(e) = client.remove_debug_sidecar(&sidecar_id).await {
+                warn!("Failed to remove debug sidecar {}: {}", sidecar_id, e);
+            }
+        }
+    }
+
+    fn remove_debug_sidecar(&self, sidecar_id: String) {
+        if let Some(client) = self.docker_client.clone() {
+            tokio::spawn(async move {
+                if let Err(e) = client.remove_debug_sidecar(&sidecar_id).await {
+                    warn!("Failed to remove debug sidecar {}: {}", sidecar_id, e);
+                }
+            });
+        }
+    }
+
+    fn enter_exec_copy_mode(&mut self) {
+        let lines = if let Some(runtime) = &self.exec_runtime {
+            runtime.parser.snapshot_lines()
+        } else if let Some(player) = &self.playback {
+            player.parser().snapshot_lines()
+        } else {
+            match &self.state.exec_view {
+                Some(exec_view) => exec_view.screen_lines.clone(),
+                None => return,
+            }
+        };
+        let view_rows = crate::ui::components::exec_viewer::exec_view_rows();
+        self.state.enter_exec_copy_mode(lines, view_rows);
+    }
+
+    fn copy_to_clipboard(&mut self, text: &str) {
+        if text.is_empty() {
+            self.state
+                .add_notification("Nothing to copy", NotificationLevel::Warning);
+            return;
+        }
+        match clipboard::copy_to_clipboard(&mut io::stdout(), text) {
+            Ok(()) => self.state.add_notification(
+                format!("Copied {} characters to clipboard", text.chars().count()),
+                NotificationLevel::Success,
+            ),
+            Err(e) => self
+                .state
+                .add_notification(format!("Copy failed: {e}"), NotificationLevel::Error),
+        }
+    }
+
+    fn toggle_exec_recording(&mut self) {
+        let Some(runtime) = &mut self.exec_runtime else {
+            self.state
+                .add_notification("No exec session to record", NotificationLevel::Warning);
+            return;
+        };
+        if runtime.recorder.is_some() {
+            self.stop_exec_recording();
+            return;
+        }
+
+        let container_name = self
+            .state
+            .exec_view
+            .as_ref()
+            .map(|v| v.container_name.clone())
+            .unwrap_or_else(|| runtime.container_id.chars().take(12).collect());
+        let dir = self
+            .config
+            .exec
+            .recordings_dir
+            .clone()
+            .unwrap_or_else(|| PathBuf::from("."));
+        if let Err(e) = std::fs::create_dir_all(&dir) {
+            self.state.add_notification(
+                format!("Failed to create {}: {e}", dir.display()),
+                NotificationLevel::Error,
+            );
+            return;
+        }
+
+        let path = dir.join(asciicast::recording_file_name(&container_name));
+        match CastRecorder::create(&path, runtime.size.0, runtime.size.1, &container_name) {
+            Ok(recorder) => {
+                runtime.recorder = Some(recorder);
+                self.state.set_exec_recording(true);
+                self.state.add_notification(
+                    format!("Recording to {}", path.display()),
+                    NotificationLevel::Info,
+                );
+            }
+            Err(e) => self.state.add_notification(
+                format!("Failed to start recording: {e}"),
+                NotificationLevel::Error,
+            ),
+        }
+    }
+
+    /// Finish the active recording, if any
+    fn stop_exec_recording(&mut self) {
+        let Some(recorder) = self.exec_runtime.as_mut().and_then(|r| r.recorder.take()) else {
+            return;
+        };
+        self.state.set_exec_recording(false);
+        match recorder.finish() {
+            Ok(path) => {
+                self.state.add_notification(
+                    format!("Saved recording to {}", path.display()),
+                    NotificationLevel::Success,
+                );
+                self.last_recording = Some(path);
+            }
+            Err(e) => self.state.add_notification(
+                format!("Failed to save recording: {e}"),
+                NotificationLevel::Error,
+            ),
+        }
+    }
+
+    fn open_play_recording_dialog(&mut self) {
+        let default_path = self
+            .last_recording
+            .clone()
+            .or_else(|| self.config.exec.recordings_dir.clone())
+            .map(|p| p.display().to_string())
+            .unwrap_or_default();
+        self.state.open_form_dialog(
+            FormDialogState::new(FormKind::PlayRecording, "Replay Exec Recording").with_text(
+                "path",
+                "File",
+                default_path,
+                "session.cast",
+            ),
+        );
+    }
+
+    async fn submit_form(&mut self) {
+        let Some(form) = self.state.form_dialog.take() else {
+            return;
+        };
+        match &form.kind {
+            FormKind::PlayRecording => self.start_playback(form.value("path")),
+            FormKind::RecreateContainer(id) => {
+                self.recreate_container(id, form.value("image"), form.toggle("pull"))
+            }
+            FormKind::SaveRunSpec => self.save_run_spec(form.value("path")),
+            FormKind::SaveDockerfile => self.save_dockerfile(form.value("path")),
+            FormKind::PullImage => self.start_pull(form.value("image").trim()),
+            FormKind::BuildImage => self.start_build(BuildOptions {
+                context_dir: PathBuf::from(form.value("context").trim()),
+                dockerfile: form.value("dockerfile").trim().to_string(),
+                tags: build::parse_tags(form.value("tags")),
+                build_args: build::parse_build_args(form.value("build_args")),
+                target: form.value("target").trim().to_string(),
+            }),
+            FormKind::TagImage(id) => self.tag_image(id, form.value("tag").trim()).await,
+            FormKind::UntagImage(id) => self.untag_image(id, form.value("tag").trim()).await,
+            FormKind::PushImage => self.start_push(form.value("tag").trim()),
+            FormKind::SaveImages(names) => self.start_save(
+                names.clone(),
+                form.value("path").trim(),
+                form.toggle("gzip"),
+            ),
+            FormKind::LoadImages => self.start_load(form.value("path").trim()),
+            FormKind::OpenRepository(index) => {
+                self.browse_repository(*index, form.value("repository").trim().to_string())
+            }
+            FormKind::ImportScanReports => {
+                self.import_scan_reports(Path::new(form.value("path").trim()))
+            }
+            FormKind::CreateVolume => {
+                self.create_volume(VolumeOptions {
+                    name: form.value("name").to_string(),
+                    driver: form.value("driver").to_string(),
+                    driver_opts: parse_volume_options(form.value("driver_opts")),
+                    labels: parse_volume_options(form.value("labels")),
+                })
+                .await
+            }
+            FormKind::CreateNetwork => {
+                self.create_network(NetworkOptions {
+                    name: form.value("name").to_string(),
+                    driver: form.value("driver").to_string(),
+                    subnet: form.value("subnet").to_string(),
+                    gateway: form.value("gateway").to_string(),
+                    ip_range: form.value("ip_range").to_string(),
+                    internal: form.toggle("internal"),
+                    attachable: form.toggle("attachable"),
+                    enable_ipv6: form.toggle("ipv6"),
+                })
+                .await
+            }
+            FormKind::ConnectNetwork => {
+                self.connect_network(
+                    form.value("network"),
+                    form.value("container"),
+                    &parse_aliases(form.value("aliases")),
+                    form.value("ip"),
+                )
+                .await
+            }
+            FormKind::DisconnectNetwork => {
+                self.disconnect_network(
+                    form.value("network"),
+                    form.value("container"),
+                    form.toggle("force"),
+                )
+                .await
+            }
+            FormKind::DownloadVolumeEntry(path) => {
+                self.download_volume_entry(path.clone(), PathBuf::from(form.value("directory")))
+            }
+            FormKind::BackupVolume(volume) => {
+                self.start_volume_backup(volume.clone(), form.value("path").to_string())
+            }
+            FormKind::RestoreVolume => {
+                let path = form.value("path").to_string();
+                let volume = match form.value("volume") {
+                    "" => match volume_backup::read_manifest(Path::new(&path)) {
+                        Ok(Some(manifest)) => manifest.volume,
+                        _ => volume_backup::volume_name_from_backup(Path::new(&path)),
+                    },
+                    name => name.to_string(),
+                };
+                self.start_volume_restore(path, volume, false)
+            }
+            FormKind::FilterFindings => self
+                .state
+                .set_finding_package(form.value("package").trim().to_string()),
+        }
+    }
+
+    /// Load Trivy/Grype reports from a file or every JSON file in a directory
+    fn import_scan_reports(&mut self, path: &Path) {
+        let (reports, errors) = scan::load_reports(path);
+        for error in &errors {
+            warn!("Skipping scan report {}", error);
+        }
+        if reports.is_empty() {
+            let reason = errors
+                .first()
+                .cloned()
+                .unwrap_or_else(|| format!("no JSON reports in {}", path.display()));
+            self.state.add_notification(
+                format!("No scan reports loaded: {}", reason),
+                NotificationLevel::Error,
+            );
+            return;
+        }
+        let loaded = reports.len();
+        let matched = self.state.add_scan_reports(reports);
+        let mut message = format!(
+            "Loaded {} scan report(s), {} image(s) matched",
+            loaded, matched
+        );
+        if !errors.is_empty() {
+            message.push_str(&format!(", {} file(s) skipped", errors.len()));
+        }
+        self.state
+            .add_notification(message, NotificationLevel::Success);
+    }
+
+    /// Reverse engineer a container into a `docker run` command and compose service
+    async fn generate_run_spec(&mut self, id: &str) {
+        let Some(client) = &self.docker_client else {
+            return;
+        };
+        match client.run_spec(id).await {
+            Ok(spec) => {
+                let name = spec.name.clone();
+                self.state
+                    .open_run_spec_view(name, spec.to_docker_run(), spec.to_compose());
+            }
+            Err(e) => {
+                self.state.add_notification(
+                    format!("Failed to generate run command: {}", e),
+                    NotificationLevel::Error,
+                );
+            }
+        }
+    }
+
+    /// Write the run command or compose snippet currently shown to a file
+    fn save_run_spec(&mut self, path: &str) {
+        let Some(view) = &self.state.run_spec_view else {
+            return;
+        };
+        match std::fs::write(path, view.current_text()) {
+            Ok(()) => self
+                .state
+                .add_notification(format!("Saved to {}", path), NotificationLevel::Success),
+            Err(e) => self.state.add_notification(
+                format!("Failed to save {}: {}", path, e),
+                NotificationLevel::Error,
+            ),
+        }
+    }
+
+    /// Write the reconstructed Dockerfile to a file
+    fn save_dockerfile(&mut self, path: &str) {
+        let Some(view) = &self.state.dockerfile_view else {
+            return;
+        };
+        match std::fs::write(path, &view.text) {
+            Ok(()) => self
+                .state
+                .add_notification(format!("Saved to {}", path), NotificationLevel::Success),
+            Err(e) => self.state.add_notification(
+                format!("Failed to save {}: {}", path, e),
+                NotificationLevel::Error,
+            ),
+        }
+    }
+
+    fn start_playback(&mut self, path: &str) {
+        if self.exec_runtime.is_some() || self.exec_start_pending.is_some() {
+            self.state.add_notification(
+                "Close the exec session before replaying",
+                NotificationLevel::Warning,
+            );
+            return;
+        }
+
+        let cast = match std::fs::read_to_string(path)
+            .map_err(ContuiError::from)
+            .and_then(|contents| asciicast::parse_cast(&contents))
+        {
+            Ok(cast) => cast,
+            Err(e) => {
+                self.state.add_notification(
+                    format!("Cannot replay {path}: {e}"),
+                    NotificationLevel::Error,
+                );
+                return;
+            }
+        };
+
+        let title = std::path::Path::new(path)
+            .file_name()
+            .map(|n| n.to_string_lossy().to_string())
+            .unwrap_or_else(|| path.to_string());
+        self.playback = Some(CastPlayer::new(cast, self.config.exec.scrollback_lines));
+        self.last_playback_tick = std::time::Instant::now();
+        self.state.open_exec_playback(title);
+        self.update_playback_status();
+    }
+
+    fn tick_playback(&mut self) {
+        let delta = self.last_playback_tick.elapsed().as_secs_f64();
+        self.last_playback_tick = std::time::Instant::now();
+
+        let Some(player) = &mut self.playback else {
+            return;
+        };
+        if player.advance(delta) {
+            let screen = player.parser().screen();
+            let lines = screen.contents().lines().map(|l| l.to_string()).collect();
+            self.state.update_exec_screen(lines, None);
+        }
+        self.update_playback_status();
+    }
+
+    fn update_playback_status(&mut self) {
+        let Some(player) = &self.playback else {
+            return;
+        };
+        self.state.set_exec_status(format_playback_status(
+            player.position(),
+            player.duration(),
+            player.is_paused(),
+            player.is_finished(),
+        ));
+    }
+
     async fn resize_exec_if_needed(&mut self) {
         let (cols, rows) =
             compute_exec_pane_size(self.state.terminal_size.0, self.state.terminal_size.1);
@@ -1776,6 +4133,9 @@ impl App {
             if runtime.size != (cols, rows) {
                 runtime.size = (cols, rows);
                 runtime.parser.set_size(rows, cols);
+                if let Some(recorder) = &mut runtime.recorder {
+                    let _ = recorder.write_resize(cols, rows);
+                }
                 if let Some(client) = &self.docker_client {
                     let _ = client
                         .resize_exec_session(&runtime.exec_id, cols, rows)
@@ -1787,6 +4147,117 @@ impl App {
 }
 
 /// Format size in human readable format
+/// Forward a pull or push stream to the UI until it ends. Stops early when
+/// the receiver is dropped, which drops the stream and aborts the transfer.
+async fn forward_transfer<T>(
+    stream: impl futures::Stream<Item = ContuiResult<T>>,
+    tx: mpsc::Sender<TransferEvent>,
+    event: fn(T) -> TransferEvent,
+) {
+    let mut stream = std::pin::pin!(stream);
+    while let Some(item) = stream.next().await {
+        match item {
+            Ok(info) => {
+                if tx.send(event(info)).await.is_err() {
+                    return;
+                }
+            }
+            Err(e) => {
+                let _ = tx.send(TransferEvent::Finished(Err(e))).await;
+                return;
+            }
+        }
+    }
+    let _ = tx.send(TransferEvent::Finished(Ok(()))).await;
+}
+
+/// Pull each tag in turn, reporting progress to `tx`. A failed pull is
+/// recorded and the batch moves on to the next tag.
+async fn pull_outdated(
+    client: DockerClient,
+    tags: Vec<String>,
+    registries: Vec<crate::config::Registry>,
+    tx: mpsc::Sender<OutdatedPullEvent>,
+) {
+    let mut failed = Vec::new();
+    let count = tags.len();
+    for (index, tag) in tags.into_iter().enumerate() {
+        if tx
+            .send(OutdatedPullEvent::Started(tag.clone(), index, count))
+            .await
+            .is_err()
+        {
+            return;
+        }
+        let credentials = registry::credentials_for(&tag, &registries);
+        let mut stream = std::pin::pin!(client.pull_image_progress(&tag, credentials));
+        let mut progress = TransferProgress::default();
+        while let Some(item) = stream.next().await {
+            match item {
+                Ok(info) => {
+                    progress.apply(&info);
+                    let _ = tx.try_send(OutdatedPullEvent::Progress(
+                        progress.transferred_bytes(),
+                        progress.total_bytes(),
+                    ));
+                }
+                Err(e) => {
+                    error!("Failed to pull {}: {}", tag, e);
+                    failed.push(tag.clone());
+                    break;
+                }
+            }
+        }
+    }
+    let _ = tx.send(OutdatedPullEvent::Finished(failed)).await;
+}
+
+/// Package the context, stream the build output to `tx` and apply any extra
+/// tags. Returns the built image ID. Stops early when `tx` is closed, which
+/// drops the request and makes the daemon abort the build.
+async fn run_build(
+    client: &DockerClient,
+    options: &BuildOptions,
+    tx: &mpsc::Sender<BuildEvent>,
+) -> ContuiResult<String> {
+    let context = {
+        let dir = options.context_dir.clone();
+        let dockerfile = options.dockerfile.clone();
+        tokio::task::spawn_blocking(move || build::pack_context(&dir, &dockerfile))
+            .await
+            .map_err(|e| DockerError::Image(format!("Failed to package build context: {}", e)))??
+    };
+
+    let mut image_id = None;
+    let mut stream = std::pin::pin!(client.build_image(options, context));
+    while let Some(item) = stream.next().await {
+        let info = match item {
+            Ok(info) => info,
+            Err(bollard::errors::Error::DockerStreamError { error }) => {
+                let _ = tx
+                    .send(BuildEvent::Output(build::error_lines(&error)))
+                    .await;
+                return Err(DockerError::Image(error).into());
+            }
+            Err(e) => return Err(DockerError::Image(format!("Build failed: {}", e)).into()),
+        };
+        if let Some(id) = info.aux.as_ref().and_then(|aux| aux.id.clone()) {
+            image_id = Some(id);
+        }
+        let lines = build::build_lines(&info);
+        if !lines.is_empty() && tx.send(BuildEvent::Output(lines)).await.is_err() {
+            return Err(DockerError::Image("Build cancelled".to_string()).into());
+        }
+    }
+
+    let image_id = image_id
+        .ok_or_else(|| DockerError::Image("Build finished without an image ID".to_string()))?;
+    for tag in options.tags.iter().skip(1) {
+        client.tag_image(&image_id, tag).await?;
+    }
+    Ok(image_id)
+}
+
 fn format_size(size: u64) -> String {
     const UNITS: &[&str] = &["B", "KB", "MB", "GB", "TB"];
     if size == 0 {
@@ -1847,6 +4318,21 @@ fn format_exec_start_status(frame: &str) -> String {
     format!("Starting {}", frame)
 }
 
+fn debug_pane_name(container_name: &str) -> String {
+    format!("{} (debug)", container_name.trim_start_matches('/'))
+}
+
+fn format_playback_status(position: f64, duration: f64, paused: bool, finished: bool) -> String {
+    let label = if finished {
+        "Finished"
+    } else if paused {
+        "Paused"
+    } else {
+        "Playing"
+    };
+    format!("{} {:.1}s/{:.1}s", label, position, duration)
+}
+
 fn exec_tick_rate(exec_focused: bool) -> Duration {
     if exec_focused {
         Duration::from_millis(50)
@@ -1858,7 +4344,10 @@ fn exec_tick_rate(exec_focused: bool) -> Duration {
 #[cfg(test)]
 mod tests {
     // Note: Most tests would require async runtime and Docker
-    use super::{compute_exec_pane_size, exec_tick_rate, format_exec_start_status, RefreshGate};
+    use super::{
+        compute_exec_pane_size, debug_pane_name, exec_tick_rate, format_exec_start_status,
+        format_playback_status, RefreshGate,
+    };
     use std::time::Duration;
 
     #[test]
@@ -1873,6 +4362,27 @@ mod tests {
         assert_eq!(format_exec_start_status("|"), "Starting |");
     }
 
+    #[test]
+    fn debug_pane_name_marks_sidecar_sessions() {
+        assert_eq!(debug_pane_name("/web"), "web (debug)");
+    }
+
+    #[test]
+    fn formats_playback_status() {
+        assert_eq!(
+            format_playback_status(1.25, 10.0, false, false),
+            "Playing 1.2s/10.0s"
+        );
+        assert_eq!(
+            format_playback_status(3.0, 10.0, true, false),
+            "Paused 3.0s/10.0s"
+        );
+        assert_eq!(
+            format_playback_status(10.0, 10.0, false, true),
+            "Finished 10.0s/10.0s"
+        );
+    }
+
     #[test]
     fn exec_tick_rate_changes_with_focus() {
         assert_eq!(exec_tick_rate(true), Duration::from_millis(50));
@@ -83,21 +83,6 @@ impl Config {
     }
 }
 
-impl Default for Config {
-    fn default() -> Self {
-        Self {
-            general: GeneralConfig::default(),
-            ui: UiConfig::default(),
-            docker: DockerConfig::default(),
-            keybindings: KeyBindings::default(),
-            registries: vec![],
-            monitoring: MonitoringConfig::default(),
-            logging: LogConfig::default(),
-            update: UpdateConfig::default(),
-        }
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
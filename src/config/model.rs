@@ -4,7 +4,7 @@ use std::path::PathBuf;
 use serde::{Deserialize, Serialize};
 
 /// Main configuration structure
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct Config {
     #[serde(default)]
     pub general: GeneralConfig,
@@ -22,6 +22,12 @@ pub struct Config {
     pub logging: LogConfig,
     #[serde(default)]
     pub update: UpdateConfig,
+    #[serde(default)]
+    pub exec: ExecConfig,
+    #[serde(default)]
+    pub images: ImagesConfig,
+    #[serde(default)]
+    pub volumes: VolumesConfig,
 }
 
 /// General application settings
@@ -141,6 +147,15 @@ pub struct RegistryAuth {
     pub token: Option<String>,
 }
 
+impl RegistryAuth {
+    /// Password to authenticate with. A token without a password is sent
+    /// as the password, which is how registries accept personal access
+    /// tokens.
+    pub fn secret(&self) -> Option<&str> {
+        self.password.as_deref().or(self.token.as_deref())
+    }
+}
+
 /// Monitoring and alerting settings
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MonitoringConfig {
@@ -236,6 +251,96 @@ impl Default for UpdateConfig {
     }
 }
 
+/// Exec pane settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecConfig {
+    /// Number of lines kept in the exec scrollback buffer
+    #[serde(default = "default_exec_scrollback")]
+    pub scrollback_lines: usize,
+    /// Directory for exec session recordings (current directory if unset)
+    #[serde(default)]
+    pub recordings_dir: Option<PathBuf>,
+    /// Toolbox image used for debug sidecars
+    #[serde(default = "default_debug_image")]
+    pub debug_image: String,
+}
+
+impl Default for ExecConfig {
+    fn default() -> Self {
+        Self {
+            scrollback_lines: default_exec_scrollback(),
+            recordings_dir: None,
+            debug_image: default_debug_image(),
+        }
+    }
+}
+
+/// Image list settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImagesConfig {
+    /// Compare local tags against their registry to flag outdated images.
+    /// Off unless enabled, since it contacts the registry of every tag.
+    #[serde(default)]
+    pub check_updates: bool,
+    /// How long a registry digest stays cached before it is checked again
+    #[serde(default = "default_update_check_ttl")]
+    pub update_check_ttl_seconds: u64,
+    /// Trivy/Grype JSON reports (files or directories) loaded at startup
+    #[serde(default)]
+    pub scan_reports: Vec<PathBuf>,
+    /// Image cleanup policies (`[[images.cleanup_policies]]`)
+    #[serde(default)]
+    pub cleanup_policies: Vec<CleanupPolicy>,
+}
+
+/// Selects images to delete. An image is selected when it matches every
+/// rule that is set and no exclusion; images used by a container never are.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct CleanupPolicy {
+    pub name: String,
+    /// Only images neither built nor tagged (e.g. pulled) in this many
+    /// days. Docker doesn't track use, so this is not last-use time
+    #[serde(default)]
+    pub unused_days: Option<u64>,
+    /// Keep the newest this many tags of each repository
+    #[serde(default)]
+    pub keep_last: Option<usize>,
+    /// Never select images with these labels (`key` or `key=value`)
+    #[serde(default)]
+    pub exclude_labels: Vec<String>,
+    /// Never select images from repositories matching these patterns (`*`
+    /// matches any run of characters)
+    #[serde(default)]
+    pub exclude_repositories: Vec<String>,
+}
+
+impl Default for ImagesConfig {
+    fn default() -> Self {
+        Self {
+            check_updates: false,
+            update_check_ttl_seconds: default_update_check_ttl(),
+            scan_reports: Vec::new(),
+            cleanup_policies: Vec::new(),
+        }
+    }
+}
+
+/// Volume list settings
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VolumesConfig {
+    /// Image of the (never started) container used to browse volumes
+    #[serde(default = "default_volume_helper_image")]
+    pub helper_image: String,
+}
+
+impl Default for VolumesConfig {
+    fn default() -> Self {
+        Self {
+            helper_image: default_volume_helper_image(),
+        }
+    }
+}
+
 // Default value functions
 fn default_poll_interval() -> u64 {
     1000
@@ -277,6 +382,22 @@ fn default_update_timeout() -> u64 {
     3
 }
 
+fn default_exec_scrollback() -> usize {
+    1000
+}
+
+fn default_debug_image() -> String {
+    "nicolaka/netshoot:latest".to_string()
+}
+
+fn default_volume_helper_image() -> String {
+    "busybox:latest".to_string()
+}
+
+fn default_update_check_ttl() -> u64 {
+    900
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -287,6 +408,19 @@ mod tests {
         assert_eq!(general.poll_interval_ms, 1000);
         assert_eq!(general.metrics_retention_seconds, 3600);
         assert_eq!(general.default_log_tail, 1000);
+
+        let exec = ExecConfig::default();
+        assert_eq!(exec.scrollback_lines, 1000);
+        assert_eq!(exec.debug_image, "nicolaka/netshoot:latest");
+
+        let images = ImagesConfig::default();
+        assert!(!images.check_updates);
+        assert_eq!(images.update_check_ttl_seconds, 900);
+        assert!(images.scan_reports.is_empty());
+        assert!(images.cleanup_policies.is_empty());
+
+        let volumes = VolumesConfig::default();
+        assert_eq!(volumes.helper_image, "busybox:latest");
     }
 
     #[test]
@@ -295,4 +429,27 @@ mod tests {
         let toml_str = toml::to_string(&config).unwrap();
         assert!(!toml_str.is_empty());
     }
+
+    #[test]
+    fn test_cleanup_policies() {
+        let config: Config = toml::from_str(
+            r#"
+            [[images.cleanup_policies]]
+            name = "stale"
+            unused_days = 30
+            exclude_repositories = ["postgres"]
+
+            [[images.cleanup_policies]]
+            name = "history"
+            keep_last = 3
+            "#,
+        )
+        .unwrap();
+        let policies = &config.images.cleanup_policies;
+        assert_eq!(policies.len(), 2);
+        assert_eq!(policies[0].unused_days, Some(30));
+        assert_eq!(policies[0].exclude_repositories, vec!["postgres"]);
+        assert_eq!(policies[1].keep_last, Some(3));
+        assert!(policies[1].exclude_labels.is_empty());
+    }
 }
@@ -15,6 +15,10 @@ pub enum ContuiError {
     #[error("Configuration error: {0}")]
     Config(#[from] ConfigError),
 
+    /// Container registry (HTTP API v2) errors
+    #[error("Registry error: {0}")]
+    Registry(String),
+
     /// IO errors
     #[error("IO error: {0}")]
     Io(#[from] std::io::Error),
@@ -120,6 +120,8 @@ pub struct ContainerSummary {
     pub health: Option<HealthStatus>,
     pub mounts: Vec<MountPoint>,
     pub networks: Vec<String>,
+    /// IP address on each network, by network name
+    pub network_ips: HashMap<String, String>,
     pub compose_project: Option<String>,
     pub compose_service: Option<String>,
 }
@@ -143,6 +145,7 @@ impl Default for ContainerSummary {
             health: None,
             mounts: vec![],
             networks: vec![],
+            network_ips: HashMap::new(),
             compose_project: None,
             compose_service: None,
         }
@@ -150,7 +153,7 @@ impl Default for ContainerSummary {
 }
 
 /// Image summary for list views
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ImageSummary {
     pub id: String,
     pub short_id: String,
@@ -178,6 +181,26 @@ pub struct VolumeSummary {
     pub scope: VolumeScope,
     pub options: HashMap<String, String>,
     pub in_use: Vec<String>,
+    /// Disk usage in bytes, from the disk usage endpoint. `None` until that
+    /// is loaded and for drivers that don't report it
+    pub size: Option<i64>,
+    /// Number of containers (running or not) that reference the volume
+    pub ref_count: Option<i64>,
+}
+
+impl VolumeSummary {
+    /// Whether Docker generated the name: a volume created for a container's
+    /// `VOLUME` or an unnamed `-v /path`
+    pub fn is_anonymous(&self) -> bool {
+        self.labels.contains_key("com.docker.volume.anonymous")
+            || (self.name.len() == 64 && self.name.bytes().all(|b| b.is_ascii_hexdigit()))
+    }
+
+    /// An anonymous volume no container references anymore, typically left
+    /// behind by a container removed without `-v`
+    pub fn is_orphan(&self) -> bool {
+        self.is_anonymous() && self.ref_count == Some(0)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -198,6 +221,8 @@ pub struct NetworkSummary {
     pub attachable: bool,
     pub ingress: bool,
     pub enable_ipv6: bool,
+    /// First IPAM subnet, if one is configured
+    pub subnet: Option<String>,
     pub connected_containers: Vec<String>,
 }
 
@@ -235,22 +260,86 @@ pub enum UiAction {
     ShowContainerStats(String),
     /// Show details for a container
     ShowContainerDetails(String),
+    /// Explain why a container last exited
+    ShowDeathReport(String),
+    /// Inspect a container and open the form to recreate it
+    OpenRecreateForm(String),
+    /// Generate a `docker run` command and compose service for a container
+    GenerateRunSpec(String),
     /// Exec into a container
     ExecContainer(String),
     /// Start container then exec
     StartContainerAndExec(String),
+    /// Exec into a debug sidecar attached to a container
+    DebugContainer(String),
     /// Send input to exec session
     ExecInput(Vec<u8>),
+    /// Enter copy mode over the exec scrollback
+    EnterExecCopyMode,
+    /// Copy text to the system clipboard
+    CopyToClipboard(String),
+    /// Start or stop recording the exec session
+    ToggleExecRecording,
+    /// Open the dialog to replay a recording
+    OpenPlayRecordingDialog,
+    /// Pause or resume recording playback
+    TogglePlaybackPause,
+    /// Stop recording playback
+    StopPlayback,
+    /// Submit the open form dialog
+    SubmitForm,
     /// Show details for an image
     ShowImageDetails(String),
+    /// Show details for a volume
+    ShowVolumeDetails(String),
+    /// Show details for a network: (ID, name)
+    ShowNetworkDetails(String, String),
+    /// Plan image cleanup with the configured policies
+    OpenCleanupPlanner,
+    /// Delete the images in the cleanup plan at this index
+    RunCleanup(usize),
+    /// Compare two images: (older ID, newer ID)
+    CompareImages(String, String),
+    /// Export an image and explore its layers
+    ExploreLayers(String),
+    /// Cancel the running layer analysis
+    CancelLayerAnalysis,
+    /// Open the registry browser
+    OpenRegistryBrowser,
+    /// List repositories of the registry at this index
+    BrowseRegistry(usize),
+    /// List tags of a repository: (registry index, repository)
+    BrowseRepository(usize, String),
+    /// Resolve a tag's manifest: (registry index, repository, tag)
+    InspectRegistryTag(usize, String, String),
+    /// Pull a tag from a registry: (registry index, repository, tag)
+    PullRegistryTag(usize, String, String),
+    /// Check image tags against their registries now, ignoring the cache
+    CheckImageUpdates,
+    /// Pull these outdated tags one after another
+    PullOutdated(Vec<String>),
     /// Remove an image
     RemoveImage(String),
+    /// Cancel the running image pull or push
+    CancelTransfer,
+    /// Cancel the running image build
+    CancelBuild,
     /// Prune dangling images
     PruneImages,
     /// Remove a volume
     RemoveVolume(String),
+    /// Remove several volumes (e.g. marked orphans)
+    RemoveVolumes(Vec<String>),
     /// Prune unused volumes
     PruneVolumes,
+    /// Browse a volume's files through a helper container
+    BrowseVolume(String),
+    /// Preview a file (path relative to the volume root) in the browsed volume
+    PreviewVolumeFile(String),
+    /// Close the volume browser and remove its helper container
+    CloseVolumeBrowser,
+    /// Restore a backup into a volume that already has files: (backup path, volume)
+    RestoreVolume(String, String),
     /// Remove a network
     RemoveNetwork(String),
     /// Prune unused networks
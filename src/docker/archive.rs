@@ -0,0 +1,306 @@
+//! Image save/load (`docker save` / `docker load`) as tar archives
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::Path;
+
+use bollard::image::ImportImageOptions;
+use bollard::models::BuildInfo;
+use bytes::Bytes;
+use flate2::write::GzEncoder;
+use flate2::Compression;
+use futures::{Future, Stream, StreamExt};
+use tokio::io::AsyncReadExt;
+use tokio::sync::{mpsc, oneshot};
+use tracing::info;
+
+use crate::core::{ContuiError, DockerError, Result};
+use crate::docker::DockerClient;
+
+/// Read size when streaming an archive to the daemon
+const LOAD_CHUNK_SIZE: usize = 256 * 1024;
+
+/// Chunks queued for the file writer before downloading waits for it
+const WRITE_QUEUE: usize = 16;
+
+/// Whether a path names a gzip-compressed archive
+pub fn is_gzip_path(path: &str) -> bool {
+    path.ends_with(".gz") || path.ends_with(".tgz")
+}
+
+/// Image references reported in `docker load` output
+pub fn loaded_references(info: &BuildInfo) -> Vec<String> {
+    info.stream
+        .as_deref()
+        .unwrap_or_default()
+        .lines()
+        .filter_map(|line| {
+            line.strip_prefix("Loaded image: ")
+                .or_else(|| line.strip_prefix("Loaded image ID: "))
+        })
+        .map(|reference| reference.trim().to_string())
+        .collect()
+}
+
+/// The output file, wrapped in a gzip encoder when asked
+enum ArchiveFile {
+    Plain(BufWriter<File>),
+    Gzip(GzEncoder<BufWriter<File>>),
+}
+
+impl ArchiveFile {
+    fn create(path: &Path, gzip: bool) -> std::io::Result<Self> {
+        let file = BufWriter::new(File::create(path)?);
+        Ok(if gzip {
+            Self::Gzip(GzEncoder::new(file, Compression::default()))
+        } else {
+            Self::Plain(file)
+        })
+    }
+
+    fn write_all(&mut self, chunk: &[u8]) -> std::io::Result<()> {
+        match self {
+            Self::Plain(file) => file.write_all(chunk),
+            Self::Gzip(encoder) => encoder.write_all(chunk),
+        }
+    }
+
+    /// Write the gzip trailer and flush, reporting any failure that
+    /// dropping the writers would swallow
+    fn finish(self) -> std::io::Result<()> {
+        let mut file = match self {
+            Self::Plain(file) => file,
+            Self::Gzip(encoder) => encoder.finish()?,
+        };
+        file.flush()
+    }
+}
+
+/// Writes downloaded chunks to a file on a blocking thread, so file I/O and
+/// compression don't hold up a runtime worker for the whole transfer
+pub(crate) struct ArchiveWriter {
+    tx: mpsc::Sender<Bytes>,
+    task: tokio::task::JoinHandle<std::io::Result<()>>,
+}
+
+impl ArchiveWriter {
+    /// Create the output file, gzip-compressed when asked
+    pub async fn create(path: &Path, gzip: bool) -> std::io::Result<Self> {
+        let path = path.to_path_buf();
+        let mut writer = tokio::task::spawn_blocking(move || ArchiveFile::create(&path, gzip))
+            .await
+            .map_err(join_error)??;
+        let (tx, mut rx) = mpsc::channel::<Bytes>(WRITE_QUEUE);
+        let task = tokio::task::spawn_blocking(move || {
+            while let Some(chunk) = rx.blocking_recv() {
+                writer.write_all(&chunk)?;
+            }
+            writer.finish()
+        });
+        Ok(Self { tx, task })
+    }
+
+    /// Queue a chunk. Returns false once writing has failed; `finish` then
+    /// reports why.
+    pub async fn write(&self, chunk: Bytes) -> bool {
+        self.tx.send(chunk).await.is_ok()
+    }
+
+    /// Wait for everything queued to be written
+    pub async fn finish(self) -> std::io::Result<()> {
+        drop(self.tx);
+        self.task.await.map_err(join_error)?
+    }
+}
+
+fn join_error(e: tokio::task::JoinError) -> std::io::Error {
+    std::io::Error::new(std::io::ErrorKind::Other, e)
+}
+
+/// Stream a file in chunks, ending with the read error if there is one
+pub(crate) fn file_chunks(
+    file: tokio::fs::File,
+) -> impl Stream<Item = std::io::Result<Bytes>> + Send + 'static {
+    futures::stream::unfold(Some(file), |file| async move {
+        let mut file = file?;
+        let mut buf = vec![0u8; LOAD_CHUNK_SIZE];
+        match file.read(&mut buf).await {
+            Ok(0) => None,
+            Ok(n) => {
+                buf.truncate(n);
+                Some((Ok(Bytes::from(buf)), Some(file)))
+            }
+            Err(e) => Some((Err(e), None)),
+        }
+    })
+}
+
+/// Turn chunks into a request body, which can't carry errors. Returns the
+/// body and a future resolving to the first read error. The body stalls
+/// after an error instead of ending, so the caller can drop the request
+/// rather than send a truncated archive.
+pub(crate) fn request_body(
+    chunks: impl Stream<Item = std::io::Result<Bytes>> + Send + 'static,
+) -> (
+    impl Stream<Item = Bytes> + Send + 'static,
+    impl Future<Output = std::io::Error> + Send + 'static,
+) {
+    let (tx, rx) = oneshot::channel();
+    let body = futures::stream::unfold((Box::pin(chunks), tx), |(mut chunks, tx)| async move {
+        match chunks.next().await? {
+            Ok(chunk) => Some((chunk, (chunks, tx))),
+            Err(e) => {
+                let _ = tx.send(e);
+                futures::future::pending().await
+            }
+        }
+    });
+    let error = async move {
+        match rx.await {
+            Ok(e) => e,
+            // The body ended without an error
+            Err(_) => futures::future::pending().await,
+        }
+    };
+    (body, error)
+}
+
+impl DockerClient {
+    /// Save images into one tar archive. `on_progress` receives the number
+    /// of uncompressed bytes written so far. Returns the total.
+    pub async fn save_images(
+        &self,
+        names: &[String],
+        path: &Path,
+        gzip: bool,
+        mut on_progress: impl FnMut(u64),
+    ) -> Result<u64> {
+        info!("Saving {} image(s) to {}", names.len(), path.display());
+        let save_error = |e: std::io::Error| {
+            DockerError::Image(format!("Failed to write {}: {}", path.display(), e))
+        };
+        let writer = ArchiveWriter::create(path, gzip)
+            .await
+            .map_err(save_error)?;
+
+        let names: Vec<&str> = names.iter().map(String::as_str).collect();
+        let mut stream = self.inner().export_images(&names);
+        let mut written = 0u64;
+        while let Some(chunk) = stream.next().await {
+            let chunk =
+                chunk.map_err(|e| DockerError::Image(format!("Failed to save images: {}", e)))?;
+            let len = chunk.len() as u64;
+            if !writer.write(chunk).await {
+                break;
+            }
+            written += len;
+            on_progress(written);
+        }
+        writer.finish().await.map_err(save_error)?;
+
+        info!("Saved {} bytes to {}", written, path.display());
+        Ok(written)
+    }
+
+    /// Load images from a tar archive (plain or compressed). `on_progress`
+    /// receives the size of each chunk sent. Returns the loaded references.
+    pub async fn load_images(
+        &self,
+        path: &Path,
+        mut on_progress: impl FnMut(u64) + Send + 'static,
+    ) -> Result<Vec<String>> {
+        info!("Loading images from {}", path.display());
+        let file = tokio::fs::File::open(path)
+            .await
+            .map_err(|e| DockerError::Image(format!("Failed to open {}: {}", path.display(), e)))?;
+
+        // The daemon detects compression itself, so the file is sent as is
+        let (body, read_error) = request_body(file_chunks(file).map(move |chunk| {
+            if let Ok(chunk) = &chunk {
+                on_progress(chunk.len() as u64);
+            }
+            chunk
+        }));
+
+        let load = async {
+            let mut stream =
+                self.inner()
+                    .import_image_stream(ImportImageOptions { quiet: false }, body, None);
+            let mut references = Vec::new();
+            while let Some(item) = stream.next().await {
+                let info =
+                    item.map_err(|e| DockerError::Image(format!("Failed to load images: {}", e)))?;
+                if let Some(error) = info.error {
+                    return Err(
+                        DockerError::Image(format!("Failed to load images: {}", error)).into(),
+                    );
+                }
+                references.extend(loaded_references(&info));
+            }
+            Ok::<_, ContuiError>(references)
+        };
+        let references = tokio::select! {
+            result = load => result?,
+            e = read_error => {
+                return Err(DockerError::Image(format!(
+                    "Failed to read {}: {}",
+                    path.display(),
+                    e
+                ))
+                .into())
+            }
+        };
+
+        info!("Loaded {:?} from {}", references, path.display());
+        Ok(references)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    #[test]
+    fn parses_loaded_references() {
+        let info = BuildInfo {
+            stream: Some("Loaded image: nginx:1.25\nLoaded image ID: sha256:abc\n".into()),
+            ..Default::default()
+        };
+        assert_eq!(loaded_references(&info), vec!["nginx:1.25", "sha256:abc"]);
+        assert!(loaded_references(&BuildInfo::default()).is_empty());
+    }
+
+    #[tokio::test]
+    async fn request_body_stalls_on_read_error() {
+        let chunks = futures::stream::iter(vec![
+            Ok(Bytes::from_static(b"tar")),
+            Err(std::io::Error::new(std::io::ErrorKind::Other, "disk gone")),
+        ]);
+        let (body, read_error) = request_body(chunks);
+        let mut body = std::pin::pin!(body);
+        assert_eq!(body.next().await, Some(Bytes::from_static(b"tar")));
+        tokio::select! {
+            _ = body.next() => panic!("body went on after a read error"),
+            e = read_error => assert_eq!(e.to_string(), "disk gone"),
+        }
+    }
+
+    #[tokio::test]
+    async fn gzip_writer_round_trips() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("images.tar.gz");
+        assert!(is_gzip_path(&path.display().to_string()));
+
+        let writer = ArchiveWriter::create(&path, true).await.unwrap();
+        assert!(writer.write(Bytes::from_static(b"tar ")).await);
+        assert!(writer.write(Bytes::from_static(b"bytes")).await);
+        writer.finish().await.unwrap();
+
+        let mut decoded = String::new();
+        flate2::read::GzDecoder::new(std::fs::File::open(&path).unwrap())
+            .read_to_string(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, "tar bytes");
+    }
+}
@@ -0,0 +1,412 @@
+//! Image builds from a local context directory
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use bollard::image::BuildImageOptions;
+use bollard::models::BuildInfo;
+use futures::Stream;
+use regex::Regex;
+use tracing::{debug, info};
+
+use crate::core::{DockerError, Result};
+use crate::docker::DockerClient;
+
+/// What to build and how to tag it
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct BuildOptions {
+    /// Context directory sent to the daemon
+    pub context_dir: PathBuf,
+    /// Dockerfile path relative to the context
+    pub dockerfile: String,
+    /// Tags to apply; the first is set by the build itself
+    pub tags: Vec<String>,
+    pub build_args: HashMap<String, String>,
+    /// Multi-stage target, empty for the last stage
+    pub target: String,
+}
+
+/// Parse `KEY=VALUE` pairs separated by commas or newlines. A bare `KEY`
+/// gets an empty value.
+pub fn parse_build_args(input: &str) -> HashMap<String, String> {
+    input
+        .split([',', '\n'])
+        .map(str::trim)
+        .filter(|pair| !pair.is_empty())
+        .map(|pair| match pair.split_once('=') {
+            Some((key, value)) => (key.trim().to_string(), value.trim().to_string()),
+            None => (pair.to_string(), String::new()),
+        })
+        .collect()
+}
+
+/// Parse tags separated by commas or whitespace
+pub fn parse_tags(input: &str) -> Vec<String> {
+    input
+        .split(|c: char| c == ',' || c.is_whitespace())
+        .filter(|t| !t.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+/// Rules from a `.dockerignore` file
+#[derive(Debug, Clone, Default)]
+pub struct DockerIgnore {
+    /// Compiled patterns; `true` marks a `!` exception that re-includes
+    rules: Vec<(Regex, bool)>,
+}
+
+impl DockerIgnore {
+    /// Parse `.dockerignore` contents
+    pub fn parse(contents: &str) -> Self {
+        let rules = contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(|line| {
+                let (pattern, exception) = match line.strip_prefix('!') {
+                    Some(rest) => (rest.trim(), true),
+                    None => (line, false),
+                };
+                let pattern = pattern.trim_start_matches("./").trim_start_matches('/');
+                let pattern = pattern.trim_end_matches('/');
+                if pattern.is_empty() {
+                    return None;
+                }
+                pattern_regex(pattern).map(|re| (re, exception))
+            })
+            .collect();
+        Self { rules }
+    }
+
+    /// Whether any rule re-includes paths
+    fn has_exceptions(&self) -> bool {
+        self.rules.iter().any(|(_, exception)| *exception)
+    }
+
+    /// Whether a context-relative path (with `/` separators) is excluded.
+    /// A pattern matching a directory also matches everything under it, and
+    /// the last matching rule wins.
+    pub fn is_excluded(&self, path: &str) -> bool {
+        let mut excluded = false;
+        for (re, exception) in &self.rules {
+            let matches = re.is_match(path)
+                || path
+                    .match_indices('/')
+                    .any(|(i, _)| re.is_match(&path[..i]));
+            if matches {
+                excluded = !exception;
+            }
+        }
+        excluded
+    }
+}
+
+/// Translate a `.dockerignore` glob into an anchored regex. `*` and `?` stay
+/// within one path component, `**` spans any number of them.
+fn pattern_regex(pattern: &str) -> Option<Regex> {
+    let mut re = String::from("^");
+    let mut chars = pattern.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '*' if chars.peek() == Some(&'*') => {
+                chars.next();
+                if chars.peek() == Some(&'/') {
+                    chars.next();
+                    re.push_str("(.*/)?");
+                } else {
+                    re.push_str(".*");
+                }
+            }
+            '*' => re.push_str("[^/]*"),
+            '?' => re.push_str("[^/]"),
+            '[' => {
+                re.push('[');
+                if chars.peek() == Some(&'!') || chars.peek() == Some(&'^') {
+                    chars.next();
+                    re.push('^');
+                }
+                for c in chars.by_ref() {
+                    if c == ']' {
+                        break;
+                    }
+                    if c == '\\' {
+                        re.push('\\');
+                    }
+                    re.push(c);
+                }
+                re.push(']');
+            }
+            '\\' => {
+                if let Some(escaped) = chars.next() {
+                    re.push_str(&regex::escape(&escaped.to_string()));
+                }
+            }
+            _ => re.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    re.push('$');
+    Regex::new(&re).ok()
+}
+
+/// Package a context directory as a tar archive, honoring `.dockerignore`.
+/// The Dockerfile and `.dockerignore` are always sent, as the docker CLI does.
+pub fn pack_context(context_dir: &Path, dockerfile: &str) -> Result<Vec<u8>> {
+    let ignore = match std::fs::read_to_string(context_dir.join(".dockerignore")) {
+        Ok(contents) => DockerIgnore::parse(&contents),
+        Err(_) => DockerIgnore::default(),
+    };
+    let keep = [
+        dockerfile.trim_start_matches("./").to_string(),
+        ".dockerignore".to_string(),
+    ];
+
+    let mut builder = tar::Builder::new(Vec::new());
+    builder.follow_symlinks(false);
+    append_dir(&mut builder, context_dir, "", &ignore, &keep)?;
+    let tar = builder
+        .into_inner()
+        .map_err(|e| DockerError::Image(format!("Failed to package build context: {}", e)))?;
+    debug!(
+        "Packed build context {} ({} bytes)",
+        context_dir.display(),
+        tar.len()
+    );
+    Ok(tar)
+}
+
+fn append_dir(
+    builder: &mut tar::Builder<Vec<u8>>,
+    dir: &Path,
+    prefix: &str,
+    ignore: &DockerIgnore,
+    keep: &[String],
+) -> Result<()> {
+    let context_error =
+        |e: std::io::Error| DockerError::Image(format!("Failed to read build context: {}", e));
+
+    let mut entries = std::fs::read_dir(dir)
+        .map_err(context_error)?
+        .collect::<std::io::Result<Vec<_>>>()
+        .map_err(context_error)?;
+    // Sorted for a stable archive, which keeps the build cache warm
+    entries.sort_by_key(|e| e.file_name());
+
+    for entry in entries {
+        let name = entry.file_name().to_string_lossy().into_owned();
+        let rel = format!("{}{}", prefix, name);
+        let excluded = ignore.is_excluded(&rel) && !keep.contains(&rel);
+        let file_type = entry.file_type().map_err(context_error)?;
+
+        if file_type.is_dir() {
+            // Exceptions or kept files may re-include something below an
+            // excluded directory
+            let dir_prefix = format!("{}/", rel);
+            let holds_kept = keep.iter().any(|k| k.starts_with(&dir_prefix));
+            if excluded && !ignore.has_exceptions() && !holds_kept {
+                continue;
+            }
+            if !excluded {
+                builder
+                    .append_dir(&rel, entry.path())
+                    .map_err(context_error)?;
+            }
+            append_dir(builder, &entry.path(), &dir_prefix, ignore, keep)?;
+        } else if !excluded {
+            builder
+                .append_path_with_name(entry.path(), &rel)
+                .map_err(context_error)?;
+        }
+    }
+    Ok(())
+}
+
+/// How a line of build output should be shown
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuildLineKind {
+    /// Start of a Dockerfile instruction
+    Step,
+    Output,
+    Error,
+}
+
+/// One line of build output
+#[derive(Debug, Clone, PartialEq)]
+pub struct BuildLine {
+    pub text: String,
+    pub kind: BuildLineKind,
+}
+
+/// Split a build error into display lines
+pub fn error_lines(error: &str) -> Vec<BuildLine> {
+    error
+        .lines()
+        .map(|line| BuildLine {
+            text: line.to_string(),
+            kind: BuildLineKind::Error,
+        })
+        .collect()
+}
+
+/// Split a build stream message into display lines
+pub fn build_lines(info: &BuildInfo) -> Vec<BuildLine> {
+    if let Some(stream) = &info.stream {
+        return stream
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                let kind = if line.starts_with("Step ") {
+                    BuildLineKind::Step
+                } else if line.starts_with("ERROR") || line.contains("returned a non-zero code") {
+                    BuildLineKind::Error
+                } else {
+                    BuildLineKind::Output
+                };
+                BuildLine {
+                    text: line.to_string(),
+                    kind,
+                }
+            })
+            .collect();
+    }
+    // Base image pull status; byte progress is too chatty for the pane
+    match (&info.status, &info.progress) {
+        (Some(status), None) => vec![BuildLine {
+            text: match &info.id {
+                Some(id) => format!("{}: {}", id, status),
+                None => status.clone(),
+            },
+            kind: BuildLineKind::Output,
+        }],
+        _ => Vec::new(),
+    }
+}
+
+impl DockerClient {
+    /// Build an image from a packed context, streaming the daemon's output.
+    /// A failing step arrives as `Error::DockerStreamError` with the
+    /// daemon's message.
+    pub fn build_image(
+        &self,
+        options: &BuildOptions,
+        context: Vec<u8>,
+    ) -> impl Stream<Item = std::result::Result<BuildInfo, bollard::errors::Error>> + '_ {
+        info!(
+            "Building {} from {}",
+            options
+                .tags
+                .first()
+                .map(String::as_str)
+                .unwrap_or("<untagged>"),
+            options.context_dir.display()
+        );
+        let build_options = BuildImageOptions {
+            dockerfile: options.dockerfile.clone(),
+            t: options.tags.first().cloned().unwrap_or_default(),
+            target: options.target.clone(),
+            buildargs: options.build_args.clone(),
+            rm: true,
+            ..Default::default()
+        };
+        self.inner()
+            .build_image(build_options, None, Some(context.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dockerignore_patterns() {
+        let ignore = DockerIgnore::parse(
+            "# comment\n\
+             node_modules\n\
+             *.log\n\
+             **/*.tmp\n\
+             /build/\n\
+             docs/*\n\
+             !docs/README.md\n",
+        );
+        assert!(ignore.is_excluded("node_modules"));
+        assert!(ignore.is_excluded("node_modules/lib/index.js"));
+        assert!(ignore.is_excluded("debug.log"));
+        assert!(!ignore.is_excluded("logs/debug.log"));
+        assert!(ignore.is_excluded("a/b/c.tmp"));
+        assert!(ignore.is_excluded("x.tmp"));
+        assert!(ignore.is_excluded("build/out.bin"));
+        assert!(ignore.is_excluded("docs/guide.md"));
+        assert!(!ignore.is_excluded("docs/README.md"));
+        assert!(!ignore.is_excluded("src/main.rs"));
+    }
+
+    #[test]
+    fn packs_context_without_ignored_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::write(root.join("Dockerfile"), "FROM scratch\n").unwrap();
+        std::fs::write(root.join(".dockerignore"), "target\n*.md\nDockerfile\n").unwrap();
+        std::fs::write(root.join("README.md"), "readme").unwrap();
+        std::fs::create_dir_all(root.join("target/debug")).unwrap();
+        std::fs::write(root.join("target/debug/app"), "bin").unwrap();
+        std::fs::create_dir(root.join("src")).unwrap();
+        std::fs::write(root.join("src/main.rs"), "fn main() {}").unwrap();
+
+        let tar = pack_context(root, "Dockerfile").unwrap();
+        let mut archive = tar::Archive::new(tar.as_slice());
+        let mut names: Vec<String> = archive
+            .entries()
+            .unwrap()
+            .map(|e| e.unwrap().path().unwrap().display().to_string())
+            .collect();
+        names.sort();
+        assert_eq!(
+            names,
+            vec![".dockerignore", "Dockerfile", "src", "src/main.rs"]
+        );
+    }
+
+    #[test]
+    fn keeps_dockerfile_inside_ignored_directory() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        std::fs::write(root.join(".dockerignore"), "docker/\n").unwrap();
+        std::fs::create_dir(root.join("docker")).unwrap();
+        std::fs::write(root.join("docker/Dockerfile"), "FROM scratch\n").unwrap();
+        std::fs::write(root.join("docker/secrets.env"), "TOKEN=x").unwrap();
+
+        let tar = pack_context(root, "./docker/Dockerfile").unwrap();
+        let mut archive = tar::Archive::new(tar.as_slice());
+        let mut names: Vec<String> = archive
+            .entries()
+            .unwrap()
+            .map(|e| e.unwrap().path().unwrap().display().to_string())
+            .collect();
+        names.sort();
+        assert_eq!(names, vec![".dockerignore", "docker/Dockerfile"]);
+    }
+
+    #[test]
+    fn parses_args_tags_and_output() {
+        let args = parse_build_args("VERSION=1.2, DEBUG ,\nURL=http://x?a=b");
+        assert_eq!(args["VERSION"], "1.2");
+        assert_eq!(args["DEBUG"], "");
+        assert_eq!(args["URL"], "http://x?a=b");
+        assert_eq!(
+            parse_tags("app:1, app:latest  reg/app"),
+            vec!["app:1", "app:latest", "reg/app"]
+        );
+
+        let lines = build_lines(&BuildInfo {
+            stream: Some("Step 2/4 : RUN make\n ---> Running in abc\n".into()),
+            ..Default::default()
+        });
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0].kind, BuildLineKind::Step);
+        assert_eq!(lines[1].kind, BuildLineKind::Output);
+
+        let lines = error_lines("The command '/bin/sh -c make' returned a non-zero code: 2");
+        assert_eq!(lines.len(), 1);
+        assert_eq!(lines[0].kind, BuildLineKind::Error);
+    }
+}
@@ -0,0 +1,427 @@
+//! Policy-based image cleanup: plan what a policy would delete, then run it
+//!
+//! Docker doesn't record when an image was last used, so "unused for N days"
+//! means no container (running or stopped) references the image and it was
+//! neither built nor tagged in the last N days. The build time (`Created`)
+//! is kept by pulls, so a freshly pulled old image counts by its last tag
+//! time (`LastTagTime` from inspect) instead.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{DateTime, Utc};
+use tracing::{info, warn};
+
+use crate::config::CleanupPolicy;
+use crate::core::{ContainerSummary, ImageSummary};
+use crate::docker::DockerClient;
+
+/// An image a policy would delete
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleanupCandidate {
+    pub id: String,
+    pub short_id: String,
+    /// Tags removed with the image (empty for dangling images)
+    pub tags: Vec<String>,
+    /// Space freed: the image size minus layers shared with other images,
+    /// when Docker reports them
+    pub size: i64,
+    pub created: DateTime<Utc>,
+    /// Why the policy selected it
+    pub reason: String,
+}
+
+impl CleanupCandidate {
+    pub fn name(&self) -> String {
+        self.tags
+            .first()
+            .cloned()
+            .unwrap_or_else(|| self.short_id.clone())
+    }
+}
+
+/// What a policy would delete
+#[derive(Debug, Clone, PartialEq)]
+pub struct CleanupPlan {
+    pub policy: String,
+    /// Newest first
+    pub candidates: Vec<CleanupCandidate>,
+    /// Matching images skipped because a container uses them
+    pub in_use: usize,
+    /// Matching images skipped by an exclusion rule
+    pub excluded: usize,
+    /// Set when the policy can't select anything
+    pub problem: Option<String>,
+}
+
+impl CleanupPlan {
+    pub fn reclaimable(&self) -> i64 {
+        self.candidates.iter().map(|c| c.size).sum()
+    }
+}
+
+/// Repository part of a `repo:tag` reference
+fn repository(tag: &str) -> &str {
+    match tag.rsplit_once(':') {
+        Some((repo, tag)) if !tag.contains('/') => repo,
+        _ => tag,
+    }
+}
+
+/// Match `text` against a pattern where `*` matches any run of characters
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let parts: Vec<&str> = pattern.split('*').collect();
+    if parts.len() == 1 {
+        return pattern == text;
+    }
+    let (first, last) = (parts[0], parts[parts.len() - 1]);
+    if !text.starts_with(first) || !text.ends_with(last) || text.len() < first.len() + last.len() {
+        return false;
+    }
+    let mut rest = &text[first.len()..text.len() - last.len()];
+    for part in &parts[1..parts.len() - 1] {
+        match rest.find(part) {
+            Some(pos) => rest = &rest[pos + part.len()..],
+            None => return false,
+        }
+    }
+    true
+}
+
+fn excluded(policy: &CleanupPolicy, image: &ImageSummary) -> bool {
+    let label_match = policy
+        .exclude_labels
+        .iter()
+        .any(|rule| match rule.split_once('=') {
+            Some((key, value)) => image.labels.get(key).is_some_and(|v| v == value),
+            None => image.labels.contains_key(rule.as_str()),
+        });
+    let repo_match = image.repo_tags.iter().any(|tag| {
+        policy
+            .exclude_repositories
+            .iter()
+            .any(|pattern| glob_match(pattern, repository(tag)))
+    });
+    label_match || repo_match
+}
+
+/// Tags beyond the newest `keep` of their repository
+fn expired_tags(images: &[ImageSummary], keep: usize) -> HashSet<&str> {
+    let mut by_repo: HashMap<&str, Vec<(&str, DateTime<Utc>)>> = HashMap::new();
+    for image in images {
+        for tag in image.repo_tags.iter().filter(|t| !t.starts_with("<none>")) {
+            by_repo
+                .entry(repository(tag))
+                .or_default()
+                .push((tag.as_str(), image.created));
+        }
+    }
+    let mut expired = HashSet::new();
+    for tags in by_repo.values_mut() {
+        tags.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+        expired.extend(tags.iter().skip(keep).map(|(tag, _)| *tag));
+    }
+    expired
+}
+
+/// Work out which images `policy` would delete. `last_tagged` maps image
+/// IDs to when they were last tagged (e.g. pulled), see
+/// [`DockerClient::last_tag_times`].
+pub fn plan_cleanup(
+    policy: &CleanupPolicy,
+    images: &[ImageSummary],
+    containers: &[ContainerSummary],
+    last_tagged: &HashMap<String, DateTime<Utc>>,
+    now: DateTime<Utc>,
+) -> CleanupPlan {
+    let mut plan = CleanupPlan {
+        policy: policy.name.clone(),
+        candidates: Vec::new(),
+        in_use: 0,
+        excluded: 0,
+        problem: None,
+    };
+    if policy.unused_days.is_none() && policy.keep_last.is_none() {
+        plan.problem = Some("set unused_days or keep_last to select images".to_string());
+        return plan;
+    }
+
+    let used: HashSet<&str> = containers.iter().map(|c| c.image_id.as_str()).collect();
+    // Parent images go away with their last child, and can't be removed before
+    let parents: HashSet<&str> = images.iter().map(|i| i.parent_id.as_str()).collect();
+    let expired = policy.keep_last.map(|keep| expired_tags(images, keep));
+
+    for image in images.iter().filter(|i| !parents.contains(i.id.as_str())) {
+        let tags: Vec<String> = image
+            .repo_tags
+            .iter()
+            .filter(|t| !t.starts_with("<none>"))
+            .cloned()
+            .collect();
+        let mut reasons = Vec::new();
+
+        if let Some(days) = policy.unused_days {
+            let tagged = last_tagged
+                .get(&image.id)
+                .copied()
+                .filter(|t| *t > image.created);
+            let age = now
+                .signed_duration_since(tagged.unwrap_or(image.created))
+                .num_days();
+            if age <= days as i64 {
+                continue;
+            }
+            reasons.push(match tagged {
+                Some(_) => format!("unused, tagged {}d ago", age),
+                None => format!("unused, built {}d ago", age),
+            });
+        }
+        if let (Some(expired), Some(keep)) = (&expired, policy.keep_last) {
+            // Untagged images have no place in a repository's history
+            if tags.is_empty() || !tags.iter().all(|t| expired.contains(t.as_str())) {
+                continue;
+            }
+            reasons.push(format!("older than the newest {}", keep));
+        }
+
+        if used.contains(image.id.as_str()) {
+            plan.in_use += 1;
+            continue;
+        }
+        if excluded(policy, image) {
+            plan.excluded += 1;
+            continue;
+        }
+
+        let shared = image.shared_size.max(0);
+        plan.candidates.push(CleanupCandidate {
+            id: image.id.clone(),
+            short_id: image.short_id.clone(),
+            tags,
+            size: (image.size - shared).max(0),
+            created: image.created,
+            reason: reasons.join(", "),
+        });
+    }
+    plan.candidates
+        .sort_by_key(|c| std::cmp::Reverse(c.created));
+    plan
+}
+
+/// Outcome of running a plan
+#[derive(Debug, Clone, Default)]
+pub struct CleanupResult {
+    pub removed: usize,
+    pub reclaimed: i64,
+    /// Image name and error for each image that could not be removed
+    pub failed: Vec<(String, String)>,
+}
+
+/// Parse an inspect timestamp, treating the zero value as unset
+fn parse_tag_time(value: &str) -> Option<DateTime<Utc>> {
+    let parsed = DateTime::parse_from_rfc3339(value).ok()?;
+    (parsed.timestamp() > 0).then(|| parsed.with_timezone(&Utc))
+}
+
+impl DockerClient {
+    /// When each image old enough for an `unused_days` rule was last
+    /// tagged. Only inspect reports this; images that fail to inspect are
+    /// left out.
+    pub async fn last_tag_times(
+        &self,
+        images: &[ImageSummary],
+        policies: &[CleanupPolicy],
+        now: DateTime<Utc>,
+    ) -> HashMap<String, DateTime<Utc>> {
+        let mut times = HashMap::new();
+        let Some(days) = policies.iter().filter_map(|p| p.unused_days).min() else {
+            return times;
+        };
+        let built_before = now - chrono::Duration::days(days as i64);
+        for image in images.iter().filter(|i| i.created < built_before) {
+            match self.inner().inspect_image(&image.id).await {
+                Ok(inspect) => {
+                    if let Some(time) = inspect
+                        .metadata
+                        .and_then(|m| m.last_tag_time)
+                        .and_then(|t| parse_tag_time(&t))
+                    {
+                        times.insert(image.id.clone(), time);
+                    }
+                }
+                Err(e) => warn!("Failed to inspect image {}: {}", image.id, e),
+            }
+        }
+        times
+    }
+
+    /// Delete the images in a plan. Tags are removed one by one, so an image
+    /// goes away with its last tag and is never force-removed from under a
+    /// container created since the plan was made.
+    pub async fn run_cleanup(&self, plan: &CleanupPlan) -> CleanupResult {
+        info!(
+            "Running cleanup policy '{}' on {} image(s)",
+            plan.policy,
+            plan.candidates.len()
+        );
+        let mut result = CleanupResult::default();
+        for candidate in &plan.candidates {
+            let targets = if candidate.tags.is_empty() {
+                vec![candidate.id.clone()]
+            } else {
+                candidate.tags.clone()
+            };
+            let mut error = None;
+            for target in &targets {
+                if let Err(e) = self.remove_image(target, false).await {
+                    error = Some(e.to_string());
+                    break;
+                }
+            }
+            match error {
+                None => {
+                    result.removed += 1;
+                    result.reclaimed += candidate.size;
+                }
+                Some(e) => result.failed.push((candidate.name(), e)),
+            }
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    fn image(id: &str, tags: &[&str], days_old: i64, now: DateTime<Utc>) -> ImageSummary {
+        ImageSummary {
+            id: id.into(),
+            short_id: id.into(),
+            repo_tags: tags.iter().map(|t| t.to_string()).collect(),
+            created: now - Duration::days(days_old),
+            size: 100,
+            shared_size: -1,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn matches_globs() {
+        assert!(glob_match("postgres", "postgres"));
+        assert!(!glob_match("postgres", "postgres-exporter"));
+        assert!(glob_match(
+            "registry.local/base/*",
+            "registry.local/base/debian"
+        ));
+        assert!(glob_match("*/ci-*", "team/ci-runner"));
+        assert!(glob_match("a*b*c", "axxbyyc"));
+        assert!(!glob_match("a*b*c", "axxc"));
+        assert!(!glob_match("ab*ba", "aba"));
+    }
+
+    #[test]
+    fn plans_by_age_and_tag_history() {
+        let now = Utc::now();
+        let images = vec![
+            image("a1", &["app:1"], 90, now),
+            image("a2", &["app:2"], 60, now),
+            image("a3", &["app:3"], 10, now),
+            image("a4", &["app:4", "app:latest"], 1, now),
+            image("db", &["postgres:16"], 200, now),
+            image("old", &["tool:1"], 45, now),
+            image("dangling", &[], 100, now),
+            ImageSummary {
+                parent_id: "dangling".into(),
+                ..image("child", &["child:1"], 5, now)
+            },
+        ];
+        let containers = vec![ContainerSummary {
+            image_id: "old".into(),
+            ..Default::default()
+        }];
+
+        let by_age = CleanupPolicy {
+            name: "stale".into(),
+            unused_days: Some(30),
+            exclude_repositories: vec!["postgres".into()],
+            ..Default::default()
+        };
+        let plan = plan_cleanup(&by_age, &images, &containers, &HashMap::new(), now);
+        let ids: Vec<&str> = plan.candidates.iter().map(|c| c.id.as_str()).collect();
+        assert_eq!(ids, vec!["a2", "a1"]);
+        assert_eq!(plan.in_use, 1);
+        assert_eq!(plan.excluded, 1);
+        assert_eq!(plan.reclaimable(), 200);
+        assert_eq!(plan.candidates[0].reason, "unused, built 60d ago");
+
+        let keep_two = CleanupPolicy {
+            name: "history".into(),
+            keep_last: Some(2),
+            ..Default::default()
+        };
+        let plan = plan_cleanup(&keep_two, &images, &containers, &HashMap::new(), now);
+        let ids: Vec<&str> = plan.candidates.iter().map(|c| c.id.as_str()).collect();
+        // app:4 and app:latest fill the two slots, so app:3 goes too
+        assert_eq!(ids, vec!["a3", "a2", "a1"]);
+
+        let both = CleanupPolicy {
+            unused_days: Some(30),
+            ..keep_two
+        };
+        assert_eq!(
+            plan_cleanup(&both, &images, &containers, &HashMap::new(), now)
+                .candidates
+                .len(),
+            2
+        );
+
+        let empty = CleanupPolicy::default();
+        assert!(
+            plan_cleanup(&empty, &images, &containers, &HashMap::new(), now)
+                .problem
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn recently_tagged_images_are_not_unused() {
+        let now = Utc::now();
+        let images = vec![
+            image("pulled", &["debian:12"], 90, now),
+            image("stale", &["debian:11"], 90, now),
+        ];
+        let last_tagged = HashMap::from([
+            ("pulled".to_string(), now - Duration::days(2)),
+            ("stale".to_string(), now - Duration::days(40)),
+        ]);
+        let policy = CleanupPolicy {
+            name: "stale".into(),
+            unused_days: Some(30),
+            ..Default::default()
+        };
+        let plan = plan_cleanup(&policy, &images, &[], &last_tagged, now);
+        assert_eq!(plan.candidates.len(), 1);
+        assert_eq!(plan.candidates[0].id, "stale");
+        assert_eq!(plan.candidates[0].reason, "unused, tagged 40d ago");
+        assert_eq!(parse_tag_time("0001-01-01T00:00:00Z"), None);
+    }
+
+    #[test]
+    fn excludes_by_label() {
+        let now = Utc::now();
+        let mut pinned = image("p", &["app:1"], 90, now);
+        pinned.labels.insert("keep".into(), "true".into());
+        let mut other = image("o", &["app:0"], 90, now);
+        other.labels.insert("keep".into(), "false".into());
+        let policy = CleanupPolicy {
+            name: "stale".into(),
+            unused_days: Some(30),
+            exclude_labels: vec!["keep=true".into()],
+            ..Default::default()
+        };
+        let plan = plan_cleanup(&policy, &[pinned, other], &[], &HashMap::new(), now);
+        assert_eq!(plan.candidates.len(), 1);
+        assert_eq!(plan.candidates[0].id, "o");
+    }
+}
@@ -1,8 +1,8 @@
 //! Container operations
 
 use bollard::container::{
-    KillContainerOptions, ListContainersOptions, RemoveContainerOptions, RestartContainerOptions,
-    StopContainerOptions,
+    KillContainerOptions, ListContainersOptions, RemoveContainerOptions, RenameContainerOptions,
+    RestartContainerOptions, StopContainerOptions,
 };
 use tracing::{debug, info, warn};
 
@@ -160,6 +160,16 @@ impl DockerClient {
         info!("Container {} removed successfully", id);
         Ok(())
     }
+
+    /// Rename a container
+    pub async fn rename_container(&self, id: &str, name: &str) -> Result<()> {
+        self.inner()
+            .rename_container(id, RenameContainerOptions { name })
+            .await
+            .map_err(|e| DockerError::Container(format!("Failed to rename {}: {}", id, e)))?;
+        info!("Container {} renamed to {}", id, name);
+        Ok(())
+    }
 }
 
 // Conversion implementations
@@ -187,19 +197,23 @@ impl From<bollard::models::ContainerSummary> for ContainerSummary {
             .clone()
             .unwrap_or_default()
             .into_iter()
-            .filter_map(|p| {
-                Some(crate::core::PortMapping {
-                    ip: p.ip.map(|s| s.to_string()),
-                    private_port: p.private_port,
-                    public_port: p.public_port.map(|p| p),
-                    protocol: p
-                        .typ
-                        .map(|t| format!("{:?}", t))
-                        .unwrap_or_else(|| "tcp".to_string()),
-                })
+            .map(|p| crate::core::PortMapping {
+                ip: p.ip.map(|s| s.to_string()),
+                private_port: p.private_port,
+                public_port: p.public_port,
+                protocol: p
+                    .typ
+                    .map(|t| format!("{:?}", t))
+                    .unwrap_or_else(|| "tcp".to_string()),
             })
             .collect();
 
+        let network_settings = c
+            .network_settings
+            .clone()
+            .and_then(|ns| ns.networks)
+            .unwrap_or_default();
+
         // Parse labels for compose project/service
         let labels = c.labels.clone().unwrap_or_default();
         let compose_project = labels.get("com.docker.compose.project").cloned();
@@ -222,11 +236,17 @@ impl From<bollard::models::ContainerSummary> for ContainerSummary {
             status,
             health: None,   // Will be populated by inspect
             mounts: vec![], // Will be populated by inspect
-            networks: c
-                .network_settings
-                .clone()
-                .map(|ns| ns.networks.unwrap_or_default().into_keys().collect())
-                .unwrap_or_default(),
+            networks: network_settings.keys().cloned().collect(),
+            network_ips: network_settings
+                .into_iter()
+                .filter_map(|(name, endpoint)| {
+                    endpoint
+                        .ip_address
+                        .filter(|ip| !ip.is_empty())
+                        .or(endpoint.global_ipv6_address.filter(|ip| !ip.is_empty()))
+                        .map(|ip| (name, ip))
+                })
+                .collect(),
             compose_project,
             compose_service,
         }
@@ -0,0 +1,123 @@
+//! Exit and restart information for crash detection
+
+use chrono::{DateTime, Datelike, Utc};
+use futures::StreamExt;
+
+use crate::core::{DockerError, Result};
+use crate::docker::DockerClient;
+
+/// How many containers to inspect at once during a refresh
+const INSPECT_CONCURRENCY: usize = 8;
+
+/// Restart and last-exit information from container inspect
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExitInfo {
+    pub container_id: String,
+    pub restart_count: i64,
+    pub restarting: bool,
+    pub exit_code: i64,
+    pub oom_killed: bool,
+    pub error: String,
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+/// Human-readable meaning of a container exit code
+pub fn exit_code_meaning(code: i64) -> String {
+    match code {
+        0 => "Exited normally".to_string(),
+        1 => "Application error".to_string(),
+        2 => "Misuse of shell builtin or invalid arguments".to_string(),
+        125 => "Container failed to run (docker run error)".to_string(),
+        126 => "Command found but could not be invoked".to_string(),
+        127 => "Command not found".to_string(),
+        128 => "Invalid exit argument".to_string(),
+        129..=192 => {
+            let signal = code - 128;
+            match signal_name(signal) {
+                Some(name) => format!("Killed by signal {} ({})", signal, name),
+                None => format!("Killed by signal {}", signal),
+            }
+        }
+        _ => format!("Exited with code {}", code),
+    }
+}
+
+fn signal_name(signal: i64) -> Option<&'static str> {
+    Some(match signal {
+        1 => "SIGHUP",
+        2 => "SIGINT",
+        3 => "SIGQUIT",
+        4 => "SIGILL",
+        6 => "SIGABRT",
+        7 => "SIGBUS",
+        8 => "SIGFPE",
+        9 => "SIGKILL",
+        11 => "SIGSEGV",
+        13 => "SIGPIPE",
+        14 => "SIGALRM",
+        15 => "SIGTERM",
+        _ => return None,
+    })
+}
+
+/// Parse a Docker timestamp, treating the zero value as unset
+fn parse_finished_at(value: Option<&str>) -> Option<DateTime<Utc>> {
+    let parsed = DateTime::parse_from_rfc3339(value?).ok()?;
+    // Docker reports "0001-01-01T00:00:00Z" for containers that never exited
+    (parsed.year() > 1).then(|| parsed.with_timezone(&Utc))
+}
+
+impl DockerClient {
+    /// Inspect a container for its restart count and last exit
+    pub async fn exit_info(&self, id: &str) -> Result<ExitInfo> {
+        let inspect = self
+            .inner()
+            .inspect_container(id, None)
+            .await
+            .map_err(|e| DockerError::Container(format!("Failed to inspect: {}", e)))?;
+        let state = inspect.state.unwrap_or_default();
+
+        Ok(ExitInfo {
+            container_id: inspect.id.unwrap_or_else(|| id.to_string()),
+            restart_count: inspect.restart_count.unwrap_or(0),
+            restarting: state.restarting.unwrap_or(false),
+            exit_code: state.exit_code.unwrap_or(0),
+            oom_killed: state.oom_killed.unwrap_or(false),
+            error: state.error.unwrap_or_default(),
+            finished_at: parse_finished_at(state.finished_at.as_deref()),
+        })
+    }
+
+    /// Inspect several containers concurrently, skipping any that fail
+    pub async fn exit_infos(&self, ids: &[String]) -> Vec<ExitInfo> {
+        futures::stream::iter(ids)
+            .map(|id| self.exit_info(id))
+            .buffer_unordered(INSPECT_CONCURRENCY)
+            .filter_map(|result| async move { result.ok() })
+            .collect()
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn explains_exit_codes() {
+        assert_eq!(exit_code_meaning(0), "Exited normally");
+        assert_eq!(exit_code_meaning(127), "Command not found");
+        assert_eq!(exit_code_meaning(137), "Killed by signal 9 (SIGKILL)");
+        assert_eq!(exit_code_meaning(139), "Killed by signal 11 (SIGSEGV)");
+        assert_eq!(exit_code_meaning(160), "Killed by signal 32");
+        assert_eq!(exit_code_meaning(3), "Exited with code 3");
+    }
+
+    #[test]
+    fn zero_finish_time_is_unset() {
+        assert_eq!(parse_finished_at(Some("0001-01-01T00:00:00Z")), None);
+        assert_eq!(parse_finished_at(None), None);
+        let parsed = parse_finished_at(Some("2024-05-01T10:00:00.123456789Z")).unwrap();
+        assert_eq!(parsed.timestamp(), 1_714_557_600);
+    }
+}
@@ -0,0 +1,155 @@
+//! Ephemeral debug sidecars for containers without a shell
+
+use std::collections::HashMap;
+
+use bollard::container::{Config, CreateContainerOptions, RemoveContainerOptions};
+use bollard::models::{HostConfig, MountPoint, MountPointTypeEnum};
+use tracing::{debug, info};
+
+use crate::core::{DockerError, Result};
+use crate::docker::DockerClient;
+
+/// Label marking a sidecar with the container it debugs
+pub const DEBUG_SIDECAR_LABEL: &str = "contui.debug-sidecar-for";
+
+/// Bind specs that mount the target's volumes and bind mounts at the same paths
+pub fn sidecar_binds(mounts: &[MountPoint]) -> Vec<String> {
+    mounts
+        .iter()
+        .filter_map(|m| {
+            let destination = m.destination.as_deref()?;
+            let source = match m.typ {
+                Some(MountPointTypeEnum::VOLUME) => m.name.as_deref()?,
+                Some(MountPointTypeEnum::BIND) => m.source.as_deref()?,
+                _ => return None,
+            };
+            let mut bind = format!("{}:{}", source, destination);
+            if m.rw == Some(false) {
+                bind.push_str(":ro");
+            }
+            Some(bind)
+        })
+        .collect()
+}
+
+impl DockerClient {
+    /// Start a toolbox container sharing the target's PID and network
+    /// namespaces and mounts. Returns the sidecar container ID.
+    pub async fn create_debug_sidecar(&self, target_id: &str, image: &str) -> Result<String> {
+        let inspect = self
+            .inner()
+            .inspect_container(target_id, None)
+            .await
+            .map_err(|e| DockerError::Container(format!("Failed to inspect: {}", e)))?;
+        let target_name = inspect
+            .name
+            .unwrap_or_default()
+            .trim_start_matches('/')
+            .to_string();
+        let binds = sidecar_binds(&inspect.mounts.unwrap_or_default());
+
+        self.ensure_image(image).await?;
+
+        let short_id: String = target_id.chars().take(12).collect();
+        let name = format!(
+            "contui-debug-{}-{}",
+            short_id,
+            &uuid::Uuid::new_v4().simple().to_string()[..6]
+        );
+        let mut labels = HashMap::new();
+        labels.insert(DEBUG_SIDECAR_LABEL.to_string(), target_name);
+
+        let config = Config {
+            image: Some(image.to_string()),
+            tty: Some(true),
+            open_stdin: Some(true),
+            labels: Some(labels),
+            host_config: Some(HostConfig {
+                pid_mode: Some(format!("container:{}", target_id)),
+                network_mode: Some(format!("container:{}", target_id)),
+                binds: if binds.is_empty() { None } else { Some(binds) },
+                cap_add: Some(vec!["SYS_PTRACE".to_string()]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        debug!("Creating debug sidecar {} for {}", name, target_id);
+        let created = self
+            .inner()
+            .create_container(
+                Some(CreateContainerOptions {
+                    name: name.as_str(),
+                    platform: None,
+                }),
+                config,
+            )
+            .await
+            .map_err(|e| DockerError::Container(format!("Failed to create sidecar: {}", e)))?;
+
+        if let Err(e) = self
+            .inner()
+            .start_container::<String>(&created.id, None)
+            .await
+        {
+            let _ = self.remove_debug_sidecar(&created.id).await;
+            return Err(DockerError::Container(format!("Failed to start sidecar: {}", e)).into());
+        }
+
+        info!("Debug sidecar {} started for {}", created.id, target_id);
+        Ok(created.id)
+    }
+
+    /// Force-remove a debug sidecar
+    pub async fn remove_debug_sidecar(&self, id: &str) -> Result<()> {
+        self.inner()
+            .remove_container(
+                id,
+                Some(RemoveContainerOptions {
+                    force: true,
+                    v: false,
+                    link: false,
+                }),
+            )
+            .await
+            .map_err(|e| DockerError::Container(format!("Failed to remove sidecar: {}", e)))?;
+        info!("Debug sidecar {} removed", id);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn binds_mirror_volumes_and_bind_mounts() {
+        let mounts = vec![
+            MountPoint {
+                typ: Some(MountPointTypeEnum::VOLUME),
+                name: Some("data".into()),
+                source: Some("/var/lib/docker/volumes/data/_data".into()),
+                destination: Some("/data".into()),
+                rw: Some(true),
+                ..Default::default()
+            },
+            MountPoint {
+                typ: Some(MountPointTypeEnum::BIND),
+                source: Some("/etc/app".into()),
+                destination: Some("/config".into()),
+                rw: Some(false),
+                ..Default::default()
+            },
+            MountPoint {
+                typ: Some(MountPointTypeEnum::TMPFS),
+                destination: Some("/tmp".into()),
+                ..Default::default()
+            },
+        ];
+
+        assert_eq!(
+            sidecar_binds(&mounts),
+            vec!["data:/data".to_string(), "/etc/app:/config:ro".to_string()]
+        );
+    }
+}
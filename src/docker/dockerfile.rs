@@ -0,0 +1,314 @@
+//! Approximate Dockerfile reconstruction from image history
+//!
+//! History only records what each step did, so the result is a readable
+//! approximation: build contexts show up as content hashes, build args are
+//! dropped and multi-stage builds collapse into their final stage.
+
+use crate::docker::ImageLayer;
+
+/// One reconstructed instruction
+#[derive(Debug, Clone, PartialEq)]
+pub struct DockerfileStep {
+    pub instruction: String,
+    pub size: i64,
+    /// Comes from the base image rather than this image's own Dockerfile
+    pub inherited: bool,
+}
+
+/// A Dockerfile rebuilt from an image's history
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReconstructedDockerfile {
+    pub image: String,
+    /// Oldest first
+    pub steps: Vec<DockerfileStep>,
+}
+
+impl ReconstructedDockerfile {
+    /// Rebuild the instructions behind `layers` (newest first, as returned
+    /// by `docker history`)
+    pub fn from_history(image: &str, layers: &[ImageLayer]) -> Self {
+        let mut steps: Vec<DockerfileStep> = layers
+            .iter()
+            .rev()
+            .filter_map(|layer| {
+                instruction(&layer.created_by).map(|instruction| DockerfileStep {
+                    instruction,
+                    size: layer.size,
+                    inherited: false,
+                })
+            })
+            .collect();
+
+        // A base image ends with its CMD/ENTRYPOINT, so everything up to the
+        // last one that is followed by further steps was inherited
+        let is_command = |step: &DockerfileStep| {
+            step.instruction.starts_with("CMD ") || step.instruction.starts_with("ENTRYPOINT ")
+        };
+        let tail = steps.iter().rev().take_while(|s| is_command(s)).count();
+        let body = steps.len() - tail;
+        if let Some(last) = steps[..body].iter().rposition(is_command) {
+            for step in &mut steps[..=last] {
+                step.inherited = true;
+            }
+        }
+
+        Self {
+            image: image.to_string(),
+            steps,
+        }
+    }
+
+    pub fn has_base(&self) -> bool {
+        self.steps.iter().any(|s| s.inherited)
+    }
+
+    /// Dockerfile text, with inherited steps listed as comments above `FROM`
+    pub fn to_text(&self) -> String {
+        let mut out = format!(
+            "# Approximate Dockerfile for {}, reconstructed from its history\n",
+            self.image
+        );
+        if self.has_base() {
+            out.push_str("# Inherited from the base image:\n");
+            for step in self.steps.iter().filter(|s| s.inherited) {
+                out.push_str(&format!("#   {}\n", step.instruction));
+            }
+            out.push_str("FROM <base image>\n");
+        } else {
+            out.push_str("FROM scratch\n");
+        }
+        for step in self.steps.iter().filter(|s| !s.inherited) {
+            out.push_str(&step.instruction);
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// Turn one `created_by` entry into a Dockerfile instruction. Handles both
+/// the classic builder (`/bin/sh -c #(nop)  ENV ...`) and BuildKit
+/// (`ENV ...`, `RUN /bin/sh -c ... # buildkit`).
+fn instruction(created_by: &str) -> Option<String> {
+    let text = created_by.trim();
+    let text = text.strip_suffix("# buildkit").unwrap_or(text).trim_end();
+    if text.is_empty() {
+        return None;
+    }
+
+    // Classic builder metadata steps
+    if let Some(rest) = text
+        .strip_prefix("/bin/sh -c #(nop)")
+        .or_else(|| text.strip_prefix("#(nop)"))
+    {
+        return Some(rebuild(rest.trim()));
+    }
+
+    // BuildKit RUN steps keep their keyword
+    let text = text.strip_prefix("RUN ").unwrap_or(text);
+    // Build args used by a RUN step: `|2 A=1 B=2 /bin/sh -c ...`
+    let text = match text.strip_prefix('|') {
+        Some(rest) => {
+            let count: usize = rest
+                .split_whitespace()
+                .next()
+                .and_then(|n| n.parse().ok())
+                .unwrap_or(0);
+            rest.splitn(count + 2, ' ').last().unwrap_or(rest)
+        }
+        None => text,
+    };
+    if let Some(command) = text.strip_prefix("/bin/sh -c ") {
+        return Some(format!("RUN {}", command.trim()));
+    }
+    let keyword = text.split_whitespace().next().unwrap_or("");
+    if keyword.chars().all(|c| c.is_ascii_uppercase()) {
+        Some(rebuild(text))
+    } else {
+        // A shell command recorded without the shell (e.g. `docker commit`)
+        Some(format!("RUN {}", text))
+    }
+}
+
+/// Normalize the arguments of a metadata instruction
+fn rebuild(text: &str) -> String {
+    let (keyword, args) = text.split_once(char::is_whitespace).unwrap_or((text, ""));
+    let args = args.trim();
+    match keyword {
+        "ENV" | "LABEL" => format!("{} {}", keyword, key_values(args)),
+        "EXPOSE" => format!("EXPOSE {}", ports(args)),
+        "CMD" | "ENTRYPOINT" => format!("{} {}", keyword, exec_form(args)),
+        // Classic builder writes `COPY file:<hash> in /dest`
+        "ADD" | "COPY" => format!("{} {}", keyword, args.replacen(" in ", " ", 1)),
+        _ => format!("{} {}", keyword, args).trim_end().to_string(),
+    }
+}
+
+/// `KEY=value` pairs with values quoted where needed. Also accepts the
+/// legacy `KEY value` form.
+fn key_values(args: &str) -> String {
+    let pairs: Vec<(&str, &str)> = if args.split_whitespace().all(|t| t.contains('=')) {
+        args.split_whitespace()
+            .filter_map(|t| t.split_once('='))
+            .collect()
+    } else if let Some((key, value)) = args.split_once('=').filter(|(k, _)| !k.contains(' ')) {
+        vec![(key, value)]
+    } else {
+        let (key, value) = args.split_once(char::is_whitespace).unwrap_or((args, ""));
+        vec![(key, value.trim())]
+    };
+    pairs
+        .into_iter()
+        .map(|(key, value)| format!("{}={}", key, quote(value)))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+fn quote(value: &str) -> String {
+    let quoted = value.len() >= 2 && value.starts_with('"') && value.ends_with('"');
+    if quoted || (!value.is_empty() && !value.contains([' ', '\t', '"', '\\'])) {
+        value.to_string()
+    } else {
+        format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+    }
+}
+
+/// `map[80/tcp:{} 443/tcp:{}]` or `80/tcp 443/tcp` as a port list
+fn ports(args: &str) -> String {
+    let inner = args
+        .strip_prefix("map[")
+        .and_then(|rest| rest.strip_suffix(']'))
+        .unwrap_or(args);
+    inner
+        .split_whitespace()
+        .map(|port| port.trim_end_matches(":{}"))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Go's `["a" "b"]` rendering of an exec form as JSON; shell forms pass
+/// through unchanged
+fn exec_form(args: &str) -> String {
+    let Some(inner) = args.strip_prefix('[').and_then(|a| a.strip_suffix(']')) else {
+        return args.to_string();
+    };
+    let mut items = Vec::new();
+    let mut chars = inner.chars();
+    while let Some(c) = chars.next() {
+        if c != '"' {
+            continue;
+        }
+        let mut item = String::new();
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => {
+                    item.push(c);
+                    item.extend(chars.next());
+                }
+                '"' => break,
+                _ => item.push(c),
+            }
+        }
+        items.push(format!("\"{}\"", item));
+    }
+    format!("[{}]", items.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn history(created_by: &[&str]) -> Vec<ImageLayer> {
+        created_by
+            .iter()
+            .rev()
+            .map(|c| ImageLayer {
+                id: "<missing>".into(),
+                created: "0".into(),
+                created_by: c.to_string(),
+                size: 0,
+                comment: String::new(),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn rebuilds_instructions() {
+        let cases = [
+            (
+                "/bin/sh -c #(nop) ADD file:9a4f77dfaba7fd2aa78186e4ef0e7486ad55101cefc1fabbc1b385601bb38920 in / ",
+                "ADD file:9a4f77dfaba7fd2aa78186e4ef0e7486ad55101cefc1fabbc1b385601bb38920 /",
+            ),
+            (
+                "/bin/sh -c #(nop)  CMD [\"nginx\" \"-g\" \"daemon off;\"]",
+                "CMD [\"nginx\", \"-g\", \"daemon off;\"]",
+            ),
+            (
+                "/bin/sh -c #(nop)  LABEL maintainer=NGINX Docker Maintainers <docker-maint@nginx.com>",
+                "LABEL maintainer=\"NGINX Docker Maintainers <docker-maint@nginx.com>\"",
+            ),
+            ("/bin/sh -c #(nop)  ENV NGINX_VERSION 1.25.3", "ENV NGINX_VERSION=1.25.3"),
+            ("/bin/sh -c #(nop)  EXPOSE 80", "EXPOSE 80"),
+            (
+                "/bin/sh -c set -x && apt-get update",
+                "RUN set -x && apt-get update",
+            ),
+            (
+                "|2 VERSION=1 MODE=prod /bin/sh -c make install",
+                "RUN make install",
+            ),
+            ("ENV PATH=/usr/local/bin:/usr/bin A=1", "ENV PATH=/usr/local/bin:/usr/bin A=1"),
+            ("EXPOSE map[443/tcp:{} 80/tcp:{}]", "EXPOSE 443/tcp 80/tcp"),
+            ("WORKDIR /app", "WORKDIR /app"),
+            ("COPY . . # buildkit", "COPY . ."),
+            (
+                "RUN /bin/sh -c npm ci --omit=dev # buildkit",
+                "RUN npm ci --omit=dev",
+            ),
+            (
+                "RUN |1 TARGET=x /bin/sh -c cargo build # buildkit",
+                "RUN cargo build",
+            ),
+            ("ENTRYPOINT [\"/entrypoint.sh\"]", "ENTRYPOINT [\"/entrypoint.sh\"]"),
+        ];
+        for (created_by, expected) in cases {
+            assert_eq!(instruction(created_by).as_deref(), Some(expected));
+        }
+        assert_eq!(instruction(""), None);
+    }
+
+    #[test]
+    fn marks_base_image_steps() {
+        let layers = history(&[
+            "/bin/sh -c #(nop) ADD file:abc in / ",
+            "/bin/sh -c #(nop)  CMD [\"bash\"]",
+            "WORKDIR /app",
+            "COPY . . # buildkit",
+            "CMD [\"./server\"]",
+        ]);
+        let dockerfile = ReconstructedDockerfile::from_history("app:1", &layers);
+        assert_eq!(
+            dockerfile
+                .steps
+                .iter()
+                .map(|s| s.inherited)
+                .collect::<Vec<_>>(),
+            vec![true, true, false, false, false]
+        );
+        assert_eq!(
+            dockerfile.to_text(),
+            "# Approximate Dockerfile for app:1, reconstructed from its history\n\
+             # Inherited from the base image:\n\
+             #   ADD file:abc /\n\
+             #   CMD [\"bash\"]\n\
+             FROM <base image>\n\
+             WORKDIR /app\n\
+             COPY . .\n\
+             CMD [\"./server\"]\n"
+        );
+
+        // Nothing follows the only CMD, so there is no base to mark
+        let base = ReconstructedDockerfile::from_history("base", &layers[3..]);
+        assert!(!base.has_base());
+        assert!(base.to_text().contains("FROM scratch\nADD file:abc /\n"));
+    }
+}
@@ -71,13 +71,11 @@ impl DockerClient {
             .entrypoint
             .unwrap_or_default()
             .into_iter()
-            .map(Into::into)
             .collect::<Vec<String>>();
         let cmd = config
             .cmd
             .unwrap_or_default()
             .into_iter()
-            .map(Into::into)
             .collect::<Vec<String>>();
         let name = inspect
             .name
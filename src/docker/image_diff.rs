@@ -0,0 +1,262 @@
+//! Side-by-side comparison of two inspected images
+
+use std::collections::BTreeMap;
+
+use crate::docker::{ImageDetails, ImageLayer};
+
+/// How a setting differs between the two images
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffKind {
+    Added,
+    Removed,
+    Changed,
+}
+
+/// One differing setting: old value (left image) and new value (right image)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DiffEntry {
+    pub key: String,
+    pub kind: DiffKind,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+/// Differences between two images, from `left` (older) to `right`
+#[derive(Debug, Clone)]
+pub struct ImageDiff {
+    pub left: String,
+    pub right: String,
+    pub env: Vec<DiffEntry>,
+    pub labels: Vec<DiffEntry>,
+    pub ports: Vec<DiffEntry>,
+    pub entrypoint: Option<DiffEntry>,
+    pub cmd: Option<DiffEntry>,
+    /// Layers both images are built on, oldest first
+    pub shared_layers: Vec<ImageLayer>,
+    /// Layers only in the left image, oldest first
+    pub left_layers: Vec<ImageLayer>,
+    /// Layers only in the right image, oldest first
+    pub right_layers: Vec<ImageLayer>,
+    pub left_size: u64,
+    pub right_size: u64,
+}
+
+impl ImageDiff {
+    /// Size change from left to right
+    pub fn size_delta(&self) -> i64 {
+        self.right_size as i64 - self.left_size as i64
+    }
+
+    /// Whether the configuration (not the layers) is identical
+    pub fn same_config(&self) -> bool {
+        self.env.is_empty()
+            && self.labels.is_empty()
+            && self.ports.is_empty()
+            && self.entrypoint.is_none()
+            && self.cmd.is_none()
+    }
+}
+
+/// Compare key/value settings, sorted by key
+fn diff_map(old: BTreeMap<String, String>, new: BTreeMap<String, String>) -> Vec<DiffEntry> {
+    let mut entries = Vec::new();
+    for (key, old_value) in &old {
+        match new.get(key) {
+            None => entries.push(DiffEntry {
+                key: key.clone(),
+                kind: DiffKind::Removed,
+                old: Some(old_value.clone()),
+                new: None,
+            }),
+            Some(new_value) if new_value != old_value => entries.push(DiffEntry {
+                key: key.clone(),
+                kind: DiffKind::Changed,
+                old: Some(old_value.clone()),
+                new: Some(new_value.clone()),
+            }),
+            Some(_) => {}
+        }
+    }
+    for (key, new_value) in new {
+        if !old.contains_key(&key) {
+            entries.push(DiffEntry {
+                key,
+                kind: DiffKind::Added,
+                old: None,
+                new: Some(new_value),
+            });
+        }
+    }
+    entries.sort_by(|a, b| a.key.cmp(&b.key));
+    entries
+}
+
+/// `KEY=value` pairs as a map
+fn env_map(env: &[String]) -> BTreeMap<String, String> {
+    env.iter()
+        .map(|var| match var.split_once('=') {
+            Some((key, value)) => (key.to_string(), value.to_string()),
+            None => (var.clone(), String::new()),
+        })
+        .collect()
+}
+
+/// Compare an entrypoint or command
+fn diff_command(
+    key: &str,
+    old: &Option<Vec<String>>,
+    new: &Option<Vec<String>>,
+) -> Option<DiffEntry> {
+    let join = |command: &Option<Vec<String>>| {
+        command
+            .as_ref()
+            .filter(|args| !args.is_empty())
+            .map(|args| args.join(" "))
+    };
+    let (old, new) = (join(old), join(new));
+    let kind = match (&old, &new) {
+        (None, None) => return None,
+        (None, Some(_)) => DiffKind::Added,
+        (Some(_), None) => DiffKind::Removed,
+        (Some(a), Some(b)) if a == b => return None,
+        (Some(_), Some(_)) => DiffKind::Changed,
+    };
+    Some(DiffEntry {
+        key: key.to_string(),
+        kind,
+        old,
+        new,
+    })
+}
+
+/// Whether two history entries are the same layer. A layer depends on all
+/// layers below it, so only a common base counts as shared.
+fn same_layer(a: &ImageLayer, b: &ImageLayer) -> bool {
+    a.created == b.created && a.created_by == b.created_by && a.size == b.size
+}
+
+/// Compare two images
+pub fn diff_images(left: &ImageDetails, right: &ImageDetails) -> ImageDiff {
+    let name = |details: &ImageDetails| {
+        details
+            .repo_tags
+            .first()
+            .cloned()
+            .unwrap_or_else(|| details.id.chars().take(19).collect())
+    };
+    let labels = |details: &ImageDetails| details.labels.iter().cloned().collect();
+    let ports = |details: &ImageDetails| {
+        details
+            .exposed_ports
+            .iter()
+            .map(|port| (port.clone(), String::new()))
+            .collect()
+    };
+
+    // History is newest first
+    let left_layers: Vec<ImageLayer> = left.layers.iter().rev().cloned().collect();
+    let right_layers: Vec<ImageLayer> = right.layers.iter().rev().cloned().collect();
+    let shared = left_layers
+        .iter()
+        .zip(&right_layers)
+        .take_while(|(a, b)| same_layer(a, b))
+        .count();
+
+    ImageDiff {
+        left: name(left),
+        right: name(right),
+        env: diff_map(env_map(&left.env), env_map(&right.env)),
+        labels: diff_map(labels(left), labels(right)),
+        ports: diff_map(ports(left), ports(right)),
+        entrypoint: diff_command("Entrypoint", &left.entrypoint, &right.entrypoint),
+        cmd: diff_command("Cmd", &left.cmd, &right.cmd),
+        shared_layers: left_layers[..shared].to_vec(),
+        left_layers: left_layers[shared..].to_vec(),
+        right_layers: right_layers[shared..].to_vec(),
+        left_size: left.size,
+        right_size: right.size,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layer(created_by: &str, size: i64) -> ImageLayer {
+        ImageLayer {
+            id: "<missing>".into(),
+            created: "1700000000".into(),
+            created_by: created_by.into(),
+            size,
+            comment: String::new(),
+        }
+    }
+
+    fn details(tag: &str, env: &[&str], cmd: &[&str], layers: Vec<ImageLayer>) -> ImageDetails {
+        ImageDetails {
+            id: format!("sha256:{}", tag),
+            repo_tags: vec![tag.into()],
+            size: layers.iter().map(|l| l.size as u64).sum(),
+            created: String::new(),
+            author: String::new(),
+            os: "linux".into(),
+            architecture: "amd64".into(),
+            exposed_ports: vec!["80/tcp".into()],
+            env: env.iter().map(|e| e.to_string()).collect(),
+            entrypoint: None,
+            cmd: Some(cmd.iter().map(|c| c.to_string()).collect()),
+            labels: vec![("version".into(), tag.into())],
+            // Newest first, like `docker history`
+            layers: layers.into_iter().rev().collect(),
+        }
+    }
+
+    #[test]
+    fn diffs_config_and_layers() {
+        let base = vec![layer("ADD rootfs", 5000), layer("ENV PATH=/bin", 0)];
+        let mut old_layers = base.clone();
+        old_layers.push(layer("COPY app-1.4 /app", 300));
+        let mut new_layers = base;
+        new_layers.push(layer("COPY app-1.5 /app", 450));
+        new_layers.push(layer("RUN chmod +x /app", 0));
+
+        let old = details(
+            "app:1.4",
+            &["PATH=/bin", "MODE=prod", "OLD=1"],
+            &["serve"],
+            old_layers,
+        );
+        let new = details(
+            "app:1.5",
+            &["PATH=/bin", "MODE=debug", "NEW=1"],
+            &["serve", "--fast"],
+            new_layers,
+        );
+        let diff = diff_images(&old, &new);
+
+        assert_eq!(
+            diff.env
+                .iter()
+                .map(|e| (e.key.as_str(), e.kind))
+                .collect::<Vec<_>>(),
+            vec![
+                ("MODE", DiffKind::Changed),
+                ("NEW", DiffKind::Added),
+                ("OLD", DiffKind::Removed),
+            ]
+        );
+        assert_eq!(diff.labels[0].new.as_deref(), Some("app:1.5"));
+        assert!(diff.ports.is_empty());
+        assert!(diff.entrypoint.is_none());
+        assert_eq!(
+            diff.cmd.as_ref().unwrap().new.as_deref(),
+            Some("serve --fast")
+        );
+
+        assert_eq!(diff.shared_layers.len(), 2);
+        assert_eq!(diff.left_layers.len(), 1);
+        assert_eq!(diff.right_layers.len(), 2);
+        assert_eq!(diff.size_delta(), 150);
+        assert!(!diff.same_config());
+    }
+}
@@ -1,11 +1,24 @@
 //! Image operations
 
-use bollard::image::{ListImagesOptions, RemoveImageOptions};
+use bollard::image::{CreateImageOptions, ListImagesOptions, RemoveImageOptions, TagImageOptions};
+use futures::StreamExt;
 use tracing::{debug, info};
 
 use crate::core::{DockerError, ImageSummary, Result};
 use crate::docker::DockerClient;
 
+/// Split an image reference into (image, tag), defaulting the tag to `latest`
+pub fn split_image_tag(image: &str) -> (&str, &str) {
+    if image.contains('@') {
+        return (image, "");
+    }
+    match image.rsplit_once(':') {
+        // A colon before the last slash belongs to a registry port
+        Some((name, tag)) if !tag.contains('/') => (name, tag),
+        _ => (image, "latest"),
+    }
+}
+
 impl DockerClient {
     /// List all images
     pub async fn list_images(&self, all: bool) -> Result<Vec<ImageSummary>> {
@@ -45,6 +58,57 @@ impl DockerClient {
         Ok(())
     }
 
+    /// Pull an image, waiting for the pull to finish
+    pub async fn pull_image(&self, image: &str) -> Result<()> {
+        info!("Pulling image {}", image);
+        let (from_image, tag) = split_image_tag(image);
+        let options = CreateImageOptions {
+            from_image,
+            tag,
+            ..Default::default()
+        };
+        let mut stream = self.inner().create_image(Some(options), None, None);
+        while let Some(item) = stream.next().await {
+            item.map_err(|e| DockerError::Image(format!("Failed to pull {}: {}", image, e)))?;
+        }
+        info!("Image {} pulled", image);
+        Ok(())
+    }
+
+    /// Add a tag (`repo[:tag]`) to an image
+    pub async fn tag_image(&self, id: &str, reference: &str) -> Result<()> {
+        info!("Tagging image {} as {}", id, reference);
+        let (repo, tag) = split_image_tag(reference);
+        self.inner()
+            .tag_image(id, Some(TagImageOptions { repo, tag }))
+            .await
+            .map_err(|e| DockerError::Image(format!("Failed to tag {}: {}", reference, e)))?;
+        Ok(())
+    }
+
+    /// Remove one tag from an image. The image itself is only deleted when
+    /// this was its last reference.
+    pub async fn untag_image(&self, reference: &str) -> Result<()> {
+        info!("Removing tag {}", reference);
+        let options = RemoveImageOptions {
+            noprune: true,
+            ..Default::default()
+        };
+        self.inner()
+            .remove_image(reference, Some(options), None)
+            .await
+            .map_err(|e| DockerError::Image(format!("Failed to untag {}: {}", reference, e)))?;
+        Ok(())
+    }
+
+    /// Pull an image if it is not present locally
+    pub async fn ensure_image(&self, image: &str) -> Result<()> {
+        if self.inner().inspect_image(image).await.is_ok() {
+            return Ok(());
+        }
+        self.pull_image(image).await
+    }
+
     /// Prune dangling images (untagged images)
     pub async fn prune_images(&self) -> Result<u64> {
         info!("Pruning dangling images");
@@ -93,6 +157,23 @@ impl From<bollard::models::ImageSummary> for ImageSummary {
 mod tests {
     use super::*;
 
+    #[test]
+    fn splits_image_tags() {
+        assert_eq!(split_image_tag("busybox"), ("busybox", "latest"));
+        assert_eq!(
+            split_image_tag("nicolaka/netshoot:v0.13"),
+            ("nicolaka/netshoot", "v0.13")
+        );
+        assert_eq!(
+            split_image_tag("localhost:5000/tools"),
+            ("localhost:5000/tools", "latest")
+        );
+        assert_eq!(
+            split_image_tag("busybox@sha256:abc"),
+            ("busybox@sha256:abc", "")
+        );
+    }
+
     // Note: These tests require Docker to be running
 
     #[tokio::test]
@@ -0,0 +1,575 @@
+//! Layer-by-layer image content analysis
+//!
+//! Reads a `docker save` archive (legacy or OCI layout), walks every layer
+//! tar and replays it over the layers below to find what each one adds,
+//! modifies and removes, and how many bytes end up shadowed by later layers.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+
+use flate2::read::GzDecoder;
+
+use crate::core::{DockerError, Result};
+
+/// Whiteout prefix marking a removed path in an overlay layer
+const WHITEOUT_PREFIX: &str = ".wh.";
+/// Whiteout marking a directory whose lower contents are hidden
+const OPAQUE_WHITEOUT: &str = ".wh..wh..opq";
+
+/// How a layer changes a path
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileChange {
+    Added,
+    Modified,
+    Removed,
+}
+
+/// A file or directory as stored in a layer tar
+#[derive(Debug, Clone)]
+pub struct RawEntry {
+    pub path: String,
+    pub size: u64,
+    pub is_dir: bool,
+}
+
+/// A path changed by a layer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LayerChange {
+    pub path: String,
+    /// File size; for removals, the bytes hidden by the removal
+    pub size: u64,
+    pub is_dir: bool,
+    pub change: FileChange,
+}
+
+/// One row of a layer's file tree
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TreeLine {
+    pub depth: usize,
+    pub name: String,
+    /// File size, or the total of changed files below a directory
+    pub size: u64,
+    pub is_dir: bool,
+    /// `None` for directories only shown as parents of changes
+    pub change: Option<FileChange>,
+}
+
+/// Contents of a single layer
+#[derive(Debug, Clone)]
+pub struct LayerAnalysis {
+    /// Short layer digest
+    pub digest: String,
+    /// Instruction that produced the layer
+    pub created_by: String,
+    /// Total bytes of files stored in the layer
+    pub size: u64,
+    pub changes: Vec<LayerChange>,
+    pub tree: Vec<TreeLine>,
+}
+
+/// A path whose earlier versions are stored but never visible
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WastedFile {
+    pub path: String,
+    /// Bytes of overwritten or removed versions
+    pub bytes: u64,
+    /// Number of overwritten or removed versions
+    pub copies: usize,
+}
+
+/// Layer contents and wasted space for an image
+#[derive(Debug, Clone, Default)]
+pub struct ImageAnalysis {
+    pub layers: Vec<LayerAnalysis>,
+    /// Largest waste first
+    pub wasted: Vec<WastedFile>,
+}
+
+impl ImageAnalysis {
+    /// Bytes stored across all layers
+    pub fn total_size(&self) -> u64 {
+        self.layers.iter().map(|l| l.size).sum()
+    }
+
+    /// Bytes stored but shadowed by later layers
+    pub fn wasted_bytes(&self) -> u64 {
+        self.wasted.iter().map(|w| w.bytes).sum()
+    }
+
+    /// Fraction of stored bytes that are visible in the final image
+    pub fn efficiency(&self) -> f64 {
+        let total = self.total_size();
+        if total == 0 {
+            return 1.0;
+        }
+        1.0 - self.wasted_bytes() as f64 / total as f64
+    }
+}
+
+/// A path present in the merged filesystem
+#[derive(Debug, Clone, Copy)]
+struct Node {
+    size: u64,
+    is_dir: bool,
+}
+
+/// Replay layers in order and record the changes and waste of each.
+/// Each layer is `(digest, created_by, entries)`.
+pub fn analyze(layers: Vec<(String, String, Vec<RawEntry>)>) -> ImageAnalysis {
+    let mut fs: BTreeMap<String, Node> = BTreeMap::new();
+    let mut wasted: HashMap<String, WastedFile> = HashMap::new();
+    let mut waste = |path: &str, bytes: u64| {
+        let entry = wasted
+            .entry(path.to_string())
+            .or_insert_with(|| WastedFile {
+                path: path.to_string(),
+                bytes: 0,
+                copies: 0,
+            });
+        entry.bytes += bytes;
+        entry.copies += 1;
+    };
+
+    let mut analyzed = Vec::new();
+    for (digest, created_by, entries) in layers {
+        let mut changes = Vec::new();
+        let entries: Vec<(String, RawEntry)> = entries
+            .into_iter()
+            .filter_map(|e| {
+                let path = normalize_path(&e.path);
+                (!path.is_empty()).then_some((path, e))
+            })
+            .collect();
+        let is_whiteout = |path: &str| file_name(path).starts_with(WHITEOUT_PREFIX);
+
+        // Whiteouts only hide lower layers, so apply them before this
+        // layer's own files (which may live in an opaque directory)
+        for (path, _) in entries.iter().filter(|(p, _)| is_whiteout(p)) {
+            let (dir, name) = split_parent(path);
+            if name == OPAQUE_WHITEOUT {
+                for (removed, node) in remove_tree(&mut fs, dir, false) {
+                    if !node.is_dir {
+                        waste(&removed, node.size);
+                        changes.push(LayerChange {
+                            path: removed,
+                            size: node.size,
+                            is_dir: false,
+                            change: FileChange::Removed,
+                        });
+                    }
+                }
+            } else {
+                let target = join_path(dir, &name[WHITEOUT_PREFIX.len()..]);
+                let removed = remove_tree(&mut fs, &target, true);
+                let Some(root) = removed.iter().find(|(p, _)| *p == target).map(|(_, n)| *n) else {
+                    continue;
+                };
+                let mut bytes = 0;
+                for (path, node) in &removed {
+                    if !node.is_dir {
+                        waste(path, node.size);
+                        bytes += node.size;
+                    }
+                }
+                changes.push(LayerChange {
+                    path: target,
+                    size: bytes,
+                    is_dir: root.is_dir,
+                    change: FileChange::Removed,
+                });
+            }
+        }
+
+        let mut size = 0;
+        for (path, entry) in entries.into_iter().filter(|(p, _)| !is_whiteout(p)) {
+            if !entry.is_dir {
+                size += entry.size;
+            }
+            let node = Node {
+                size: entry.size,
+                is_dir: entry.is_dir,
+            };
+            let change = match fs.insert(path.clone(), node) {
+                None => FileChange::Added,
+                // Directories are re-listed whenever a child changes
+                Some(old) if old.is_dir && entry.is_dir => continue,
+                Some(old) => {
+                    if !old.is_dir {
+                        waste(&path, old.size);
+                    }
+                    FileChange::Modified
+                }
+            };
+            changes.push(LayerChange {
+                path,
+                size: entry.size,
+                is_dir: entry.is_dir,
+                change,
+            });
+        }
+
+        let tree = tree_lines(&changes);
+        analyzed.push(LayerAnalysis {
+            digest,
+            created_by,
+            size,
+            changes,
+            tree,
+        });
+    }
+
+    let mut wasted: Vec<WastedFile> = wasted.into_values().collect();
+    wasted.sort_by(|a, b| b.bytes.cmp(&a.bytes).then_with(|| a.path.cmp(&b.path)));
+    ImageAnalysis {
+        layers: analyzed,
+        wasted,
+    }
+}
+
+/// Build a file tree (parents before children) from a layer's changes
+pub fn tree_lines(changes: &[LayerChange]) -> Vec<TreeLine> {
+    let mut nodes: BTreeMap<Vec<&str>, TreeLine> = BTreeMap::new();
+    for change in changes {
+        let parts: Vec<&str> = change.path.split('/').collect();
+        for depth in 1..parts.len() {
+            let parent = nodes
+                .entry(parts[..depth].to_vec())
+                .or_insert_with(|| TreeLine {
+                    depth: depth - 1,
+                    name: parts[depth - 1].to_string(),
+                    size: 0,
+                    is_dir: true,
+                    change: None,
+                });
+            parent.size += change.size;
+        }
+        let node = nodes.entry(parts.clone()).or_insert_with(|| TreeLine {
+            depth: parts.len() - 1,
+            name: parts[parts.len() - 1].to_string(),
+            size: 0,
+            is_dir: change.is_dir,
+            change: None,
+        });
+        node.size += change.size;
+        node.change = Some(change.change);
+    }
+    nodes.into_values().collect()
+}
+
+/// Analyze a `docker save` archive of a single image
+pub fn analyze_archive(path: &Path) -> Result<ImageAnalysis> {
+    let invalid =
+        |message: String| DockerError::Image(format!("Invalid image archive: {}", message));
+    let io_error = |e: std::io::Error| invalid(e.to_string());
+
+    // Pass 1: the manifest names the config and the layer order
+    let manifest: serde_json::Value = read_member(path, "manifest.json")?
+        .ok_or_else(|| invalid("manifest.json not found".into()))
+        .and_then(|data| serde_json::from_slice(&data).map_err(|e| invalid(e.to_string())))?;
+    let image = manifest
+        .get(0)
+        .ok_or_else(|| invalid("empty manifest".into()))?;
+    let layer_paths: Vec<String> = image["Layers"]
+        .as_array()
+        .map(|layers| {
+            layers
+                .iter()
+                .filter_map(|l| l.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default();
+    let config_path = image["Config"].as_str().unwrap_or_default().to_string();
+
+    // Pass 2: config history and layer contents, in archive order
+    let mut history = Vec::new();
+    let mut contents: HashMap<String, Vec<RawEntry>> = HashMap::new();
+    let mut archive = tar::Archive::new(File::open(path).map_err(io_error)?);
+    for entry in archive.entries().map_err(io_error)? {
+        let mut entry = entry.map_err(io_error)?;
+        let name = normalize_path(&entry.path().map_err(io_error)?.to_string_lossy());
+        if name == config_path {
+            let mut data = Vec::new();
+            entry.read_to_end(&mut data).map_err(io_error)?;
+            history = config_history(&data);
+        } else if layer_paths.contains(&name) && !contents.contains_key(&name) {
+            let entries = read_layer(&mut entry).map_err(io_error)?;
+            contents.insert(name, entries);
+        }
+    }
+
+    let mut layers = Vec::new();
+    for (index, layer_path) in layer_paths.iter().enumerate() {
+        let entries = contents
+            .get(layer_path)
+            .cloned()
+            .ok_or_else(|| invalid(format!("layer {} not found", layer_path)))?;
+        let created_by = history.get(index).cloned().unwrap_or_default();
+        layers.push((short_layer_digest(layer_path), created_by, entries));
+    }
+    Ok(analyze(layers))
+}
+
+/// Read one member of a tar archive into memory
+fn read_member(path: &Path, member: &str) -> Result<Option<Vec<u8>>> {
+    let io_error = |e: std::io::Error| DockerError::Image(format!("Invalid image archive: {}", e));
+    let mut archive = tar::Archive::new(File::open(path).map_err(io_error)?);
+    for entry in archive.entries().map_err(io_error)? {
+        let mut entry = entry.map_err(io_error)?;
+        if normalize_path(&entry.path().map_err(io_error)?.to_string_lossy()) == member {
+            let mut data = Vec::new();
+            entry.read_to_end(&mut data).map_err(io_error)?;
+            return Ok(Some(data));
+        }
+    }
+    Ok(None)
+}
+
+/// List the entries of a layer tar, which may be gzip-compressed
+fn read_layer(reader: impl Read) -> std::io::Result<Vec<RawEntry>> {
+    let mut reader = BufReader::new(reader);
+    let gzip = reader.fill_buf()?.starts_with(&[0x1f, 0x8b]);
+    let reader: Box<dyn Read> = if gzip {
+        Box::new(GzDecoder::new(reader))
+    } else {
+        Box::new(reader)
+    };
+
+    let mut entries = Vec::new();
+    let mut archive = tar::Archive::new(reader);
+    for entry in archive.entries()? {
+        let entry = entry?;
+        let header = entry.header();
+        entries.push(RawEntry {
+            path: entry.path()?.to_string_lossy().into_owned(),
+            size: header.size().unwrap_or(0),
+            is_dir: header.entry_type().is_dir(),
+        });
+    }
+    Ok(entries)
+}
+
+/// Instructions of the non-empty history entries, one per layer
+fn config_history(config: &[u8]) -> Vec<String> {
+    let Ok(config) = serde_json::from_slice::<serde_json::Value>(config) else {
+        return Vec::new();
+    };
+    config["history"]
+        .as_array()
+        .map(|history| {
+            history
+                .iter()
+                .filter(|h| !h["empty_layer"].as_bool().unwrap_or(false))
+                .map(|h| {
+                    let created_by = h["created_by"].as_str().unwrap_or_default();
+                    created_by
+                        .trim_start_matches("/bin/sh -c #(nop) ")
+                        .trim()
+                        .to_string()
+                })
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Short digest from a layer path (`blobs/sha256/<hex>` or `<hex>/layer.tar`)
+fn short_layer_digest(path: &str) -> String {
+    let hex = path
+        .strip_prefix("blobs/sha256/")
+        .unwrap_or_else(|| path.split('/').next().unwrap_or(path));
+    hex.chars().take(12).collect()
+}
+
+/// Strip `./`, leading and trailing slashes from a tar path
+fn normalize_path(path: &str) -> String {
+    path.trim_start_matches("./").trim_matches('/').to_string()
+}
+
+fn split_parent(path: &str) -> (&str, &str) {
+    path.rsplit_once('/').unwrap_or(("", path))
+}
+
+fn file_name(path: &str) -> &str {
+    split_parent(path).1
+}
+
+fn join_path(dir: &str, name: &str) -> String {
+    if dir.is_empty() {
+        name.to_string()
+    } else {
+        format!("{}/{}", dir, name)
+    }
+}
+
+/// Remove everything below `dir` (and `dir` itself when `inclusive`)
+fn remove_tree(fs: &mut BTreeMap<String, Node>, dir: &str, inclusive: bool) -> Vec<(String, Node)> {
+    let prefix = if dir.is_empty() {
+        String::new()
+    } else {
+        format!("{}/", dir)
+    };
+    let mut removed: Vec<String> = fs
+        .range(prefix.clone()..)
+        .take_while(|(path, _)| path.starts_with(&prefix))
+        .map(|(path, _)| path.clone())
+        .collect();
+    if inclusive && fs.contains_key(dir) {
+        removed.push(dir.to_string());
+    }
+    removed
+        .into_iter()
+        .filter_map(|path| fs.remove(&path).map(|node| (path, node)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file(path: &str, size: u64) -> RawEntry {
+        RawEntry {
+            path: path.into(),
+            size,
+            is_dir: false,
+        }
+    }
+
+    fn dir(path: &str) -> RawEntry {
+        RawEntry {
+            path: path.into(),
+            size: 0,
+            is_dir: true,
+        }
+    }
+
+    #[test]
+    fn tracks_changes_and_waste_across_layers() {
+        let analysis = analyze(vec![
+            (
+                "base".into(),
+                "ADD rootfs".into(),
+                vec![
+                    dir("etc/"),
+                    file("etc/os-release", 10),
+                    dir("var/cache/"),
+                    file("var/cache/a", 100),
+                    file("var/cache/b", 50),
+                    file("tmp.tar", 500),
+                ],
+            ),
+            (
+                "app".into(),
+                "RUN update".into(),
+                vec![
+                    dir("etc/"),
+                    file("etc/os-release", 12),
+                    file(".wh.tmp.tar", 0),
+                    file("var/cache/.wh..wh..opq", 0),
+                    file("var/cache/c", 5),
+                ],
+            ),
+        ]);
+
+        let changes = &analysis.layers[1].changes;
+        let find = |path: &str| changes.iter().find(|c| c.path == path).unwrap().change;
+        assert_eq!(find("etc/os-release"), FileChange::Modified);
+        assert_eq!(find("tmp.tar"), FileChange::Removed);
+        assert_eq!(find("var/cache/a"), FileChange::Removed);
+        assert_eq!(find("var/cache/c"), FileChange::Added);
+        assert!(!changes.iter().any(|c| c.path == "etc"));
+        assert_eq!(analysis.layers[1].size, 17);
+
+        assert_eq!(analysis.wasted_bytes(), 10 + 500 + 100 + 50);
+        assert_eq!(analysis.wasted[0].path, "tmp.tar");
+        assert_eq!(analysis.total_size(), 660 + 17);
+    }
+
+    #[test]
+    fn builds_tree_with_parent_sizes() {
+        let changes = vec![
+            LayerChange {
+                path: "usr/bin/app".into(),
+                size: 30,
+                is_dir: false,
+                change: FileChange::Added,
+            },
+            LayerChange {
+                path: "usr/lib/libx.so".into(),
+                size: 20,
+                is_dir: false,
+                change: FileChange::Modified,
+            },
+        ];
+        let tree = tree_lines(&changes);
+        let rows: Vec<(usize, &str, u64, Option<FileChange>)> = tree
+            .iter()
+            .map(|l| (l.depth, l.name.as_str(), l.size, l.change))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                (0, "usr", 50, None),
+                (1, "bin", 30, None),
+                (2, "app", 30, Some(FileChange::Added)),
+                (1, "lib", 20, None),
+                (2, "libx.so", 20, Some(FileChange::Modified)),
+            ]
+        );
+    }
+
+    fn append(builder: &mut tar::Builder<impl std::io::Write>, path: &str, data: &[u8]) {
+        let mut header = tar::Header::new_gnu();
+        header.set_size(data.len() as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        builder.append_data(&mut header, path, data).unwrap();
+    }
+
+    fn layer_tar(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut builder = tar::Builder::new(Vec::new());
+        for (path, data) in files {
+            append(&mut builder, path, data);
+        }
+        builder.into_inner().unwrap()
+    }
+
+    #[test]
+    fn reads_oci_save_archive() {
+        let config = br#"{"history":[
+            {"created_by":"/bin/sh -c #(nop) ADD file:abc in / "},
+            {"created_by":"/bin/sh -c #(nop)  CMD [\"sh\"]","empty_layer":true},
+            {"created_by":"RUN rm /big"}
+        ]}"#;
+        let manifest = br#"[{"Config":"blobs/sha256/cfg","RepoTags":["app:1"],
+            "Layers":["blobs/sha256/aaaaaaaaaaaaaaaa","blobs/sha256/bbbbbbbbbbbbbbbb"]}]"#;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("image.tar");
+        let mut builder = tar::Builder::new(File::create(&path).unwrap());
+        append(&mut builder, "blobs/sha256/cfg", config);
+        append(
+            &mut builder,
+            "blobs/sha256/aaaaaaaaaaaaaaaa",
+            &layer_tar(&[("big", &[0; 64]), ("bin/sh", b"sh")]),
+        );
+        append(
+            &mut builder,
+            "blobs/sha256/bbbbbbbbbbbbbbbb",
+            &layer_tar(&[(".wh.big", b"")]),
+        );
+        // The legacy layout puts the manifest last
+        append(&mut builder, "manifest.json", manifest);
+        builder.finish().unwrap();
+        drop(builder);
+
+        let analysis = analyze_archive(&path).unwrap();
+        assert_eq!(analysis.layers.len(), 2);
+        assert_eq!(analysis.layers[0].digest, "aaaaaaaaaaaa");
+        assert_eq!(analysis.layers[0].created_by, "ADD file:abc in /");
+        assert_eq!(analysis.layers[0].size, 66);
+        assert_eq!(analysis.layers[1].created_by, "RUN rm /big");
+        assert_eq!(analysis.layers[1].changes[0].change, FileChange::Removed);
+        assert_eq!(analysis.wasted_bytes(), 64);
+    }
+}
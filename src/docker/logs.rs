@@ -40,6 +40,41 @@ impl DockerClient {
             ..Default::default()
         };
 
+        self.read_log_entries(id, options, tail).await
+    }
+
+    /// Fetch the last N lines of logs written before `until`
+    pub async fn fetch_logs_until(
+        &self,
+        id: &str,
+        until: chrono::DateTime<chrono::Utc>,
+        tail: usize,
+    ) -> Result<Vec<LogEntry>> {
+        debug!(
+            "Fetching last {} log lines before {} for container {}",
+            tail, until, id
+        );
+
+        let options = LogsOptions::<String> {
+            stdout: true,
+            stderr: true,
+            timestamps: true,
+            follow: false,
+            // `until` has one-second resolution; round up to include the final lines
+            until: until.timestamp() + 1,
+            tail: tail.to_string(),
+            ..Default::default()
+        };
+
+        self.read_log_entries(id, options, tail).await
+    }
+
+    async fn read_log_entries(
+        &self,
+        id: &str,
+        options: LogsOptions<String>,
+        tail: usize,
+    ) -> Result<Vec<LogEntry>> {
         let mut stream = self.inner().logs(id, Some(options));
         let mut entries = Vec::new();
 
@@ -1,19 +1,46 @@
+pub mod archive;
+pub mod build;
+pub mod cleanup;
 pub mod client;
 pub mod containers;
+pub mod crash;
+pub mod debug;
+pub mod dockerfile;
 pub mod exec;
+pub mod image_diff;
 pub mod image_inspect;
 pub mod images;
 pub mod inspect;
+pub mod layers;
 pub mod logs;
 pub mod networks;
+pub mod recreate;
+pub mod run_spec;
 pub mod stats;
 pub mod system;
+pub mod transfer;
+pub mod volume_backup;
+pub mod volume_browser;
 pub mod volumes;
 
+pub use build::{BuildLine, BuildLineKind, BuildOptions};
+pub use cleanup::{plan_cleanup, CleanupCandidate, CleanupPlan, CleanupResult};
 pub use client::DockerClient;
+pub use crash::{exit_code_meaning, ExitInfo};
+pub use dockerfile::{DockerfileStep, ReconstructedDockerfile};
 pub use exec::{looks_like_shell, select_exec_command};
-pub use image_inspect::{format_signed_size, format_size, ImageDetails};
+pub use image_diff::{diff_images, DiffEntry, DiffKind, ImageDiff};
+pub use image_inspect::{format_signed_size, format_size, ImageDetails, ImageLayer};
 pub use inspect::ContainerDetails;
+pub use layers::{FileChange, ImageAnalysis, LayerAnalysis, TreeLine, WastedFile};
 pub use logs::LogEntry;
+pub use networks::{parse_aliases, IpamSubnet, NetworkDetails, NetworkEndpoint, NetworkOptions};
+pub use run_spec::RunSpec;
 pub use stats::{format_bytes, StatsEntry};
-pub use system::{format_bytes_size, PruneOptions, PruneResult, SystemDiskUsage, SystemInfo};
+pub use system::{
+    format_bytes_size, PruneOptions, PruneResult, SystemDiskUsage, SystemInfo, VolumeUsage,
+};
+pub use transfer::{LayerProgress, TransferProgress};
+pub use volume_backup::{BackupManifest, RestoreOutcome};
+pub use volume_browser::{FilePreview, VolumeEntry, VolumeEntryKind};
+pub use volumes::{parse_volume_options, VolumeContainer, VolumeDetails, VolumeOptions};
@@ -1,12 +1,284 @@
 //! Network operations
 
-use bollard::network::ListNetworksOptions;
+use std::collections::HashMap;
+
+use bollard::models::{EndpointIpamConfig, EndpointSettings, Ipam, IpamConfig};
+use bollard::network::{
+    ConnectNetworkOptions, CreateNetworkOptions, DisconnectNetworkOptions, InspectNetworkOptions,
+    ListNetworksOptions,
+};
 use tracing::{debug, info};
 
 use crate::core::{DockerError, NetworkScope, NetworkSummary, Result};
 use crate::docker::DockerClient;
 
+/// What network to create and how
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NetworkOptions {
+    pub name: String,
+    /// Network driver, empty for `bridge`
+    pub driver: String,
+    /// Subnet in CIDR notation, empty to let IPAM pick one
+    pub subnet: String,
+    pub gateway: String,
+    /// Part of the subnet to allocate container addresses from
+    pub ip_range: String,
+    pub internal: bool,
+    pub attachable: bool,
+    pub enable_ipv6: bool,
+}
+
+impl NetworkOptions {
+    /// IPAM configuration. A gateway or IP range only makes sense within a
+    /// subnet, so they need one.
+    fn ipam(&self) -> Result<Ipam> {
+        if self.subnet.is_empty() {
+            if !self.gateway.is_empty() || !self.ip_range.is_empty() {
+                return Err(DockerError::Network(
+                    "A gateway or IP range needs a subnet".to_string(),
+                )
+                .into());
+            }
+            return Ok(Ipam::default());
+        }
+        let non_empty = |s: &str| (!s.is_empty()).then(|| s.to_string());
+        Ok(Ipam {
+            driver: Some("default".to_string()),
+            config: Some(vec![IpamConfig {
+                subnet: Some(self.subnet.clone()),
+                gateway: non_empty(&self.gateway),
+                ip_range: non_empty(&self.ip_range),
+                ..Default::default()
+            }]),
+            options: None,
+        })
+    }
+}
+
+/// Split network aliases on whitespace or commas
+pub fn parse_aliases(input: &str) -> Vec<String> {
+    input
+        .split(|c: char| c.is_whitespace() || c == ',')
+        .filter(|a| !a.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Endpoint settings for a connection with aliases and an optional static
+/// IPv4 or IPv6 address
+fn endpoint_settings(aliases: &[String], ip: &str) -> Result<EndpointSettings> {
+    let ipam_config = match ip {
+        "" => None,
+        ip => match ip.parse::<std::net::IpAddr>() {
+            Ok(std::net::IpAddr::V4(_)) => Some(EndpointIpamConfig {
+                ipv4_address: Some(ip.to_string()),
+                ..Default::default()
+            }),
+            Ok(std::net::IpAddr::V6(_)) => Some(EndpointIpamConfig {
+                ipv6_address: Some(ip.to_string()),
+                ..Default::default()
+            }),
+            Err(_) => {
+                return Err(DockerError::Network(format!("Invalid IP address: {}", ip)).into())
+            }
+        },
+    };
+    Ok(EndpointSettings {
+        aliases: (!aliases.is_empty()).then(|| aliases.to_vec()),
+        ipam_config,
+        ..Default::default()
+    })
+}
+
+/// Network details from inspect
+#[derive(Debug, Clone)]
+pub struct NetworkDetails {
+    pub id: String,
+    pub name: String,
+    pub driver: String,
+    pub scope: String,
+    pub created: String,
+    pub internal: bool,
+    pub attachable: bool,
+    pub enable_ipv6: bool,
+    pub ipam_driver: String,
+    pub subnets: Vec<IpamSubnet>,
+    pub options: Vec<(String, String)>,
+    pub labels: Vec<(String, String)>,
+    pub containers: Vec<NetworkEndpoint>,
+}
+
+/// One IPAM pool of a network
+#[derive(Debug, Clone, PartialEq)]
+pub struct IpamSubnet {
+    pub subnet: String,
+    pub gateway: String,
+    pub ip_range: String,
+}
+
+/// A container's attachment to a network
+#[derive(Debug, Clone, PartialEq)]
+pub struct NetworkEndpoint {
+    pub container_id: String,
+    pub name: String,
+    /// CIDR notation, empty when unassigned
+    pub ipv4_address: String,
+    pub ipv6_address: String,
+    pub mac_address: String,
+}
+
+impl From<bollard::models::Network> for NetworkDetails {
+    fn from(n: bollard::models::Network) -> Self {
+        let sorted = |map: Option<HashMap<String, String>>| {
+            let mut pairs: Vec<_> = map.unwrap_or_default().into_iter().collect();
+            pairs.sort();
+            pairs
+        };
+        let ipam = n.ipam.unwrap_or_default();
+        let subnets = ipam
+            .config
+            .unwrap_or_default()
+            .into_iter()
+            .map(|config| IpamSubnet {
+                subnet: config.subnet.unwrap_or_default(),
+                gateway: config.gateway.unwrap_or_default(),
+                ip_range: config.ip_range.unwrap_or_default(),
+            })
+            .collect();
+        let mut containers: Vec<NetworkEndpoint> = n
+            .containers
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(id, c)| NetworkEndpoint {
+                name: c
+                    .name
+                    .unwrap_or_else(|| id.chars().take(12).collect::<String>()),
+                container_id: id,
+                ipv4_address: c.ipv4_address.unwrap_or_default(),
+                ipv6_address: c.ipv6_address.unwrap_or_default(),
+                mac_address: c.mac_address.unwrap_or_default(),
+            })
+            .collect();
+        containers.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Self {
+            id: n.id.unwrap_or_default(),
+            name: n.name.unwrap_or_default(),
+            driver: n.driver.unwrap_or_default(),
+            scope: n.scope.unwrap_or_default(),
+            created: n.created.unwrap_or_default(),
+            internal: n.internal.unwrap_or(false),
+            attachable: n.attachable.unwrap_or(false),
+            enable_ipv6: n.enable_ipv6.unwrap_or(false),
+            ipam_driver: ipam.driver.unwrap_or_else(|| "default".to_string()),
+            subnets,
+            options: sorted(n.options),
+            labels: sorted(n.labels),
+            containers,
+        }
+    }
+}
+
 impl DockerClient {
+    /// Create a network, returning its ID
+    pub async fn create_network(&self, options: &NetworkOptions) -> Result<String> {
+        info!(
+            "Creating network '{}' (driver={})",
+            options.name, options.driver
+        );
+        if options.name.is_empty() {
+            return Err(DockerError::Network("A network needs a name".to_string()).into());
+        }
+
+        let driver = if options.driver.is_empty() {
+            "bridge"
+        } else {
+            options.driver.as_str()
+        };
+        let config = CreateNetworkOptions {
+            name: options.name.as_str(),
+            driver,
+            internal: options.internal,
+            attachable: options.attachable,
+            enable_ipv6: options.enable_ipv6,
+            ipam: options.ipam()?,
+            ..Default::default()
+        };
+
+        let response = self
+            .inner()
+            .create_network(config)
+            .await
+            .map_err(|e| DockerError::Network(format!("Failed to create network: {}", e)))?;
+
+        info!("Network {} created ({})", options.name, response.id);
+        Ok(response.id)
+    }
+
+    /// Connect a container to a network, with optional aliases and a static
+    /// IP address (empty for automatic)
+    pub async fn connect_network(
+        &self,
+        network: &str,
+        container: &str,
+        aliases: &[String],
+        ip: &str,
+    ) -> Result<()> {
+        info!("Connecting {} to network {}", container, network);
+
+        let config = ConnectNetworkOptions {
+            container,
+            endpoint_config: endpoint_settings(aliases, ip)?,
+        };
+        self.inner()
+            .connect_network(network, config)
+            .await
+            .map_err(|e| {
+                DockerError::Network(format!(
+                    "Failed to connect {} to {}: {}",
+                    container, network, e
+                ))
+            })?;
+        Ok(())
+    }
+
+    /// Disconnect a container from a network
+    pub async fn disconnect_network(
+        &self,
+        network: &str,
+        container: &str,
+        force: bool,
+    ) -> Result<()> {
+        info!(
+            "Disconnecting {} from network {} (force={})",
+            container, network, force
+        );
+
+        self.inner()
+            .disconnect_network(network, DisconnectNetworkOptions { container, force })
+            .await
+            .map_err(|e| {
+                DockerError::Network(format!(
+                    "Failed to disconnect {} from {}: {}",
+                    container, network, e
+                ))
+            })?;
+        Ok(())
+    }
+
+    /// Inspect a network, including its connected containers
+    pub async fn inspect_network(&self, id: &str) -> Result<NetworkDetails> {
+        debug!("Inspecting network {}", id);
+
+        let network = self
+            .inner()
+            .inspect_network(id, None::<InspectNetworkOptions<String>>)
+            .await
+            .map_err(|e| DockerError::Network(format!("Failed to inspect {}: {}", id, e)))?;
+
+        Ok(network.into())
+    }
+
     /// List all networks
     pub async fn list_networks(&self) -> Result<Vec<NetworkSummary>> {
         debug!("Listing networks");
@@ -69,13 +341,12 @@ impl From<bollard::models::Network> for NetworkSummary {
         };
 
         // Get subnet from IPAM config if available
-        let _subnet = n
+        let subnet = n
             .ipam
             .as_ref()
             .and_then(|ipam| ipam.config.as_ref())
             .and_then(|configs| configs.first())
-            .and_then(|config| config.subnet.clone())
-            .unwrap_or_else(|| "-".to_string());
+            .and_then(|config| config.subnet.clone());
 
         // Get connected containers
         let connected_containers = n
@@ -97,6 +368,7 @@ impl From<bollard::models::Network> for NetworkSummary {
             attachable: n.attachable.unwrap_or(false),
             ingress: n.ingress.unwrap_or(false),
             enable_ipv6: n.enable_ipv6.unwrap_or(false),
+            subnet,
             connected_containers,
         }
     }
@@ -106,6 +378,109 @@ impl From<bollard::models::Network> for NetworkSummary {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_network_ipam() {
+        let mut options = NetworkOptions {
+            name: "backend".into(),
+            ..Default::default()
+        };
+        assert!(options.ipam().unwrap().config.is_none());
+
+        options.gateway = "172.28.0.1".into();
+        assert!(options.ipam().is_err());
+
+        options.subnet = "172.28.0.0/16".into();
+        let config = options.ipam().unwrap().config.unwrap();
+        assert_eq!(config[0].subnet.as_deref(), Some("172.28.0.0/16"));
+        assert_eq!(config[0].gateway.as_deref(), Some("172.28.0.1"));
+        assert_eq!(config[0].ip_range, None);
+    }
+
+    #[test]
+    fn test_endpoint_settings() {
+        assert_eq!(
+            parse_aliases("db, database  pg"),
+            vec!["db", "database", "pg"]
+        );
+
+        let settings = endpoint_settings(&parse_aliases("db"), "").unwrap();
+        assert_eq!(settings.aliases, Some(vec!["db".to_string()]));
+        assert!(settings.ipam_config.is_none());
+
+        let settings = endpoint_settings(&[], "172.28.5.10").unwrap();
+        assert!(settings.aliases.is_none());
+        let ipam = settings.ipam_config.unwrap();
+        assert_eq!(ipam.ipv4_address.as_deref(), Some("172.28.5.10"));
+
+        let ipam = endpoint_settings(&[], "fd00::10")
+            .unwrap()
+            .ipam_config
+            .unwrap();
+        assert_eq!(ipam.ipv6_address.as_deref(), Some("fd00::10"));
+        assert!(ipam.ipv4_address.is_none());
+
+        assert!(endpoint_settings(&[], "172.28.5.0/24").is_err());
+    }
+
+    #[test]
+    fn test_network_details_from_inspect() {
+        use bollard::models::{Ipam, IpamConfig, Network, NetworkContainer};
+
+        let network = Network {
+            id: Some("f00d".into()),
+            name: Some("backend".into()),
+            driver: Some("bridge".into()),
+            ipam: Some(Ipam {
+                driver: Some("default".into()),
+                config: Some(vec![IpamConfig {
+                    subnet: Some("172.20.0.0/16".into()),
+                    gateway: Some("172.20.0.1".into()),
+                    ..Default::default()
+                }]),
+                options: None,
+            }),
+            containers: Some(HashMap::from([
+                (
+                    "b".repeat(64),
+                    NetworkContainer {
+                        name: Some("web".into()),
+                        ipv4_address: Some("172.20.0.3/16".into()),
+                        mac_address: Some("02:42:ac:14:00:03".into()),
+                        ..Default::default()
+                    },
+                ),
+                (
+                    "a".repeat(64),
+                    NetworkContainer {
+                        name: Some("db".into()),
+                        ipv4_address: Some("172.20.0.2/16".into()),
+                        ipv6_address: Some("fd00::2/64".into()),
+                        ..Default::default()
+                    },
+                ),
+            ])),
+            options: Some(HashMap::from([(
+                "com.docker.network.bridge.name".to_string(),
+                "br-backend".to_string(),
+            )])),
+            ..Default::default()
+        };
+
+        let details = NetworkDetails::from(network);
+        assert_eq!(
+            details.subnets,
+            vec![IpamSubnet {
+                subnet: "172.20.0.0/16".into(),
+                gateway: "172.20.0.1".into(),
+                ip_range: String::new(),
+            }]
+        );
+        let names: Vec<&str> = details.containers.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["db", "web"]);
+        assert_eq!(details.containers[0].ipv6_address, "fd00::2/64");
+        assert_eq!(details.options.len(), 1);
+    }
+
     // Note: These tests require Docker to be running
 
     #[tokio::test]
@@ -0,0 +1,536 @@
+//! Recreate a container from its inspected configuration
+
+use std::collections::HashMap;
+
+use bollard::auth::DockerCredentials;
+use bollard::container::{
+    Config, CreateContainerOptions, NetworkingConfig, RemoveContainerOptions,
+};
+use bollard::models::{
+    ContainerConfig, ContainerInspectResponse, EndpointSettings, HostConfig, ImageConfig, Mount,
+    MountPointTypeEnum, MountTypeEnum,
+};
+use bollard::network::ConnectNetworkOptions;
+use futures::StreamExt;
+use tracing::{info, warn};
+
+use crate::core::{DockerError, Result};
+use crate::docker::DockerClient;
+
+/// Network modes that do not take endpoint settings
+fn is_special_network_mode(mode: &str) -> bool {
+    matches!(mode, "host" | "none") || mode.starts_with("container:")
+}
+
+/// Keep only user-supplied endpoint settings; addresses and IDs are assigned
+/// by the daemon and would clash with the old container.
+fn reusable_endpoint(endpoint: &EndpointSettings, short_id: &str) -> EndpointSettings {
+    EndpointSettings {
+        ipam_config: endpoint.ipam_config.clone(),
+        links: endpoint.links.clone(),
+        aliases: endpoint
+            .aliases
+            .as_ref()
+            .map(|aliases| {
+                aliases
+                    .iter()
+                    .filter(|a| a.as_str() != short_id)
+                    .cloned()
+                    .collect::<Vec<_>>()
+            })
+            .filter(|aliases| !aliases.is_empty()),
+        driver_opts: endpoint.driver_opts.clone(),
+        ..Default::default()
+    }
+}
+
+/// Mounts that reattach the old container's volumes at the same paths.
+/// Volumes from image `VOLUME` instructions and unnamed `-v /path` mounts
+/// only live in the container's mount list; without this the replacement
+/// would start on fresh, empty anonymous volumes.
+fn carried_volumes(inspect: &ContainerInspectResponse, host_config: &HostConfig) -> Vec<Mount> {
+    // Paths the copied host config already mounts something at
+    let taken: Vec<&str> = host_config
+        .binds
+        .iter()
+        .flatten()
+        .filter_map(|bind| bind.split(':').nth(1))
+        .chain(
+            host_config
+                .mounts
+                .iter()
+                .flatten()
+                .filter_map(|m| m.target.as_deref()),
+        )
+        .collect();
+
+    inspect
+        .mounts
+        .iter()
+        .flatten()
+        .filter(|m| m.typ == Some(MountPointTypeEnum::VOLUME))
+        .filter_map(|m| {
+            let name = m.name.as_deref().filter(|n| !n.is_empty())?;
+            let target = m.destination.as_deref()?;
+            if taken.contains(&target) {
+                return None;
+            }
+            Some(Mount {
+                target: Some(target.to_string()),
+                source: Some(name.to_string()),
+                typ: Some(MountTypeEnum::VOLUME),
+                read_only: Some(!m.rw.unwrap_or(true)),
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+/// The image a container was created from, as given at create time (the
+/// list only shows an ID once the tag has moved). Refuses containers
+/// started with `--rm`, which the daemon deletes as soon as they stop.
+pub fn recreatable_image(inspect: &ContainerInspectResponse) -> Result<String> {
+    let auto_remove = inspect
+        .host_config
+        .as_ref()
+        .and_then(|h| h.auto_remove)
+        .unwrap_or(false);
+    if auto_remove {
+        return Err(DockerError::Container(
+            "Container was started with --rm and would be deleted when stopped".to_string(),
+        )
+        .into());
+    }
+    inspect
+        .config
+        .as_ref()
+        .and_then(|c| c.image.clone())
+        .filter(|i| !i.is_empty())
+        .ok_or_else(|| DockerError::Container("Container has no image".to_string()).into())
+}
+
+/// Drop settings the container inherited from its old image, so the
+/// replacement picks up the new image's defaults instead
+fn without_image_defaults(mut config: ContainerConfig, image: &ImageConfig) -> ContainerConfig {
+    fn same<T: PartialEq>(value: &mut Option<T>, default: &Option<T>) {
+        if value.is_some() && value == default {
+            *value = None;
+        }
+    }
+
+    if let (Some(env), Some(image_env)) = (config.env.as_mut(), image.env.as_ref()) {
+        env.retain(|e| !image_env.contains(e));
+    }
+    if let (Some(labels), Some(image_labels)) = (config.labels.as_mut(), image.labels.as_ref()) {
+        labels.retain(|k, v| image_labels.get(k) != Some(v));
+    }
+    if let (Some(ports), Some(image_ports)) =
+        (config.exposed_ports.as_mut(), image.exposed_ports.as_ref())
+    {
+        ports.retain(|port, _| !image_ports.contains_key(port));
+    }
+    if let (Some(volumes), Some(image_volumes)) = (config.volumes.as_mut(), image.volumes.as_ref())
+    {
+        volumes.retain(|path, _| !image_volumes.contains_key(path));
+    }
+    same(&mut config.cmd, &image.cmd);
+    same(&mut config.entrypoint, &image.entrypoint);
+    same(&mut config.working_dir, &image.working_dir);
+    same(&mut config.user, &image.user);
+    same(&mut config.healthcheck, &image.healthcheck);
+    same(&mut config.stop_signal, &image.stop_signal);
+    same(&mut config.shell, &image.shell);
+    config
+}
+
+/// Build the create config for a replacement of `inspect` running `image`.
+/// `old_image` is the config of the image the container was created from;
+/// values equal to it are left for the new image to set. Returns the config
+/// (attached to the primary network) and the extra networks to connect
+/// before starting.
+pub fn recreate_config(
+    inspect: &ContainerInspectResponse,
+    image: &str,
+    old_image: Option<&ImageConfig>,
+) -> (Config<String>, Vec<(String, EndpointSettings)>) {
+    let id = inspect.id.as_deref().unwrap_or_default();
+    let short_id: String = id.chars().take(12).collect();
+
+    let mut config = inspect.config.clone().unwrap_or_default();
+    if let Some(old_image) = old_image {
+        config = without_image_defaults(config, old_image);
+    }
+    let mut config: Config<String> = config.into();
+    config.image = Some(image.to_string());
+    // Docker defaults the hostname to the short ID; let the new one get its own
+    if config.hostname.as_deref() == Some(short_id.as_str()) {
+        config.hostname = None;
+    }
+
+    let mut host_config = inspect.host_config.clone().unwrap_or_default();
+    let volumes = carried_volumes(inspect, &host_config);
+    if !volumes.is_empty() {
+        host_config
+            .mounts
+            .get_or_insert_with(Vec::new)
+            .extend(volumes);
+    }
+    let network_mode = host_config
+        .network_mode
+        .clone()
+        .unwrap_or_else(|| "default".to_string());
+    config.host_config = Some(host_config);
+
+    let mut networks: Vec<(String, EndpointSettings)> = inspect
+        .network_settings
+        .as_ref()
+        .and_then(|n| n.networks.clone())
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(name, endpoint)| {
+            let endpoint = reusable_endpoint(&endpoint, &short_id);
+            (name, endpoint)
+        })
+        .collect();
+    networks.sort_by(|a, b| a.0.cmp(&b.0));
+
+    if is_special_network_mode(&network_mode) {
+        return (config, Vec::new());
+    }
+
+    let primary_name = if network_mode == "default" {
+        "bridge"
+    } else {
+        network_mode.as_str()
+    };
+    if let Some(pos) = networks.iter().position(|(name, _)| name == primary_name) {
+        let (name, endpoint) = networks.remove(pos);
+        config.networking_config = Some(NetworkingConfig {
+            endpoints_config: HashMap::from([(name, endpoint)]),
+        });
+    }
+
+    (config, networks)
+}
+
+impl DockerClient {
+    /// The image to offer when recreating a container; see [`recreatable_image`]
+    pub async fn recreate_image(&self, id: &str) -> Result<String> {
+        let inspect = self
+            .inner()
+            .inspect_container(id, None)
+            .await
+            .map_err(|e| DockerError::Container(format!("Failed to inspect: {}", e)))?;
+        recreatable_image(&inspect)
+    }
+
+    /// Replace a container with a new one built from the same configuration,
+    /// optionally on a different or freshly pulled image (using `credentials`
+    /// for the pull). The old container is stopped and renamed out of the
+    /// way, and restored if the new one fails to start. The new container
+    /// takes over the old one's volumes. Returns the new container ID.
+    pub async fn recreate_container(
+        &self,
+        id: &str,
+        image: Option<&str>,
+        pull: bool,
+        credentials: Option<DockerCredentials>,
+    ) -> Result<String> {
+        let inspect = self
+            .inner()
+            .inspect_container(id, None)
+            .await
+            .map_err(|e| DockerError::Container(format!("Failed to inspect: {}", e)))?;
+        let name = inspect
+            .name
+            .clone()
+            .unwrap_or_default()
+            .trim_start_matches('/')
+            .to_string();
+        let current_image = recreatable_image(&inspect)?;
+        let image = image
+            .filter(|i| !i.is_empty())
+            .map_or(current_image, str::to_string);
+        let was_running = inspect
+            .state
+            .as_ref()
+            .and_then(|s| s.running)
+            .unwrap_or(false);
+
+        // Pull before touching the old container so a failed pull changes nothing
+        if pull {
+            let mut stream = std::pin::pin!(self.pull_image_progress(&image, credentials));
+            while let Some(item) = stream.next().await {
+                item?;
+            }
+        }
+
+        // Image defaults are best effort; without them everything is copied
+        let old_image_id = inspect.image.clone().unwrap_or_default();
+        let old_image = match self.inner().inspect_image(&old_image_id).await {
+            Ok(old_image) => old_image.config,
+            Err(e) => {
+                warn!("Failed to inspect image {}: {}", old_image_id, e);
+                None
+            }
+        };
+
+        info!("Recreating container {} ({}) with {}", name, id, image);
+        if was_running {
+            self.stop_container(id, None).await?;
+        }
+        let backup_name = format!(
+            "{}-contui-old-{}",
+            name,
+            &uuid::Uuid::new_v4().simple().to_string()[..6]
+        );
+        self.rename_container(id, &backup_name).await?;
+
+        let (config, extra_networks) = recreate_config(&inspect, &image, old_image.as_ref());
+        let created = match self
+            .inner()
+            .create_container(
+                Some(CreateContainerOptions {
+                    name: name.as_str(),
+                    platform: None,
+                }),
+                config,
+            )
+            .await
+        {
+            Ok(created) => created,
+            Err(e) => {
+                self.restore_container(id, &name, was_running).await;
+                return Err(DockerError::Container(format!(
+                    "Failed to create new container: {}; restored the original",
+                    e
+                ))
+                .into());
+            }
+        };
+
+        let mut result = Ok(());
+        for (network, endpoint_config) in extra_networks {
+            result = self
+                .inner()
+                .connect_network(
+                    &network,
+                    ConnectNetworkOptions {
+                        container: created.id.clone(),
+                        endpoint_config,
+                    },
+                )
+                .await
+                .map_err(|e| format!("Failed to connect network {}: {}", network, e));
+            if result.is_err() {
+                break;
+            }
+        }
+        if result.is_ok() {
+            result = self
+                .inner()
+                .start_container::<String>(&created.id, None)
+                .await
+                .map_err(|e| format!("Failed to start new container: {}", e));
+        }
+        if let Err(message) = result {
+            let _ = self
+                .inner()
+                .remove_container(
+                    &created.id,
+                    Some(RemoveContainerOptions {
+                        force: true,
+                        ..Default::default()
+                    }),
+                )
+                .await;
+            self.restore_container(id, &name, was_running).await;
+            return Err(
+                DockerError::Container(format!("{}; restored the original", message)).into(),
+            );
+        }
+
+        if let Err(e) = self.remove_container(id, false, false).await {
+            warn!(
+                "Recreated {} but failed to remove the old container: {}",
+                name, e
+            );
+        }
+        info!("Container {} recreated as {}", name, created.id);
+        Ok(created.id)
+    }
+
+    /// Put the original container back after a failed recreate
+    async fn restore_container(&self, id: &str, name: &str, was_running: bool) {
+        if let Err(e) = self.rename_container(id, name).await {
+            warn!("Rollback: failed to rename {} back to {}: {}", id, name, e);
+        }
+        if was_running {
+            if let Err(e) = self.start_container(id).await {
+                warn!("Rollback: failed to restart {}: {}", name, e);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bollard::models::{ContainerConfig, MountPoint, NetworkSettings};
+
+    fn inspect(network_mode: &str, networks: &[&str]) -> ContainerInspectResponse {
+        ContainerInspectResponse {
+            id: Some("0123456789abcdef".into()),
+            name: Some("/web".into()),
+            config: Some(ContainerConfig {
+                hostname: Some("0123456789ab".into()),
+                image: Some("nginx:1.25".into()),
+                env: Some(vec!["A=1".into()]),
+                ..Default::default()
+            }),
+            host_config: Some(HostConfig {
+                network_mode: Some(network_mode.into()),
+                ..Default::default()
+            }),
+            network_settings: Some(NetworkSettings {
+                networks: Some(
+                    networks
+                        .iter()
+                        .map(|n| {
+                            (
+                                n.to_string(),
+                                EndpointSettings {
+                                    aliases: Some(vec!["web".into(), "0123456789ab".into()]),
+                                    ip_address: Some("172.18.0.2".into()),
+                                    network_id: Some("netid".into()),
+                                    ..Default::default()
+                                },
+                            )
+                        })
+                        .collect(),
+                ),
+                ..Default::default()
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn copies_config_and_swaps_image() {
+        let (config, extra) = recreate_config(&inspect("default", &["bridge"]), "nginx:1.27", None);
+        assert_eq!(config.image.as_deref(), Some("nginx:1.27"));
+        assert_eq!(config.env, Some(vec!["A=1".to_string()]));
+        assert_eq!(config.hostname, None);
+        assert!(config.host_config.is_some());
+        assert!(extra.is_empty());
+
+        let endpoints = config.networking_config.unwrap().endpoints_config;
+        let bridge = &endpoints["bridge"];
+        assert_eq!(bridge.aliases, Some(vec!["web".to_string()]));
+        assert_eq!(bridge.ip_address, None);
+        assert_eq!(bridge.network_id, None);
+    }
+
+    #[test]
+    fn drops_old_image_defaults() {
+        let mut old = inspect("default", &["bridge"]);
+        let config = old.config.as_mut().unwrap();
+        config.env = Some(vec![
+            "PATH=/usr/bin".into(),
+            "NGINX_VERSION=1.25".into(),
+            "A=1".into(),
+        ]);
+        config.cmd = Some(vec!["nginx".into(), "-g".into(), "daemon off;".into()]);
+        config.working_dir = Some("/srv".into());
+        config.labels = Some(HashMap::from([
+            ("maintainer".into(), "nginx".into()),
+            ("team".into(), "web".into()),
+        ]));
+        let old_image = ImageConfig {
+            env: Some(vec!["PATH=/usr/bin".into(), "NGINX_VERSION=1.25".into()]),
+            cmd: Some(vec!["nginx".into(), "-g".into(), "daemon off;".into()]),
+            labels: Some(HashMap::from([("maintainer".into(), "nginx".into())])),
+            ..Default::default()
+        };
+
+        let (config, _) = recreate_config(&old, "nginx:1.27", Some(&old_image));
+        assert_eq!(config.env, Some(vec!["A=1".to_string()]));
+        assert_eq!(config.cmd, None);
+        assert_eq!(config.working_dir.as_deref(), Some("/srv"));
+        assert_eq!(
+            config.labels,
+            Some(HashMap::from([("team".to_string(), "web".to_string())]))
+        );
+    }
+
+    #[test]
+    fn refuses_auto_remove_containers() {
+        let mut old = inspect("default", &["bridge"]);
+        assert_eq!(recreatable_image(&old).unwrap(), "nginx:1.25");
+        old.host_config.as_mut().unwrap().auto_remove = Some(true);
+        assert!(recreatable_image(&old).is_err());
+    }
+
+    #[test]
+    fn keeps_volume_mounts() {
+        let mount = |typ, name: Option<&str>, destination: &str, rw| MountPoint {
+            typ: Some(typ),
+            name: name.map(str::to_string),
+            source: Some("/var/lib/docker/volumes/x/_data".into()),
+            destination: Some(destination.into()),
+            rw: Some(rw),
+            ..Default::default()
+        };
+        let mut old = inspect("default", &["bridge"]);
+        old.host_config.as_mut().unwrap().binds = Some(vec!["pgconf:/etc/postgresql:ro".into()]);
+        old.mounts = Some(vec![
+            // Anonymous volume from the image's VOLUME instruction
+            mount(
+                MountPointTypeEnum::VOLUME,
+                Some("3f1c0ffee"),
+                "/var/lib/postgresql/data",
+                true,
+            ),
+            // Already in the binds, so carried over by the host config
+            mount(
+                MountPointTypeEnum::VOLUME,
+                Some("pgconf"),
+                "/etc/postgresql",
+                false,
+            ),
+            mount(MountPointTypeEnum::BIND, None, "/backup", true),
+        ]);
+
+        let (config, _) = recreate_config(&old, "postgres:17", None);
+        let host_config = config.host_config.unwrap();
+        assert_eq!(
+            host_config.binds,
+            Some(vec!["pgconf:/etc/postgresql:ro".to_string()])
+        );
+        let mounts = host_config.mounts.unwrap();
+        assert_eq!(mounts.len(), 1);
+        assert_eq!(mounts[0].source.as_deref(), Some("3f1c0ffee"));
+        assert_eq!(
+            mounts[0].target.as_deref(),
+            Some("/var/lib/postgresql/data")
+        );
+        assert_eq!(mounts[0].typ, Some(MountTypeEnum::VOLUME));
+        assert_eq!(mounts[0].read_only, Some(false));
+    }
+
+    #[test]
+    fn extra_networks_are_connected_separately() {
+        let (config, extra) = recreate_config(&inspect("app", &["app", "db", "cache"]), "x", None);
+        let endpoints = config.networking_config.unwrap().endpoints_config;
+        assert!(endpoints.contains_key("app"));
+        let names: Vec<&str> = extra.iter().map(|(n, _)| n.as_str()).collect();
+        assert_eq!(names, vec!["cache", "db"]);
+    }
+
+    #[test]
+    fn shared_network_namespace_has_no_endpoints() {
+        let (config, extra) = recreate_config(&inspect("container:abc", &[]), "x", None);
+        assert!(config.networking_config.is_none());
+        assert!(extra.is_empty());
+    }
+}
@@ -0,0 +1,702 @@
+//! Reverse-engineer a `docker run` command and a compose service from a container
+//!
+//! Values that match the image defaults (env, command, entrypoint, working
+//! directory, user, labels) are left out so the output only carries what was
+//! set when the container was started.
+
+use bollard::models::{
+    ContainerInspectResponse, ImageConfig, MountPointTypeEnum, RestartPolicyNameEnum,
+};
+
+use crate::core::{DockerError, Result};
+use crate::docker::DockerClient;
+
+/// Settings of a container that differ from its image
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RunSpec {
+    pub name: String,
+    pub image: String,
+    pub hostname: Option<String>,
+    pub user: Option<String>,
+    pub working_dir: Option<String>,
+    pub entrypoint: Option<Vec<String>>,
+    pub cmd: Option<Vec<String>>,
+    pub env: Vec<String>,
+    /// `[ip:]host:container[/proto]` publish specs
+    pub ports: Vec<String>,
+    /// `source:destination[:ro]` volume and bind specs
+    pub volumes: Vec<String>,
+    pub tmpfs: Vec<String>,
+    pub network_mode: Option<String>,
+    /// Networks beyond the first, connected after start
+    pub extra_networks: Vec<String>,
+    pub restart: Option<String>,
+    pub labels: Vec<(String, String)>,
+    pub extra_hosts: Vec<String>,
+    pub cap_add: Vec<String>,
+    pub cap_drop: Vec<String>,
+    pub privileged: bool,
+    pub tty: bool,
+    pub interactive: bool,
+    pub read_only: bool,
+    pub init: bool,
+    pub memory: Option<i64>,
+    pub cpus: Option<f64>,
+}
+
+/// Labels added by Docker tooling rather than the user
+fn is_tool_label(key: &str) -> bool {
+    key.starts_with("com.docker.compose.") || key.starts_with("desktop.docker.io/")
+}
+
+/// Docker names anonymous volumes with a 64 character hex ID
+fn is_anonymous_volume(name: &str) -> bool {
+    name.len() == 64 && name.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+impl RunSpec {
+    /// Build the spec from container inspect data and the image's config
+    pub fn from_inspect(inspect: &ContainerInspectResponse, image: Option<&ImageConfig>) -> Self {
+        let config = inspect.config.clone().unwrap_or_default();
+        let host = inspect.host_config.clone().unwrap_or_default();
+        let image_default = ImageConfig::default();
+        let image = image.unwrap_or(&image_default);
+        let short_id: String = inspect
+            .id
+            .as_deref()
+            .unwrap_or_default()
+            .chars()
+            .take(12)
+            .collect();
+
+        let differs = |value: &Option<String>, default: &Option<String>| -> Option<String> {
+            value
+                .clone()
+                .filter(|v| !v.is_empty() && Some(v) != default.as_ref())
+        };
+
+        let image_env = image.env.clone().unwrap_or_default();
+        let env = config
+            .env
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|e| !image_env.contains(e))
+            .collect();
+
+        let image_labels = image.labels.clone().unwrap_or_default();
+        let mut labels: Vec<(String, String)> = config
+            .labels
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(k, v)| !is_tool_label(k) && image_labels.get(k) != Some(v))
+            .collect();
+        labels.sort();
+
+        let mut ports: Vec<String> = host
+            .port_bindings
+            .unwrap_or_default()
+            .into_iter()
+            .flat_map(|(container_port, bindings)| {
+                let target = container_port
+                    .strip_suffix("/tcp")
+                    .unwrap_or(&container_port)
+                    .to_string();
+                bindings
+                    .unwrap_or_default()
+                    .into_iter()
+                    .map(move |b| {
+                        let host_port = b.host_port.unwrap_or_default();
+                        let host_ip = b.host_ip.unwrap_or_default();
+                        match (host_ip.as_str(), host_port.as_str()) {
+                            (_, "") => target.clone(),
+                            ("" | "0.0.0.0", _) => format!("{}:{}", host_port, target),
+                            _ => format!("{}:{}:{}", host_ip, host_port, target),
+                        }
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect();
+        ports.sort();
+
+        let image_volumes = image.volumes.clone().unwrap_or_default();
+        let mut volumes = Vec::new();
+        let mut tmpfs = Vec::new();
+        for mount in inspect.mounts.clone().unwrap_or_default() {
+            let Some(destination) = mount.destination else {
+                continue;
+            };
+            let source = match mount.typ {
+                Some(MountPointTypeEnum::BIND) => mount.source.unwrap_or_default(),
+                Some(MountPointTypeEnum::VOLUME) => {
+                    let name = mount.name.unwrap_or_default();
+                    // Anonymous volumes for image VOLUMEs come back on their own
+                    if is_anonymous_volume(&name) && image_volumes.contains_key(&destination) {
+                        continue;
+                    }
+                    name
+                }
+                Some(MountPointTypeEnum::TMPFS) => {
+                    tmpfs.push(destination);
+                    continue;
+                }
+                _ => continue,
+            };
+            let mut spec = format!("{}:{}", source, destination);
+            if mount.rw == Some(false) {
+                spec.push_str(":ro");
+            }
+            volumes.push(spec);
+        }
+        tmpfs.extend(host.tmpfs.unwrap_or_default().into_keys());
+        volumes.sort();
+        tmpfs.sort();
+        tmpfs.dedup();
+
+        let network_mode = host
+            .network_mode
+            .filter(|m| !m.is_empty() && m != "default" && m != "bridge");
+        let mut extra_networks: Vec<String> = inspect
+            .network_settings
+            .as_ref()
+            .and_then(|n| n.networks.as_ref())
+            .map(|n| n.keys().cloned().collect())
+            .unwrap_or_default();
+        let primary_network = network_mode.as_deref().unwrap_or("bridge");
+        extra_networks.retain(|n| n != primary_network);
+        extra_networks.sort();
+
+        let entrypoint = config
+            .entrypoint
+            .filter(|e| !e.is_empty() && Some(e) != image.entrypoint.as_ref());
+        // Overriding the entrypoint drops the image CMD, so any CMD left was given explicitly
+        let cmd = config
+            .cmd
+            .filter(|c| !c.is_empty() && (entrypoint.is_some() || Some(c) != image.cmd.as_ref()));
+
+        let restart = host.restart_policy.and_then(|p| match p.name? {
+            RestartPolicyNameEnum::EMPTY | RestartPolicyNameEnum::NO => None,
+            RestartPolicyNameEnum::ON_FAILURE => match p.maximum_retry_count {
+                Some(n) if n > 0 => Some(format!("on-failure:{}", n)),
+                _ => Some("on-failure".to_string()),
+            },
+            name => Some(name.to_string()),
+        });
+
+        Self {
+            name: inspect
+                .name
+                .clone()
+                .unwrap_or_default()
+                .trim_start_matches('/')
+                .to_string(),
+            image: config.image.unwrap_or_default(),
+            hostname: config.hostname.filter(|h| !h.is_empty() && *h != short_id),
+            user: differs(&config.user, &image.user),
+            working_dir: differs(&config.working_dir, &image.working_dir),
+            entrypoint,
+            cmd,
+            env,
+            ports,
+            volumes,
+            tmpfs,
+            network_mode,
+            extra_networks,
+            restart,
+            labels,
+            extra_hosts: host.extra_hosts.unwrap_or_default(),
+            cap_add: host.cap_add.unwrap_or_default(),
+            cap_drop: host.cap_drop.unwrap_or_default(),
+            privileged: host.privileged.unwrap_or(false),
+            tty: config.tty.unwrap_or(false),
+            interactive: config.open_stdin.unwrap_or(false),
+            read_only: host.readonly_rootfs.unwrap_or(false),
+            init: host.init.unwrap_or(false),
+            memory: host.memory.filter(|m| *m > 0),
+            cpus: host
+                .nano_cpus
+                .filter(|n| *n > 0)
+                .map(|n| n as f64 / 1_000_000_000.0),
+        }
+    }
+
+    /// Render as a multi-line `docker run` command
+    pub fn to_docker_run(&self) -> String {
+        let mut args: Vec<String> = vec!["-d".into()];
+        let mut flag =
+            |name: &str, value: &str| args.push(format!("{} {}", name, shell_quote(value)));
+
+        if !self.name.is_empty() {
+            flag("--name", &self.name);
+        }
+        if let Some(hostname) = &self.hostname {
+            flag("--hostname", hostname);
+        }
+        if let Some(user) = &self.user {
+            flag("--user", user);
+        }
+        if let Some(dir) = &self.working_dir {
+            flag("--workdir", dir);
+        }
+        if let Some(restart) = &self.restart {
+            flag("--restart", restart);
+        }
+        if let Some(mode) = &self.network_mode {
+            flag("--network", mode);
+        }
+        for port in &self.ports {
+            flag("-p", port);
+        }
+        for volume in &self.volumes {
+            flag("-v", volume);
+        }
+        for path in &self.tmpfs {
+            flag("--tmpfs", path);
+        }
+        for env in &self.env {
+            flag("-e", env);
+        }
+        for (key, value) in &self.labels {
+            flag("--label", &format!("{}={}", key, value));
+        }
+        for host in &self.extra_hosts {
+            flag("--add-host", host);
+        }
+        for cap in &self.cap_add {
+            flag("--cap-add", cap);
+        }
+        for cap in &self.cap_drop {
+            flag("--cap-drop", cap);
+        }
+        if let Some(memory) = self.memory {
+            flag("--memory", &memory.to_string());
+        }
+        if let Some(cpus) = self.cpus {
+            flag("--cpus", &cpus.to_string());
+        }
+        // `--entrypoint` takes a single executable; the rest become arguments
+        let mut trailing: Vec<String> = Vec::new();
+        if let Some(entrypoint) = &self.entrypoint {
+            if let Some((first, rest)) = entrypoint.split_first() {
+                flag("--entrypoint", first);
+                trailing.extend(rest.iter().cloned());
+            }
+        }
+        if self.privileged {
+            args.push("--privileged".into());
+        }
+        if self.read_only {
+            args.push("--read-only".into());
+        }
+        if self.init {
+            args.push("--init".into());
+        }
+        if self.interactive {
+            args.push("-i".into());
+        }
+        if self.tty {
+            args.push("-t".into());
+        }
+
+        let mut image_line = shell_quote(&self.image);
+        trailing.extend(self.cmd.iter().flatten().cloned());
+        for arg in &trailing {
+            image_line.push(' ');
+            image_line.push_str(&shell_quote(arg));
+        }
+        args.push(image_line);
+
+        let mut out = String::from("docker run \\\n");
+        out.push_str(
+            &args
+                .iter()
+                .map(|a| format!("  {}", a))
+                .collect::<Vec<_>>()
+                .join(" \\\n"),
+        );
+        out.push('\n');
+        for network in &self.extra_networks {
+            out.push_str(&format!(
+                "docker network connect {} {}\n",
+                shell_quote(network),
+                shell_quote(&self.name)
+            ));
+        }
+        out
+    }
+
+    /// Render as a `docker-compose.yml` service
+    pub fn to_compose(&self) -> String {
+        let mut out = format!("services:\n  {}:\n", compose_service_name(&self.name));
+
+        push_scalar(&mut out, "image", &self.image);
+        if !self.name.is_empty() {
+            push_scalar(&mut out, "container_name", &self.name);
+        }
+        if let Some(hostname) = &self.hostname {
+            push_scalar(&mut out, "hostname", hostname);
+        }
+        if let Some(user) = &self.user {
+            push_scalar(&mut out, "user", user);
+        }
+        if let Some(dir) = &self.working_dir {
+            push_scalar(&mut out, "working_dir", dir);
+        }
+        if let Some(restart) = &self.restart {
+            push_scalar(&mut out, "restart", restart);
+        }
+        if let Some(mode) = self
+            .network_mode
+            .as_deref()
+            .filter(|m| is_builtin_network_mode(m))
+        {
+            push_scalar(&mut out, "network_mode", mode);
+        }
+        if let Some(memory) = self.memory {
+            push_scalar(&mut out, "mem_limit", &memory.to_string());
+        }
+        if let Some(cpus) = self.cpus {
+            push_scalar(&mut out, "cpus", &cpus.to_string());
+        }
+        if let Some(entrypoint) = &self.entrypoint {
+            out.push_str(&format!("    entrypoint: {}\n", yaml_list(entrypoint)));
+        }
+        if let Some(cmd) = &self.cmd {
+            out.push_str(&format!("    command: {}\n", yaml_list(cmd)));
+        }
+
+        push_list(&mut out, "ports", &self.ports);
+        push_list(&mut out, "volumes", &self.volumes);
+        push_list(&mut out, "tmpfs", &self.tmpfs);
+        push_list(&mut out, "environment", &self.env);
+        let labels: Vec<String> = self
+            .labels
+            .iter()
+            .map(|(k, v)| format!("{}={}", k, v))
+            .collect();
+        push_list(&mut out, "labels", &labels);
+        push_list(&mut out, "extra_hosts", &self.extra_hosts);
+        push_list(&mut out, "cap_add", &self.cap_add);
+        push_list(&mut out, "cap_drop", &self.cap_drop);
+
+        let networks: Vec<String> = self
+            .network_mode
+            .iter()
+            .filter(|m| !is_builtin_network_mode(m))
+            .chain(self.extra_networks.iter())
+            .cloned()
+            .collect();
+        push_list(&mut out, "networks", &networks);
+
+        for (key, enabled) in [
+            ("privileged", self.privileged),
+            ("read_only", self.read_only),
+            ("init", self.init),
+            ("stdin_open", self.interactive),
+            ("tty", self.tty),
+        ] {
+            if enabled {
+                out.push_str(&format!("    {}: true\n", key));
+            }
+        }
+
+        // Networks and named volumes already exist outside this compose file
+        if !networks.is_empty() {
+            out.push_str("networks:\n");
+            for network in &networks {
+                out.push_str(&format!("  {}:\n    external: true\n", network));
+            }
+        }
+        let named_volumes: Vec<&str> = self
+            .volumes
+            .iter()
+            .filter_map(|v| v.split(':').next())
+            .filter(|source| !source.starts_with('/') && !source.starts_with('.'))
+            .collect();
+        if !named_volumes.is_empty() {
+            out.push_str("volumes:\n");
+            for volume in named_volumes {
+                out.push_str(&format!("  {}:\n    external: true\n", volume));
+            }
+        }
+        out
+    }
+}
+
+fn push_scalar(out: &mut String, key: &str, value: &str) {
+    out.push_str(&format!("    {}: {}\n", key, yaml_quote(value)));
+}
+
+fn push_list(out: &mut String, key: &str, items: &[String]) {
+    if items.is_empty() {
+        return;
+    }
+    out.push_str(&format!("    {}:\n", key));
+    for item in items {
+        out.push_str(&format!("      - {}\n", yaml_quote(item)));
+    }
+}
+
+fn is_builtin_network_mode(mode: &str) -> bool {
+    matches!(mode, "host" | "none")
+        || mode.starts_with("container:")
+        || mode.starts_with("service:")
+}
+
+/// Compose service keys are limited to `[a-zA-Z0-9._-]`
+fn compose_service_name(name: &str) -> String {
+    let cleaned: String = name
+        .chars()
+        .map(|c| {
+            if c.is_ascii_alphanumeric() || matches!(c, '.' | '_' | '-') {
+                c
+            } else {
+                '_'
+            }
+        })
+        .collect();
+    if cleaned.is_empty() {
+        "app".to_string()
+    } else {
+        cleaned
+    }
+}
+
+/// Quote a shell word when it contains anything but safe characters
+pub fn shell_quote(value: &str) -> String {
+    let safe = !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "-_./:=@%+,".contains(c));
+    if safe {
+        value.to_string()
+    } else {
+        format!("'{}'", value.replace('\'', r"'\''"))
+    }
+}
+
+/// JSON strings are valid YAML scalars and need no further escaping rules.
+/// Compose interpolates `$` in every value, so it is doubled to stay literal.
+fn yaml_quote(value: &str) -> String {
+    let value = value.replace('$', "$$");
+    serde_json::to_string(&value).unwrap_or_else(|_| format!("\"{}\"", value))
+}
+
+fn yaml_list(items: &[String]) -> String {
+    format!(
+        "[{}]",
+        items
+            .iter()
+            .map(|i| yaml_quote(i))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
+
+impl DockerClient {
+    /// Inspect a container and its image and build a run spec
+    pub async fn run_spec(&self, id: &str) -> Result<RunSpec> {
+        let inspect = self
+            .inner()
+            .inspect_container(id, None)
+            .await
+            .map_err(|e| DockerError::Container(format!("Failed to inspect: {}", e)))?;
+
+        // Image defaults are best effort; without them nothing is filtered out
+        let image_id = inspect.image.clone().unwrap_or_default();
+        let image_config = match self.inner().inspect_image(&image_id).await {
+            Ok(image) => image.config,
+            Err(e) => {
+                tracing::warn!("Failed to inspect image {}: {}", image_id, e);
+                None
+            }
+        };
+
+        Ok(RunSpec::from_inspect(&inspect, image_config.as_ref()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bollard::models::{
+        ContainerConfig, EndpointSettings, HostConfig, MountPoint, NetworkSettings, PortBinding,
+        RestartPolicy,
+    };
+    use std::collections::HashMap;
+
+    fn sample() -> (ContainerInspectResponse, ImageConfig) {
+        let inspect = ContainerInspectResponse {
+            id: Some("0123456789abcdef".into()),
+            name: Some("/web".into()),
+            config: Some(ContainerConfig {
+                hostname: Some("0123456789ab".into()),
+                image: Some("nginx:1.25".into()),
+                env: Some(vec![
+                    "PATH=/usr/bin".into(),
+                    "MODE=prod".into(),
+                    "GREETING=hello world".into(),
+                ]),
+                cmd: Some(vec!["nginx".into(), "-g".into(), "daemon off;".into()]),
+                labels: Some(HashMap::from([
+                    ("maintainer".into(), "nginx".into()),
+                    ("team".into(), "web".into()),
+                    ("com.docker.compose.project".into(), "x".into()),
+                ])),
+                ..Default::default()
+            }),
+            host_config: Some(HostConfig {
+                network_mode: Some("frontend".into()),
+                port_bindings: Some(HashMap::from([
+                    (
+                        "80/tcp".into(),
+                        Some(vec![PortBinding {
+                            host_ip: Some("0.0.0.0".into()),
+                            host_port: Some("8080".into()),
+                        }]),
+                    ),
+                    (
+                        "53/udp".into(),
+                        Some(vec![PortBinding {
+                            host_ip: Some("127.0.0.1".into()),
+                            host_port: Some("5353".into()),
+                        }]),
+                    ),
+                ])),
+                restart_policy: Some(RestartPolicy {
+                    name: Some(RestartPolicyNameEnum::UNLESS_STOPPED),
+                    maximum_retry_count: Some(0),
+                }),
+                memory: Some(268_435_456),
+                ..Default::default()
+            }),
+            mounts: Some(vec![
+                MountPoint {
+                    typ: Some(MountPointTypeEnum::VOLUME),
+                    name: Some("html".into()),
+                    destination: Some("/usr/share/nginx/html".into()),
+                    rw: Some(true),
+                    ..Default::default()
+                },
+                MountPoint {
+                    typ: Some(MountPointTypeEnum::BIND),
+                    source: Some("/srv/nginx.conf".into()),
+                    destination: Some("/etc/nginx/nginx.conf".into()),
+                    rw: Some(false),
+                    ..Default::default()
+                },
+                MountPoint {
+                    typ: Some(MountPointTypeEnum::VOLUME),
+                    name: Some("a".repeat(64)),
+                    destination: Some("/cache".into()),
+                    ..Default::default()
+                },
+            ]),
+            network_settings: Some(NetworkSettings {
+                networks: Some(HashMap::from([
+                    ("frontend".into(), EndpointSettings::default()),
+                    ("backend".into(), EndpointSettings::default()),
+                ])),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+        let image = ImageConfig {
+            env: Some(vec!["PATH=/usr/bin".into()]),
+            cmd: Some(vec!["nginx".into(), "-g".into(), "daemon off;".into()]),
+            labels: Some(HashMap::from([("maintainer".into(), "nginx".into())])),
+            volumes: Some(HashMap::from([("/cache".into(), HashMap::new())])),
+            ..Default::default()
+        };
+        (inspect, image)
+    }
+
+    #[test]
+    fn leaves_out_image_defaults() {
+        let (inspect, image) = sample();
+        let spec = RunSpec::from_inspect(&inspect, Some(&image));
+
+        assert_eq!(spec.name, "web");
+        assert_eq!(spec.hostname, None);
+        assert_eq!(spec.cmd, None);
+        assert_eq!(spec.env, vec!["MODE=prod", "GREETING=hello world"]);
+        assert_eq!(spec.labels, vec![("team".to_string(), "web".to_string())]);
+        assert_eq!(spec.ports, vec!["127.0.0.1:5353:53/udp", "8080:80"]);
+        assert_eq!(
+            spec.volumes,
+            vec![
+                "/srv/nginx.conf:/etc/nginx/nginx.conf:ro",
+                "html:/usr/share/nginx/html"
+            ]
+        );
+        assert_eq!(spec.network_mode.as_deref(), Some("frontend"));
+        assert_eq!(spec.extra_networks, vec!["backend"]);
+        assert_eq!(spec.restart.as_deref(), Some("unless-stopped"));
+    }
+
+    #[test]
+    fn renders_docker_run() {
+        let (inspect, image) = sample();
+        let run = RunSpec::from_inspect(&inspect, Some(&image)).to_docker_run();
+
+        assert!(run.starts_with("docker run \\\n  -d \\\n  --name web"));
+        assert!(run.contains("  -p 8080:80 \\\n"));
+        assert!(run.contains("  -e 'GREETING=hello world' \\\n"));
+        assert!(run.contains("  --memory 268435456 \\\n"));
+        assert!(run.contains("  --network frontend \\\n"));
+        assert!(run.contains("  nginx:1.25\n"));
+        assert!(run.ends_with("docker network connect backend web\n"));
+    }
+
+    #[test]
+    fn entrypoint_override_keeps_explicit_command() {
+        let (mut inspect, image) = sample();
+        if let Some(config) = inspect.config.as_mut() {
+            config.entrypoint = Some(vec!["/bin/sh".into(), "-c".into()]);
+            config.cmd = Some(vec!["echo it's me".into()]);
+        }
+        let run = RunSpec::from_inspect(&inspect, Some(&image)).to_docker_run();
+        assert!(run.contains("  --entrypoint /bin/sh \\\n"));
+        assert!(run.contains("  nginx:1.25 -c 'echo it'\\''s me'\n"));
+    }
+
+    #[test]
+    fn renders_compose_service() {
+        let (inspect, image) = sample();
+        let compose = RunSpec::from_inspect(&inspect, Some(&image)).to_compose();
+
+        assert!(compose.starts_with("services:\n  web:\n    image: \"nginx:1.25\"\n"));
+        assert!(compose.contains("    restart: \"unless-stopped\"\n"));
+        assert!(compose.contains("    ports:\n      - \"127.0.0.1:5353:53/udp\"\n"));
+        assert!(compose.contains("    networks:\n      - \"frontend\"\n      - \"backend\"\n"));
+        assert!(compose.contains("networks:\n  frontend:\n    external: true\n"));
+        assert!(compose.contains("volumes:\n  html:\n    external: true\n"));
+        assert!(!compose.contains("command:"));
+        assert!(!compose.contains("network_mode"));
+    }
+
+    #[test]
+    fn escapes_dollars_for_compose() {
+        let (mut inspect, image) = sample();
+        let config = inspect.config.as_mut().unwrap();
+        config.env.as_mut().unwrap().push("PASSWORD=pa$word".into());
+        config
+            .labels
+            .as_mut()
+            .unwrap()
+            .insert("price".into(), "${FREE}".into());
+        let spec = RunSpec::from_inspect(&inspect, Some(&image));
+
+        let compose = spec.to_compose();
+        assert!(compose.contains("      - \"PASSWORD=pa$$word\"\n"));
+        assert!(compose.contains("      - \"price=$${FREE}\"\n"));
+        // The shell command keeps them as they are
+        assert!(spec.to_docker_run().contains("'PASSWORD=pa$word'"));
+    }
+
+    #[test]
+    fn quotes_shell_words() {
+        assert_eq!(shell_quote("nginx:1.25"), "nginx:1.25");
+        assert_eq!(shell_quote("a b"), "'a b'");
+        assert_eq!(shell_quote(""), "''");
+    }
+}
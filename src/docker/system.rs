@@ -1,5 +1,7 @@
 //! Docker system operations (disk usage, prune)
 
+use std::collections::HashMap;
+
 use bollard::models::SystemDataUsageResponse;
 use serde::{Deserialize, Serialize};
 use tracing::info;
@@ -27,6 +29,15 @@ impl ResourceUsage {
     }
 }
 
+/// Disk usage of a single volume
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VolumeUsage {
+    /// Size in bytes
+    pub size: i64,
+    /// Number of containers referencing the volume
+    pub ref_count: i64,
+}
+
 /// System-wide disk usage information
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SystemDiskUsage {
@@ -38,6 +49,9 @@ pub struct SystemDiskUsage {
     pub volumes: ResourceUsage,
     /// Build cache usage
     pub build_cache: ResourceUsage,
+    /// Per-volume usage by volume name (only volumes that report it)
+    #[serde(default)]
+    pub volume_usage: HashMap<String, VolumeUsage>,
 }
 
 impl SystemDiskUsage {
@@ -221,6 +235,9 @@ fn parse_disk_usage(response: SystemDataUsageResponse) -> SystemDiskUsage {
             // Only count size if it's available (not -1)
             if size >= 0 {
                 usage.volumes.total += size;
+                usage
+                    .volume_usage
+                    .insert(volume.name.clone(), VolumeUsage { size, ref_count });
             }
             usage.volumes.count += 1;
 
@@ -260,3 +277,41 @@ fn parse_disk_usage(response: SystemDataUsageResponse) -> SystemDiskUsage {
 
     usage
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bollard::models::{Volume, VolumeUsageData};
+
+    fn volume(name: &str, usage: Option<(i64, i64)>) -> Volume {
+        Volume {
+            name: name.to_string(),
+            usage_data: usage.map(|(size, ref_count)| VolumeUsageData { size, ref_count }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_volume_usage() {
+        let usage = parse_disk_usage(SystemDataUsageResponse {
+            volumes: Some(vec![
+                volume("data", Some((2048, 1))),
+                volume("cache", Some((512, 0))),
+                volume("nfs", Some((-1, -1))),
+                volume("plugin", None),
+            ]),
+            ..Default::default()
+        });
+        assert_eq!(usage.volumes.count, 4);
+        assert_eq!(usage.volumes.total, 2560);
+        assert_eq!(usage.volumes.reclaimable, 512);
+        assert_eq!(usage.volume_usage.len(), 2);
+        assert_eq!(
+            usage.volume_usage["data"],
+            VolumeUsage {
+                size: 2048,
+                ref_count: 1
+            }
+        );
+    }
+}
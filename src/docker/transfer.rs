@@ -0,0 +1,332 @@
+//! Image pulls and pushes with per-layer progress
+
+use bollard::auth::DockerCredentials;
+use bollard::image::{CreateImageOptions, PushImageOptions};
+use bollard::models::{CreateImageInfo, PushImageInfo};
+use futures::{Stream, StreamExt};
+use tracing::info;
+
+use crate::core::{DockerError, Result};
+use crate::docker::images::split_image_tag;
+use crate::docker::DockerClient;
+
+/// Progress of one layer in a pull
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LayerProgress {
+    /// Short layer ID as reported by the daemon
+    pub id: String,
+    /// Latest status, e.g. "Downloading" or "Pull complete"
+    pub status: String,
+    /// Bytes downloaded or uploaded so far
+    pub transferred: u64,
+    /// Compressed layer size, 0 until known
+    pub size: u64,
+}
+
+impl LayerProgress {
+    /// Whether the layer needs no more work
+    pub fn is_complete(&self) -> bool {
+        matches!(
+            self.status.as_str(),
+            "Pull complete" | "Already exists" | "Pushed"
+        )
+    }
+
+    /// Completion in the range 0.0..=1.0
+    pub fn ratio(&self) -> f64 {
+        if self.is_complete() {
+            1.0
+        } else if self.size == 0 {
+            0.0
+        } else {
+            (self.transferred as f64 / self.size as f64).min(1.0)
+        }
+    }
+}
+
+/// Accumulated progress of a pull or push, built from the daemon's stream
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct TransferProgress {
+    /// Layers in the order the daemon first mentioned them
+    pub layers: Vec<LayerProgress>,
+    /// Latest message not tied to a layer
+    pub status: String,
+    /// Manifest digest reported at the end of the transfer
+    pub digest: Option<String>,
+    /// Push only: layers uploaded
+    pub layers_pushed: usize,
+    /// Push only: layers the registry already had
+    pub layers_existing: usize,
+}
+
+impl TransferProgress {
+    /// Fold one stream message into the progress
+    pub fn apply(&mut self, info: &CreateImageInfo) {
+        let status = info.status.clone().unwrap_or_default();
+        if let Some(digest) = status.strip_prefix("Digest: ") {
+            self.digest = Some(digest.trim().to_string());
+            return;
+        }
+
+        // Layer messages carry the layer ID; the rest describe the whole pull
+        let layer_id = info
+            .id
+            .as_deref()
+            .filter(|_| !status.starts_with("Pulling from") && !status.starts_with("Status:"));
+        let Some(layer_id) = layer_id else {
+            if !status.is_empty() {
+                self.status = status;
+            }
+            return;
+        };
+
+        let index = match self.layers.iter().position(|l| l.id == layer_id) {
+            Some(index) => index,
+            None => {
+                self.layers.push(LayerProgress {
+                    id: layer_id.to_string(),
+                    ..Default::default()
+                });
+                self.layers.len() - 1
+            }
+        };
+        let layer = &mut self.layers[index];
+        let detail = info.progress_detail.as_ref();
+        match status.as_str() {
+            "Downloading" => {
+                if let Some(total) = detail.and_then(|d| d.total).filter(|t| *t > 0) {
+                    layer.size = total as u64;
+                }
+                if let Some(current) = detail.and_then(|d| d.current) {
+                    layer.transferred = current.max(0) as u64;
+                }
+            }
+            "Download complete" => layer.transferred = layer.size,
+            _ => {}
+        }
+        layer.status = status;
+    }
+
+    /// Fold one push stream message into the progress. bollard's push
+    /// messages carry no layer ID, so uploads are told apart by their size
+    /// and finished layers are only counted.
+    pub fn apply_push(&mut self, info: &PushImageInfo) {
+        let status = info.status.clone().unwrap_or_default();
+        // e.g. "1.0: digest: sha256:abc... size: 1570"
+        if let Some((_, rest)) = status.split_once("digest: ") {
+            self.digest = rest.split_whitespace().next().map(String::from);
+            return;
+        }
+        match status.as_str() {
+            "Pushing" => {
+                let detail = info.progress_detail.as_ref();
+                let Some(total) = detail.and_then(|d| d.total).filter(|t| *t > 0) else {
+                    return;
+                };
+                let total = total as u64;
+                let index = match self
+                    .layers
+                    .iter()
+                    .position(|l| l.size == total && !l.is_complete())
+                {
+                    Some(index) => index,
+                    None => {
+                        self.layers.push(LayerProgress {
+                            id: format!("layer {}", self.layers.len() + 1),
+                            size: total,
+                            ..Default::default()
+                        });
+                        self.layers.len() - 1
+                    }
+                };
+                let layer = &mut self.layers[index];
+                layer.status = status;
+                if let Some(current) = detail.and_then(|d| d.current) {
+                    layer.transferred = (current.max(0) as u64).min(total);
+                }
+            }
+            "Pushed" => self.layers_pushed += 1,
+            "Layer already exists" => self.layers_existing += 1,
+            "Preparing" | "Waiting" | "" => {}
+            _ => self.status = status,
+        }
+    }
+
+    /// Mark every layer finished once the push has succeeded
+    pub fn complete_push(&mut self) {
+        for layer in &mut self.layers {
+            layer.transferred = layer.size;
+            layer.status = "Pushed".to_string();
+        }
+    }
+
+    /// Bytes transferred across all layers
+    pub fn transferred_bytes(&self) -> u64 {
+        self.layers
+            .iter()
+            .map(|l| {
+                if l.is_complete() {
+                    l.size
+                } else {
+                    l.transferred
+                }
+            })
+            .sum()
+    }
+
+    /// Known compressed size across all layers
+    pub fn total_bytes(&self) -> u64 {
+        self.layers.iter().map(|l| l.size).sum()
+    }
+}
+
+impl DockerClient {
+    /// Pull an image, streaming the daemon's progress messages. Dropping the
+    /// stream cancels the pull.
+    pub fn pull_image_progress(
+        &self,
+        image: &str,
+        credentials: Option<DockerCredentials>,
+    ) -> impl Stream<Item = Result<CreateImageInfo>> + 'static {
+        info!("Pulling image {} with progress", image);
+        let (from_image, tag) = split_image_tag(image);
+        let options = CreateImageOptions {
+            from_image: from_image.to_string(),
+            tag: tag.to_string(),
+            ..Default::default()
+        };
+        let image = image.to_string();
+        self.inner()
+            .create_image(Some(options), None, credentials)
+            .map(move |item| {
+                let info = item
+                    .map_err(|e| DockerError::Image(format!("Failed to pull {}: {}", image, e)))?;
+                match &info.error {
+                    Some(error) => Err(DockerError::Image(format!(
+                        "Failed to pull {}: {}",
+                        image, error
+                    ))
+                    .into()),
+                    None => Ok(info),
+                }
+            })
+    }
+
+    /// Push a tag to its registry, streaming the daemon's progress messages.
+    /// Dropping the stream cancels the push.
+    pub fn push_image_progress(
+        &self,
+        reference: &str,
+        credentials: Option<DockerCredentials>,
+    ) -> impl Stream<Item = Result<PushImageInfo>> + 'static {
+        info!("Pushing image {}", reference);
+        let (name, tag) = split_image_tag(reference);
+        let reference = reference.to_string();
+        self.inner()
+            .push_image(
+                name,
+                Some(PushImageOptions {
+                    tag: tag.to_string(),
+                }),
+                credentials,
+            )
+            .map(move |item| {
+                item.map_err(|e| {
+                    DockerError::Image(format!("Failed to push {}: {}", reference, e)).into()
+                })
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bollard::models::ProgressDetail;
+
+    fn msg(id: Option<&str>, status: &str, progress: Option<(i64, i64)>) -> CreateImageInfo {
+        CreateImageInfo {
+            id: id.map(String::from),
+            status: Some(status.to_string()),
+            progress_detail: progress.map(|(current, total)| ProgressDetail {
+                current: Some(current),
+                total: Some(total),
+            }),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn push_progress_by_layer_size() {
+        let push = |status: &str, progress: Option<(i64, i64)>| PushImageInfo {
+            status: Some(status.to_string()),
+            progress_detail: progress.map(|(current, total)| ProgressDetail {
+                current: Some(current),
+                total: Some(total),
+            }),
+            ..Default::default()
+        };
+        let mut progress = TransferProgress::default();
+        for info in [
+            push("The push refers to repository [localhost:5000/app]", None),
+            push("Preparing", None),
+            push("Layer already exists", None),
+            push("Pushing", Some((10, 100))),
+            push("Pushing", Some((20, 300))),
+            push("Pushing", Some((100, 100))),
+            push("Pushed", None),
+            push("1.0: digest: sha256:abc size: 1570", None),
+        ] {
+            progress.apply_push(&info);
+        }
+        assert_eq!(
+            progress.status,
+            "The push refers to repository [localhost:5000/app]"
+        );
+        assert_eq!(progress.layers.len(), 2);
+        assert_eq!(progress.layers[0].transferred, 100);
+        assert_eq!(progress.transferred_bytes(), 120);
+        assert_eq!((progress.layers_pushed, progress.layers_existing), (1, 1));
+        assert_eq!(progress.digest.as_deref(), Some("sha256:abc"));
+
+        progress.complete_push();
+        assert_eq!(progress.transferred_bytes(), progress.total_bytes());
+    }
+
+    #[test]
+    fn tracks_layers_bytes_and_digest() {
+        let mut progress = TransferProgress::default();
+        for info in [
+            msg(Some("1.25"), "Pulling from library/nginx", None),
+            msg(Some("aaa"), "Already exists", None),
+            msg(Some("bbb"), "Pulling fs layer", None),
+            msg(Some("bbb"), "Downloading", Some((100, 400))),
+            msg(Some("ccc"), "Downloading", Some((50, 100))),
+        ] {
+            progress.apply(&info);
+        }
+        assert_eq!(progress.status, "Pulling from library/nginx");
+        let ids: Vec<&str> = progress.layers.iter().map(|l| l.id.as_str()).collect();
+        assert_eq!(ids, vec!["aaa", "bbb", "ccc"]);
+        assert_eq!(progress.layers[0].ratio(), 1.0);
+        assert_eq!(progress.layers[1].ratio(), 0.25);
+        assert_eq!(progress.transferred_bytes(), 150);
+        assert_eq!(progress.total_bytes(), 500);
+
+        progress.apply(&msg(Some("bbb"), "Download complete", None));
+        progress.apply(&msg(Some("bbb"), "Extracting", Some((10, 900))));
+        assert_eq!(progress.layers[1].transferred, 400);
+        assert_eq!(progress.layers[1].status, "Extracting");
+
+        progress.apply(&msg(None, "Digest: sha256:abc", None));
+        progress.apply(&msg(
+            None,
+            "Status: Downloaded newer image for nginx:1.25",
+            None,
+        ));
+        assert_eq!(progress.digest.as_deref(), Some("sha256:abc"));
+        assert_eq!(
+            progress.status,
+            "Status: Downloaded newer image for nginx:1.25"
+        );
+    }
+}
@@ -0,0 +1,332 @@
+//! Volume backup to and restore from tar archives
+//!
+//! Both go through a helper container (see `volume_browser`): a backup
+//! downloads the archive of the mounted volume and gzips it locally, a
+//! restore uploads the archive into the mount. Each backup gets a sidecar
+//! manifest (`<backup>.json`) recording the volume's driver, options and
+//! labels, so a restore into a new volume recreates it the same way.
+
+use std::path::{Path, PathBuf};
+
+use bollard::container::{DownloadFromContainerOptions, UploadToContainerOptions};
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+use tracing::{info, warn};
+
+use crate::core::{DockerError, Result};
+use crate::docker::archive::{file_chunks, is_gzip_path, request_body, ArchiveWriter};
+use crate::docker::volume_browser::{strip_root, MOUNT_PATH};
+use crate::docker::{DockerClient, VolumeOptions};
+
+/// What a backup was taken from, stored next to the archive
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BackupManifest {
+    pub volume: String,
+    pub driver: String,
+    #[serde(default)]
+    pub options: std::collections::HashMap<String, String>,
+    #[serde(default)]
+    pub labels: std::collections::HashMap<String, String>,
+    pub created: DateTime<Utc>,
+    /// Uncompressed archive size
+    pub size: u64,
+}
+
+/// Path of the manifest written next to a backup
+pub fn manifest_path(backup: &Path) -> PathBuf {
+    let mut path = backup.as_os_str().to_owned();
+    path.push(".json");
+    PathBuf::from(path)
+}
+
+/// Read a backup's manifest, if it has one
+pub fn read_manifest(backup: &Path) -> Result<Option<BackupManifest>> {
+    let path = manifest_path(backup);
+    let data = match std::fs::read(&path) {
+        Ok(data) => data,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => {
+            return Err(
+                DockerError::Volume(format!("Failed to read {}: {}", path.display(), e)).into(),
+            )
+        }
+    };
+    serde_json::from_slice(&data).map(Some).map_err(|e| {
+        DockerError::Volume(format!("Invalid manifest {}: {}", path.display(), e)).into()
+    })
+}
+
+/// Volume name for a backup file without a manifest: `data.tar.gz` -> `data`
+pub fn volume_name_from_backup(backup: &Path) -> String {
+    let name = backup
+        .file_name()
+        .map(|n| n.to_string_lossy().to_string())
+        .unwrap_or_default();
+    [".tar.gz", ".tgz", ".tar"]
+        .iter()
+        .find_map(|ext| name.strip_suffix(ext))
+        .unwrap_or(&name)
+        .to_string()
+}
+
+/// Whether the start of a volume archive shows any file besides the root
+fn archive_has_files(data: &[u8]) -> bool {
+    let mut archive = tar::Archive::new(data);
+    let Ok(entries) = archive.entries() else {
+        return false;
+    };
+    entries.map_while(|entry| entry.ok()).any(|entry| {
+        entry
+            .path()
+            .is_ok_and(|path| !strip_root(&path.to_string_lossy()).is_empty())
+    })
+}
+
+/// Outcome of a restore
+#[derive(Debug, Clone, PartialEq)]
+pub enum RestoreOutcome {
+    /// Archive bytes uploaded; `created` when the volume was new
+    Restored { created: bool, bytes: u64 },
+    /// The volume has files and overwriting wasn't allowed
+    NotEmpty,
+}
+
+impl DockerClient {
+    /// Whether a volume exists
+    async fn volume_exists(&self, name: &str) -> Result<bool> {
+        match self.inner().inspect_volume(name).await {
+            Ok(_) => Ok(true),
+            Err(bollard::errors::Error::DockerResponseServerError {
+                status_code: 404, ..
+            }) => Ok(false),
+            Err(e) => Err(DockerError::Volume(format!("Failed to inspect {}: {}", name, e)).into()),
+        }
+    }
+
+    /// Whether a helper's volume has any files. Only the first few archive
+    /// blocks are read.
+    async fn helper_volume_has_files(&self, helper_id: &str) -> Result<bool> {
+        let mut stream = self.inner().download_from_container(
+            helper_id,
+            Some(DownloadFromContainerOptions { path: MOUNT_PATH }),
+        );
+        let mut data = Vec::new();
+        while let Some(chunk) = stream.next().await {
+            let chunk =
+                chunk.map_err(|e| DockerError::Volume(format!("Failed to read volume: {}", e)))?;
+            data.extend_from_slice(&chunk);
+            if data.len() >= 8 * 1024 {
+                break;
+            }
+        }
+        Ok(archive_has_files(&data))
+    }
+
+    /// Back up a volume to a gzipped tar (plain tar unless the path ends in
+    /// `.gz`/`.tgz`) and write its manifest. `on_progress` receives the
+    /// archive bytes read so far.
+    pub async fn backup_volume(
+        &self,
+        volume: &str,
+        path: &Path,
+        helper_image: &str,
+        mut on_progress: impl FnMut(u64),
+    ) -> Result<BackupManifest> {
+        info!("Backing up volume {} to {}", volume, path.display());
+        let details = self
+            .inner()
+            .inspect_volume(volume)
+            .await
+            .map_err(|e| DockerError::Volume(format!("Failed to inspect {}: {}", volume, e)))?;
+        let helper_id = self
+            .create_volume_helper(volume, helper_image, true)
+            .await?;
+
+        let write_error = |e: std::io::Error| {
+            DockerError::Volume(format!("Failed to write {}: {}", path.display(), e))
+        };
+        let result: Result<u64> = async {
+            let writer = ArchiveWriter::create(path, is_gzip_path(&path.to_string_lossy()))
+                .await
+                .map_err(write_error)?;
+            let mut stream = self.inner().download_from_container(
+                &helper_id,
+                Some(DownloadFromContainerOptions { path: MOUNT_PATH }),
+            );
+            let mut read = 0u64;
+            while let Some(chunk) = stream.next().await {
+                let chunk = chunk
+                    .map_err(|e| DockerError::Volume(format!("Failed to read volume: {}", e)))?;
+                let len = chunk.len() as u64;
+                if !writer.write(chunk).await {
+                    break;
+                }
+                read += len;
+                on_progress(read);
+            }
+            writer.finish().await.map_err(write_error)?;
+            Ok(read)
+        }
+        .await;
+        if let Err(e) = self.remove_volume_helper(&helper_id).await {
+            warn!("Failed to remove volume helper {}: {}", helper_id, e);
+        }
+        let size = match result {
+            Ok(size) => size,
+            Err(e) => {
+                let _ = std::fs::remove_file(path);
+                return Err(e);
+            }
+        };
+
+        let manifest = BackupManifest {
+            volume: volume.to_string(),
+            driver: details.driver,
+            options: details.options,
+            labels: details.labels,
+            created: Utc::now(),
+            size,
+        };
+        let json = serde_json::to_vec_pretty(&manifest)
+            .map_err(|e| DockerError::Volume(format!("Failed to write manifest: {}", e)))?;
+        std::fs::write(manifest_path(path), json).map_err(write_error)?;
+        info!("Backed up {} ({} bytes)", volume, size);
+        Ok(manifest)
+    }
+
+    /// Restore a backup into `volume`, creating it (from the manifest, when
+    /// there is one) if it doesn't exist. Unless `overwrite` is set, a volume
+    /// that already has files is left alone. `on_progress` receives the
+    /// archive bytes sent so far.
+    pub async fn restore_volume(
+        &self,
+        path: &Path,
+        volume: &str,
+        helper_image: &str,
+        overwrite: bool,
+        mut on_progress: impl FnMut(u64) + Send + 'static,
+    ) -> Result<RestoreOutcome> {
+        info!("Restoring {} into volume {}", path.display(), volume);
+        let file = tokio::fs::File::open(path).await.map_err(|e| {
+            DockerError::Volume(format!("Failed to open {}: {}", path.display(), e))
+        })?;
+
+        let created = !self.volume_exists(volume).await?;
+        if created {
+            let manifest = read_manifest(path)?;
+            let options = VolumeOptions {
+                name: volume.to_string(),
+                driver: manifest
+                    .as_ref()
+                    .map(|m| m.driver.clone())
+                    .unwrap_or_default(),
+                driver_opts: manifest
+                    .as_ref()
+                    .map(|m| m.options.clone())
+                    .unwrap_or_default(),
+                labels: manifest.map(|m| m.labels).unwrap_or_default(),
+            };
+            self.create_volume(&options).await?;
+        }
+        let helper_id = self
+            .create_volume_helper(volume, helper_image, false)
+            .await?;
+
+        let result: Result<RestoreOutcome> = async {
+            if !created && !overwrite && self.helper_volume_has_files(&helper_id).await? {
+                return Ok(RestoreOutcome::NotEmpty);
+            }
+            let mut sent = 0u64;
+            let (body, read_error) = request_body(file_chunks(file).map(move |chunk| {
+                if let Ok(chunk) = &chunk {
+                    sent += chunk.len() as u64;
+                    on_progress(sent);
+                }
+                chunk
+            }));
+            // The archive holds `volume/...`, which lands on the mount
+            let upload = self.inner().upload_to_container_streaming(
+                &helper_id,
+                Some(UploadToContainerOptions {
+                    path: "/",
+                    no_overwrite_dir_non_dir: "",
+                }),
+                body,
+            );
+            tokio::select! {
+                result = upload => {
+                    result.map_err(|e| DockerError::Volume(format!("Failed to restore: {}", e)))?
+                }
+                e = read_error => {
+                    return Err(DockerError::Volume(format!(
+                        "Failed to read {}: {}",
+                        path.display(),
+                        e
+                    ))
+                    .into())
+                }
+            }
+            let bytes = std::fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+            Ok(RestoreOutcome::Restored { created, bytes })
+        }
+        .await;
+        if let Err(e) = self.remove_volume_helper(&helper_id).await {
+            warn!("Failed to remove volume helper {}: {}", helper_id, e);
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn archive(paths: &[&str]) -> Vec<u8> {
+        let mut builder = tar::Builder::new(Vec::new());
+        for path in paths {
+            let mut header = tar::Header::new_gnu();
+            if path.ends_with('/') {
+                header.set_entry_type(tar::EntryType::Directory);
+            }
+            header.set_mode(0o755);
+            header.set_size(1);
+            builder.append_data(&mut header, path, &b"x"[..]).unwrap();
+        }
+        builder.into_inner().unwrap()
+    }
+
+    #[test]
+    fn detects_files_in_a_volume_archive() {
+        assert!(!archive_has_files(&archive(&["volume/"])));
+        assert!(archive_has_files(&archive(&["volume/", "volume/a"])));
+        // Cut short after the first file's header
+        let data = archive(&["volume/", "volume/big"]);
+        assert!(archive_has_files(&data[..1536]));
+    }
+
+    #[test]
+    fn manifest_sits_next_to_the_backup() {
+        let dir = tempfile::tempdir().unwrap();
+        let backup = dir.path().join("data.tar.gz");
+        assert_eq!(manifest_path(&backup), dir.path().join("data.tar.gz.json"));
+        assert_eq!(volume_name_from_backup(&backup), "data");
+        assert_eq!(volume_name_from_backup(Path::new("pg.tar")), "pg");
+        assert!(read_manifest(&backup).unwrap().is_none());
+
+        let manifest = BackupManifest {
+            volume: "data".into(),
+            driver: "local".into(),
+            options: [("type".to_string(), "tmpfs".to_string())].into(),
+            labels: [("team".to_string(), "db".to_string())].into(),
+            created: Utc::now(),
+            size: 1024,
+        };
+        std::fs::write(
+            manifest_path(&backup),
+            serde_json::to_vec(&manifest).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(read_manifest(&backup).unwrap(), Some(manifest));
+    }
+}
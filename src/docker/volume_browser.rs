@@ -0,0 +1,522 @@
+//! Browse a volume's files through a stopped helper container
+//!
+//! The volume is mounted read-only into a container that is created but
+//! never started; the archive API can still read its mounts. This works on
+//! Docker Desktop and remote hosts, where the volume's mountpoint isn't on
+//! the local filesystem.
+//!
+//! The listing streams the whole volume once, parsing the archive as it
+//! arrives: only headers are kept and file contents are skipped, nothing is
+//! written to disk. Previews stop reading after the first `PREVIEW_BYTES`.
+
+use std::collections::HashMap;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use bollard::container::{
+    Config, CreateContainerOptions, DownloadFromContainerOptions, RemoveContainerOptions,
+};
+use bollard::models::HostConfig;
+use bytes::Bytes;
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use tokio::sync::mpsc;
+use tracing::{debug, info};
+
+use crate::core::{DockerError, Result};
+use crate::docker::archive::ArchiveWriter;
+use crate::docker::DockerClient;
+
+/// Label marking a helper with the volume it browses
+pub const VOLUME_HELPER_LABEL: &str = "contui.volume-browser-for";
+
+/// Where the volume is mounted in the helper
+pub(super) const MOUNT_PATH: &str = "/volume";
+
+/// Bytes of a file shown in a preview
+pub const PREVIEW_BYTES: usize = 64 * 1024;
+
+/// Downloaded chunks queued for the listing parser
+const LIST_QUEUE: usize = 16;
+
+/// Kind of a volume entry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum VolumeEntryKind {
+    File,
+    Directory,
+    /// Symbolic link and its target
+    Symlink(String),
+    Other,
+}
+
+/// A file or directory in a volume
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolumeEntry {
+    /// Path relative to the volume root, without a trailing slash
+    pub path: String,
+    pub kind: VolumeEntryKind,
+    pub size: u64,
+    pub mode: u32,
+    pub modified: Option<DateTime<Utc>>,
+}
+
+impl VolumeEntry {
+    pub fn name(&self) -> &str {
+        self.path.rsplit('/').next().unwrap_or(&self.path)
+    }
+
+    /// Path of the containing directory, empty at the root
+    pub fn parent(&self) -> &str {
+        self.path.rsplit_once('/').map_or("", |(parent, _)| parent)
+    }
+
+    pub fn is_dir(&self) -> bool {
+        self.kind == VolumeEntryKind::Directory
+    }
+}
+
+/// First part of a file, for previewing
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilePreview {
+    pub path: String,
+    pub lines: Vec<String>,
+    /// Only the first `PREVIEW_BYTES` are shown
+    pub truncated: bool,
+}
+
+/// Drop the first component of an archive path: the archive of `/volume`
+/// holds `volume/...`
+pub(super) fn strip_root(path: &str) -> &str {
+    let path = path.trim_start_matches("./").trim_end_matches('/');
+    path.split_once('/').map_or("", |(_, rest)| rest)
+}
+
+/// List the entries of a volume archive, sorted by path. Every ancestor
+/// directory is included even if the archive skipped it.
+pub fn list_archive(reader: impl Read) -> std::io::Result<Vec<VolumeEntry>> {
+    let mut entries: HashMap<String, VolumeEntry> = HashMap::new();
+    let mut archive = tar::Archive::new(reader);
+    for entry in archive.entries()? {
+        let entry = entry?;
+        let header = entry.header();
+        let raw = entry.path()?.to_string_lossy().to_string();
+        let path = strip_root(&raw).to_string();
+        if path.is_empty() {
+            continue;
+        }
+        let kind = match header.entry_type() {
+            tar::EntryType::Regular | tar::EntryType::Continuous => VolumeEntryKind::File,
+            tar::EntryType::Directory => VolumeEntryKind::Directory,
+            tar::EntryType::Symlink => VolumeEntryKind::Symlink(
+                entry
+                    .link_name()?
+                    .map(|target| target.to_string_lossy().to_string())
+                    .unwrap_or_default(),
+            ),
+            _ => VolumeEntryKind::Other,
+        };
+        let modified = header
+            .mtime()
+            .ok()
+            .and_then(|t| DateTime::from_timestamp(t as i64, 0));
+        let mut parent = path.as_str();
+        while let Some((dir, _)) = parent.rsplit_once('/') {
+            entries
+                .entry(dir.to_string())
+                .or_insert_with(|| VolumeEntry {
+                    path: dir.to_string(),
+                    kind: VolumeEntryKind::Directory,
+                    size: 0,
+                    mode: 0o755,
+                    modified: None,
+                });
+            parent = dir;
+        }
+        entries.insert(
+            path.clone(),
+            VolumeEntry {
+                path,
+                kind,
+                size: header.size().unwrap_or(0),
+                mode: header.mode().unwrap_or(0),
+                modified,
+            },
+        );
+    }
+    let mut entries: Vec<VolumeEntry> = entries.into_values().collect();
+    entries.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(entries)
+}
+
+/// Blocking reader over chunks sent from async code, so an archive can be
+/// parsed while it downloads. Ends when the sender is dropped.
+struct ChunkReader {
+    rx: mpsc::Receiver<Bytes>,
+    chunk: Bytes,
+}
+
+impl Read for ChunkReader {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        while self.chunk.is_empty() {
+            match self.rx.blocking_recv() {
+                Some(chunk) => self.chunk = chunk,
+                None => return Ok(0),
+            }
+        }
+        let n = buf.len().min(self.chunk.len());
+        buf[..n].copy_from_slice(&self.chunk.split_to(n));
+        Ok(n)
+    }
+}
+
+/// Turn the start of a file into preview lines: text as is, anything with a
+/// NUL byte as a hex dump
+pub fn preview_lines(data: &[u8]) -> Vec<String> {
+    if !data.contains(&0) {
+        return String::from_utf8_lossy(data)
+            .lines()
+            .map(|line| line.replace('\t', "    "))
+            .collect();
+    }
+    data.chunks(16)
+        .take(64)
+        .enumerate()
+        .map(|(row, chunk)| {
+            let hex: Vec<String> = chunk.iter().map(|b| format!("{:02x}", b)).collect();
+            let ascii: String = chunk
+                .iter()
+                .map(|&b| {
+                    if b.is_ascii_graphic() || b == b' ' {
+                        b as char
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+            format!("{:08x}  {:<47}  {}", row * 16, hex.join(" "), ascii)
+        })
+        .collect()
+}
+
+/// Read the start of the first file in a (possibly cut short) archive
+fn read_preview(archive: &[u8], path: &str) -> std::io::Result<FilePreview> {
+    let mut archive = tar::Archive::new(archive);
+    for entry in archive.entries()? {
+        let mut entry = entry?;
+        if !entry.header().entry_type().is_file() {
+            continue;
+        }
+        let size = entry.header().size().unwrap_or(0);
+        let mut data = Vec::new();
+        (&mut entry)
+            .take(PREVIEW_BYTES as u64)
+            .read_to_end(&mut data)?;
+        return Ok(FilePreview {
+            path: path.to_string(),
+            lines: preview_lines(&data),
+            truncated: size > data.len() as u64,
+        });
+    }
+    Err(std::io::Error::new(
+        std::io::ErrorKind::NotFound,
+        "not a regular file",
+    ))
+}
+
+impl DockerClient {
+    /// Create (but don't start) a container with `volume` mounted, read-only
+    /// unless restoring into it. Returns the helper container ID.
+    pub async fn create_volume_helper(
+        &self,
+        volume: &str,
+        image: &str,
+        read_only: bool,
+    ) -> Result<String> {
+        self.ensure_image(image).await?;
+
+        let name = format!(
+            "contui-volume-{}",
+            &uuid::Uuid::new_v4().simple().to_string()[..8]
+        );
+        let mut labels = HashMap::new();
+        labels.insert(VOLUME_HELPER_LABEL.to_string(), volume.to_string());
+        let config = Config {
+            image: Some(image.to_string()),
+            // Never run; a command is only needed to create the container
+            cmd: Some(vec!["true".to_string()]),
+            labels: Some(labels),
+            network_disabled: Some(true),
+            host_config: Some(HostConfig {
+                binds: Some(vec![format!(
+                    "{}:{}{}",
+                    volume,
+                    MOUNT_PATH,
+                    if read_only { ":ro" } else { "" }
+                )]),
+                ..Default::default()
+            }),
+            ..Default::default()
+        };
+
+        debug!("Creating volume helper {} for {}", name, volume);
+        let created = self
+            .inner()
+            .create_container(
+                Some(CreateContainerOptions {
+                    name: name.as_str(),
+                    platform: None,
+                }),
+                config,
+            )
+            .await
+            .map_err(|e| DockerError::Volume(format!("Failed to create helper: {}", e)))?;
+        info!("Volume helper {} created for {}", created.id, volume);
+        Ok(created.id)
+    }
+
+    /// Remove a volume helper (never the volume itself)
+    pub async fn remove_volume_helper(&self, id: &str) -> Result<()> {
+        self.inner()
+            .remove_container(
+                id,
+                Some(RemoveContainerOptions {
+                    force: true,
+                    v: false,
+                    link: false,
+                }),
+            )
+            .await
+            .map_err(|e| DockerError::Volume(format!("Failed to remove helper: {}", e)))?;
+        info!("Volume helper {} removed", id);
+        Ok(())
+    }
+
+    /// List every entry in the volume. The archive is parsed on a blocking
+    /// thread as it downloads. `on_progress` receives the bytes read so far.
+    pub async fn list_volume(
+        &self,
+        helper_id: &str,
+        mut on_progress: impl FnMut(u64),
+    ) -> Result<Vec<VolumeEntry>> {
+        let (tx, rx) = mpsc::channel::<Bytes>(LIST_QUEUE);
+        let parser = tokio::task::spawn_blocking(move || {
+            list_archive(ChunkReader {
+                rx,
+                chunk: Bytes::new(),
+            })
+        });
+
+        let mut stream = self.inner().download_from_container(
+            helper_id,
+            Some(DownloadFromContainerOptions { path: MOUNT_PATH }),
+        );
+        let mut download = Ok(());
+        let mut read = 0u64;
+        while let Some(chunk) = stream.next().await {
+            let chunk = match chunk {
+                Ok(chunk) => chunk,
+                Err(e) => {
+                    download = Err(DockerError::Volume(format!("Failed to read volume: {}", e)));
+                    break;
+                }
+            };
+            read += chunk.len() as u64;
+            // The parser only stops early on a broken archive, which it reports
+            if tx.send(chunk).await.is_err() {
+                break;
+            }
+            on_progress(read);
+        }
+        drop(tx);
+
+        let listed = parser
+            .await
+            .map_err(|e| DockerError::Volume(format!("Failed to list volume: {}", e)))?;
+        download?;
+        listed.map_err(|e| DockerError::Volume(format!("Failed to list volume: {}", e)).into())
+    }
+
+    /// Stream the archive of a path in the volume (relative to its root) to
+    /// a file. `on_progress` receives the bytes written so far.
+    pub async fn download_volume_archive(
+        &self,
+        helper_id: &str,
+        path: &str,
+        destination: &Path,
+        mut on_progress: impl FnMut(u64),
+    ) -> Result<u64> {
+        let source = volume_path(path);
+        let write_error = |e: std::io::Error| {
+            DockerError::Volume(format!("Failed to write {}: {}", destination.display(), e))
+        };
+        let writer = ArchiveWriter::create(destination, false)
+            .await
+            .map_err(write_error)?;
+        let mut stream = self.inner().download_from_container(
+            helper_id,
+            Some(DownloadFromContainerOptions {
+                path: source.as_str(),
+            }),
+        );
+        let mut written = 0u64;
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk
+                .map_err(|e| DockerError::Volume(format!("Failed to read {}: {}", source, e)))?;
+            let len = chunk.len() as u64;
+            if !writer.write(chunk).await {
+                break;
+            }
+            written += len;
+            on_progress(written);
+        }
+        writer.finish().await.map_err(write_error)?;
+        Ok(written)
+    }
+
+    /// Read the first `PREVIEW_BYTES` of a file in the volume. The download
+    /// stops as soon as enough has arrived, so large files are cheap.
+    pub async fn preview_volume_file(&self, helper_id: &str, path: &str) -> Result<FilePreview> {
+        let source = volume_path(path);
+        let mut stream = self.inner().download_from_container(
+            helper_id,
+            Some(DownloadFromContainerOptions {
+                path: source.as_str(),
+            }),
+        );
+        // Room for the header blocks, including long-name extensions
+        let wanted = PREVIEW_BYTES + 8 * 1024;
+        let mut data = Vec::new();
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk
+                .map_err(|e| DockerError::Volume(format!("Failed to read {}: {}", source, e)))?;
+            data.extend_from_slice(&chunk);
+            if data.len() >= wanted {
+                break;
+            }
+        }
+        read_preview(&data, path)
+            .map_err(|e| DockerError::Volume(format!("Failed to preview {}: {}", path, e)).into())
+    }
+
+    /// Copy a file or directory from the volume into `directory`. Returns
+    /// the local path.
+    pub async fn download_volume_entry(
+        &self,
+        helper_id: &str,
+        path: &str,
+        directory: &Path,
+    ) -> Result<PathBuf> {
+        info!("Downloading {} to {}", path, directory.display());
+        let archive =
+            std::env::temp_dir().join(format!("contui-volume-{}.tar", uuid::Uuid::new_v4()));
+        let result: Result<()> = async {
+            self.download_volume_archive(helper_id, path, &archive, |_| {})
+                .await?;
+            let (archive, directory) = (archive.clone(), directory.to_path_buf());
+            tokio::task::spawn_blocking(move || {
+                std::fs::create_dir_all(&directory)?;
+                tar::Archive::new(std::fs::File::open(&archive)?).unpack(&directory)
+            })
+            .await
+            .map_err(|e| DockerError::Volume(e.to_string()))?
+            .map_err(|e| DockerError::Volume(format!("Failed to extract {}: {}", path, e)))?;
+            Ok(())
+        }
+        .await;
+        let _ = tokio::fs::remove_file(&archive).await;
+        result?;
+        let name = path.rsplit('/').next().unwrap_or(path);
+        Ok(directory.join(name))
+    }
+}
+
+/// Path inside the helper for a path relative to the volume root
+fn volume_path(path: &str) -> String {
+    let path = path.trim_matches('/');
+    if path.is_empty() {
+        MOUNT_PATH.to_string()
+    } else {
+        format!("{}/{}", MOUNT_PATH, path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn archive(files: &[(&str, &[u8])]) -> Vec<u8> {
+        let mut builder = tar::Builder::new(Vec::new());
+        let mut dir = tar::Header::new_gnu();
+        dir.set_entry_type(tar::EntryType::Directory);
+        dir.set_mode(0o755);
+        dir.set_size(0);
+        builder
+            .append_data(&mut dir, "volume/", std::io::empty())
+            .unwrap();
+        for (path, data) in files {
+            let mut header = tar::Header::new_gnu();
+            header.set_mode(0o644);
+            header.set_size(data.len() as u64);
+            header.set_mtime(1_700_000_000);
+            builder.append_data(&mut header, path, *data).unwrap();
+        }
+        builder.into_inner().unwrap()
+    }
+
+    #[test]
+    fn lists_entries_relative_to_the_volume() {
+        let data = archive(&[("volume/pg/base/1", b"x"), ("volume/README", b"hello")]);
+        let entries = list_archive(&data[..]).unwrap();
+        let paths: Vec<&str> = entries.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, vec!["README", "pg", "pg/base", "pg/base/1"]);
+        assert!(entries[1].is_dir());
+        assert_eq!(entries[0].size, 5);
+        assert_eq!(entries[3].name(), "1");
+        assert_eq!(entries[3].parent(), "pg/base");
+        assert_eq!(entries[0].parent(), "");
+        assert!(entries[0].modified.is_some());
+    }
+
+    #[tokio::test]
+    async fn lists_while_chunks_arrive() {
+        let data = archive(&[("volume/a/b", b"some data"), ("volume/c", b"more")]);
+        let (tx, rx) = mpsc::channel(1);
+        let parser = tokio::task::spawn_blocking(move || {
+            list_archive(ChunkReader {
+                rx,
+                chunk: Bytes::new(),
+            })
+        });
+        for chunk in data.chunks(100) {
+            // The parser hangs up at the end-of-archive marker, before the padding
+            if tx.send(Bytes::copy_from_slice(chunk)).await.is_err() {
+                break;
+            }
+        }
+        drop(tx);
+
+        let entries = parser.await.unwrap().unwrap();
+        let paths: Vec<&str> = entries.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, vec!["a", "a/b", "c"]);
+    }
+
+    #[test]
+    fn previews_text_and_binary() {
+        let data = archive(&[("notes.txt", b"one\n\ttwo\n")]);
+        let preview = read_preview(&data, "notes.txt").unwrap();
+        assert_eq!(preview.lines, vec!["one", "    two"]);
+        assert!(!preview.truncated);
+
+        let big = vec![b'a'; PREVIEW_BYTES + 10];
+        let data = archive(&[("big", &big)]);
+        // Cut short like an aborted download
+        let preview = read_preview(&data[..PREVIEW_BYTES + 2048], "big").unwrap();
+        assert!(preview.truncated);
+
+        let lines = preview_lines(&[0x7f, b'E', b'L', b'F', 0, 1]);
+        assert_eq!(
+            lines,
+            vec![format!("00000000  {:<47}  .ELF..", "7f 45 4c 46 00 01")]
+        );
+    }
+}
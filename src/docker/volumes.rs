@@ -1,12 +1,158 @@
 //! Volume operations
 
-use bollard::volume::ListVolumesOptions;
+use std::collections::HashMap;
+
+use bollard::container::ListContainersOptions;
+use bollard::volume::{CreateVolumeOptions, ListVolumesOptions};
 use tracing::{debug, info};
 
 use crate::core::{DockerError, Result, VolumeScope, VolumeSummary};
 use crate::docker::DockerClient;
 
+/// What to create and how
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VolumeOptions {
+    /// Volume name, empty to let Docker generate one
+    pub name: String,
+    /// Volume driver, empty for `local`
+    pub driver: String,
+    /// Driver options, e.g. `type=nfs o=addr=10.0.0.2,rw device=:/export`
+    pub driver_opts: HashMap<String, String>,
+    pub labels: HashMap<String, String>,
+}
+
+/// Volume details from inspect
+#[derive(Debug, Clone)]
+pub struct VolumeDetails {
+    pub name: String,
+    pub driver: String,
+    pub mountpoint: String,
+    pub scope: String,
+    pub created: String,
+    pub options: Vec<(String, String)>,
+    pub labels: Vec<(String, String)>,
+    /// Driver-reported status keys
+    pub status: Vec<String>,
+    pub containers: Vec<VolumeContainer>,
+}
+
+/// A container that mounts a volume
+#[derive(Debug, Clone, PartialEq)]
+pub struct VolumeContainer {
+    pub name: String,
+    pub state: String,
+    /// Mount path inside the container
+    pub destination: String,
+    pub read_write: bool,
+}
+
+impl VolumeDetails {
+    /// Combine an inspected volume with the containers mounting it
+    fn from_inspect(
+        volume: bollard::models::Volume,
+        containers: Vec<bollard::models::ContainerSummary>,
+    ) -> Self {
+        let sorted = |map: HashMap<String, String>| {
+            let mut pairs: Vec<_> = map.into_iter().collect();
+            pairs.sort();
+            pairs
+        };
+        let mut status: Vec<String> = volume.status.unwrap_or_default().into_keys().collect();
+        status.sort();
+
+        let mut attached: Vec<VolumeContainer> = containers
+            .into_iter()
+            .map(|c| {
+                let mount = c
+                    .mounts
+                    .unwrap_or_default()
+                    .into_iter()
+                    .find(|m| m.name.as_deref() == Some(volume.name.as_str()));
+                VolumeContainer {
+                    name: c
+                        .names
+                        .and_then(|n| n.into_iter().next())
+                        .map(|n| n.trim_start_matches('/').to_string())
+                        .unwrap_or_else(|| c.id.unwrap_or_default().chars().take(12).collect()),
+                    state: c.state.unwrap_or_default(),
+                    destination: mount
+                        .as_ref()
+                        .and_then(|m| m.destination.clone())
+                        .unwrap_or_default(),
+                    read_write: mount.and_then(|m| m.rw).unwrap_or(true),
+                }
+            })
+            .collect();
+        attached.sort_by(|a, b| a.name.cmp(&b.name));
+
+        Self {
+            scope: match volume.scope {
+                Some(bollard::models::VolumeScopeEnum::GLOBAL) => "global".to_string(),
+                _ => "local".to_string(),
+            },
+            created: volume.created_at.unwrap_or_default(),
+            options: sorted(volume.options),
+            labels: sorted(volume.labels),
+            status,
+            containers: attached,
+            name: volume.name,
+            driver: volume.driver,
+            mountpoint: volume.mountpoint,
+        }
+    }
+}
+
+/// Parse `KEY=VALUE` pairs separated by whitespace. Values may contain `=`
+/// and `,` (NFS mount options do), so commas don't split pairs. A bare `KEY`
+/// gets an empty value.
+pub fn parse_volume_options(input: &str) -> HashMap<String, String> {
+    input
+        .split_whitespace()
+        .map(|pair| match pair.split_once('=') {
+            Some((key, value)) => (key.to_string(), value.to_string()),
+            None => (pair.to_string(), String::new()),
+        })
+        .collect()
+}
+
 impl DockerClient {
+    /// Create a volume
+    pub async fn create_volume(&self, options: &VolumeOptions) -> Result<VolumeSummary> {
+        info!(
+            "Creating volume '{}' (driver={})",
+            options.name, options.driver
+        );
+
+        let driver = if options.driver.is_empty() {
+            "local"
+        } else {
+            options.driver.as_str()
+        };
+        let config = CreateVolumeOptions {
+            name: options.name.as_str(),
+            driver,
+            driver_opts: options
+                .driver_opts
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.as_str()))
+                .collect(),
+            labels: options
+                .labels
+                .iter()
+                .map(|(k, v)| (k.as_str(), v.as_str()))
+                .collect(),
+        };
+
+        let volume = self
+            .inner()
+            .create_volume(config)
+            .await
+            .map_err(|e| DockerError::Volume(format!("Failed to create volume: {}", e)))?;
+
+        info!("Volume {} created successfully", volume.name);
+        Ok(volume.into())
+    }
+
     /// List all volumes
     pub async fn list_volumes(&self) -> Result<Vec<VolumeSummary>> {
         debug!("Listing volumes");
@@ -27,6 +173,28 @@ impl DockerClient {
         Ok(volume_list.into_iter().map(|v| v.into()).collect())
     }
 
+    /// Inspect a volume and find the containers (running or not) using it
+    pub async fn inspect_volume(&self, name: &str) -> Result<VolumeDetails> {
+        debug!("Inspecting volume {}", name);
+
+        let volume = self
+            .inner()
+            .inspect_volume(name)
+            .await
+            .map_err(|e| DockerError::Volume(format!("Failed to inspect {}: {}", name, e)))?;
+        let containers = self
+            .inner()
+            .list_containers(Some(ListContainersOptions::<String> {
+                all: true,
+                filters: HashMap::from([("volume".to_string(), vec![name.to_string()])]),
+                ..Default::default()
+            }))
+            .await
+            .map_err(|e| DockerError::Volume(format!("Failed to list containers: {}", e)))?;
+
+        Ok(VolumeDetails::from_inspect(volume, containers))
+    }
+
     /// Remove a volume
     pub async fn remove_volume(&self, name: &str, force: bool) -> Result<()> {
         info!("Removing volume: {} (force={})", name, force);
@@ -106,6 +274,8 @@ impl From<bollard::models::Volume> for VolumeSummary {
             scope,
             options: v.options,
             in_use: vec![], // Will be populated by checking containers
+            size: v.usage_data.as_ref().map(|u| u.size).filter(|s| *s >= 0),
+            ref_count: v.usage_data.map(|u| u.ref_count).filter(|c| *c >= 0),
         }
     }
 }
@@ -114,6 +284,69 @@ impl From<bollard::models::Volume> for VolumeSummary {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_volume_details_from_inspect() {
+        use bollard::models::{ContainerSummary, MountPoint};
+
+        let volume = bollard::models::Volume {
+            name: "pgdata".into(),
+            driver: "local".into(),
+            mountpoint: "/var/lib/docker/volumes/pgdata/_data".into(),
+            options: HashMap::from([
+                ("type".to_string(), "none".to_string()),
+                ("o".to_string(), "bind".to_string()),
+            ]),
+            ..Default::default()
+        };
+        let container = |name: &str, rw: bool| ContainerSummary {
+            names: Some(vec![format!("/{}", name)]),
+            state: Some("running".into()),
+            mounts: Some(vec![
+                MountPoint {
+                    name: Some("other".into()),
+                    destination: Some("/other".into()),
+                    ..Default::default()
+                },
+                MountPoint {
+                    name: Some("pgdata".into()),
+                    destination: Some("/var/lib/postgresql/data".into()),
+                    rw: Some(rw),
+                    ..Default::default()
+                },
+            ]),
+            ..Default::default()
+        };
+
+        let details = VolumeDetails::from_inspect(
+            volume,
+            vec![container("pg", true), container("backup", false)],
+        );
+        assert_eq!(details.scope, "local");
+        assert_eq!(details.options[0], ("o".to_string(), "bind".to_string()));
+        assert_eq!(
+            details.containers[0],
+            VolumeContainer {
+                name: "backup".into(),
+                state: "running".into(),
+                destination: "/var/lib/postgresql/data".into(),
+                read_write: false,
+            }
+        );
+        assert_eq!(details.containers[1].name, "pg");
+    }
+
+    #[test]
+    fn test_parse_volume_options() {
+        let opts =
+            parse_volume_options("type=nfs  o=addr=10.0.0.2,rw,nfsvers=4\ndevice=:/export ro");
+        assert_eq!(opts.len(), 4);
+        assert_eq!(opts["type"], "nfs");
+        assert_eq!(opts["o"], "addr=10.0.0.2,rw,nfsvers=4");
+        assert_eq!(opts["device"], ":/export");
+        assert_eq!(opts["ro"], "");
+        assert!(parse_volume_options("  ").is_empty());
+    }
+
     // Note: These tests require Docker to be running
 
     #[tokio::test]
@@ -0,0 +1,341 @@
+//! Record and replay exec sessions in asciicast v2 format
+//!
+//! A `.cast` file is a JSON header line followed by one JSON array per event:
+//! `[seconds, "o", "output"]` for output and `[seconds, "r", "COLSxROWS"]` for
+//! resizes. See <https://docs.asciinema.org/manual/asciicast/v2/>.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::{ContuiError, Result};
+use crate::exec::scrollback::ScrollbackParser;
+
+/// asciicast v2 header line
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CastHeader {
+    pub version: u32,
+    pub width: u16,
+    pub height: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+}
+
+/// A replayable event from a cast file
+#[derive(Debug, Clone, PartialEq)]
+pub enum CastEvent {
+    Output(String),
+    Resize { cols: u16, rows: u16 },
+}
+
+/// A parsed cast file
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cast {
+    pub header: CastHeader,
+    /// Events with their offset in seconds from the start of the recording
+    pub events: Vec<(f64, CastEvent)>,
+}
+
+impl Cast {
+    /// Length of the recording in seconds
+    pub fn duration(&self) -> f64 {
+        self.events.last().map(|(t, _)| *t).unwrap_or(0.0)
+    }
+}
+
+/// Parse the contents of an asciicast v2 file. Event types other than
+/// output and resize (input, markers) are skipped.
+pub fn parse_cast(contents: &str) -> Result<Cast> {
+    let mut lines = contents.lines().filter(|l| !l.trim().is_empty());
+    let header_line = lines
+        .next()
+        .ok_or_else(|| ContuiError::Other("Empty cast file".to_string()))?;
+    let header: CastHeader = serde_json::from_str(header_line)?;
+    if header.version != 2 {
+        return Err(ContuiError::Other(format!(
+            "Unsupported asciicast version {}",
+            header.version
+        )));
+    }
+
+    let mut events = Vec::new();
+    for line in lines {
+        let (time, kind, data): (f64, String, String) = serde_json::from_str(line)?;
+        let event = match kind.as_str() {
+            "o" => CastEvent::Output(data),
+            "r" => match parse_resize(&data) {
+                Some((cols, rows)) => CastEvent::Resize { cols, rows },
+                None => continue,
+            },
+            _ => continue,
+        };
+        events.push((time, event));
+    }
+
+    Ok(Cast { header, events })
+}
+
+fn parse_resize(data: &str) -> Option<(u16, u16)> {
+    let (cols, rows) = data.split_once('x')?;
+    Some((cols.trim().parse().ok()?, rows.trim().parse().ok()?))
+}
+
+/// Default file name for a new recording of `container_name`
+pub fn recording_file_name(container_name: &str) -> String {
+    let timestamp = chrono::Local::now().format("%Y-%m-%d_%H-%M-%S");
+    format!(
+        "{}_{}.cast",
+        container_name.trim_start_matches('/').replace('/', "_"),
+        timestamp
+    )
+}
+
+/// Writes exec output to a `.cast` file as it arrives
+pub struct CastRecorder {
+    writer: BufWriter<File>,
+    path: PathBuf,
+    started: Instant,
+    /// Trailing bytes of an incomplete UTF-8 sequence from the last chunk
+    pending: Vec<u8>,
+}
+
+impl CastRecorder {
+    /// Create the file and write the header
+    pub fn create(path: &Path, cols: u16, rows: u16, title: &str) -> Result<Self> {
+        let file = File::create(path)?;
+        let mut recorder = Self {
+            writer: BufWriter::new(file),
+            path: path.to_path_buf(),
+            started: Instant::now(),
+            pending: Vec::new(),
+        };
+        let header = CastHeader {
+            version: 2,
+            width: cols,
+            height: rows,
+            timestamp: Some(chrono::Utc::now().timestamp()),
+            title: Some(title.to_string()),
+        };
+        writeln!(recorder.writer, "{}", serde_json::to_string(&header)?)?;
+        Ok(recorder)
+    }
+
+    /// Path of the file being written
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    fn elapsed(&self) -> f64 {
+        (self.started.elapsed().as_secs_f64() * 1_000_000.0).round() / 1_000_000.0
+    }
+
+    fn write_event(&mut self, kind: &str, data: &str) -> Result<()> {
+        let line = serde_json::to_string(&(self.elapsed(), kind, data))?;
+        writeln!(self.writer, "{}", line)?;
+        Ok(())
+    }
+
+    /// Record an output chunk
+    pub fn write_output(&mut self, bytes: &[u8]) -> Result<()> {
+        self.pending.extend_from_slice(bytes);
+        let valid_up_to = match std::str::from_utf8(&self.pending) {
+            Ok(_) => self.pending.len(),
+            // Hold back an incomplete sequence at the end for the next chunk
+            Err(e) if e.error_len().is_none() => e.valid_up_to(),
+            Err(_) => self.pending.len(),
+        };
+        let chunk: Vec<u8> = self.pending.drain(..valid_up_to).collect();
+        if chunk.is_empty() {
+            return Ok(());
+        }
+        let text = String::from_utf8_lossy(&chunk).into_owned();
+        self.write_event("o", &text)
+    }
+
+    /// Record a terminal resize
+    pub fn write_resize(&mut self, cols: u16, rows: u16) -> Result<()> {
+        self.write_event("r", &format!("{}x{}", cols, rows))
+    }
+
+    /// Flush and close the file
+    pub fn finish(mut self) -> Result<PathBuf> {
+        if !self.pending.is_empty() {
+            let rest = std::mem::take(&mut self.pending);
+            let text = String::from_utf8_lossy(&rest).into_owned();
+            self.write_event("o", &text)?;
+        }
+        self.writer.flush()?;
+        Ok(self.path)
+    }
+}
+
+/// Replays a cast through a vt100 parser
+pub struct CastPlayer {
+    cast: Cast,
+    next_event: usize,
+    parser: ScrollbackParser,
+    /// Playback position in seconds
+    position: f64,
+    paused: bool,
+}
+
+impl CastPlayer {
+    pub fn new(cast: Cast, scrollback_lines: usize) -> Self {
+        let parser = ScrollbackParser::new(
+            cast.header.height.max(1),
+            cast.header.width.max(1),
+            scrollback_lines,
+        );
+        Self {
+            cast,
+            next_event: 0,
+            parser,
+            position: 0.0,
+            paused: false,
+        }
+    }
+
+    /// Advance playback by `delta` seconds. Returns true when the screen changed.
+    pub fn advance(&mut self, delta: f64) -> bool {
+        if self.paused {
+            return false;
+        }
+        self.position = (self.position + delta).min(self.cast.duration());
+
+        let mut changed = false;
+        while let Some((time, event)) = self.cast.events.get(self.next_event) {
+            if *time > self.position {
+                break;
+            }
+            match event {
+                CastEvent::Output(data) => self.parser.process(data.as_bytes()),
+                CastEvent::Resize { cols, rows } => {
+                    self.parser.set_size((*rows).max(1), (*cols).max(1))
+                }
+            }
+            self.next_event += 1;
+            changed = true;
+        }
+        changed
+    }
+
+    pub fn toggle_pause(&mut self) {
+        self.paused = !self.paused;
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.next_event >= self.cast.events.len()
+    }
+
+    pub fn position(&self) -> f64 {
+        self.position
+    }
+
+    pub fn duration(&self) -> f64 {
+        self.cast.duration()
+    }
+
+    pub fn parser(&self) -> &ScrollbackParser {
+        &self.parser
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"{"version": 2, "width": 20, "height": 3, "timestamp": 1700000000}
+[0.1, "o", "hello\r\n"]
+[0.5, "i", "ls\r"]
+[1.0, "r", "30x4"]
+[1.5, "o", "world\r\n"]
+"#;
+
+    #[test]
+    fn parses_cast_file() {
+        let cast = parse_cast(SAMPLE).unwrap();
+        assert_eq!(cast.header.width, 20);
+        assert_eq!(cast.header.height, 3);
+        assert_eq!(
+            cast.events,
+            vec![
+                (0.1, CastEvent::Output("hello\r\n".into())),
+                (1.0, CastEvent::Resize { cols: 30, rows: 4 }),
+                (1.5, CastEvent::Output("world\r\n".into())),
+            ]
+        );
+        assert_eq!(cast.duration(), 1.5);
+    }
+
+    #[test]
+    fn rejects_other_versions() {
+        assert!(parse_cast(r#"{"version": 1, "width": 80, "height": 24}"#).is_err());
+        assert!(parse_cast("").is_err());
+    }
+
+    #[test]
+    fn recorder_round_trips_through_parser() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("session.cast");
+
+        let mut recorder = CastRecorder::create(&path, 80, 24, "web").unwrap();
+        recorder.write_output(b"hi\r\n").unwrap();
+        // "é" split across two chunks must come out whole
+        recorder.write_output(&[0xc3]).unwrap();
+        recorder.write_output(&[0xa9]).unwrap();
+        recorder.write_resize(100, 30).unwrap();
+        let saved = recorder.finish().unwrap();
+        assert_eq!(saved, path);
+
+        let cast = parse_cast(&std::fs::read_to_string(&path).unwrap()).unwrap();
+        assert_eq!(cast.header.width, 80);
+        assert_eq!(cast.header.title.as_deref(), Some("web"));
+        let kinds: Vec<CastEvent> = cast.events.into_iter().map(|(_, e)| e).collect();
+        assert_eq!(
+            kinds,
+            vec![
+                CastEvent::Output("hi\r\n".into()),
+                CastEvent::Output("é".into()),
+                CastEvent::Resize {
+                    cols: 100,
+                    rows: 30
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn player_replays_events_in_time() {
+        let mut player = CastPlayer::new(parse_cast(SAMPLE).unwrap(), 100);
+
+        assert!(!player.advance(0.05));
+        assert!(player.advance(0.1));
+        assert_eq!(player.parser().snapshot_lines(), vec!["hello"]);
+
+        player.toggle_pause();
+        assert!(!player.advance(5.0));
+        player.toggle_pause();
+
+        assert!(player.advance(5.0));
+        assert!(player.is_finished());
+        assert_eq!(player.parser().screen().size(), (4, 30));
+        assert_eq!(player.parser().snapshot_lines(), vec!["hello", "world"]);
+        assert_eq!(player.position(), 1.5);
+    }
+
+    #[test]
+    fn recording_file_name_is_sanitized() {
+        let name = recording_file_name("/my/app");
+        assert!(name.starts_with("my_app_"));
+        assert!(name.ends_with(".cast"));
+    }
+}
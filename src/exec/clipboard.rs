@@ -0,0 +1,34 @@
+//! Copy text to the system clipboard through the terminal (OSC 52)
+
+use std::io::Write;
+
+use base64::Engine;
+
+/// Build the OSC 52 escape sequence that sets the clipboard to `text`.
+pub fn osc52_sequence(text: &str) -> String {
+    let encoded = base64::engine::general_purpose::STANDARD.encode(text.as_bytes());
+    format!("\x1b]52;c;{}\x07", encoded)
+}
+
+/// Write the OSC 52 sequence for `text` to the given terminal writer.
+pub fn copy_to_clipboard<W: Write>(writer: &mut W, text: &str) -> std::io::Result<()> {
+    writer.write_all(osc52_sequence(text).as_bytes())?;
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builds_osc52_sequence() {
+        assert_eq!(osc52_sequence("hello"), "\x1b]52;c;aGVsbG8=\x07");
+    }
+
+    #[test]
+    fn writes_sequence_to_writer() {
+        let mut out = Vec::new();
+        copy_to_clipboard(&mut out, "hi").unwrap();
+        assert_eq!(out, b"\x1b]52;c;aGk=\x07".to_vec());
+    }
+}
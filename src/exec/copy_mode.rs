@@ -0,0 +1,373 @@
+//! Copy mode for the exec pane: vi-style movement, search and selection
+//! over a snapshot of the scrollback buffer.
+
+/// Copy mode state. `lines` is a frozen snapshot, so the exec process can keep
+/// writing to the live screen while the user moves around.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CopyModeState {
+    pub lines: Vec<String>,
+    /// Cursor as (line, column), column counted in characters
+    pub cursor: (usize, usize),
+    /// Selection start when visual selection is active
+    pub anchor: Option<(usize, usize)>,
+    /// First line shown in the viewport
+    pub scroll_offset: usize,
+    /// Search text being typed after `/`
+    pub search_input: Option<String>,
+    /// Last committed search
+    pub search_pattern: Option<String>,
+}
+
+impl CopyModeState {
+    /// Enter copy mode with the cursor on the last line of output
+    pub fn new(lines: Vec<String>, view_height: usize) -> Self {
+        let last = lines.len().saturating_sub(1);
+        let mut state = Self {
+            lines,
+            cursor: (last, 0),
+            anchor: None,
+            scroll_offset: 0,
+            search_input: None,
+            search_pattern: None,
+        };
+        state.scroll_to_cursor(view_height);
+        state
+    }
+
+    fn line_len(&self, line: usize) -> usize {
+        self.lines.get(line).map(|l| l.chars().count()).unwrap_or(0)
+    }
+
+    fn char_at(&self, line: usize, col: usize) -> Option<char> {
+        self.lines.get(line).and_then(|l| l.chars().nth(col))
+    }
+
+    fn clamp_col(&mut self) {
+        let max = self.line_len(self.cursor.0).saturating_sub(1);
+        self.cursor.1 = self.cursor.1.min(max);
+    }
+
+    pub fn move_left(&mut self) {
+        self.cursor.1 = self.cursor.1.saturating_sub(1);
+    }
+
+    pub fn move_right(&mut self) {
+        let max = self.line_len(self.cursor.0).saturating_sub(1);
+        self.cursor.1 = (self.cursor.1 + 1).min(max);
+    }
+
+    pub fn move_up(&mut self, count: usize) {
+        self.cursor.0 = self.cursor.0.saturating_sub(count);
+        self.clamp_col();
+    }
+
+    pub fn move_down(&mut self, count: usize) {
+        let last = self.lines.len().saturating_sub(1);
+        self.cursor.0 = (self.cursor.0 + count).min(last);
+        self.clamp_col();
+    }
+
+    pub fn line_start(&mut self) {
+        self.cursor.1 = 0;
+    }
+
+    pub fn line_end(&mut self) {
+        self.cursor.1 = self.line_len(self.cursor.0).saturating_sub(1);
+    }
+
+    pub fn top(&mut self) {
+        self.cursor = (0, 0);
+    }
+
+    pub fn bottom(&mut self) {
+        self.cursor = (self.lines.len().saturating_sub(1), 0);
+    }
+
+    /// Move to the start of the next word (vi `w`)
+    pub fn word_forward(&mut self) {
+        let (mut line, mut col) = self.cursor;
+        let start_is_word = self.char_at(line, col).is_some_and(|c| !c.is_whitespace());
+
+        // Skip the rest of the current word
+        if start_is_word {
+            while self.char_at(line, col).is_some_and(|c| !c.is_whitespace()) {
+                col += 1;
+            }
+        }
+
+        // Skip whitespace, crossing line boundaries
+        loop {
+            match self.char_at(line, col) {
+                Some(c) if c.is_whitespace() => col += 1,
+                Some(_) => break,
+                None => {
+                    if line + 1 >= self.lines.len() {
+                        return;
+                    }
+                    line += 1;
+                    col = 0;
+                    if self.line_len(line) > 0
+                        && self.char_at(line, 0).is_some_and(|c| !c.is_whitespace())
+                    {
+                        break;
+                    }
+                }
+            }
+        }
+
+        self.cursor = (line, col);
+    }
+
+    /// Move to the start of the previous word (vi `b`)
+    pub fn word_backward(&mut self) {
+        let (mut line, mut col) = self.cursor;
+
+        // Step back one character, crossing line boundaries
+        let step_back = |state: &Self, line: &mut usize, col: &mut usize| -> bool {
+            if *col > 0 {
+                *col -= 1;
+                true
+            } else if *line > 0 {
+                *line -= 1;
+                *col = state.line_len(*line);
+                true
+            } else {
+                false
+            }
+        };
+
+        // Skip whitespace before the cursor
+        loop {
+            if !step_back(self, &mut line, &mut col) {
+                self.cursor = (0, 0);
+                return;
+            }
+            if self.char_at(line, col).is_some_and(|c| !c.is_whitespace()) {
+                break;
+            }
+        }
+
+        // Walk back to the start of the word
+        while col > 0
+            && self
+                .char_at(line, col - 1)
+                .is_some_and(|c| !c.is_whitespace())
+        {
+            col -= 1;
+        }
+
+        self.cursor = (line, col);
+    }
+
+    /// Start or clear a visual selection at the cursor
+    pub fn toggle_selection(&mut self) {
+        self.anchor = match self.anchor {
+            Some(_) => None,
+            None => Some(self.cursor),
+        };
+    }
+
+    /// Ordered (start, end) of the selection, both inclusive
+    pub fn selection_bounds(&self) -> Option<((usize, usize), (usize, usize))> {
+        self.anchor.map(|anchor| {
+            if anchor <= self.cursor {
+                (anchor, self.cursor)
+            } else {
+                (self.cursor, anchor)
+            }
+        })
+    }
+
+    /// Whether the cell at (line, col) is inside the selection
+    pub fn is_selected(&self, line: usize, col: usize) -> bool {
+        match self.selection_bounds() {
+            Some((start, end)) => (line, col) >= start && (line, col) <= end,
+            None => false,
+        }
+    }
+
+    /// Text covered by the selection, or the cursor line when nothing is selected
+    pub fn selected_text(&self) -> String {
+        let Some((start, end)) = self.selection_bounds() else {
+            return self
+                .lines
+                .get(self.cursor.0)
+                .map(|l| l.trim_end().to_string())
+                .unwrap_or_default();
+        };
+
+        let mut out = Vec::new();
+        for line in start.0..=end.0 {
+            let text = self.lines.get(line).map(String::as_str).unwrap_or("");
+            let from = if line == start.0 { start.1 } else { 0 };
+            let piece: String = if line == end.0 {
+                text.chars()
+                    .skip(from)
+                    .take((end.1 + 1).saturating_sub(from))
+                    .collect()
+            } else {
+                text.chars().skip(from).collect()
+            };
+            out.push(piece.trim_end().to_string());
+        }
+        out.join("\n")
+    }
+
+    /// Begin typing a search pattern
+    pub fn start_search(&mut self) {
+        self.search_input = Some(String::new());
+    }
+
+    /// Commit the typed pattern and jump to the first match after the cursor
+    pub fn commit_search(&mut self) -> bool {
+        let Some(input) = self.search_input.take() else {
+            return false;
+        };
+        if input.is_empty() {
+            return false;
+        }
+        self.search_pattern = Some(input);
+        self.search_next()
+    }
+
+    /// Jump to the next match of the last search, wrapping around
+    pub fn search_next(&mut self) -> bool {
+        self.search(true)
+    }
+
+    /// Jump to the previous match of the last search, wrapping around
+    pub fn search_previous(&mut self) -> bool {
+        self.search(false)
+    }
+
+    fn search(&mut self, forward: bool) -> bool {
+        let Some(pattern) = self.search_pattern.as_ref().map(|p| p.to_lowercase()) else {
+            return false;
+        };
+        let count = self.lines.len();
+        if count == 0 {
+            return false;
+        }
+
+        let matches_in = |line: &str| -> Vec<usize> {
+            let lower = line.to_lowercase();
+            lower
+                .match_indices(&pattern)
+                .map(|(byte, _)| lower[..byte].chars().count())
+                .collect()
+        };
+
+        let (cur_line, cur_col) = self.cursor;
+        for step in 0..=count {
+            let line = if forward {
+                (cur_line + step) % count
+            } else {
+                (cur_line + count - step % count) % count
+            };
+            let cols = matches_in(&self.lines[line]);
+            let hit = if forward {
+                cols.into_iter().find(|&c| step > 0 || c > cur_col)
+            } else {
+                cols.into_iter().rev().find(|&c| step > 0 || c < cur_col)
+            };
+            if let Some(col) = hit {
+                self.cursor = (line, col);
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Keep the cursor inside a viewport of `view_height` lines
+    pub fn scroll_to_cursor(&mut self, view_height: usize) {
+        let view_height = view_height.max(1);
+        if self.cursor.0 < self.scroll_offset {
+            self.scroll_offset = self.cursor.0;
+        } else if self.cursor.0 >= self.scroll_offset + view_height {
+            self.scroll_offset = self.cursor.0 + 1 - view_height;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(lines: &[&str]) -> CopyModeState {
+        CopyModeState::new(lines.iter().map(|l| l.to_string()).collect(), 3)
+    }
+
+    #[test]
+    fn starts_on_last_line_and_scrolls_into_view() {
+        let s = state(&["a", "b", "c", "d", "e"]);
+        assert_eq!(s.cursor, (4, 0));
+        assert_eq!(s.scroll_offset, 2);
+    }
+
+    #[test]
+    fn vertical_movement_clamps_column() {
+        let mut s = state(&["short", "a much longer line"]);
+        s.line_end();
+        assert_eq!(s.cursor, (1, 17));
+        s.move_up(1);
+        assert_eq!(s.cursor, (0, 4));
+        s.move_up(5);
+        assert_eq!(s.cursor.0, 0);
+    }
+
+    #[test]
+    fn word_motions() {
+        let mut s = state(&["foo bar", "baz"]);
+        s.top();
+        s.word_forward();
+        assert_eq!(s.cursor, (0, 4));
+        s.word_forward();
+        assert_eq!(s.cursor, (1, 0));
+        s.word_backward();
+        assert_eq!(s.cursor, (0, 4));
+        s.word_backward();
+        assert_eq!(s.cursor, (0, 0));
+    }
+
+    #[test]
+    fn selection_across_lines() {
+        let mut s = state(&["hello world", "second line"]);
+        s.top();
+        s.word_forward();
+        s.toggle_selection();
+        s.move_down(1);
+        s.line_start();
+        s.word_forward();
+        s.move_left();
+        assert_eq!(s.selected_text(), "world\nsecond");
+        assert!(s.is_selected(0, 6));
+        assert!(!s.is_selected(0, 5));
+    }
+
+    #[test]
+    fn selected_text_without_selection_is_cursor_line() {
+        let s = state(&["one", "two  "]);
+        assert_eq!(s.selected_text(), "two");
+    }
+
+    #[test]
+    fn search_wraps_and_goes_backwards() {
+        let mut s = state(&["error one", "ok", "ERROR two"]);
+        s.start_search();
+        s.search_input.as_mut().unwrap().push_str("error");
+        assert!(s.commit_search());
+        assert_eq!(s.cursor, (0, 0));
+        assert!(s.search_next());
+        assert_eq!(s.cursor, (2, 0));
+        assert!(s.search_previous());
+        assert_eq!(s.cursor, (0, 0));
+    }
+
+    #[test]
+    fn search_without_match_keeps_cursor() {
+        let mut s = state(&["abc"]);
+        s.search_pattern = Some("zzz".into());
+        assert!(!s.search_next());
+        assert_eq!(s.cursor, (0, 0));
+    }
+}
@@ -1,4 +1,8 @@
 //! Exec helpers
 
+pub mod asciicast;
+pub mod clipboard;
+pub mod copy_mode;
 pub mod input;
+pub mod scrollback;
 pub mod spinner;
@@ -0,0 +1,148 @@
+//! Scrollback history for exec sessions
+//!
+//! vt100 0.15 keeps scrolled-off rows internally, but cannot show more than
+//! one screen of them at a time. `ScrollbackParser` feeds output line by line
+//! and copies every row that leaves the screen into its own bounded history.
+
+use std::collections::VecDeque;
+
+/// A vt100 parser that remembers lines scrolled off the top of the screen
+pub struct ScrollbackParser {
+    parser: vt100::Parser,
+    history: VecDeque<String>,
+    capacity: usize,
+}
+
+impl ScrollbackParser {
+    /// Create a parser for a `rows` x `cols` screen keeping `capacity` lines of history
+    pub fn new(rows: u16, cols: u16, capacity: usize) -> Self {
+        Self {
+            parser: vt100::Parser::new(rows, cols, internal_scrollback(rows)),
+            history: VecDeque::new(),
+            capacity,
+        }
+    }
+
+    /// Process terminal output
+    pub fn process(&mut self, bytes: &[u8]) {
+        if self.capacity == 0 {
+            self.parser.process(bytes);
+            return;
+        }
+        // Each newline scrolls at most one row, so processing line by line keeps
+        // the number of new scrollback rows within what vt100 can show us.
+        for segment in bytes.split_inclusive(|b| *b == b'\n') {
+            self.process_segment(segment);
+        }
+    }
+
+    fn process_segment(&mut self, segment: &[u8]) {
+        // Park the view one row into the scrollback: vt100 advances the offset
+        // for every row pushed while it is non-zero, which counts new rows.
+        self.parser.set_scrollback(usize::MAX);
+        let had_history = self.parser.screen().scrollback() > 0;
+        self.parser.set_scrollback(1);
+
+        self.parser.process(segment);
+
+        let pushed = if had_history {
+            self.parser.screen().scrollback().saturating_sub(1)
+        } else {
+            self.parser.set_scrollback(usize::MAX);
+            self.parser.screen().scrollback()
+        };
+
+        let (rows, cols) = self.parser.screen().size();
+        let pushed = pushed.min(rows as usize);
+        if pushed > 0 {
+            self.parser.set_scrollback(pushed);
+            let new_rows: Vec<String> = self.parser.screen().rows(0, cols).take(pushed).collect();
+            for row in new_rows {
+                self.history.push_back(row);
+            }
+            while self.history.len() > self.capacity {
+                self.history.pop_front();
+            }
+        }
+        self.parser.set_scrollback(0);
+    }
+
+    /// Resize the screen
+    pub fn set_size(&mut self, rows: u16, cols: u16) {
+        self.parser.set_size(rows, cols);
+    }
+
+    /// Current screen state
+    pub fn screen(&self) -> &vt100::Screen {
+        self.parser.screen()
+    }
+
+    /// Every remembered line: history first, then the visible screen
+    pub fn snapshot_lines(&self) -> Vec<String> {
+        let (_, cols) = self.parser.screen().size();
+        let mut lines: Vec<String> = self.history.iter().cloned().collect();
+        lines.extend(self.parser.screen().rows(0, cols));
+
+        // Drop blank rows below the last line of output
+        while lines.last().is_some_and(|l| l.trim().is_empty()) {
+            lines.pop();
+        }
+
+        lines
+    }
+}
+
+/// Rows vt100 itself keeps; only needs to exceed what one segment can scroll
+fn internal_scrollback(rows: u16) -> usize {
+    rows as usize + 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn snapshot_includes_lines_scrolled_off_screen() {
+        let mut parser = ScrollbackParser::new(3, 20, 100);
+        for i in 0..10 {
+            parser.process(format!("line {i}\r\n").as_bytes());
+        }
+
+        let lines = parser.snapshot_lines();
+        let expected: Vec<String> = (0..10).map(|i| format!("line {i}")).collect();
+        assert_eq!(lines, expected);
+        assert_eq!(parser.screen().scrollback(), 0);
+    }
+
+    #[test]
+    fn snapshot_handles_many_lines_in_one_chunk() {
+        let mut parser = ScrollbackParser::new(2, 20, 100);
+        let chunk: String = (0..50).map(|i| format!("line {i}\r\n")).collect();
+        parser.process(chunk.as_bytes());
+
+        let lines = parser.snapshot_lines();
+        assert_eq!(lines.len(), 50);
+        assert_eq!(lines[0], "line 0");
+        assert_eq!(lines[49], "line 49");
+    }
+
+    #[test]
+    fn snapshot_respects_capacity() {
+        let mut parser = ScrollbackParser::new(2, 20, 3);
+        for i in 0..10 {
+            parser.process(format!("line {i}\r\n").as_bytes());
+        }
+
+        let lines = parser.snapshot_lines();
+        assert_eq!(lines.first().map(String::as_str), Some("line 6"));
+        assert_eq!(lines.last().map(String::as_str), Some("line 9"));
+    }
+
+    #[test]
+    fn snapshot_without_scrollback_is_visible_screen() {
+        let mut parser = ScrollbackParser::new(4, 20, 0);
+        parser.process(b"hello\r\nworld");
+
+        assert_eq!(parser.snapshot_lines(), vec!["hello", "world"]);
+    }
+}
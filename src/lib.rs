@@ -8,6 +8,7 @@ pub mod core;
 pub mod docker;
 pub mod exec;
 pub mod registry;
+pub mod scan;
 pub mod state;
 pub mod ui;
 pub mod update;
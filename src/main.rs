@@ -5,7 +5,7 @@ use tracing::{debug, info, warn};
 use contui::app::App;
 use contui::config::Config;
 use contui::core::ConnectionInfo;
-use contui::docker::DockerClient;
+use contui::docker::{format_bytes_size, plan_cleanup, DockerClient};
 use contui::update::{
     check_for_updates, check_for_updates_now, install_update, is_interactive, prompt_for_update,
     save_skip_version, UpdateCheckResult, UpdateDecision, UpdateInfo,
@@ -61,6 +61,16 @@ enum Commands {
         purge: bool,
     },
 
+    /// List the images the configured cleanup policies would delete
+    Cleanup {
+        /// Only run this policy
+        #[arg(long, value_name = "NAME")]
+        policy: Option<String>,
+        /// Delete the listed images instead of only showing them
+        #[arg(long)]
+        apply: bool,
+    },
+
     /// Show version information
     Version,
 }
@@ -80,6 +90,9 @@ async fn main() -> Result<()> {
         Some(Commands::Uninstall { purge }) => {
             return uninstall_self(purge).await;
         }
+        Some(Commands::Cleanup { ref policy, apply }) => {
+            return cleanup_images(&cli, policy.as_deref(), apply).await;
+        }
         Some(Commands::Version) => {
             print_version();
             return Ok(());
@@ -287,13 +300,7 @@ async fn run_tui(cli: Cli) -> Result<()> {
     info!("Starting Contui v{}", env!("CARGO_PKG_VERSION"));
 
     // Load configuration
-    let config = match &cli.config {
-        Some(path) => Config::load(path)?,
-        None => Config::load_default().unwrap_or_default(),
-    };
-
-    // Override config with CLI arguments
-    let config = apply_cli_overrides(config, &cli);
+    let config = load_config(&cli)?;
 
     info!("Configuration loaded successfully");
 
@@ -332,6 +339,103 @@ async fn run_tui(cli: Cli) -> Result<()> {
     Ok(())
 }
 
+/// Load the configuration file and apply CLI overrides
+fn load_config(cli: &Cli) -> Result<Config> {
+    let config = match &cli.config {
+        Some(path) => Config::load(path)?,
+        None => Config::load_default().unwrap_or_default(),
+    };
+    Ok(apply_cli_overrides(config, cli))
+}
+
+/// Print what each cleanup policy would delete, and delete it with `--apply`
+async fn cleanup_images(cli: &Cli, only: Option<&str>, apply: bool) -> Result<()> {
+    let config = load_config(cli)?;
+    let policies: Vec<_> = config
+        .images
+        .cleanup_policies
+        .iter()
+        .filter(|p| only.map_or(true, |name| p.name == name))
+        .collect();
+    if policies.is_empty() {
+        match only {
+            Some(name) => anyhow::bail!("No cleanup policy named '{}' in the config", name),
+            None => anyhow::bail!(
+                "No cleanup policies configured (add [[images.cleanup_policies]] to the config)"
+            ),
+        }
+    }
+
+    let client = match &config.docker.host {
+        Some(host) => DockerClient::with_host(host).await?,
+        None => DockerClient::from_env().await?,
+    };
+    let mut failures = 0;
+    for policy in policies {
+        // Plan each policy against the images left by the previous one
+        let images = client.list_images(true).await?;
+        let containers = client.list_containers(true).await?;
+        let now = chrono::Utc::now();
+        let last_tagged = client
+            .last_tag_times(&images, std::slice::from_ref(policy), now)
+            .await;
+        let plan = plan_cleanup(policy, &images, &containers, &last_tagged, now);
+
+        if let Some(problem) = &plan.problem {
+            println!("Policy '{}' selects nothing: {}", plan.policy, problem);
+            continue;
+        }
+        let mut skipped = Vec::new();
+        if plan.in_use > 0 {
+            skipped.push(format!("{} in use", plan.in_use));
+        }
+        if plan.excluded > 0 {
+            skipped.push(format!("{} excluded", plan.excluded));
+        }
+        println!(
+            "Policy '{}': {} image(s), {} reclaimable{}",
+            plan.policy,
+            plan.candidates.len(),
+            format_bytes_size(plan.reclaimable()),
+            if skipped.is_empty() {
+                String::new()
+            } else {
+                format!(" (skipped: {})", skipped.join(", "))
+            }
+        );
+        for candidate in &plan.candidates {
+            println!(
+                "  {:<40} {:<14} {:>10}  {}",
+                candidate.name(),
+                candidate.short_id,
+                format_bytes_size(candidate.size),
+                candidate.reason
+            );
+        }
+
+        if apply && !plan.candidates.is_empty() {
+            let result = client.run_cleanup(&plan).await;
+            println!(
+                "  Removed {} image(s), reclaimed {}",
+                result.removed,
+                format_bytes_size(result.reclaimed)
+            );
+            for (name, error) in &result.failed {
+                eprintln!("  ✗ {}: {}", name, error);
+            }
+            failures += result.failed.len();
+        }
+    }
+
+    if !apply {
+        println!("\nDry run: re-run with --apply to delete these images.");
+    }
+    if failures > 0 {
+        anyhow::bail!("{} image(s) could not be removed", failures);
+    }
+    Ok(())
+}
+
 fn apply_cli_overrides(mut config: Config, cli: &Cli) -> Config {
     if let Some(host) = &cli.host {
         config.docker.host = Some(host.clone());
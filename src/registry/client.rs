@@ -0,0 +1,651 @@
+//! Registry HTTP API v2 client (catalog, tags and manifests)
+
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use reqwest::header::{HeaderMap, ACCEPT, CONTENT_TYPE, LINK, WWW_AUTHENTICATE};
+use reqwest::{Method, Response, StatusCode};
+use serde_json::Value;
+use tracing::debug;
+
+use super::{normalize_host, registry_for, registry_host, url_host, DOCKER_HUB};
+use crate::config::{Registry, RegistryAuth};
+use crate::core::{ContuiError, Result};
+
+/// API endpoint serving Docker Hub
+const DOCKER_HUB_API: &str = "https://registry-1.docker.io";
+
+/// Manifest types we understand, multi-arch indexes first
+const MANIFEST_ACCEPT: &str = "application/vnd.oci.image.index.v1+json, \
+    application/vnd.docker.distribution.manifest.list.v2+json, \
+    application/vnd.oci.image.manifest.v1+json, \
+    application/vnd.docker.distribution.manifest.v2+json";
+
+/// Page size for catalog and tag list requests
+const PAGE_SIZE: usize = 100;
+
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// One platform-specific image of a tag
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PlatformManifest {
+    /// e.g. `linux/arm64/v8`
+    pub platform: String,
+    pub digest: String,
+    /// Compressed size of the config and layers
+    pub size: u64,
+}
+
+/// A resolved tag
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestInfo {
+    pub digest: String,
+    pub media_type: String,
+    pub platforms: Vec<PlatformManifest>,
+}
+
+impl ManifestInfo {
+    /// Whether the tag is a multi-arch index
+    pub fn is_index(&self) -> bool {
+        self.media_type.contains("index") || self.media_type.contains("manifest.list")
+    }
+}
+
+/// A parsed `WWW-Authenticate` challenge
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Challenge {
+    pub scheme: String,
+    pub params: HashMap<String, String>,
+}
+
+/// Parse a `WWW-Authenticate` header, e.g.
+/// `Bearer realm="https://auth.example/token",service="registry",scope="repository:app:pull"`
+pub fn parse_challenge(header: &str) -> Option<Challenge> {
+    let (scheme, rest) = header.trim().split_once(' ').unwrap_or((header.trim(), ""));
+    if scheme.is_empty() {
+        return None;
+    }
+
+    let mut params = HashMap::new();
+    let mut chars = rest.chars().peekable();
+    loop {
+        while chars.peek().is_some_and(|c| *c == ',' || c.is_whitespace()) {
+            chars.next();
+        }
+        let key: String = chars.by_ref().take_while(|c| *c != '=').collect();
+        if key.is_empty() {
+            break;
+        }
+        // Quoted values may contain commas (e.g. `scope="repo:app:pull,push"`)
+        let value: String = if chars.peek() == Some(&'"') {
+            chars.next();
+            chars.by_ref().take_while(|c| *c != '"').collect()
+        } else {
+            chars.by_ref().take_while(|c| *c != ',').collect()
+        };
+        params.insert(key.trim().to_ascii_lowercase(), value);
+    }
+
+    Some(Challenge {
+        scheme: scheme.to_ascii_lowercase(),
+        params,
+    })
+}
+
+/// API base URL for a configured registry URL. Docker Hub maps to its API
+/// host; local registries default to plain HTTP like the Docker daemon.
+pub fn api_base(url: &str) -> String {
+    let host = url_host(url);
+    if normalize_host(host) == DOCKER_HUB {
+        return DOCKER_HUB_API.to_string();
+    }
+    let insecure = url.starts_with("http://")
+        || (!url.starts_with("https://")
+            && (host.starts_with("localhost") || host.starts_with("127.0.0.1")));
+    format!("{}://{}", if insecure { "http" } else { "https" }, host)
+}
+
+/// Next page URL from a `Link: </v2/_catalog?last=x&n=100>; rel="next"` header
+fn next_link(headers: &HeaderMap, base_url: &str) -> Option<String> {
+    let link = headers.get(LINK)?.to_str().ok()?;
+    if !link.contains("rel=\"next\"") {
+        return None;
+    }
+    let target = link.split_once('<')?.1.split_once('>')?.0;
+    Some(if target.starts_with("http") {
+        target.to_string()
+    } else {
+        format!("{}{}", base_url, target)
+    })
+}
+
+/// `os/arch[/variant]` from a manifest platform or image config
+fn platform_name(value: &Value) -> String {
+    ["os", "architecture", "variant"]
+        .iter()
+        .filter_map(|key| value[key].as_str().filter(|s| !s.is_empty()))
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+/// Compressed size of an image manifest's config and layers
+fn image_size(manifest: &Value) -> u64 {
+    let layers: u64 = manifest["layers"]
+        .as_array()
+        .map(|layers| layers.iter().filter_map(|l| l["size"].as_u64()).sum())
+        .unwrap_or(0);
+    manifest["config"]["size"].as_u64().unwrap_or(0) + layers
+}
+
+fn string_list(value: &Value) -> Vec<String> {
+    value
+        .as_array()
+        .map(|items| {
+            items
+                .iter()
+                .filter_map(|i| i.as_str().map(str::to_string))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+fn request_error(e: reqwest::Error) -> ContuiError {
+    ContuiError::Registry(e.to_string())
+}
+
+/// How to authenticate a request
+enum Credentials<'a> {
+    Bearer(&'a str),
+    Basic(&'a RegistryAuth),
+}
+
+/// Client for one registry. Cheap to clone; clones share the token cache.
+#[derive(Debug, Clone)]
+pub struct RegistryClient {
+    http: reqwest::Client,
+    base_url: String,
+    auth: Option<RegistryAuth>,
+    /// Bearer tokens by scope
+    tokens: Arc<Mutex<HashMap<String, String>>>,
+}
+
+impl RegistryClient {
+    /// Create a client for a configured registry
+    pub fn new(registry: &Registry) -> Self {
+        Self::with_base_url(api_base(&registry.url), registry.auth.clone())
+    }
+
+    /// Create a client for the registry serving `image`, with the
+    /// configured credentials when one matches
+    pub fn for_image(image: &str, registries: &[Registry]) -> Self {
+        match registry_for(image, registries) {
+            Some(registry) => Self::new(registry),
+            None => Self::with_base_url(api_base(registry_host(image)), None),
+        }
+    }
+
+    /// Create a client for an API base URL such as `http://localhost:5000`
+    pub fn with_base_url(base_url: impl Into<String>, auth: Option<RegistryAuth>) -> Self {
+        let http = reqwest::Client::builder()
+            .timeout(REQUEST_TIMEOUT)
+            .user_agent(concat!("contui/", env!("CARGO_PKG_VERSION")))
+            .build()
+            .unwrap_or_default();
+        Self {
+            http,
+            base_url: base_url.into().trim_end_matches('/').to_string(),
+            auth,
+            tokens: Arc::default(),
+        }
+    }
+
+    /// Whether this client talks to Docker Hub
+    pub fn is_docker_hub(&self) -> bool {
+        self.base_url == DOCKER_HUB_API
+    }
+
+    /// Docker Hub keeps official images under `library/`
+    fn repository_path(&self, repository: &str) -> String {
+        if self.is_docker_hub() && !repository.contains('/') {
+            format!("library/{}", repository)
+        } else {
+            repository.to_string()
+        }
+    }
+
+    /// Image reference for pulling `repository:tag` from this registry
+    pub fn reference(&self, repository: &str, tag: &str) -> String {
+        if self.is_docker_hub() {
+            format!("{}:{}", repository, tag)
+        } else {
+            format!("{}/{}:{}", url_host(&self.base_url), repository, tag)
+        }
+    }
+
+    /// Repository names from the catalog. Not every registry offers one
+    /// (Docker Hub does not).
+    pub async fn catalog(&self) -> Result<Vec<String>> {
+        let url = format!("{}/v2/_catalog?n={}", self.base_url, PAGE_SIZE);
+        self.paginated(url, "registry:catalog:*", "repositories")
+            .await
+    }
+
+    /// Tags of a repository
+    pub async fn tags(&self, repository: &str) -> Result<Vec<String>> {
+        let path = self.repository_path(repository);
+        let url = format!("{}/v2/{}/tags/list?n={}", self.base_url, path, PAGE_SIZE);
+        self.paginated(url, &format!("repository:{}:pull", path), "tags")
+            .await
+    }
+
+    /// Resolve a tag to its digest and per-platform images
+    pub async fn manifest(&self, repository: &str, reference: &str) -> Result<ManifestInfo> {
+        let path = self.repository_path(repository);
+        let scope = format!("repository:{}:pull", path);
+        let (digest, media_type, manifest) = self.fetch_manifest(&path, reference, &scope).await?;
+
+        let platforms = if let Some(manifests) = manifest["manifests"].as_array() {
+            let mut platforms = Vec::new();
+            for entry in manifests {
+                let platform = platform_name(&entry["platform"]);
+                // Build attestations are listed as unknown/unknown
+                if platform.starts_with("unknown") {
+                    continue;
+                }
+                let digest = entry["digest"].as_str().unwrap_or_default().to_string();
+                let (_, _, child) = self.fetch_manifest(&path, &digest, &scope).await?;
+                platforms.push(PlatformManifest {
+                    platform,
+                    digest,
+                    size: image_size(&child),
+                });
+            }
+            platforms
+        } else {
+            // A single image names its platform in the config blob
+            let config_digest = manifest["config"]["digest"].as_str().unwrap_or_default();
+            let url = format!("{}/v2/{}/blobs/{}", self.base_url, path, config_digest);
+            let config: Value = self
+                .get(&url, &scope, None)
+                .await?
+                .json()
+                .await
+                .map_err(request_error)?;
+            vec![PlatformManifest {
+                platform: platform_name(&config),
+                digest: digest.clone(),
+                size: image_size(&manifest),
+            }]
+        };
+
+        Ok(ManifestInfo {
+            digest,
+            media_type,
+            platforms,
+        })
+    }
+
+    /// Current digest of a tag, without downloading the manifest
+    pub async fn tag_digest(&self, repository: &str, tag: &str) -> Result<String> {
+        let path = self.repository_path(repository);
+        let url = format!("{}/v2/{}/manifests/{}", self.base_url, path, tag);
+        let response = self
+            .request(
+                Method::HEAD,
+                &url,
+                &format!("repository:{}:pull", path),
+                Some(MANIFEST_ACCEPT),
+            )
+            .await?;
+        response
+            .headers()
+            .get("docker-content-digest")
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string)
+            .ok_or_else(|| ContuiError::Registry(format!("{}: no digest in response", url)))
+    }
+
+    /// Fetch a manifest: (digest, media type, body)
+    async fn fetch_manifest(
+        &self,
+        path: &str,
+        reference: &str,
+        scope: &str,
+    ) -> Result<(String, String, Value)> {
+        let url = format!("{}/v2/{}/manifests/{}", self.base_url, path, reference);
+        let response = self.get(&url, scope, Some(MANIFEST_ACCEPT)).await?;
+        let header = |name: &str| {
+            response
+                .headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .unwrap_or_default()
+                .to_string()
+        };
+        let digest = header("docker-content-digest");
+        let content_type = header(CONTENT_TYPE.as_str());
+        let body: Value = response.json().await.map_err(request_error)?;
+        let media_type = body["mediaType"]
+            .as_str()
+            .map(str::to_string)
+            .unwrap_or(content_type);
+        let digest = if digest.is_empty() && reference.starts_with("sha256:") {
+            reference.to_string()
+        } else {
+            digest
+        };
+        Ok((digest, media_type, body))
+    }
+
+    /// Collect a string list across `Link` pages
+    async fn paginated(&self, mut url: String, scope: &str, key: &str) -> Result<Vec<String>> {
+        let mut items = Vec::new();
+        loop {
+            let response = self.get(&url, scope, None).await?;
+            let next = next_link(response.headers(), &self.base_url);
+            let body: Value = response.json().await.map_err(request_error)?;
+            items.extend(string_list(&body[key]));
+            match next {
+                Some(next) => url = next,
+                None => return Ok(items),
+            }
+        }
+    }
+
+    async fn get(&self, url: &str, scope: &str, accept: Option<&str>) -> Result<Response> {
+        self.request(Method::GET, url, scope, accept).await
+    }
+
+    /// Request with registry auth: try a cached token, then answer the
+    /// server's challenge once
+    async fn request(
+        &self,
+        method: Method,
+        url: &str,
+        scope: &str,
+        accept: Option<&str>,
+    ) -> Result<Response> {
+        let cached = self.tokens.lock().unwrap().get(scope).cloned();
+        let response = self
+            .send(
+                method.clone(),
+                url,
+                accept,
+                cached.as_deref().map(Credentials::Bearer),
+            )
+            .await?;
+        if response.status() != StatusCode::UNAUTHORIZED {
+            return Self::check(url, response).await;
+        }
+
+        let challenge = response
+            .headers()
+            .get(WWW_AUTHENTICATE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(parse_challenge)
+            .ok_or_else(|| ContuiError::Registry(format!("{}: unauthorized", url)))?;
+        let response = match challenge.scheme.as_str() {
+            "bearer" => {
+                let token = self.fetch_token(&challenge, scope).await?;
+                self.tokens
+                    .lock()
+                    .unwrap()
+                    .insert(scope.to_string(), token.clone());
+                self.send(method, url, accept, Some(Credentials::Bearer(&token)))
+                    .await?
+            }
+            "basic" => {
+                let auth = self.auth.as_ref().ok_or_else(|| {
+                    ContuiError::Registry(format!("{}: credentials required", url))
+                })?;
+                self.send(method, url, accept, Some(Credentials::Basic(auth)))
+                    .await?
+            }
+            other => {
+                return Err(ContuiError::Registry(format!(
+                    "Unsupported auth scheme '{}'",
+                    other
+                )))
+            }
+        };
+        Self::check(url, response).await
+    }
+
+    async fn send(
+        &self,
+        method: Method,
+        url: &str,
+        accept: Option<&str>,
+        credentials: Option<Credentials<'_>>,
+    ) -> Result<Response> {
+        let mut request = self.http.request(method, url);
+        if let Some(accept) = accept {
+            request = request.header(ACCEPT, accept);
+        }
+        request = match credentials {
+            Some(Credentials::Bearer(token)) => request.bearer_auth(token),
+            Some(Credentials::Basic(auth)) => request.basic_auth(&auth.username, auth.secret()),
+            None => request,
+        };
+        request.send().await.map_err(request_error)
+    }
+
+    /// Exchange credentials (or nothing, for anonymous pulls) for a token
+    async fn fetch_token(&self, challenge: &Challenge, scope: &str) -> Result<String> {
+        let realm = challenge
+            .params
+            .get("realm")
+            .ok_or_else(|| ContuiError::Registry("Auth challenge without realm".into()))?;
+        let mut query = vec![("scope", challenge.params.get("scope").map_or(scope, |s| s))];
+        if let Some(service) = challenge.params.get("service") {
+            query.push(("service", service));
+        }
+        debug!("Requesting registry token from {} for {:?}", realm, query);
+
+        let mut request = self.http.get(realm).query(&query);
+        if let Some(auth) = &self.auth {
+            request = request.basic_auth(&auth.username, auth.secret());
+        }
+        let response = Self::check(realm, request.send().await.map_err(request_error)?).await?;
+        let body: Value = response.json().await.map_err(request_error)?;
+        body["token"]
+            .as_str()
+            .or_else(|| body["access_token"].as_str())
+            .map(str::to_string)
+            .ok_or_else(|| ContuiError::Registry("Token response without a token".into()))
+    }
+
+    /// Turn an error status into an error with the registry's message
+    async fn check(url: &str, response: Response) -> Result<Response> {
+        let status = response.status();
+        if status.is_success() {
+            return Ok(response);
+        }
+        let body: Value = response.json().await.unwrap_or_default();
+        let message = body["errors"][0]["message"]
+            .as_str()
+            .map(str::to_string)
+            .unwrap_or_else(|| status.to_string());
+        Err(ContuiError::Registry(format!("{}: {}", url, message)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Response from the stand-in registry: status, headers, body
+    type Reply = (u16, Vec<(&'static str, String)>, String);
+
+    /// Serve HTTP/1.1 requests on localhost, one per connection. The handler
+    /// gets the base URL, the request path and the Authorization header.
+    async fn stand_in(handler: fn(&str, &str, &str) -> Reply) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        let server_base = base.clone();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut request = Vec::new();
+                let mut buf = [0u8; 1024];
+                while !request.windows(4).any(|w| w == b"\r\n\r\n") {
+                    let n = socket.read(&mut buf).await.unwrap();
+                    if n == 0 {
+                        break;
+                    }
+                    request.extend_from_slice(&buf[..n]);
+                }
+                let request = String::from_utf8_lossy(&request);
+                let path = request.split(' ').nth(1).unwrap_or_default();
+                let auth = request
+                    .lines()
+                    .find_map(|l| l.strip_prefix("authorization: "))
+                    .unwrap_or_default();
+                let (status, headers, body) = handler(&server_base, path, auth);
+                let mut response = format!(
+                    "HTTP/1.1 {} X\r\nContent-Length: {}\r\nConnection: close\r\n",
+                    status,
+                    body.len()
+                );
+                for (name, value) in headers {
+                    response.push_str(&format!("{}: {}\r\n", name, value));
+                }
+                response.push_str("\r\n");
+                response.push_str(&body);
+                let _ = socket.write_all(response.as_bytes()).await;
+            }
+        });
+        base
+    }
+
+    #[test]
+    fn parses_challenges() {
+        let challenge = parse_challenge(
+            r#"Bearer realm="https://auth.docker.io/token",service="registry.docker.io",scope="repository:a/b:pull,push""#,
+        )
+        .unwrap();
+        assert_eq!(challenge.scheme, "bearer");
+        assert_eq!(challenge.params["realm"], "https://auth.docker.io/token");
+        assert_eq!(challenge.params["scope"], "repository:a/b:pull,push");
+
+        let basic = parse_challenge(r#"Basic realm="Registry Realm""#).unwrap();
+        assert_eq!(basic.scheme, "basic");
+        assert!(parse_challenge("").is_none());
+    }
+
+    #[test]
+    fn resolves_api_base_and_references() {
+        assert_eq!(api_base("https://index.docker.io/v1/"), DOCKER_HUB_API);
+        assert_eq!(api_base("ghcr.io"), "https://ghcr.io");
+        assert_eq!(api_base("localhost:5000"), "http://localhost:5000");
+        assert_eq!(api_base("http://registry.lan"), "http://registry.lan");
+
+        let hub = RegistryClient::with_base_url(DOCKER_HUB_API, None);
+        assert_eq!(hub.repository_path("nginx"), "library/nginx");
+        assert_eq!(hub.reference("nginx", "1.25"), "nginx:1.25");
+        assert!(RegistryClient::for_image("nginx:latest", &[]).is_docker_hub());
+        let local = RegistryClient::with_base_url("http://localhost:5000/", None);
+        assert_eq!(
+            local.reference("team/app", "1"),
+            "localhost:5000/team/app:1"
+        );
+    }
+
+    #[tokio::test]
+    async fn token_auth_and_tag_pages() {
+        let base = stand_in(|base, path, auth| match (path, auth) {
+            (p, "") if p.starts_with("/v2/") => (
+                401,
+                vec![(
+                    "WWW-Authenticate",
+                    format!(
+                        r#"Bearer realm="{}/token",service="test",scope="repository:app:pull""#,
+                        base
+                    ),
+                )],
+                String::new(),
+            ),
+            // me:pw
+            (p, "Basic bWU6cHc=") if p.starts_with("/token?") => {
+                (200, vec![], r#"{"token":"t1"}"#.into())
+            }
+            ("/v2/app/tags/list?n=100", "Bearer t1") => (
+                200,
+                vec![(
+                    "Link",
+                    r#"</v2/app/tags/list?last=2&n=100>; rel="next""#.into(),
+                )],
+                r#"{"name":"app","tags":["1","2"]}"#.into(),
+            ),
+            ("/v2/app/tags/list?last=2&n=100", "Bearer t1") => {
+                (200, vec![], r#"{"name":"app","tags":["3"]}"#.into())
+            }
+            _ => (
+                404,
+                vec![],
+                r#"{"errors":[{"message":"unexpected request"}]}"#.into(),
+            ),
+        })
+        .await;
+
+        let client = RegistryClient::with_base_url(
+            base,
+            Some(RegistryAuth {
+                username: "me".into(),
+                password: Some("pw".into()),
+                token: None,
+            }),
+        );
+        assert_eq!(client.tags("app").await.unwrap(), vec!["1", "2", "3"]);
+
+        let error = client.tags("missing").await.unwrap_err();
+        assert!(error.to_string().contains("unexpected request"));
+    }
+
+    #[tokio::test]
+    async fn resolves_multi_arch_index() {
+        let base = stand_in(|_, path, _| match path {
+            "/v2/app/manifests/1" => (
+                200,
+                vec![("Docker-Content-Digest", "sha256:index".into())],
+                r#"{"mediaType":"application/vnd.oci.image.index.v1+json","manifests":[
+                    {"digest":"sha256:amd","platform":{"os":"linux","architecture":"amd64"}},
+                    {"digest":"sha256:arm","platform":{"os":"linux","architecture":"arm64","variant":"v8"}},
+                    {"digest":"sha256:att","platform":{"os":"unknown","architecture":"unknown"}}
+                ]}"#
+                .into(),
+            ),
+            "/v2/app/manifests/sha256:amd" | "/v2/app/manifests/sha256:arm" => (
+                200,
+                vec![],
+                r#"{"config":{"size":10},"layers":[{"size":100},{"size":200}]}"#.into(),
+            ),
+            _ => (404, vec![], String::new()),
+        })
+        .await;
+
+        let client = RegistryClient::with_base_url(base, None);
+        let manifest = client.manifest("app", "1").await.unwrap();
+        assert!(manifest.is_index());
+        assert_eq!(manifest.digest, "sha256:index");
+        assert_eq!(client.tag_digest("app", "1").await.unwrap(), "sha256:index");
+        assert!(client.tag_digest("app", "2").await.is_err());
+        assert_eq!(
+            manifest.platforms,
+            vec![
+                PlatformManifest {
+                    platform: "linux/amd64".into(),
+                    digest: "sha256:amd".into(),
+                    size: 310,
+                },
+                PlatformManifest {
+                    platform: "linux/arm64/v8".into(),
+                    digest: "sha256:arm".into(),
+                    size: 310,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,220 @@
+//! Image freshness: whether local tags are behind their registry
+
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use super::{normalize_host, registry_host, DOCKER_HUB};
+
+/// A tag reference split into registry host, repository and tag
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ImageRef {
+    pub host: String,
+    pub repository: String,
+    pub tag: String,
+}
+
+impl ImageRef {
+    /// Parse `host/repo:tag`, filling in Docker Hub and `latest`. Image IDs
+    /// and digest-pinned references have no tag to follow and give `None`.
+    pub fn parse(image: &str) -> Option<Self> {
+        if image.is_empty()
+            || image.starts_with("<none>")
+            || image.starts_with("sha256:")
+            || image.contains('@')
+        {
+            return None;
+        }
+        let explicit_host = registry_host(image);
+        let rest = image
+            .strip_prefix(explicit_host)
+            .and_then(|rest| rest.strip_prefix('/'))
+            .unwrap_or(image);
+        let (repository, tag) = match rest.rsplit_once(':') {
+            Some((repository, tag)) if !tag.contains('/') => (repository, tag),
+            _ => (rest, "latest"),
+        };
+        let host = normalize_host(explicit_host).to_string();
+        let repository = if host == DOCKER_HUB && !repository.contains('/') {
+            format!("library/{}", repository)
+        } else {
+            repository.to_string()
+        };
+        Some(Self {
+            host,
+            repository,
+            tag: tag.to_string(),
+        })
+    }
+
+    /// Fully qualified form, so `nginx` and `docker.io/library/nginx:latest`
+    /// compare equal
+    pub fn canonical(&self) -> String {
+        format!("{}/{}:{}", self.host, self.repository, self.tag)
+    }
+
+    /// Whether a `name@digest` entry from `RepoDigests` is for this repository
+    fn same_repository(&self, name: &str) -> bool {
+        Self::parse(name)
+            .is_some_and(|other| other.host == self.host && other.repository == self.repository)
+    }
+}
+
+/// How a local tag compares to the registry
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Freshness {
+    UpToDate,
+    Outdated {
+        remote_digest: String,
+    },
+    /// Not comparable, e.g. a locally built image or an unreachable registry
+    Unknown(String),
+}
+
+/// Compare the digests a local image was pulled with against the digest
+/// the registry currently serves for the tag
+pub fn compare(reference: &ImageRef, repo_digests: &[String], remote_digest: &str) -> Freshness {
+    let local: Vec<&str> = repo_digests
+        .iter()
+        .filter_map(|entry| entry.split_once('@'))
+        .filter(|(name, _)| reference.same_repository(name))
+        .map(|(_, digest)| digest)
+        .collect();
+    if local.is_empty() {
+        Freshness::Unknown("not pulled from a registry".to_string())
+    } else if local.contains(&remote_digest) {
+        Freshness::UpToDate
+    } else {
+        Freshness::Outdated {
+            remote_digest: remote_digest.to_string(),
+        }
+    }
+}
+
+/// Remote tag digests keyed by canonical reference, kept for a TTL so
+/// browsing doesn't hit the registry on every refresh
+#[derive(Debug, Clone)]
+pub struct DigestCache {
+    ttl: Duration,
+    /// Lookup time (`None` once expired by hand) and digest or error
+    entries: HashMap<String, (Option<Instant>, Result<String, String>)>,
+}
+
+impl Default for DigestCache {
+    fn default() -> Self {
+        Self::new(Duration::from_secs(900))
+    }
+}
+
+impl DigestCache {
+    pub fn new(ttl: Duration) -> Self {
+        Self {
+            ttl,
+            entries: HashMap::new(),
+        }
+    }
+
+    /// Last lookup result, even when expired
+    pub fn get(&self, canonical: &str) -> Option<&Result<String, String>> {
+        self.entries.get(canonical).map(|(_, result)| result)
+    }
+
+    /// Whether a reference was never looked up or its result expired
+    pub fn needs_check(&self, canonical: &str, now: Instant) -> bool {
+        self.entries
+            .get(canonical)
+            .and_then(|(checked, _)| *checked)
+            .map_or(true, |checked| now.duration_since(checked) >= self.ttl)
+    }
+
+    /// Record a lookup result
+    pub fn insert(&mut self, canonical: String, result: Result<String, String>, now: Instant) {
+        self.entries.insert(canonical, (Some(now), result));
+    }
+
+    /// Expire every entry so the next pass checks again, keeping the old
+    /// results on screen until then
+    pub fn expire(&mut self) {
+        for (checked, _) in self.entries.values_mut() {
+            *checked = None;
+        }
+    }
+
+    /// Freshness of a local tag, if its remote digest is known
+    pub fn freshness(&self, reference: &ImageRef, repo_digests: &[String]) -> Option<Freshness> {
+        Some(match self.get(&reference.canonical())? {
+            Ok(digest) => compare(reference, repo_digests, digest),
+            Err(error) => Freshness::Unknown(error.clone()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_references() {
+        let nginx = ImageRef::parse("nginx").unwrap();
+        assert_eq!(nginx.canonical(), "docker.io/library/nginx:latest");
+        assert_eq!(
+            ImageRef::parse("docker.io/library/nginx:latest").unwrap(),
+            nginx
+        );
+        assert_eq!(
+            ImageRef::parse("localhost:5000/team/app:1.2").unwrap(),
+            ImageRef {
+                host: "localhost:5000".into(),
+                repository: "team/app".into(),
+                tag: "1.2".into(),
+            }
+        );
+        assert_eq!(ImageRef::parse("localhost:5000/app").unwrap().tag, "latest");
+        assert!(ImageRef::parse("sha256:abc").is_none());
+        assert!(ImageRef::parse("nginx@sha256:abc").is_none());
+        assert!(ImageRef::parse("<none>:<none>").is_none());
+    }
+
+    #[test]
+    fn compares_repo_digests() {
+        let reference = ImageRef::parse("nginx:latest").unwrap();
+        let digests = vec![
+            "ghcr.io/mirror/nginx@sha256:other".to_string(),
+            "nginx@sha256:old".to_string(),
+        ];
+        assert_eq!(
+            compare(&reference, &digests, "sha256:old"),
+            Freshness::UpToDate
+        );
+        assert_eq!(
+            compare(&reference, &digests, "sha256:new"),
+            Freshness::Outdated {
+                remote_digest: "sha256:new".into()
+            }
+        );
+        assert!(matches!(
+            compare(&reference, &[], "sha256:new"),
+            Freshness::Unknown(_)
+        ));
+    }
+
+    #[test]
+    fn cache_expires_entries() {
+        let reference = ImageRef::parse("app:1").unwrap();
+        let key = reference.canonical();
+        let now = Instant::now();
+        let mut cache = DigestCache::new(Duration::from_secs(60));
+        assert!(cache.needs_check(&key, now));
+
+        cache.insert(key.clone(), Ok("sha256:new".into()), now);
+        assert!(!cache.needs_check(&key, now + Duration::from_secs(30)));
+        assert!(cache.needs_check(&key, now + Duration::from_secs(60)));
+        assert!(matches!(
+            cache.freshness(&reference, &["app@sha256:old".to_string()]),
+            Some(Freshness::Outdated { .. })
+        ));
+
+        cache.expire();
+        assert!(cache.needs_check(&key, now));
+        assert!(cache.get(&key).is_some());
+    }
+}
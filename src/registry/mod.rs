@@ -1,11 +1,114 @@
 //! Registry integration (Docker Hub, etc.)
 
-// Registry integration will be implemented in US-019
+pub mod client;
+pub mod freshness;
+
+use bollard::auth::DockerCredentials;
+
+pub use client::{ManifestInfo, PlatformManifest, RegistryClient};
+pub use freshness::{DigestCache, Freshness, ImageRef};
+
+use crate::config::Registry;
+
+/// Registry host used for references without an explicit one
+pub const DOCKER_HUB: &str = "docker.io";
+
+/// Registry host an image reference points at, e.g. `ghcr.io` for
+/// `ghcr.io/owner/app:1.0` and `docker.io` for `nginx`
+pub fn registry_host(image: &str) -> &str {
+    match image.split_once('/') {
+        // Only a first component that looks like a host names a registry
+        Some((first, _)) if first.contains(['.', ':']) || first == "localhost" => first,
+        _ => DOCKER_HUB,
+    }
+}
+
+/// Reduce a configured registry URL to the host it refers to
+fn url_host(url: &str) -> &str {
+    let url = url
+        .trim_start_matches("https://")
+        .trim_start_matches("http://");
+    url.split('/').next().unwrap_or(url)
+}
+
+/// Docker Hub goes by several names
+fn normalize_host(host: &str) -> &str {
+    match host {
+        "index.docker.io" | "registry-1.docker.io" | "registry.hub.docker.com" => DOCKER_HUB,
+        _ => host,
+    }
+}
+
+/// Configured registry serving `image`, if any
+pub fn registry_for<'a>(image: &str, registries: &'a [Registry]) -> Option<&'a Registry> {
+    let host = normalize_host(registry_host(image));
+    registries
+        .iter()
+        .find(|r| normalize_host(url_host(&r.url)).eq_ignore_ascii_case(host))
+}
+
+/// Credentials to send when pulling or pushing `image`
+pub fn credentials_for(image: &str, registries: &[Registry]) -> Option<DockerCredentials> {
+    let registry = registry_for(image, registries)?;
+    let auth = registry.auth.as_ref()?;
+    Some(DockerCredentials {
+        username: Some(auth.username.clone()),
+        password: auth.secret().map(str::to_string),
+        serveraddress: Some(url_host(&registry.url).to_string()),
+        ..Default::default()
+    })
+}
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use crate::config::RegistryAuth;
+
+    fn registry(url: &str, auth: Option<RegistryAuth>) -> Registry {
+        Registry {
+            name: url.to_string(),
+            url: url.to_string(),
+            auth,
+            is_default: false,
+        }
+    }
+
+    fn auth(password: Option<&str>, token: Option<&str>) -> Option<RegistryAuth> {
+        Some(RegistryAuth {
+            username: "me".into(),
+            password: password.map(String::from),
+            token: token.map(String::from),
+        })
+    }
+
     #[test]
-    fn test_placeholder() {
-        assert!(true);
+    fn finds_registry_host() {
+        assert_eq!(registry_host("nginx"), "docker.io");
+        assert_eq!(registry_host("library/nginx:1.25"), "docker.io");
+        assert_eq!(registry_host("ghcr.io/owner/app:1.0"), "ghcr.io");
+        assert_eq!(registry_host("localhost:5000/app"), "localhost:5000");
+        assert_eq!(registry_host("localhost/app"), "localhost");
+    }
+
+    #[test]
+    fn matches_configured_registry() {
+        let registries = vec![
+            registry("https://ghcr.io/", auth(Some("secret"), None)),
+            registry("https://index.docker.io/v1/", auth(None, Some("pat"))),
+            registry("registry.local:5000", None),
+        ];
+
+        let ghcr = credentials_for("ghcr.io/owner/app", &registries).unwrap();
+        assert_eq!(ghcr.username.as_deref(), Some("me"));
+        assert_eq!(ghcr.password.as_deref(), Some("secret"));
+        assert_eq!(ghcr.serveraddress.as_deref(), Some("ghcr.io"));
+
+        let hub = credentials_for("nginx:latest", &registries).unwrap();
+        assert_eq!(hub.password.as_deref(), Some("pat"));
+
+        // Matched but without auth
+        assert!(registry_for("registry.local:5000/app", &registries).is_some());
+        assert!(credentials_for("registry.local:5000/app", &registries).is_none());
+        assert!(credentials_for("quay.io/app", &registries).is_none());
     }
 }
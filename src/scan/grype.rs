@@ -0,0 +1,105 @@
+//! Grype JSON reports (`grype <image> -o json`)
+
+use serde::Deserialize;
+
+use super::{digests, Finding, ScanReport, Scanner, Severity};
+use crate::core::Result;
+
+#[derive(Deserialize)]
+struct Report {
+    #[serde(default)]
+    matches: Vec<Match>,
+    #[serde(default)]
+    source: Option<Source>,
+}
+
+#[derive(Deserialize)]
+struct Source {
+    #[serde(default)]
+    target: Option<SourceTarget>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "camelCase")]
+struct SourceTarget {
+    #[serde(default)]
+    user_input: String,
+    #[serde(rename = "imageID", default)]
+    image_id: String,
+    #[serde(default)]
+    manifest_digest: String,
+    #[serde(default)]
+    repo_digests: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct Match {
+    vulnerability: Vulnerability,
+    artifact: Artifact,
+}
+
+#[derive(Deserialize)]
+struct Vulnerability {
+    id: String,
+    #[serde(default)]
+    severity: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    fix: Option<Fix>,
+}
+
+#[derive(Deserialize)]
+struct Fix {
+    #[serde(default)]
+    versions: Vec<String>,
+}
+
+#[derive(Deserialize)]
+struct Artifact {
+    name: String,
+    #[serde(default)]
+    version: String,
+}
+
+pub(super) fn parse(value: serde_json::Value) -> Result<ScanReport> {
+    let report: Report = serde_json::from_value(value)?;
+    let target = report
+        .source
+        .and_then(|source| source.target)
+        .unwrap_or_default();
+    let findings = report
+        .matches
+        .into_iter()
+        .map(|m| Finding {
+            id: m.vulnerability.id,
+            package: m.artifact.name,
+            installed_version: m.artifact.version,
+            fixed_version: m
+                .vulnerability
+                .fix
+                .filter(|fix| !fix.versions.is_empty())
+                .map(|fix| fix.versions.join(", ")),
+            severity: Severity::parse(&m.vulnerability.severity),
+            // Grype has no title; the first line of the description is close
+            title: m
+                .vulnerability
+                .description
+                .lines()
+                .next()
+                .unwrap_or_default()
+                .to_string(),
+        })
+        .collect();
+    Ok(ScanReport {
+        scanner: Scanner::Grype,
+        path: Default::default(),
+        image: target.user_input,
+        digests: digests(
+            [target.image_id.as_str(), target.manifest_digest.as_str()]
+                .into_iter()
+                .chain(target.repo_digests.iter().map(String::as_str)),
+        ),
+        findings,
+    })
+}
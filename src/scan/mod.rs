@@ -0,0 +1,380 @@
+//! Vulnerability scanner reports (Trivy, Grype) loaded from local JSON files
+//!
+//! Reports are matched to local images by digest: the image ID or any of
+//! its repo digests. Nothing here talks to the network.
+
+pub mod grype;
+pub mod trivy;
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use crate::core::{ContuiError, ImageSummary, Result};
+
+/// Finding severity, most severe first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Severity {
+    Critical,
+    High,
+    Medium,
+    Low,
+    Unknown,
+}
+
+impl Severity {
+    pub const ALL: [Severity; 5] = [
+        Severity::Critical,
+        Severity::High,
+        Severity::Medium,
+        Severity::Low,
+        Severity::Unknown,
+    ];
+
+    /// Parse a scanner's severity name; Grype's "Negligible" counts as low
+    pub fn parse(name: &str) -> Self {
+        match name.to_ascii_lowercase().as_str() {
+            "critical" => Severity::Critical,
+            "high" => Severity::High,
+            "medium" => Severity::Medium,
+            "low" | "negligible" => Severity::Low,
+            _ => Severity::Unknown,
+        }
+    }
+
+    /// One-letter abbreviation for the summary column
+    pub fn letter(self) -> char {
+        match self {
+            Severity::Critical => 'C',
+            Severity::High => 'H',
+            Severity::Medium => 'M',
+            Severity::Low => 'L',
+            Severity::Unknown => '?',
+        }
+    }
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Severity::Critical => "CRITICAL",
+            Severity::High => "HIGH",
+            Severity::Medium => "MEDIUM",
+            Severity::Low => "LOW",
+            Severity::Unknown => "UNKNOWN",
+        };
+        f.write_str(name)
+    }
+}
+
+/// One vulnerable package
+#[derive(Debug, Clone, PartialEq)]
+pub struct Finding {
+    pub id: String,
+    pub package: String,
+    pub installed_version: String,
+    pub fixed_version: Option<String>,
+    pub severity: Severity,
+    pub title: String,
+}
+
+/// Number of findings per severity
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SeverityCounts {
+    counts: [usize; 5],
+}
+
+impl SeverityCounts {
+    pub fn get(&self, severity: Severity) -> usize {
+        self.counts[severity as usize]
+    }
+
+    pub fn total(&self) -> usize {
+        self.counts.iter().sum()
+    }
+
+    /// Most severe level with at least one finding
+    pub fn worst(&self) -> Option<Severity> {
+        Severity::ALL.into_iter().find(|s| self.get(*s) > 0)
+    }
+
+    /// Compact form like `C2 H5 M1`, or `none` for a clean report
+    pub fn short(&self) -> String {
+        if self.total() == 0 {
+            return "none".to_string();
+        }
+        Severity::ALL
+            .into_iter()
+            .filter(|s| self.get(*s) > 0)
+            .map(|s| format!("{}{}", s.letter(), self.get(s)))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+impl FromIterator<Severity> for SeverityCounts {
+    fn from_iter<I: IntoIterator<Item = Severity>>(severities: I) -> Self {
+        let mut counts = SeverityCounts::default();
+        for severity in severities {
+            counts.counts[severity as usize] += 1;
+        }
+        counts
+    }
+}
+
+/// Which scanner produced a report
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Scanner {
+    Trivy,
+    Grype,
+}
+
+impl fmt::Display for Scanner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Scanner::Trivy => "Trivy",
+            Scanner::Grype => "Grype",
+        })
+    }
+}
+
+/// A parsed scanner report
+#[derive(Debug, Clone, PartialEq)]
+pub struct ScanReport {
+    pub scanner: Scanner,
+    pub path: PathBuf,
+    /// Image reference the scan was run against
+    pub image: String,
+    /// `sha256:...` digests identifying the scanned image
+    pub digests: Vec<String>,
+    pub findings: Vec<Finding>,
+}
+
+impl ScanReport {
+    /// Parse a Trivy or Grype JSON report
+    pub fn parse(path: &Path, json: &str) -> Result<Self> {
+        let value: serde_json::Value = serde_json::from_str(json)?;
+        let mut report = if value.get("matches").is_some() {
+            grype::parse(value)?
+        } else if value.get("SchemaVersion").is_some() || value.get("Results").is_some() {
+            trivy::parse(value)?
+        } else {
+            return Err(ContuiError::Other(
+                "not a Trivy or Grype JSON report".to_string(),
+            ));
+        };
+        report.path = path.to_path_buf();
+        report
+            .findings
+            .sort_by(|a, b| (a.severity, &a.package, &a.id).cmp(&(b.severity, &b.package, &b.id)));
+        report
+            .findings
+            .dedup_by(|a, b| a.id == b.id && a.package == b.package);
+        Ok(report)
+    }
+
+    pub fn counts(&self) -> SeverityCounts {
+        self.findings.iter().map(|f| f.severity).collect()
+    }
+
+    /// Whether this report is for a local image
+    pub fn matches(&self, image: &ImageSummary) -> bool {
+        let repo_digests = image
+            .repo_digests
+            .iter()
+            .filter_map(|entry| entry.split_once('@').map(|(_, digest)| digest));
+        std::iter::once(image.id.as_str())
+            .chain(repo_digests)
+            .any(|digest| self.digests.iter().any(|d| d == digest))
+    }
+
+    /// Findings at or above `min_severity` whose package contains `package`
+    pub fn filtered<'a>(
+        &'a self,
+        min_severity: Option<Severity>,
+        package: &'a str,
+    ) -> impl Iterator<Item = &'a Finding> + 'a {
+        let package = package.to_lowercase();
+        self.findings.iter().filter(move |f| {
+            min_severity.map_or(true, |min| f.severity <= min)
+                && (package.is_empty() || f.package.to_lowercase().contains(&package))
+        })
+    }
+}
+
+/// Collect digests from `name@sha256:...` entries and bare digests
+pub(crate) fn digests<'a>(entries: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let mut digests: Vec<String> = entries
+        .into_iter()
+        .map(|entry| entry.rsplit_once('@').map_or(entry, |(_, digest)| digest))
+        .filter(|digest| digest.starts_with("sha256:"))
+        .map(str::to_string)
+        .collect();
+    digests.sort();
+    digests.dedup();
+    digests
+}
+
+/// Load a report file, or every `.json` file in a directory. Returns the
+/// reports and an error message for each file that could not be read.
+pub fn load_reports(path: &Path) -> (Vec<ScanReport>, Vec<String>) {
+    let files = if path.is_dir() {
+        match std::fs::read_dir(path) {
+            Ok(entries) => {
+                let mut files: Vec<PathBuf> = entries
+                    .filter_map(|entry| entry.ok().map(|e| e.path()))
+                    .filter(|p| p.extension().is_some_and(|ext| ext == "json"))
+                    .collect();
+                files.sort();
+                files
+            }
+            Err(e) => return (vec![], vec![format!("{}: {}", path.display(), e)]),
+        }
+    } else {
+        vec![path.to_path_buf()]
+    };
+
+    let mut reports = Vec::new();
+    let mut errors = Vec::new();
+    for file in files {
+        let result = std::fs::read_to_string(&file)
+            .map_err(ContuiError::from)
+            .and_then(|json| ScanReport::parse(&file, &json));
+        match result {
+            Ok(report) => reports.push(report),
+            Err(e) => errors.push(format!("{}: {}", file.display(), e)),
+        }
+    }
+    (reports, errors)
+}
+
+/// Loaded reports; a newer report for the same file replaces the old one
+#[derive(Debug, Clone, Default)]
+pub struct ScanReports {
+    reports: Vec<Arc<ScanReport>>,
+}
+
+impl ScanReports {
+    pub fn add(&mut self, report: ScanReport) {
+        self.reports.retain(|r| r.path != report.path);
+        self.reports.push(Arc::new(report));
+    }
+
+    pub fn len(&self) -> usize {
+        self.reports.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.reports.is_empty()
+    }
+
+    /// Most recently loaded report for an image
+    pub fn for_image(&self, image: &ImageSummary) -> Option<&Arc<ScanReport>> {
+        self.reports.iter().rev().find(|r| r.matches(image))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TRIVY: &str = r#"{
+        "SchemaVersion": 2,
+        "ArtifactName": "app:1",
+        "ArtifactType": "container_image",
+        "Metadata": {
+            "ImageID": "sha256:aaa",
+            "RepoDigests": ["registry.local/app@sha256:bbb"]
+        },
+        "Results": [
+            {"Target": "app:1 (debian 12.4)", "Vulnerabilities": [
+                {"VulnerabilityID": "CVE-2024-1", "PkgName": "openssl", "InstalledVersion": "3.0.1",
+                 "FixedVersion": "3.0.2", "Severity": "CRITICAL", "Title": "Buffer overflow"},
+                {"VulnerabilityID": "CVE-2024-2", "PkgName": "zlib", "InstalledVersion": "1.2",
+                 "Severity": "LOW"}
+            ]},
+            {"Target": "app/package-lock.json"}
+        ]
+    }"#;
+
+    const GRYPE: &str = r#"{
+        "matches": [
+            {"vulnerability": {"id": "GHSA-xxxx", "severity": "High", "description": "Prototype pollution",
+                               "fix": {"versions": ["4.17.21"], "state": "fixed"}},
+             "artifact": {"name": "lodash", "version": "4.17.20", "type": "npm"}},
+            {"vulnerability": {"id": "CVE-2024-3", "severity": "Negligible", "fix": {"versions": [], "state": "not-fixed"}},
+             "artifact": {"name": "bash", "version": "5.2"}}
+        ],
+        "source": {"type": "image", "target": {
+            "userInput": "web:2", "imageID": "sha256:ccc", "manifestDigest": "sha256:ddd",
+            "repoDigests": ["web@sha256:ddd"]
+        }},
+        "descriptor": {"name": "grype", "version": "0.74.0"}
+    }"#;
+
+    fn image(id: &str, repo_digests: &[&str]) -> ImageSummary {
+        ImageSummary {
+            id: id.into(),
+            repo_digests: repo_digests.iter().map(|d| d.to_string()).collect(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn parses_trivy_reports() {
+        let report = ScanReport::parse(Path::new("app.json"), TRIVY).unwrap();
+        assert_eq!(report.scanner, Scanner::Trivy);
+        assert_eq!(report.image, "app:1");
+        assert_eq!(report.digests, vec!["sha256:aaa", "sha256:bbb"]);
+        assert_eq!(report.counts().short(), "C1 L1");
+        assert_eq!(report.counts().worst(), Some(Severity::Critical));
+        let first = &report.findings[0];
+        assert_eq!(first.package, "openssl");
+        assert_eq!(first.fixed_version.as_deref(), Some("3.0.2"));
+
+        assert!(report.matches(&image("sha256:aaa", &[])));
+        assert!(report.matches(&image("sha256:zzz", &["app@sha256:bbb"])));
+        assert!(!report.matches(&image("sha256:zzz", &["app@sha256:other"])));
+    }
+
+    #[test]
+    fn parses_grype_reports() {
+        let report = ScanReport::parse(Path::new("web.json"), GRYPE).unwrap();
+        assert_eq!(report.scanner, Scanner::Grype);
+        assert_eq!(report.image, "web:2");
+        assert_eq!(report.digests, vec!["sha256:ccc", "sha256:ddd"]);
+        assert_eq!(report.counts().short(), "H1 L1");
+        assert_eq!(report.findings[0].title, "Prototype pollution");
+        assert_eq!(report.findings[1].fixed_version, None);
+
+        let high: Vec<&str> = report
+            .filtered(Some(Severity::High), "")
+            .map(|f| f.id.as_str())
+            .collect();
+        assert_eq!(high, vec!["GHSA-xxxx"]);
+        assert_eq!(report.filtered(None, "BASH").count(), 1);
+
+        assert!(ScanReport::parse(Path::new("x.json"), "{}").is_err());
+    }
+
+    #[test]
+    fn loads_directories_and_replaces_reloaded_files() {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::write(dir.path().join("app.json"), TRIVY).unwrap();
+        std::fs::write(dir.path().join("broken.json"), "not json").unwrap();
+        std::fs::write(dir.path().join("notes.txt"), "ignored").unwrap();
+
+        let (reports, errors) = load_reports(dir.path());
+        assert_eq!(reports.len(), 1);
+        assert_eq!(errors.len(), 1);
+        assert!(errors[0].contains("broken.json"));
+
+        let mut loaded = ScanReports::default();
+        for report in reports.into_iter().chain(load_reports(dir.path()).0) {
+            loaded.add(report);
+        }
+        assert_eq!(loaded.len(), 1);
+        assert!(loaded.for_image(&image("sha256:aaa", &[])).is_some());
+        assert!(loaded.for_image(&image("sha256:ccc", &[])).is_none());
+    }
+}
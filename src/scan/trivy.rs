@@ -0,0 +1,76 @@
+//! Trivy JSON reports (`trivy image --format json`)
+
+use serde::Deserialize;
+
+use super::{digests, Finding, ScanReport, Scanner, Severity};
+use crate::core::Result;
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Report {
+    #[serde(default)]
+    artifact_name: String,
+    #[serde(default)]
+    metadata: Metadata,
+    #[serde(default)]
+    results: Vec<Target>,
+}
+
+#[derive(Deserialize, Default)]
+#[serde(rename_all = "PascalCase")]
+struct Metadata {
+    #[serde(rename = "ImageID", default)]
+    image_id: String,
+    #[serde(default)]
+    repo_digests: Vec<String>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Target {
+    #[serde(default)]
+    vulnerabilities: Option<Vec<Vulnerability>>,
+}
+
+#[derive(Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct Vulnerability {
+    #[serde(rename = "VulnerabilityID")]
+    vulnerability_id: String,
+    pkg_name: String,
+    #[serde(default)]
+    installed_version: String,
+    #[serde(default)]
+    fixed_version: Option<String>,
+    #[serde(default)]
+    severity: String,
+    #[serde(default)]
+    title: String,
+}
+
+pub(super) fn parse(value: serde_json::Value) -> Result<ScanReport> {
+    let report: Report = serde_json::from_value(value)?;
+    let findings = report
+        .results
+        .into_iter()
+        .flat_map(|target| target.vulnerabilities.unwrap_or_default())
+        .map(|v| Finding {
+            id: v.vulnerability_id,
+            package: v.pkg_name,
+            installed_version: v.installed_version,
+            fixed_version: v.fixed_version.filter(|f| !f.is_empty()),
+            severity: Severity::parse(&v.severity),
+            title: v.title,
+        })
+        .collect();
+    Ok(ScanReport {
+        scanner: Scanner::Trivy,
+        path: Default::default(),
+        image: report.artifact_name,
+        digests: digests(
+            std::iter::once(report.metadata.image_id.as_str())
+                .chain(report.metadata.repo_digests.iter().map(String::as_str)),
+        ),
+        findings,
+    })
+}
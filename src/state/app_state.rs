@@ -1,12 +1,20 @@
 //! Application state management
 
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
 use chrono::Utc;
 
 use crate::core::{
     ConfirmAction, ConnectionInfo, ContainerSummary, ImageSummary, NetworkSummary,
-    NotificationLevel, Tab, VolumeSummary,
+    NotificationLevel, SortDirection, Tab, VolumeSummary,
 };
 use crate::docker::{PruneOptions, SystemDiskUsage};
+use crate::exec::copy_mode::CopyModeState;
+use crate::registry::{DigestCache, Freshness, ImageRef};
+use crate::scan::{ScanReport, ScanReports, Severity, SeverityCounts};
+use crate::state::image_tree::{self, ContainerFilter};
+use crate::state::restart_tracker::{ExitCheckBaseline, RestartTracker};
 
 /// Main application state
 #[derive(Debug, Clone)]
@@ -17,18 +25,39 @@ pub struct AppState {
     pub focused_panel: Panel,
 
     // Docker data
+    /// Containers shown in the list (after `container_filter`)
     pub containers: Vec<ContainerSummary>,
+    /// Every container, regardless of the filter
+    pub all_containers: Vec<ContainerSummary>,
+    pub container_filter: Option<ContainerFilter>,
     pub selected_container: Option<String>,
     pub container_list_selected: usize,
     pub images: Vec<ImageSummary>,
     pub selected_image: Option<String>,
     pub image_list_selected: usize,
+    /// Image IDs marked for multi-image actions (e.g. save)
+    pub marked_images: HashSet<String>,
+    /// Show images as a parent/child tree with their containers
+    pub image_tree_view: bool,
+    /// Registry digests of local tags, for flagging outdated images
+    pub remote_digests: DigestCache,
+    /// Imported vulnerability scanner reports
+    pub scan_reports: ScanReports,
     pub volumes: Vec<VolumeSummary>,
     pub selected_volume: Option<String>,
     pub volume_list_selected: usize,
+    /// Volume to select once a refresh lists it (e.g. just created)
+    pub pending_volume_select: Option<String>,
+    /// Volume names marked for batch removal
+    pub marked_volumes: HashSet<String>,
+    pub volume_sort: VolumeSortKey,
+    pub volume_sort_direction: SortDirection,
     pub networks: Vec<NetworkSummary>,
     pub selected_network: Option<String>,
     pub network_list_selected: usize,
+    /// Show networks as a graph of their containers
+    pub network_topology_view: bool,
+    pub restart_tracker: RestartTracker,
 
     // Connection
     pub docker_connected: bool,
@@ -58,13 +87,75 @@ pub struct AppState {
     // Image detail view state
     pub image_detail_view: Option<ImageDetailViewState>,
 
+    // Volume and network detail view state
+    pub volume_detail_view: Option<VolumeDetailViewState>,
+    pub network_detail_view: Option<NetworkDetailViewState>,
+
+    // Two-image comparison state
+    pub image_diff_view: Option<ImageDiffViewState>,
+
+    // Reconstructed Dockerfile viewer state
+    pub dockerfile_view: Option<DockerfileViewState>,
+
+    // Image cleanup planner state
+    pub cleanup_view: Option<CleanupViewState>,
+
+    // Volume file browser state
+    pub volume_browser: Option<VolumeBrowserState>,
+
+    // Image layer explorer state
+    pub layer_explorer: Option<LayerExplorerState>,
+
+    // Registry browser state
+    pub registry_browser: Option<RegistryBrowserState>,
+
+    // "Why did it die" panel state
+    pub death_report: Option<DeathReportState>,
+
+    // Generated `docker run` / compose viewer state
+    pub run_spec_view: Option<RunSpecViewState>,
+
+    // Image pull/push progress dialog state
+    pub transfer_view: Option<TransferViewState>,
+
+    // Image build output pane state
+    pub build_view: Option<BuildViewState>,
+
+    // Image to select once it shows up in the list (e.g. after a build)
+    pub pending_image_select: Option<String>,
+
     // Prune dialog state
     pub prune_dialog: Option<PruneDialogState>,
+    pub form_dialog: Option<FormDialogState>,
+
+    // Background jobs shown in the header
+    pub jobs: Vec<BackgroundJob>,
+    pub next_job_id: u64,
 
     // Async operations tracking
     pub loading: bool,
 }
 
+/// A long-running background operation (e.g. saving images)
+#[derive(Debug, Clone)]
+pub struct BackgroundJob {
+    pub id: u64,
+    pub label: String,
+    /// Bytes processed so far
+    pub done_bytes: u64,
+    /// Expected total, when known up front
+    pub total_bytes: Option<u64>,
+}
+
+impl BackgroundJob {
+    /// Completion percentage, when the total is known
+    pub fn percent(&self) -> Option<u64> {
+        self.total_bytes
+            .filter(|total| *total > 0)
+            .map(|total| (self.done_bytes.saturating_mul(100) / total).min(100))
+    }
+}
+
 /// Log level filter
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum LogLevelFilter {
@@ -74,6 +165,39 @@ pub enum LogLevelFilter {
     Info,
 }
 
+/// Column the Volumes tab is sorted by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VolumeSortKey {
+    Name,
+    Size,
+    RefCount,
+    Created,
+}
+
+impl VolumeSortKey {
+    /// The next column in the sort cycle
+    pub fn next(self) -> Self {
+        match self {
+            VolumeSortKey::Name => VolumeSortKey::Size,
+            VolumeSortKey::Size => VolumeSortKey::RefCount,
+            VolumeSortKey::RefCount => VolumeSortKey::Created,
+            VolumeSortKey::Created => VolumeSortKey::Name,
+        }
+    }
+
+    /// Order two volumes by this column (ascending). Volumes without usage
+    /// data sort before any size or count.
+    pub fn compare(self, a: &VolumeSummary, b: &VolumeSummary) -> std::cmp::Ordering {
+        let by_key = match self {
+            VolumeSortKey::Name => std::cmp::Ordering::Equal,
+            VolumeSortKey::Size => a.size.cmp(&b.size),
+            VolumeSortKey::RefCount => a.ref_count.cmp(&b.ref_count),
+            VolumeSortKey::Created => a.created_at.cmp(&b.created_at),
+        };
+        by_key.then_with(|| a.name.cmp(&b.name))
+    }
+}
+
 /// Log view state
 #[derive(Debug, Clone)]
 pub struct LogViewState {
@@ -113,6 +237,11 @@ pub struct ExecViewState {
     pub status: String,
     pub screen_lines: Vec<String>,
     pub cursor: Option<(u16, u16)>,
+    pub copy_mode: Option<CopyModeState>,
+    /// Session output is being written to a `.cast` file
+    pub recording: bool,
+    /// Pane shows a recording being replayed instead of a live session
+    pub playback: bool,
 }
 
 /// Detail view state
@@ -124,6 +253,141 @@ pub struct DetailViewState {
     pub scroll_offset: usize,
 }
 
+/// "Why did it die" panel state
+#[derive(Debug, Clone)]
+pub struct DeathReportState {
+    pub container_id: String,
+    pub container_name: String,
+    /// Last exit, `None` while loading
+    pub info: Option<crate::docker::ExitInfo>,
+    /// Restarts seen in the crash-loop window
+    pub recent_restarts: usize,
+    /// Log lines leading up to the exit
+    pub logs: Vec<crate::docker::LogEntry>,
+    pub scroll_offset: usize,
+}
+
+/// Generated `docker run` / compose viewer state
+#[derive(Debug, Clone)]
+pub struct RunSpecViewState {
+    pub container_name: String,
+    pub docker_run: String,
+    pub compose: String,
+    /// Show the compose snippet instead of the run command
+    pub show_compose: bool,
+    pub scroll_offset: usize,
+}
+
+impl RunSpecViewState {
+    /// Text of the format currently shown
+    pub fn current_text(&self) -> &str {
+        if self.show_compose {
+            &self.compose
+        } else {
+            &self.docker_run
+        }
+    }
+
+    /// Default file name for saving the format currently shown
+    pub fn default_file_name(&self) -> String {
+        let name = self
+            .container_name
+            .trim_start_matches('/')
+            .replace('/', "_");
+        if self.show_compose {
+            format!("{}.compose.yml", name)
+        } else {
+            format!("{}.run.sh", name)
+        }
+    }
+}
+
+/// Reconstructed Dockerfile viewer state
+#[derive(Debug, Clone)]
+pub struct DockerfileViewState {
+    pub image_name: String,
+    pub text: String,
+    pub scroll_offset: usize,
+}
+
+impl DockerfileViewState {
+    /// Default file name for saving
+    pub fn default_file_name(&self) -> String {
+        format!("{}.Dockerfile", self.image_name.replace(['/', ':'], "_"))
+    }
+}
+
+/// Image cleanup planner state: one plan per configured policy
+#[derive(Debug, Clone)]
+pub struct CleanupViewState {
+    pub plans: Arc<Vec<crate::docker::CleanupPlan>>,
+    /// Index of the policy shown
+    pub selected: usize,
+    pub scroll_offset: usize,
+}
+
+impl CleanupViewState {
+    pub fn current(&self) -> Option<&crate::docker::CleanupPlan> {
+        self.plans.get(self.selected)
+    }
+}
+
+/// Volume file browser state
+#[derive(Debug, Clone)]
+pub struct VolumeBrowserState {
+    pub volume: String,
+    /// Every entry, sorted by path; `None` while the volume is read
+    pub entries: Option<Arc<Vec<crate::docker::VolumeEntry>>>,
+    /// Directory shown, relative to the volume root (empty at the root)
+    pub cwd: String,
+    pub selected: usize,
+    /// File shown over the listing
+    pub preview: Option<crate::docker::FilePreview>,
+    pub preview_scroll: usize,
+}
+
+impl VolumeBrowserState {
+    /// Entries of the current directory, directories first
+    pub fn children(&self) -> Vec<&crate::docker::VolumeEntry> {
+        let mut children: Vec<_> = self
+            .entries
+            .iter()
+            .flat_map(|entries| entries.iter())
+            .filter(|entry| entry.parent() == self.cwd)
+            .collect();
+        children.sort_by_key(|entry| !entry.is_dir());
+        children
+    }
+
+    pub fn selected_entry(&self) -> Option<&crate::docker::VolumeEntry> {
+        self.children().get(self.selected).copied()
+    }
+}
+
+/// Image pull/push progress dialog state
+#[derive(Debug, Clone)]
+pub struct TransferViewState {
+    pub image: String,
+    /// Pushing rather than pulling
+    pub push: bool,
+    pub progress: crate::docker::TransferProgress,
+    /// Set once the transfer has finished, successfully or not
+    pub done: bool,
+    pub error: Option<String>,
+}
+
+/// Image build output pane state
+#[derive(Debug, Clone)]
+pub struct BuildViewState {
+    pub title: String,
+    pub lines: Vec<crate::docker::BuildLine>,
+    /// Lines scrolled back from the end; 0 follows new output
+    pub scroll_back: usize,
+    /// Set once the build has finished, successfully or not
+    pub done: bool,
+    pub error: Option<String>,
+}
+
 /// Image detail view state
 #[derive(Debug, Clone)]
 pub struct ImageDetailViewState {
@@ -131,6 +395,106 @@ pub struct ImageDetailViewState {
     pub image_name: String,
     pub details: Option<crate::docker::ImageDetails>,
     pub scroll_offset: usize,
+    /// Imported vulnerability report for the image
+    pub scan: Option<Arc<ScanReport>>,
+    /// Only show findings at or above this severity
+    pub finding_severity: Option<Severity>,
+    /// Only show findings for packages containing this text
+    pub finding_package: String,
+}
+
+/// Volume detail view state
+#[derive(Debug, Clone)]
+pub struct VolumeDetailViewState {
+    pub volume_name: String,
+    pub details: Option<crate::docker::VolumeDetails>,
+    pub scroll_offset: usize,
+}
+
+/// Network detail view state
+#[derive(Debug, Clone)]
+pub struct NetworkDetailViewState {
+    pub network_id: String,
+    pub network_name: String,
+    pub details: Option<crate::docker::NetworkDetails>,
+    pub scroll_offset: usize,
+}
+
+/// Two-image comparison state
+#[derive(Debug, Clone)]
+pub struct ImageDiffViewState {
+    /// Names of the older and newer image
+    pub left_name: String,
+    pub right_name: String,
+    /// Shared so the per-frame state clone stays cheap
+    pub diff: Option<Arc<crate::docker::ImageDiff>>,
+    pub scroll_offset: usize,
+}
+
+/// Image layer explorer state
+#[derive(Debug, Clone)]
+pub struct LayerExplorerState {
+    pub image_name: String,
+    /// Shared so the per-frame state clone stays cheap
+    pub analysis: Option<Arc<crate::docker::ImageAnalysis>>,
+    pub selected_layer: usize,
+    /// Scroll offset in the file tree or wasted-space list
+    pub scroll_offset: usize,
+    /// Show the wasted-space list instead of the layer's file tree
+    pub show_wasted: bool,
+}
+
+/// Level shown by the registry browser
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RegistryLevel {
+    Registries,
+    Repositories,
+    Tags,
+}
+
+/// Registry browser state (registries > repositories > tags)
+#[derive(Debug, Clone)]
+pub struct RegistryBrowserState {
+    /// Display names of the browsable registries
+    pub registries: Vec<String>,
+    pub level: RegistryLevel,
+    pub registry_selected: usize,
+    pub repositories: Vec<String>,
+    pub repository_selected: usize,
+    /// Repository whose tags are shown
+    pub repository: Option<String>,
+    pub tags: Vec<String>,
+    pub tag_selected: usize,
+    /// Resolved manifest and the tag it belongs to
+    pub manifest: Option<(String, crate::registry::ManifestInfo)>,
+    pub loading: bool,
+    pub error: Option<String>,
+}
+
+impl RegistryBrowserState {
+    /// Items and selection of the current level
+    pub fn current_list(&self) -> (&[String], usize) {
+        match self.level {
+            RegistryLevel::Registries => (&self.registries, self.registry_selected),
+            RegistryLevel::Repositories => (&self.repositories, self.repository_selected),
+            RegistryLevel::Tags => (&self.tags, self.tag_selected),
+        }
+    }
+
+    /// Selected item of the current level
+    pub fn selected_item(&self) -> Option<&str> {
+        let (items, selected) = self.current_list();
+        items.get(selected).map(String::as_str)
+    }
+
+    /// Manifest of the selected tag, once resolved
+    pub fn selected_manifest(&self) -> Option<&crate::registry::ManifestInfo> {
+        let tag = self.tags.get(self.tag_selected)?;
+        self.manifest
+            .as_ref()
+            .filter(|(t, _)| t == tag)
+            .map(|(_, m)| m)
+    }
 }
 
 /// Prune dialog state
@@ -163,6 +527,174 @@ impl Default for PruneDialogState {
     }
 }
 
+/// What a form dialog submits to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FormKind {
+    /// Replay an asciicast recording in the exec pane
+    PlayRecording,
+    /// Recreate the container with this ID
+    RecreateContainer(String),
+    /// Save the generated run command or compose snippet
+    SaveRunSpec,
+    /// Save the reconstructed Dockerfile
+    SaveDockerfile,
+    /// Load Trivy/Grype reports from a file or directory
+    ImportScanReports,
+    /// Filter the image detail view's findings by package
+    FilterFindings,
+    /// Pull an image
+    PullImage,
+    /// Build an image from a context directory
+    BuildImage,
+    /// Add a tag to the image with this ID
+    TagImage(String),
+    /// Remove a tag from the image with this ID
+    UntagImage(String),
+    /// Push a tag to its registry
+    PushImage,
+    /// Save these images to a tar archive
+    SaveImages(Vec<String>),
+    /// Browse a repository (typed by name) in the registry at this index
+    OpenRepository(usize),
+    /// Load images from a tar archive
+    LoadImages,
+    /// Create a volume
+    CreateVolume,
+    /// Copy this path out of the browsed volume
+    DownloadVolumeEntry(String),
+    /// Back up the volume with this name
+    BackupVolume(String),
+    /// Restore a volume backup
+    RestoreVolume,
+    /// Create a network
+    CreateNetwork,
+    /// Connect a container to a network
+    ConnectNetwork,
+    /// Disconnect a container from a network
+    DisconnectNetwork,
+}
+
+/// Input kind of a form field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormFieldKind {
+    Text,
+    Toggle,
+}
+
+/// A single form field
+#[derive(Debug, Clone)]
+pub struct FormField {
+    pub key: &'static str,
+    pub label: String,
+    pub value: String,
+    pub placeholder: String,
+    pub kind: FormFieldKind,
+}
+
+/// Generic form dialog state (text fields and toggles)
+#[derive(Debug, Clone)]
+pub struct FormDialogState {
+    pub kind: FormKind,
+    pub title: String,
+    pub fields: Vec<FormField>,
+    /// Index of the focused field
+    pub selected_index: usize,
+}
+
+impl FormDialogState {
+    pub fn new(kind: FormKind, title: impl Into<String>) -> Self {
+        Self {
+            kind,
+            title: title.into(),
+            fields: Vec::new(),
+            selected_index: 0,
+        }
+    }
+
+    /// Add a text field
+    pub fn with_text(
+        mut self,
+        key: &'static str,
+        label: impl Into<String>,
+        value: impl Into<String>,
+        placeholder: impl Into<String>,
+    ) -> Self {
+        self.fields.push(FormField {
+            key,
+            label: label.into(),
+            value: value.into(),
+            placeholder: placeholder.into(),
+            kind: FormFieldKind::Text,
+        });
+        self
+    }
+
+    /// Add an on/off field
+    pub fn with_toggle(mut self, key: &'static str, label: impl Into<String>, on: bool) -> Self {
+        self.fields.push(FormField {
+            key,
+            label: label.into(),
+            value: if on { "true" } else { "false" }.to_string(),
+            placeholder: String::new(),
+            kind: FormFieldKind::Toggle,
+        });
+        self
+    }
+
+    /// Trimmed value of a text field (empty if missing)
+    pub fn value(&self, key: &str) -> &str {
+        self.fields
+            .iter()
+            .find(|f| f.key == key)
+            .map(|f| f.value.trim())
+            .unwrap_or("")
+    }
+
+    /// State of a toggle field
+    pub fn toggle(&self, key: &str) -> bool {
+        self.value(key) == "true"
+    }
+
+    pub fn next_field(&mut self) {
+        if !self.fields.is_empty() {
+            self.selected_index = (self.selected_index + 1) % self.fields.len();
+        }
+    }
+
+    pub fn previous_field(&mut self) {
+        if !self.fields.is_empty() {
+            self.selected_index = (self.selected_index + self.fields.len() - 1) % self.fields.len();
+        }
+    }
+
+    /// Type a character into the focused field (space flips a toggle)
+    pub fn input_char(&mut self, c: char) {
+        if let Some(field) = self.fields.get_mut(self.selected_index) {
+            match field.kind {
+                FormFieldKind::Text => field.value.push(c),
+                FormFieldKind::Toggle if c == ' ' => {
+                    field.value = if field.value == "true" {
+                        "false"
+                    } else {
+                        "true"
+                    }
+                    .to_string();
+                }
+                FormFieldKind::Toggle => {}
+            }
+        }
+    }
+
+    /// Delete the last character of the focused text field
+    pub fn backspace(&mut self) {
+        if let Some(field) = self.fields.get_mut(self.selected_index) {
+            if field.kind == FormFieldKind::Text {
+                field.value.pop();
+            }
+        }
+    }
+}
+
 /// Panel focus areas
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum Panel {
@@ -187,17 +719,29 @@ impl AppState {
             previous_tab: None,
             focused_panel: Panel::Sidebar,
             containers: vec![],
+            all_containers: vec![],
+            container_filter: None,
             selected_container: None,
             container_list_selected: 0,
             images: vec![],
             selected_image: None,
             image_list_selected: 0,
+            marked_images: HashSet::new(),
+            image_tree_view: false,
+            remote_digests: DigestCache::default(),
+            scan_reports: ScanReports::default(),
             volumes: vec![],
             selected_volume: None,
             volume_list_selected: 0,
+            pending_volume_select: None,
+            marked_volumes: HashSet::new(),
+            volume_sort: VolumeSortKey::Name,
+            volume_sort_direction: SortDirection::Ascending,
             networks: vec![],
             selected_network: None,
             network_list_selected: 0,
+            network_topology_view: false,
+            restart_tracker: RestartTracker::default(),
             docker_connected: false,
             connection_info: ConnectionInfo::default(),
             disk_usage: None,
@@ -209,8 +753,24 @@ impl AppState {
             exec_view: None,
             detail_view: None,
             image_detail_view: None,
+            volume_detail_view: None,
+            network_detail_view: None,
+            image_diff_view: None,
+            dockerfile_view: None,
+            cleanup_view: None,
+            volume_browser: None,
+            layer_explorer: None,
+            registry_browser: None,
+            death_report: None,
+            run_spec_view: None,
+            transfer_view: None,
+            build_view: None,
+            pending_image_select: None,
             prune_dialog: None,
+            form_dialog: None,
             confirm_dialog: None,
+            jobs: vec![],
+            next_job_id: 0,
             loading: false,
         }
     }
@@ -240,6 +800,25 @@ impl AppState {
     /// Update images list
     pub fn update_images(&mut self, images: Vec<ImageSummary>) {
         self.images = images;
+        if self.image_tree_view {
+            self.images = image_tree::tree_order(&self.images)
+                .into_iter()
+                .map(|(index, _)| self.images[index].clone())
+                .collect();
+        }
+        let images = &self.images;
+        self.marked_images
+            .retain(|id| images.iter().any(|i| &i.id == id));
+        if let Some(pending) = &self.pending_image_select {
+            if let Some(index) = self
+                .images
+                .iter()
+                .position(|i| &i.id == pending || i.repo_tags.contains(pending))
+            {
+                self.image_list_selected = index;
+                self.pending_image_select = None;
+            }
+        }
         // Adjust selection if needed
         if !self.images.is_empty() {
             if self.image_list_selected >= self.images.len() {
@@ -252,6 +831,56 @@ impl AppState {
         }
     }
 
+    /// Switch the Images tab between list and tree view, keeping the
+    /// selected image
+    pub fn toggle_image_tree(&mut self) {
+        self.image_tree_view = !self.image_tree_view;
+        let mut images = std::mem::take(&mut self.images);
+        if !self.image_tree_view {
+            // The daemon lists newest first
+            images.sort_by_key(|image| std::cmp::Reverse(image.created));
+        }
+        if let Some(selected) = self.selected_image.clone() {
+            self.pending_image_select = Some(selected);
+        }
+        self.update_images(images);
+    }
+
+    /// Containers and child images that keep an image from being removed
+    pub fn image_dependents(&self, id: &str) -> (Vec<String>, Vec<String>) {
+        let containers = self
+            .all_containers
+            .iter()
+            .filter(|c| c.image_id == id)
+            .map(image_tree::container_name)
+            .collect();
+        let children = self
+            .images
+            .iter()
+            .filter(|i| i.parent_id == id)
+            .map(image_tree::image_name)
+            .collect();
+        (containers, children)
+    }
+
+    /// Show only the containers of an image (and images built on it), or
+    /// every container again
+    pub fn set_container_filter(&mut self, filter: Option<ContainerFilter>) {
+        self.container_filter = filter;
+        self.container_list_selected = 0;
+        let containers = std::mem::take(&mut self.all_containers);
+        self.update_containers(containers);
+    }
+
+    /// Toggle the mark on the selected image
+    pub fn toggle_image_mark(&mut self) {
+        if let Some(id) = &self.selected_image {
+            if !self.marked_images.remove(id) {
+                self.marked_images.insert(id.clone());
+            }
+        }
+    }
+
     /// Navigate to next image in list
     pub fn next_image(&mut self) {
         if self.images.is_empty() {
@@ -276,7 +905,15 @@ impl AppState {
 
     /// Update containers list
     pub fn update_containers(&mut self, containers: Vec<ContainerSummary>) {
-        self.containers = containers;
+        self.containers = match &self.container_filter {
+            Some(filter) => containers
+                .iter()
+                .filter(|c| filter.matches(c))
+                .cloned()
+                .collect(),
+            None => containers.clone(),
+        };
+        self.all_containers = containers;
         // Adjust selection if needed
         if !self.containers.is_empty() {
             if self.container_list_selected >= self.containers.len() {
@@ -290,6 +927,31 @@ impl AppState {
         }
     }
 
+    /// Record restart and exit information from a refresh. Listed
+    /// containers that weren't re-inspected keep their last information.
+    pub fn update_exit_infos(&mut self, mut infos: Vec<crate::docker::ExitInfo>) {
+        let inspected: HashSet<String> = infos.iter().map(|i| i.container_id.clone()).collect();
+        for container in &self.all_containers {
+            if inspected.contains(&container.id) {
+                continue;
+            }
+            if let Some(history) = self.restart_tracker.get(&container.id) {
+                infos.push(history.info.clone());
+            }
+        }
+        self.restart_tracker.observe(infos, Utc::now());
+    }
+
+    /// State and status of the containers whose exit information is tracked,
+    /// so the next refresh only inspects the ones that changed
+    pub fn exit_check_baseline(&self) -> ExitCheckBaseline {
+        self.all_containers
+            .iter()
+            .filter(|c| self.restart_tracker.get(&c.id).is_some())
+            .map(|c| (c.id.clone(), (c.state, c.status.clone())))
+            .collect()
+    }
+
     /// Navigate to next container in list
     pub fn next_container(&mut self) {
         if self.containers.is_empty() {
@@ -312,9 +974,34 @@ impl AppState {
         self.selected_container = Some(self.containers[self.container_list_selected].id.clone());
     }
 
-    /// Update volumes list
-    pub fn update_volumes(&mut self, volumes: Vec<VolumeSummary>) {
+    /// Update volumes list, filling in sizes from the last disk usage and
+    /// keeping the selected volume selected
+    pub fn update_volumes(&mut self, mut volumes: Vec<VolumeSummary>) {
+        if let Some(usage) = &self.disk_usage {
+            for volume in &mut volumes {
+                if let Some(u) = usage.volume_usage.get(&volume.name) {
+                    volume.size = Some(u.size);
+                    volume.ref_count = Some(u.ref_count);
+                }
+            }
+        }
+        let (key, direction) = (self.volume_sort, self.volume_sort_direction);
+        volumes.sort_by(|a, b| match direction {
+            SortDirection::Ascending => key.compare(a, b),
+            SortDirection::Descending => key.compare(b, a),
+        });
         self.volumes = volumes;
+        let volumes = &self.volumes;
+        self.marked_volumes
+            .retain(|name| volumes.iter().any(|v| &v.name == name));
+
+        let position = |name: &String| self.volumes.iter().position(|v| &v.name == name);
+        if let Some(index) = self.pending_volume_select.as_ref().and_then(position) {
+            self.volume_list_selected = index;
+            self.pending_volume_select = None;
+        } else if let Some(index) = self.selected_volume.as_ref().and_then(position) {
+            self.volume_list_selected = index;
+        }
         // Adjust selection if needed
         if !self.volumes.is_empty() {
             if self.volume_list_selected >= self.volumes.len() {
@@ -327,6 +1014,51 @@ impl AppState {
         }
     }
 
+    /// Sort the volumes by the next column
+    pub fn cycle_volume_sort(&mut self) {
+        self.volume_sort = self.volume_sort.next();
+        // Biggest and most used first is what one sorts by size for
+        self.volume_sort_direction = match self.volume_sort {
+            VolumeSortKey::Size | VolumeSortKey::RefCount => SortDirection::Descending,
+            _ => SortDirection::Ascending,
+        };
+        let volumes = std::mem::take(&mut self.volumes);
+        self.update_volumes(volumes);
+    }
+
+    /// Reverse the volume sort order
+    pub fn reverse_volume_sort(&mut self) {
+        self.volume_sort_direction = self.volume_sort_direction.toggle();
+        let volumes = std::mem::take(&mut self.volumes);
+        self.update_volumes(volumes);
+    }
+
+    /// Toggle the mark on the selected volume
+    pub fn toggle_volume_mark(&mut self) {
+        if let Some(name) = &self.selected_volume {
+            if !self.marked_volumes.remove(name) {
+                self.marked_volumes.insert(name.clone());
+            }
+        }
+    }
+
+    /// Mark every orphaned volume, or clear the marks if they all are
+    /// marked already. Returns how many orphans there are.
+    pub fn mark_orphan_volumes(&mut self) -> usize {
+        let orphans: HashSet<String> = self
+            .volumes
+            .iter()
+            .filter(|v| v.is_orphan())
+            .map(|v| v.name.clone())
+            .collect();
+        if !orphans.is_empty() && orphans.is_subset(&self.marked_volumes) {
+            self.marked_volumes.clear();
+        } else {
+            self.marked_volumes.extend(orphans.iter().cloned());
+        }
+        orphans.len()
+    }
+
     /// Navigate to next volume in list
     pub fn next_volume(&mut self) {
         if self.volumes.is_empty() {
@@ -367,6 +1099,8 @@ impl AppState {
     /// Update disk usage information
     pub fn update_disk_usage(&mut self, disk_usage: SystemDiskUsage) {
         self.disk_usage = Some(disk_usage);
+        let volumes = std::mem::take(&mut self.volumes);
+        self.update_volumes(volumes);
     }
 
     /// Navigate to next network in list
@@ -678,6 +1412,9 @@ impl AppState {
             status: "Starting".to_string(),
             screen_lines: vec![],
             cursor: None,
+            copy_mode: None,
+            recording: false,
+            playback: false,
         });
         // Avoid stacking bottom panels
         self.stats_view = None;
@@ -719,6 +1456,36 @@ impl AppState {
         }
     }
 
+    /// Open the exec pane as a player for a recording
+    pub fn open_exec_playback(&mut self, title: String) {
+        self.open_exec_view(String::new(), title);
+        if let Some(exec_view) = &mut self.exec_view {
+            exec_view.playback = true;
+            exec_view.status = "Playing".to_string();
+        }
+    }
+
+    /// Mark whether the exec session is being recorded
+    pub fn set_exec_recording(&mut self, recording: bool) {
+        if let Some(exec_view) = &mut self.exec_view {
+            exec_view.recording = recording;
+        }
+    }
+
+    /// Enter exec copy mode over a snapshot of the scrollback
+    pub fn enter_exec_copy_mode(&mut self, lines: Vec<String>, view_height: usize) {
+        if let Some(exec_view) = &mut self.exec_view {
+            exec_view.copy_mode = Some(CopyModeState::new(lines, view_height));
+        }
+    }
+
+    /// Leave exec copy mode
+    pub fn exit_exec_copy_mode(&mut self) {
+        if let Some(exec_view) = &mut self.exec_view {
+            exec_view.copy_mode = None;
+        }
+    }
+
     // ==================== Detail View Methods ====================
 
     /// Open detail view for a container
@@ -757,15 +1524,120 @@ impl AppState {
         }
     }
 
+    // ==================== Death Report Methods ====================
+
+    /// Open the "why did it die" panel for a container
+    pub fn open_death_report(&mut self, container_id: String, container_name: String) {
+        self.death_report = Some(DeathReportState {
+            container_id,
+            container_name,
+            info: None,
+            recent_restarts: 0,
+            logs: vec![],
+            scroll_offset: 0,
+        });
+    }
+
+    /// Close the "why did it die" panel
+    pub fn close_death_report(&mut self) {
+        self.death_report = None;
+    }
+
+    /// Fill the panel with the last exit and the logs before it
+    pub fn set_death_report_content(
+        &mut self,
+        info: crate::docker::ExitInfo,
+        logs: Vec<crate::docker::LogEntry>,
+    ) {
+        let recent_restarts = self
+            .restart_tracker
+            .get(&info.container_id)
+            .map(|h| h.recent_restarts(Utc::now()))
+            .unwrap_or(0);
+        if let Some(report) = &mut self.death_report {
+            report.info = Some(info);
+            report.recent_restarts = recent_restarts;
+            report.logs = logs;
+        }
+    }
+
+    /// Scroll up in the death report
+    pub fn scroll_death_report_up(&mut self, amount: usize) {
+        if let Some(report) = &mut self.death_report {
+            report.scroll_offset = report.scroll_offset.saturating_sub(amount);
+        }
+    }
+
+    /// Scroll down in the death report
+    pub fn scroll_death_report_down(&mut self, amount: usize) {
+        if let Some(report) = &mut self.death_report {
+            report.scroll_offset += amount;
+        }
+    }
+
+    // ==================== Run Spec View Methods ====================
+
+    /// Open the generated `docker run` / compose viewer
+    pub fn open_run_spec_view(
+        &mut self,
+        container_name: String,
+        docker_run: String,
+        compose: String,
+    ) {
+        self.run_spec_view = Some(RunSpecViewState {
+            container_name,
+            docker_run,
+            compose,
+            show_compose: false,
+            scroll_offset: 0,
+        });
+    }
+
+    /// Close the run spec viewer
+    pub fn close_run_spec_view(&mut self) {
+        self.run_spec_view = None;
+    }
+
+    /// Switch between the run command and the compose snippet
+    pub fn toggle_run_spec_format(&mut self) {
+        if let Some(view) = &mut self.run_spec_view {
+            view.show_compose = !view.show_compose;
+            view.scroll_offset = 0;
+        }
+    }
+
+    /// Scroll up in the run spec viewer
+    pub fn scroll_run_spec_up(&mut self, amount: usize) {
+        if let Some(view) = &mut self.run_spec_view {
+            view.scroll_offset = view.scroll_offset.saturating_sub(amount);
+        }
+    }
+
+    /// Scroll down in the run spec viewer
+    pub fn scroll_run_spec_down(&mut self, amount: usize) {
+        if let Some(view) = &mut self.run_spec_view {
+            view.scroll_offset += amount;
+        }
+    }
+
     // ==================== Image Detail View Methods ====================
 
     /// Open image detail view
     pub fn open_image_detail_view(&mut self, image_id: String, image_name: String) {
+        let scan = self
+            .images
+            .iter()
+            .find(|i| i.id == image_id)
+            .and_then(|image| self.scan_reports.for_image(image))
+            .cloned();
         self.image_detail_view = Some(ImageDetailViewState {
             image_id,
             image_name,
             details: None,
             scroll_offset: 0,
+            scan,
+            finding_severity: None,
+            finding_package: String::new(),
         });
     }
 
@@ -796,20 +1668,524 @@ impl AppState {
         }
     }
 
-    /// Open prune dialog
-    pub fn open_prune_dialog(&mut self) {
-        self.prune_dialog = Some(PruneDialogState::default());
+    /// Show only findings at or above the next severity (all, critical,
+    /// high, medium, low, then all again)
+    pub fn cycle_finding_severity(&mut self) {
+        if let Some(view) = &mut self.image_detail_view {
+            view.finding_severity = match view.finding_severity {
+                None => Some(Severity::Critical),
+                Some(Severity::Critical) => Some(Severity::High),
+                Some(Severity::High) => Some(Severity::Medium),
+                Some(Severity::Medium) => Some(Severity::Low),
+                Some(_) => None,
+            };
+        }
     }
 
-    /// Close prune dialog
-    pub fn close_prune_dialog(&mut self) {
-        self.prune_dialog = None;
+    /// Show only findings for packages containing `package`
+    pub fn set_finding_package(&mut self, package: String) {
+        if let Some(view) = &mut self.image_detail_view {
+            view.finding_package = package;
+        }
     }
 
-    /// Navigate up in prune dialog
-    pub fn prune_dialog_prev(&mut self) {
-        if let Some(dialog) = &mut self.prune_dialog {
-            if dialog.selected_index > 0 {
+    // ==================== Volume and Network Detail Methods ====================
+
+    /// Open the volume detail view while the volume is inspected
+    pub fn open_volume_detail_view(&mut self, volume_name: String) {
+        self.volume_detail_view = Some(VolumeDetailViewState {
+            volume_name,
+            details: None,
+            scroll_offset: 0,
+        });
+    }
+
+    /// Close volume detail view
+    pub fn close_volume_detail_view(&mut self) {
+        self.volume_detail_view = None;
+    }
+
+    /// Set volume detail view content
+    pub fn set_volume_detail_view_content(&mut self, details: crate::docker::VolumeDetails) {
+        if let Some(view) = &mut self.volume_detail_view {
+            view.details = Some(details);
+        }
+    }
+
+    /// Scroll up in volume detail view
+    pub fn scroll_volume_detail_view_up(&mut self, amount: usize) {
+        if let Some(view) = &mut self.volume_detail_view {
+            view.scroll_offset = view.scroll_offset.saturating_sub(amount);
+        }
+    }
+
+    /// Scroll down in volume detail view
+    pub fn scroll_volume_detail_view_down(&mut self, amount: usize) {
+        if let Some(view) = &mut self.volume_detail_view {
+            view.scroll_offset += amount;
+        }
+    }
+
+    /// Open the network detail view while the network is inspected
+    pub fn open_network_detail_view(&mut self, network_id: String, network_name: String) {
+        self.network_detail_view = Some(NetworkDetailViewState {
+            network_id,
+            network_name,
+            details: None,
+            scroll_offset: 0,
+        });
+    }
+
+    /// Close network detail view
+    pub fn close_network_detail_view(&mut self) {
+        self.network_detail_view = None;
+    }
+
+    /// Set network detail view content
+    pub fn set_network_detail_view_content(&mut self, details: crate::docker::NetworkDetails) {
+        if let Some(view) = &mut self.network_detail_view {
+            view.details = Some(details);
+        }
+    }
+
+    /// Scroll up in network detail view
+    pub fn scroll_network_detail_view_up(&mut self, amount: usize) {
+        if let Some(view) = &mut self.network_detail_view {
+            view.scroll_offset = view.scroll_offset.saturating_sub(amount);
+        }
+    }
+
+    /// Scroll down in network detail view
+    pub fn scroll_network_detail_view_down(&mut self, amount: usize) {
+        if let Some(view) = &mut self.network_detail_view {
+            view.scroll_offset += amount;
+        }
+    }
+
+    // ==================== Image Diff Methods ====================
+
+    /// Open the comparison while both images are inspected
+    pub fn open_image_diff(&mut self, left_name: String, right_name: String) {
+        self.image_diff_view = Some(ImageDiffViewState {
+            left_name,
+            right_name,
+            diff: None,
+            scroll_offset: 0,
+        });
+    }
+
+    /// Set the finished comparison
+    pub fn set_image_diff(&mut self, diff: crate::docker::ImageDiff) {
+        if let Some(view) = &mut self.image_diff_view {
+            view.diff = Some(Arc::new(diff));
+        }
+    }
+
+    /// Close the comparison
+    pub fn close_image_diff(&mut self) {
+        self.image_diff_view = None;
+    }
+
+    /// Scroll up in the comparison
+    pub fn scroll_image_diff_up(&mut self, amount: usize) {
+        if let Some(view) = &mut self.image_diff_view {
+            view.scroll_offset = view.scroll_offset.saturating_sub(amount);
+        }
+    }
+
+    /// Scroll down in the comparison
+    pub fn scroll_image_diff_down(&mut self, amount: usize) {
+        if let Some(view) = &mut self.image_diff_view {
+            view.scroll_offset = view.scroll_offset.saturating_add(amount);
+        }
+    }
+
+    // ==================== Dockerfile View Methods ====================
+
+    /// Open the reconstructed Dockerfile viewer
+    pub fn open_dockerfile_view(&mut self, image_name: String, text: String) {
+        self.dockerfile_view = Some(DockerfileViewState {
+            image_name,
+            text,
+            scroll_offset: 0,
+        });
+    }
+
+    /// Close the Dockerfile viewer
+    pub fn close_dockerfile_view(&mut self) {
+        self.dockerfile_view = None;
+    }
+
+    /// Scroll up in the Dockerfile viewer
+    pub fn scroll_dockerfile_up(&mut self, amount: usize) {
+        if let Some(view) = &mut self.dockerfile_view {
+            view.scroll_offset = view.scroll_offset.saturating_sub(amount);
+        }
+    }
+
+    /// Scroll down in the Dockerfile viewer
+    pub fn scroll_dockerfile_down(&mut self, amount: usize) {
+        if let Some(view) = &mut self.dockerfile_view {
+            view.scroll_offset = view.scroll_offset.saturating_add(amount);
+        }
+    }
+
+    // ==================== Cleanup Planner Methods ====================
+
+    /// Show the plans, keeping the selected policy when re-planning
+    pub fn open_cleanup_view(&mut self, plans: Vec<crate::docker::CleanupPlan>) {
+        let selected = self
+            .cleanup_view
+            .as_ref()
+            .map_or(0, |view| view.selected)
+            .min(plans.len().saturating_sub(1));
+        self.cleanup_view = Some(CleanupViewState {
+            plans: Arc::new(plans),
+            selected,
+            scroll_offset: 0,
+        });
+    }
+
+    /// Close the cleanup planner
+    pub fn close_cleanup_view(&mut self) {
+        self.cleanup_view = None;
+    }
+
+    /// Show the next (or previous) policy's plan
+    pub fn cycle_cleanup_policy(&mut self, forward: bool) {
+        if let Some(view) = &mut self.cleanup_view {
+            let count = view.plans.len().max(1);
+            view.selected = if forward {
+                (view.selected + 1) % count
+            } else {
+                (view.selected + count - 1) % count
+            };
+            view.scroll_offset = 0;
+        }
+    }
+
+    /// Scroll up in the cleanup planner
+    pub fn scroll_cleanup_up(&mut self, amount: usize) {
+        if let Some(view) = &mut self.cleanup_view {
+            view.scroll_offset = view.scroll_offset.saturating_sub(amount);
+        }
+    }
+
+    /// Scroll down in the cleanup planner
+    pub fn scroll_cleanup_down(&mut self, amount: usize) {
+        if let Some(view) = &mut self.cleanup_view {
+            view.scroll_offset = view.scroll_offset.saturating_add(amount);
+        }
+    }
+
+    // ==================== Volume Browser Methods ====================
+
+    /// Open the volume browser while the volume is read
+    pub fn open_volume_browser(&mut self, volume: String) {
+        self.volume_browser = Some(VolumeBrowserState {
+            volume,
+            entries: None,
+            cwd: String::new(),
+            selected: 0,
+            preview: None,
+            preview_scroll: 0,
+        });
+    }
+
+    /// Show the volume's entries
+    pub fn set_volume_entries(&mut self, entries: Vec<crate::docker::VolumeEntry>) {
+        if let Some(browser) = &mut self.volume_browser {
+            browser.entries = Some(Arc::new(entries));
+        }
+    }
+
+    /// Close the volume browser
+    pub fn close_volume_browser(&mut self) {
+        self.volume_browser = None;
+    }
+
+    /// Select the next entry of the current directory
+    pub fn next_volume_entry(&mut self) {
+        if let Some(browser) = &mut self.volume_browser {
+            let count = browser.children().len();
+            if count > 0 {
+                browser.selected = (browser.selected + 1) % count;
+            }
+        }
+    }
+
+    /// Select the previous entry of the current directory
+    pub fn previous_volume_entry(&mut self) {
+        if let Some(browser) = &mut self.volume_browser {
+            let count = browser.children().len();
+            if count > 0 {
+                browser.selected = (browser.selected + count - 1) % count;
+            }
+        }
+    }
+
+    /// Open the selected directory
+    pub fn enter_volume_dir(&mut self) {
+        if let Some(browser) = &mut self.volume_browser {
+            if let Some(path) = browser
+                .selected_entry()
+                .filter(|entry| entry.is_dir())
+                .map(|entry| entry.path.clone())
+            {
+                browser.cwd = path;
+                browser.selected = 0;
+            }
+        }
+    }
+
+    /// Go to the parent directory, selecting the one just left
+    pub fn leave_volume_dir(&mut self) {
+        if let Some(browser) = &mut self.volume_browser {
+            if browser.cwd.is_empty() {
+                return;
+            }
+            let left = std::mem::take(&mut browser.cwd);
+            browser.cwd = left
+                .rsplit_once('/')
+                .map_or(String::new(), |(parent, _)| parent.to_string());
+            browser.selected = browser
+                .children()
+                .iter()
+                .position(|entry| entry.path == left)
+                .unwrap_or(0);
+        }
+    }
+
+    /// Show a file preview over the listing
+    pub fn set_volume_preview(&mut self, preview: crate::docker::FilePreview) {
+        if let Some(browser) = &mut self.volume_browser {
+            browser.preview = Some(preview);
+            browser.preview_scroll = 0;
+        }
+    }
+
+    /// Close the file preview
+    pub fn close_volume_preview(&mut self) {
+        if let Some(browser) = &mut self.volume_browser {
+            browser.preview = None;
+        }
+    }
+
+    /// Scroll up in the file preview
+    pub fn scroll_volume_preview_up(&mut self, amount: usize) {
+        if let Some(browser) = &mut self.volume_browser {
+            browser.preview_scroll = browser.preview_scroll.saturating_sub(amount);
+        }
+    }
+
+    /// Scroll down in the file preview
+    pub fn scroll_volume_preview_down(&mut self, amount: usize) {
+        if let Some(browser) = &mut self.volume_browser {
+            browser.preview_scroll = browser.preview_scroll.saturating_add(amount);
+        }
+    }
+
+    // ==================== Layer Explorer Methods ====================
+
+    /// Open the layer explorer while the image is exported and analyzed
+    pub fn open_layer_explorer(&mut self, image_name: String) {
+        self.layer_explorer = Some(LayerExplorerState {
+            image_name,
+            analysis: None,
+            selected_layer: 0,
+            scroll_offset: 0,
+            show_wasted: false,
+        });
+    }
+
+    /// Set the finished analysis
+    pub fn set_layer_analysis(&mut self, analysis: crate::docker::ImageAnalysis) {
+        if let Some(explorer) = &mut self.layer_explorer {
+            explorer.analysis = Some(Arc::new(analysis));
+        }
+    }
+
+    /// Close the layer explorer
+    pub fn close_layer_explorer(&mut self) {
+        self.layer_explorer = None;
+    }
+
+    /// Select the next layer
+    pub fn next_layer(&mut self) {
+        if let Some(explorer) = &mut self.layer_explorer {
+            let count = explorer.analysis.as_ref().map_or(0, |a| a.layers.len());
+            if explorer.selected_layer + 1 < count {
+                explorer.selected_layer += 1;
+                explorer.scroll_offset = 0;
+            }
+        }
+    }
+
+    /// Select the previous layer
+    pub fn previous_layer(&mut self) {
+        if let Some(explorer) = &mut self.layer_explorer {
+            if explorer.selected_layer > 0 {
+                explorer.selected_layer -= 1;
+                explorer.scroll_offset = 0;
+            }
+        }
+    }
+
+    /// Switch between the layer's file tree and the wasted-space list
+    pub fn toggle_layer_wasted(&mut self) {
+        if let Some(explorer) = &mut self.layer_explorer {
+            explorer.show_wasted = !explorer.show_wasted;
+            explorer.scroll_offset = 0;
+        }
+    }
+
+    /// Scroll up in the layer explorer
+    pub fn scroll_layer_explorer_up(&mut self, amount: usize) {
+        if let Some(explorer) = &mut self.layer_explorer {
+            explorer.scroll_offset = explorer.scroll_offset.saturating_sub(amount);
+        }
+    }
+
+    /// Scroll down in the layer explorer
+    pub fn scroll_layer_explorer_down(&mut self, amount: usize) {
+        if let Some(explorer) = &mut self.layer_explorer {
+            explorer.scroll_offset = explorer.scroll_offset.saturating_add(amount);
+        }
+    }
+
+    // ==================== Registry Browser Methods ====================
+
+    /// Open the registry browser on the list of registries
+    pub fn open_registry_browser(&mut self, registries: Vec<String>) {
+        self.registry_browser = Some(RegistryBrowserState {
+            registries,
+            level: RegistryLevel::Registries,
+            registry_selected: 0,
+            repositories: Vec::new(),
+            repository_selected: 0,
+            repository: None,
+            tags: Vec::new(),
+            tag_selected: 0,
+            manifest: None,
+            loading: false,
+            error: None,
+        });
+    }
+
+    /// Close the registry browser
+    pub fn close_registry_browser(&mut self) {
+        self.registry_browser = None;
+    }
+
+    /// Move the selection in the current level
+    pub fn registry_browser_move(&mut self, down: bool) {
+        if let Some(browser) = &mut self.registry_browser {
+            let (items, _) = browser.current_list();
+            let len = items.len();
+            let selected = match browser.level {
+                RegistryLevel::Registries => &mut browser.registry_selected,
+                RegistryLevel::Repositories => &mut browser.repository_selected,
+                RegistryLevel::Tags => &mut browser.tag_selected,
+            };
+            if down && *selected + 1 < len {
+                *selected += 1;
+            } else if !down && *selected > 0 {
+                *selected -= 1;
+            }
+        }
+    }
+
+    /// Go down a level and wait for its items to load
+    pub fn registry_browser_enter(&mut self, level: RegistryLevel, repository: Option<String>) {
+        if let Some(browser) = &mut self.registry_browser {
+            match level {
+                RegistryLevel::Registries => {}
+                RegistryLevel::Repositories => {
+                    browser.repositories.clear();
+                    browser.repository_selected = 0;
+                }
+                RegistryLevel::Tags => {
+                    browser.tags.clear();
+                    browser.tag_selected = 0;
+                    browser.manifest = None;
+                    browser.repository = repository;
+                }
+            }
+            browser.level = level;
+            browser.loading = true;
+            browser.error = None;
+        }
+    }
+
+    /// Go up a level; returns false when already at the top
+    pub fn registry_browser_back(&mut self) -> bool {
+        let Some(browser) = &mut self.registry_browser else {
+            return false;
+        };
+        browser.loading = false;
+        browser.error = None;
+        browser.level = match browser.level {
+            RegistryLevel::Registries => return false,
+            RegistryLevel::Repositories => RegistryLevel::Registries,
+            RegistryLevel::Tags => RegistryLevel::Repositories,
+        };
+        true
+    }
+
+    /// Mark a registry request as running (e.g. resolving a manifest)
+    pub fn set_registry_loading(&mut self) {
+        if let Some(browser) = &mut self.registry_browser {
+            browser.loading = true;
+            browser.error = None;
+        }
+    }
+
+    /// Record a failed registry request
+    pub fn set_registry_error(&mut self, error: String) {
+        if let Some(browser) = &mut self.registry_browser {
+            browser.loading = false;
+            browser.error = Some(error);
+        }
+    }
+
+    /// Set the repositories of the selected registry
+    pub fn set_registry_repositories(&mut self, repositories: Vec<String>) {
+        if let Some(browser) = &mut self.registry_browser {
+            browser.loading = false;
+            browser.repositories = repositories;
+        }
+    }
+
+    /// Set the tags of the open repository
+    pub fn set_registry_tags(&mut self, tags: Vec<String>) {
+        if let Some(browser) = &mut self.registry_browser {
+            browser.loading = false;
+            browser.tags = tags;
+        }
+    }
+
+    /// Set the resolved manifest of a tag
+    pub fn set_registry_manifest(&mut self, tag: String, manifest: crate::registry::ManifestInfo) {
+        if let Some(browser) = &mut self.registry_browser {
+            browser.loading = false;
+            browser.manifest = Some((tag, manifest));
+        }
+    }
+
+    /// Open prune dialog
+    pub fn open_prune_dialog(&mut self) {
+        self.prune_dialog = Some(PruneDialogState::default());
+    }
+
+    /// Close prune dialog
+    pub fn close_prune_dialog(&mut self) {
+        self.prune_dialog = None;
+    }
+
+    /// Navigate up in prune dialog
+    pub fn prune_dialog_prev(&mut self) {
+        if let Some(dialog) = &mut self.prune_dialog {
+            if dialog.selected_index > 0 {
                 dialog.selected_index -= 1;
             }
         }
@@ -856,6 +2232,245 @@ impl AppState {
             build_cache: dialog.build_cache,
         })
     }
+
+    // ==================== Transfer View Methods ====================
+
+    /// Open the pull or push progress dialog for an image
+    pub fn open_transfer_view(&mut self, image: String, push: bool) {
+        self.transfer_view = Some(TransferViewState {
+            image,
+            push,
+            progress: Default::default(),
+            done: false,
+            error: None,
+        });
+    }
+
+    /// Mark the transfer as finished, with an error message on failure
+    pub fn finish_transfer(&mut self, error: Option<String>) {
+        if let Some(view) = &mut self.transfer_view {
+            view.done = true;
+            view.error = error;
+        }
+    }
+
+    /// Close the pull/push progress dialog
+    pub fn close_transfer_view(&mut self) {
+        self.transfer_view = None;
+    }
+
+    // ==================== Build View Methods ====================
+
+    /// Open the build output pane
+    pub fn open_build_view(&mut self, title: String) {
+        self.build_view = Some(BuildViewState {
+            title,
+            lines: Vec::new(),
+            scroll_back: 0,
+            done: false,
+            error: None,
+        });
+    }
+
+    /// Append build output, keeping the view still when scrolled back
+    pub fn push_build_lines(&mut self, lines: Vec<crate::docker::BuildLine>) {
+        if let Some(view) = &mut self.build_view {
+            if view.scroll_back > 0 {
+                view.scroll_back += lines.len();
+            }
+            view.lines.extend(lines);
+        }
+    }
+
+    /// Mark the build as finished, with an error message on failure
+    pub fn finish_build(&mut self, error: Option<String>) {
+        if let Some(view) = &mut self.build_view {
+            view.done = true;
+            view.error = error;
+        }
+    }
+
+    /// Close the build output pane
+    pub fn close_build_view(&mut self) {
+        self.build_view = None;
+    }
+
+    /// Scroll back through build output
+    pub fn scroll_build_up(&mut self, amount: usize) {
+        if let Some(view) = &mut self.build_view {
+            view.scroll_back = view
+                .scroll_back
+                .saturating_add(amount)
+                .min(view.lines.len());
+        }
+    }
+
+    /// Scroll towards the end of build output
+    pub fn scroll_build_down(&mut self, amount: usize) {
+        if let Some(view) = &mut self.build_view {
+            view.scroll_back = view.scroll_back.saturating_sub(amount);
+        }
+    }
+
+    // ==================== Background Job Methods ====================
+
+    /// Register a background job and return its ID
+    pub fn start_job(&mut self, label: impl Into<String>, total_bytes: Option<u64>) -> u64 {
+        self.next_job_id += 1;
+        self.jobs.push(BackgroundJob {
+            id: self.next_job_id,
+            label: label.into(),
+            done_bytes: 0,
+            total_bytes,
+        });
+        self.next_job_id
+    }
+
+    /// Record progress on a background job
+    pub fn update_job(&mut self, id: u64, done_bytes: u64) {
+        if let Some(job) = self.jobs.iter_mut().find(|j| j.id == id) {
+            job.done_bytes = done_bytes;
+        }
+    }
+
+    /// Change what a background job is working on, e.g. the next image of
+    /// a batch
+    pub fn relabel_job(&mut self, id: u64, label: impl Into<String>, total_bytes: Option<u64>) {
+        if let Some(job) = self.jobs.iter_mut().find(|j| j.id == id) {
+            job.label = label.into();
+            job.done_bytes = 0;
+            job.total_bytes = total_bytes;
+        }
+    }
+
+    /// Remove a finished background job
+    pub fn finish_job(&mut self, id: u64) {
+        self.jobs.retain(|j| j.id != id);
+    }
+
+    // ==================== Vulnerability Report Methods ====================
+
+    /// Add loaded reports; returns how many local images they cover
+    pub fn add_scan_reports(&mut self, reports: Vec<ScanReport>) -> usize {
+        for report in reports {
+            self.scan_reports.add(report);
+        }
+        self.image_scan_counts().len()
+    }
+
+    /// Finding counts per image ID, for images with a report
+    pub fn image_scan_counts(&self) -> HashMap<String, SeverityCounts> {
+        self.images
+            .iter()
+            .filter_map(|image| {
+                let report = self.scan_reports.for_image(image)?;
+                Some((image.id.clone(), report.counts()))
+            })
+            .collect()
+    }
+
+    // ==================== Image Freshness Methods ====================
+
+    /// Tags of pulled images whose registry digest is unknown or expired,
+    /// one per canonical reference
+    pub fn digest_checks_due(&self, now: std::time::Instant) -> Vec<String> {
+        let mut seen = HashSet::new();
+        self.images
+            .iter()
+            .filter(|image| !image.repo_digests.is_empty())
+            .flat_map(|image| &image.repo_tags)
+            .filter(|tag| {
+                ImageRef::parse(tag).is_some_and(|reference| {
+                    let canonical = reference.canonical();
+                    self.remote_digests.needs_check(&canonical, now) && seen.insert(canonical)
+                })
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// Record the registry digest (or lookup error) of a tag
+    pub fn set_remote_digest(
+        &mut self,
+        tag: &str,
+        result: Result<String, String>,
+        now: std::time::Instant,
+    ) {
+        if let Some(reference) = ImageRef::parse(tag) {
+            self.remote_digests
+                .insert(reference.canonical(), result, now);
+        }
+    }
+
+    /// Local tags that are behind their registry
+    pub fn outdated_tags(&self) -> Vec<String> {
+        self.images
+            .iter()
+            .flat_map(|image| {
+                image.repo_tags.iter().filter(|tag| {
+                    ImageRef::parse(tag).is_some_and(|reference| {
+                        matches!(
+                            self.remote_digests
+                                .freshness(&reference, &image.repo_digests),
+                            Some(Freshness::Outdated { .. })
+                        )
+                    })
+                })
+            })
+            .cloned()
+            .collect()
+    }
+
+    /// IDs of images with at least one outdated tag
+    pub fn outdated_images(&self) -> HashSet<String> {
+        let outdated = self.outdated_tags();
+        self.images
+            .iter()
+            .filter(|image| image.repo_tags.iter().any(|tag| outdated.contains(tag)))
+            .map(|image| image.id.clone())
+            .collect()
+    }
+
+    /// IDs of containers running an outdated image, or an image their tag
+    /// no longer points at locally (e.g. after a pull)
+    pub fn outdated_containers(&self) -> HashSet<String> {
+        let outdated_images = self.outdated_images();
+        self.containers
+            .iter()
+            .filter(|container| {
+                if outdated_images.contains(&container.image_id) {
+                    return true;
+                }
+                let Some(reference) = ImageRef::parse(&container.image) else {
+                    return false;
+                };
+                let canonical = reference.canonical();
+                self.images
+                    .iter()
+                    .find(|image| {
+                        image
+                            .repo_tags
+                            .iter()
+                            .filter_map(|tag| ImageRef::parse(tag))
+                            .any(|tag| tag.canonical() == canonical)
+                    })
+                    .is_some_and(|image| image.id != container.image_id)
+            })
+            .map(|container| container.id.clone())
+            .collect()
+    }
+
+    // ==================== Form Dialog Methods ====================
+
+    /// Open a form dialog
+    pub fn open_form_dialog(&mut self, form: FormDialogState) {
+        self.form_dialog = Some(form);
+    }
+
+    /// Close the form dialog
+    pub fn close_form_dialog(&mut self) {
+        self.form_dialog = None;
+    }
 }
 
 impl Default for AppState {
@@ -938,4 +2553,381 @@ mod tests {
         let exec_view = state.exec_view.as_ref().unwrap();
         assert_eq!(exec_view.cursor, Some((2, 4)));
     }
+
+    #[test]
+    fn exec_copy_mode_enter_exit() {
+        let mut state = AppState::new();
+        state.open_exec_view("abc".into(), "web".into());
+        state.enter_exec_copy_mode(vec!["one".into(), "two".into()], 8);
+        let copy_mode = state.exec_view.as_ref().unwrap().copy_mode.as_ref();
+        assert_eq!(copy_mode.map(|c| c.cursor), Some((1, 0)));
+
+        // Live output keeps updating while copy mode holds its snapshot
+        state.update_exec_screen(vec!["three".into()], None);
+        let exec_view = state.exec_view.as_ref().unwrap();
+        assert_eq!(exec_view.copy_mode.as_ref().unwrap().lines.len(), 2);
+
+        state.exit_exec_copy_mode();
+        assert!(state.exec_view.as_ref().unwrap().copy_mode.is_none());
+    }
+
+    #[test]
+    fn form_dialog_editing() {
+        let mut form = FormDialogState::new(FormKind::PlayRecording, "Play")
+            .with_text("path", "File", "a", "")
+            .with_toggle("flag", "Flag", false);
+
+        form.input_char('b');
+        form.backspace();
+        form.input_char('c');
+        assert_eq!(form.value("path"), "ac");
+
+        form.next_field();
+        form.input_char('x');
+        assert!(!form.toggle("flag"));
+        form.input_char(' ');
+        assert!(form.toggle("flag"));
+
+        form.next_field();
+        assert_eq!(form.selected_index, 0);
+        form.previous_field();
+        assert_eq!(form.selected_index, 1);
+        assert_eq!(form.value("missing"), "");
+    }
+
+    #[test]
+    fn death_report_uses_tracked_restarts() {
+        let mut state = AppState::new();
+        let info = |restart_count| crate::docker::ExitInfo {
+            container_id: "abc".into(),
+            restart_count,
+            restarting: false,
+            exit_code: 1,
+            oom_killed: false,
+            error: String::new(),
+            finished_at: None,
+        };
+        state.update_exit_infos(vec![info(0)]);
+        state.update_exit_infos(vec![info(2)]);
+
+        // A listed container that wasn't re-inspected keeps its history
+        state.update_containers(vec![crate::core::ContainerSummary {
+            id: "abc".into(),
+            ..Default::default()
+        }]);
+        assert!(state.exit_check_baseline().contains_key("abc"));
+        state.update_exit_infos(vec![]);
+        assert_eq!(
+            state.restart_tracker.get("abc").unwrap().info.restart_count,
+            2
+        );
+
+        state.open_death_report("abc".into(), "web".into());
+        assert!(state.death_report.as_ref().unwrap().info.is_none());
+
+        state.set_death_report_content(info(2), vec![]);
+        let report = state.death_report.as_ref().unwrap();
+        assert_eq!(report.recent_restarts, 2);
+        assert_eq!(report.info.as_ref().unwrap().exit_code, 1);
+
+        state.close_death_report();
+        assert!(state.death_report.is_none());
+    }
+
+    #[test]
+    fn built_image_is_selected_once_listed() {
+        let image = |id: &str, tag: &str| ImageSummary {
+            id: id.into(),
+            repo_tags: vec![tag.into()],
+            ..Default::default()
+        };
+        let mut state = AppState {
+            pending_image_select: Some("sha256:new".into()),
+            ..Default::default()
+        };
+
+        state.update_images(vec![image("sha256:old", "old:1")]);
+        assert_eq!(state.pending_image_select.as_deref(), Some("sha256:new"));
+
+        state.update_images(vec![
+            image("sha256:old", "old:1"),
+            image("sha256:new", "app:latest"),
+        ]);
+        assert_eq!(state.image_list_selected, 1);
+        assert_eq!(state.selected_image.as_deref(), Some("sha256:new"));
+        assert!(state.pending_image_select.is_none());
+    }
+
+    #[test]
+    fn created_volume_is_selected_once_listed() {
+        let volume = |name: &str| VolumeSummary {
+            name: name.into(),
+            driver: "local".into(),
+            mountpoint: String::new(),
+            created_at: Utc::now(),
+            status: HashMap::new(),
+            labels: HashMap::new(),
+            scope: crate::core::VolumeScope::Local,
+            options: HashMap::new(),
+            in_use: vec![],
+            size: None,
+            ref_count: None,
+        };
+        let mut state = AppState {
+            pending_volume_select: Some("data".into()),
+            ..Default::default()
+        };
+
+        state.update_volumes(vec![volume("cache")]);
+        assert_eq!(state.pending_volume_select.as_deref(), Some("data"));
+
+        state.update_volumes(vec![volume("cache"), volume("data")]);
+        assert_eq!(state.volume_list_selected, 1);
+        assert_eq!(state.selected_volume.as_deref(), Some("data"));
+        assert!(state.pending_volume_select.is_none());
+    }
+
+    #[test]
+    fn volumes_take_usage_and_sort_by_it() {
+        use crate::docker::VolumeUsage;
+
+        let volume = |name: &str| VolumeSummary {
+            name: name.into(),
+            driver: "local".into(),
+            mountpoint: String::new(),
+            created_at: Utc::now(),
+            status: HashMap::new(),
+            labels: HashMap::new(),
+            scope: crate::core::VolumeScope::Local,
+            options: HashMap::new(),
+            in_use: vec![],
+            size: None,
+            ref_count: None,
+        };
+        let mut state = AppState::default();
+        state.update_volumes(vec![volume("c"), volume("a"), volume("b")]);
+        let names = |state: &AppState| -> Vec<String> {
+            state.volumes.iter().map(|v| v.name.clone()).collect()
+        };
+        assert_eq!(names(&state), vec!["a", "b", "c"]);
+        state.next_volume();
+        assert_eq!(state.selected_volume.as_deref(), Some("b"));
+
+        let usage = |size, ref_count| VolumeUsage { size, ref_count };
+        state.update_disk_usage(SystemDiskUsage {
+            volume_usage: HashMap::from([
+                ("a".to_string(), usage(10, 1)),
+                ("b".to_string(), usage(300, 0)),
+            ]),
+            ..Default::default()
+        });
+        assert_eq!(state.volumes[1].size, Some(300));
+        assert_eq!(state.volumes[2].ref_count, None);
+
+        state.cycle_volume_sort();
+        assert_eq!(state.volume_sort, VolumeSortKey::Size);
+        assert_eq!(names(&state), vec!["b", "a", "c"]);
+        // The selection follows the volume
+        assert_eq!(state.volume_list_selected, 0);
+        assert_eq!(state.selected_volume.as_deref(), Some("b"));
+
+        state.reverse_volume_sort();
+        assert_eq!(names(&state), vec!["c", "a", "b"]);
+
+        // A refresh keeps the usage from the last disk usage
+        state.update_volumes(vec![volume("a"), volume("b")]);
+        assert_eq!(names(&state), vec!["a", "b"]);
+        assert_eq!(state.volumes[1].size, Some(300));
+    }
+
+    #[test]
+    fn volume_browser_navigation() {
+        use crate::docker::{VolumeEntry, VolumeEntryKind};
+
+        let entry = |path: &str, kind: VolumeEntryKind| VolumeEntry {
+            path: path.into(),
+            kind,
+            size: 0,
+            mode: 0o644,
+            modified: None,
+        };
+        let mut state = AppState::default();
+        state.open_volume_browser("data".into());
+        state.set_volume_entries(vec![
+            entry("README", VolumeEntryKind::File),
+            entry("pg", VolumeEntryKind::Directory),
+            entry("pg/base", VolumeEntryKind::Directory),
+            entry("pg/conf", VolumeEntryKind::File),
+            entry("tmp", VolumeEntryKind::Directory),
+        ]);
+
+        let names = |state: &AppState| -> Vec<String> {
+            let browser = state.volume_browser.as_ref().unwrap();
+            browser
+                .children()
+                .iter()
+                .map(|e| e.name().to_string())
+                .collect()
+        };
+        // Directories first
+        assert_eq!(names(&state), vec!["pg", "tmp", "README"]);
+
+        state.enter_volume_dir();
+        assert_eq!(state.volume_browser.as_ref().unwrap().cwd, "pg");
+        assert_eq!(names(&state), vec!["base", "conf"]);
+
+        // Files can't be entered
+        state.next_volume_entry();
+        state.enter_volume_dir();
+        assert_eq!(state.volume_browser.as_ref().unwrap().cwd, "pg");
+
+        state.leave_volume_dir();
+        let browser = state.volume_browser.as_ref().unwrap();
+        assert_eq!(browser.cwd, "");
+        assert_eq!(browser.selected_entry().unwrap().path, "pg");
+    }
+
+    #[test]
+    fn build_output_holds_position_when_scrolled_back() {
+        let line = |text: &str| crate::docker::BuildLine {
+            text: text.into(),
+            kind: crate::docker::BuildLineKind::Output,
+        };
+        let mut state = AppState::default();
+        state.open_build_view("app".into());
+        state.push_build_lines(vec![line("a"), line("b"), line("c")]);
+        state.scroll_build_up(1);
+        state.push_build_lines(vec![line("d"), line("e")]);
+        assert_eq!(state.build_view.as_ref().unwrap().scroll_back, 3);
+
+        state.scroll_build_down(usize::MAX);
+        state.push_build_lines(vec![line("f")]);
+        assert_eq!(state.build_view.as_ref().unwrap().scroll_back, 0);
+    }
+
+    #[test]
+    fn image_marks_follow_the_image_list() {
+        let image = |id: &str| ImageSummary {
+            id: id.into(),
+            ..Default::default()
+        };
+        let mut state = AppState::default();
+        state.update_images(vec![image("sha256:a"), image("sha256:b")]);
+        state.toggle_image_mark();
+        state.next_image();
+        state.toggle_image_mark();
+        state.toggle_image_mark();
+        assert_eq!(state.marked_images, HashSet::from(["sha256:a".to_string()]));
+
+        // Marks on removed images are dropped
+        state.update_images(vec![image("sha256:b")]);
+        assert!(state.marked_images.is_empty());
+    }
+
+    #[test]
+    fn background_job_progress() {
+        let mut state = AppState::default();
+        let first = state.start_job("Loading a.tar", Some(200));
+        let second = state.start_job("Saving b.tar", None);
+        state.update_job(first, 50);
+        assert_eq!(state.jobs[0].percent(), Some(25));
+        assert_eq!(state.jobs[1].percent(), None);
+
+        state.finish_job(first);
+        assert_eq!(state.jobs.len(), 1);
+        assert_eq!(state.jobs[0].id, second);
+    }
+
+    #[test]
+    fn flags_outdated_images_and_containers() {
+        let now = std::time::Instant::now();
+        let image = |id: &str, tags: &[&str], digests: &[&str]| ImageSummary {
+            id: id.into(),
+            repo_tags: tags.iter().map(|t| t.to_string()).collect(),
+            repo_digests: digests.iter().map(|d| d.to_string()).collect(),
+            ..Default::default()
+        };
+        let container = |id: &str, image: &str, image_id: &str| ContainerSummary {
+            id: id.into(),
+            image: image.into(),
+            image_id: image_id.into(),
+            ..Default::default()
+        };
+        let mut state = AppState::default();
+        state.update_images(vec![
+            image("sha256:app", &["app:1"], &["app@sha256:old"]),
+            image("sha256:web", &["nginx:latest"], &["nginx@sha256:new"]),
+            image("sha256:built", &["local:dev"], &[]),
+        ]);
+        state.update_containers(vec![
+            container("c1", "app:1", "sha256:app"),
+            // Started before nginx:latest was pulled again
+            container("c2", "nginx", "sha256:web-old"),
+            container("c3", "nginx:latest", "sha256:web"),
+        ]);
+
+        // Locally built images are never looked up
+        assert_eq!(state.digest_checks_due(now), vec!["app:1", "nginx:latest"]);
+        state.set_remote_digest("app:1", Ok("sha256:new".into()), now);
+        state.set_remote_digest("nginx:latest", Ok("sha256:new".into()), now);
+        assert!(state.digest_checks_due(now).is_empty());
+
+        assert_eq!(state.outdated_tags(), vec!["app:1"]);
+        assert_eq!(
+            state.outdated_images(),
+            HashSet::from(["sha256:app".to_string()])
+        );
+        assert_eq!(
+            state.outdated_containers(),
+            HashSet::from(["c1".to_string(), "c2".to_string()])
+        );
+    }
+
+    #[test]
+    fn image_tree_and_container_filter() {
+        let image = |id: &str, parent: &str, created: i64| ImageSummary {
+            id: id.into(),
+            short_id: id.into(),
+            parent_id: parent.into(),
+            created: chrono::DateTime::from_timestamp(created, 0).unwrap(),
+            ..Default::default()
+        };
+        let container = |id: &str, image_id: &str| ContainerSummary {
+            id: id.into(),
+            names: vec![id.into()],
+            image_id: image_id.into(),
+            ..Default::default()
+        };
+        let mut state = AppState::default();
+        state.update_images(vec![image("app", "base", 2), image("base", "", 1)]);
+        state.update_containers(vec![container("web", "app"), container("db", "other")]);
+
+        // Tree view puts parents first and keeps the selection
+        state.toggle_image_tree();
+        let ids: Vec<&str> = state.images.iter().map(|i| i.id.as_str()).collect();
+        assert_eq!(ids, vec!["base", "app"]);
+        assert_eq!(state.selected_image.as_deref(), Some("app"));
+        state.toggle_image_tree();
+        assert_eq!(state.images[0].id, "app");
+
+        assert_eq!(
+            state.image_dependents("base"),
+            (vec![], vec!["app".to_string()])
+        );
+        assert_eq!(
+            state.image_dependents("app"),
+            (vec!["web".to_string()], vec![])
+        );
+
+        // Filtering by ancestor keeps containers of child images
+        let filter = ContainerFilter::ancestor(&state.images, "base");
+        state.set_container_filter(Some(filter));
+        assert_eq!(state.containers.len(), 1);
+        assert_eq!(state.selected_container.as_deref(), Some("web"));
+        state.update_containers(vec![container("web", "app"), container("api", "base")]);
+        assert_eq!(state.containers.len(), 2);
+        assert_eq!(state.all_containers.len(), 2);
+        state.set_container_filter(None);
+        assert_eq!(state.containers.len(), 2);
+    }
 }
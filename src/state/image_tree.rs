@@ -0,0 +1,165 @@
+//! Parent/child relationships between local images
+
+use std::collections::{HashMap, HashSet};
+
+use crate::core::{ContainerSummary, ImageSummary};
+
+/// Images in tree order: each image followed by its children, depth first.
+/// Returns (index into `images`, depth). Images whose parent isn't present
+/// locally are roots.
+pub fn tree_order(images: &[ImageSummary]) -> Vec<(usize, usize)> {
+    let ids: HashSet<&str> = images.iter().map(|i| i.id.as_str()).collect();
+    let mut children: HashMap<&str, Vec<usize>> = HashMap::new();
+    let mut roots = Vec::new();
+    for (index, image) in images.iter().enumerate() {
+        if !image.parent_id.is_empty() && ids.contains(image.parent_id.as_str()) {
+            children
+                .entry(image.parent_id.as_str())
+                .or_default()
+                .push(index);
+        } else {
+            roots.push(index);
+        }
+    }
+
+    let mut order = Vec::with_capacity(images.len());
+    let mut visited = HashSet::new();
+    let mut stack: Vec<(usize, usize)> = roots.into_iter().rev().map(|i| (i, 0)).collect();
+    while let Some((index, depth)) = stack.pop() {
+        if !visited.insert(index) {
+            continue;
+        }
+        order.push((index, depth));
+        if let Some(kids) = children.get(images[index].id.as_str()) {
+            stack.extend(kids.iter().rev().map(|&kid| (kid, depth + 1)));
+        }
+    }
+    order
+}
+
+/// IDs of every image built on top of `id`, at any depth
+pub fn descendants(images: &[ImageSummary], id: &str) -> HashSet<String> {
+    let mut found = HashSet::new();
+    let mut frontier = vec![id.to_string()];
+    while let Some(parent) = frontier.pop() {
+        for image in images.iter().filter(|i| i.parent_id == parent) {
+            if found.insert(image.id.clone()) {
+                frontier.push(image.id.clone());
+            }
+        }
+    }
+    found
+}
+
+/// Display name of an image: its first tag, or its short ID
+pub fn image_name(image: &ImageSummary) -> String {
+    image
+        .repo_tags
+        .iter()
+        .find(|t| !t.starts_with("<none>"))
+        .cloned()
+        .unwrap_or_else(|| image.short_id.clone())
+}
+
+/// Display name of a container
+pub fn container_name(container: &ContainerSummary) -> String {
+    container
+        .names
+        .first()
+        .cloned()
+        .unwrap_or_else(|| container.short_id.clone())
+}
+
+/// Containers limited to those created from a set of images, like
+/// `docker ps --filter ancestor=...`
+#[derive(Debug, Clone, PartialEq)]
+pub struct ContainerFilter {
+    /// Shown in the container list title
+    pub label: String,
+    pub image_ids: HashSet<String>,
+}
+
+impl ContainerFilter {
+    /// Containers of `id` and of every image built on it
+    pub fn ancestor(images: &[ImageSummary], id: &str) -> Self {
+        let label = images
+            .iter()
+            .find(|i| i.id == id)
+            .map(image_name)
+            .unwrap_or_else(|| id.to_string());
+        let mut image_ids = descendants(images, id);
+        image_ids.insert(id.to_string());
+        Self {
+            label: format!("ancestor={}", label),
+            image_ids,
+        }
+    }
+
+    pub fn matches(&self, container: &ContainerSummary) -> bool {
+        self.image_ids.contains(&container.image_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(id: &str, parent: &str) -> ImageSummary {
+        ImageSummary {
+            id: id.into(),
+            short_id: id.into(),
+            parent_id: parent.into(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn orders_images_depth_first() {
+        let images = vec![
+            image("app", "base"),
+            image("other", ""),
+            image("base", ""),
+            image("app-debug", "app"),
+            image("tool", "base"),
+            image("orphan", "sha256:gone"),
+        ];
+        let order: Vec<(&str, usize)> = tree_order(&images)
+            .into_iter()
+            .map(|(i, depth)| (images[i].id.as_str(), depth))
+            .collect();
+        assert_eq!(
+            order,
+            vec![
+                ("other", 0),
+                ("base", 0),
+                ("app", 1),
+                ("app-debug", 2),
+                ("tool", 1),
+                ("orphan", 0),
+            ]
+        );
+    }
+
+    #[test]
+    fn ancestor_filter_covers_descendants() {
+        let images = vec![
+            image("base", ""),
+            image("app", "base"),
+            image("app-debug", "app"),
+            image("other", ""),
+        ];
+        assert_eq!(
+            descendants(&images, "base"),
+            HashSet::from(["app".to_string(), "app-debug".to_string()])
+        );
+
+        let filter = ContainerFilter::ancestor(&images, "app");
+        assert_eq!(filter.label, "ancestor=app");
+        let container = |image_id: &str| ContainerSummary {
+            image_id: image_id.into(),
+            ..Default::default()
+        };
+        assert!(filter.matches(&container("app-debug")));
+        assert!(!filter.matches(&container("base")));
+    }
+}
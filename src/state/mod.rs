@@ -1,8 +1,17 @@
 //! Application state management
 
 pub mod app_state;
+pub mod image_tree;
+pub mod network_topology;
+pub mod restart_tracker;
 
 pub use app_state::{
-    AppState, DetailViewState, ExecViewState, ImageDetailViewState, LogLevelFilter, LogViewState,
-    Notification, Panel, StatsViewState,
+    AppState, BackgroundJob, BuildViewState, CleanupViewState, DeathReportState, DetailViewState,
+    DockerfileViewState, ExecViewState, FormDialogState, FormField, FormFieldKind, FormKind,
+    ImageDetailViewState, ImageDiffViewState, LayerExplorerState, LogLevelFilter, LogViewState,
+    NetworkDetailViewState, Notification, Panel, RegistryBrowserState, RegistryLevel,
+    RunSpecViewState, StatsViewState, TransferViewState, VolumeBrowserState, VolumeDetailViewState,
+    VolumeSortKey,
 };
+pub use image_tree::ContainerFilter;
+pub use restart_tracker::{ExitCheckBaseline, RestartHistory, RestartTracker};
@@ -0,0 +1,181 @@
+//! Which containers sit on which networks, for the topology view
+
+use crate::core::{ContainerSummary, NetworkSummary};
+use crate::state::image_tree::container_name;
+
+/// A container attached to a network
+#[derive(Debug, Clone)]
+pub struct Attachment<'a> {
+    pub container: &'a ContainerSummary,
+    /// Address on this network, if the container has one (running)
+    pub ip: Option<&'a str>,
+    /// The container's other networks; two containers can only talk
+    /// directly over a network they share
+    pub also_on: Vec<&'a str>,
+}
+
+/// Containers attached to `network`, by name. Membership comes from the
+/// containers' network lists, plus the network's own list when the daemon
+/// filled it in.
+pub fn attachments<'a>(
+    network: &NetworkSummary,
+    containers: &'a [ContainerSummary],
+) -> Vec<Attachment<'a>> {
+    let mut attached: Vec<Attachment> = containers
+        .iter()
+        .filter(|c| {
+            c.networks.contains(&network.name) || network.connected_containers.contains(&c.id)
+        })
+        .map(|c| Attachment {
+            container: c,
+            ip: c.network_ips.get(&network.name).map(String::as_str),
+            also_on: c
+                .networks
+                .iter()
+                .filter(|n| **n != network.name)
+                .map(String::as_str)
+                .collect(),
+        })
+        .collect();
+    attached.sort_by_key(|a| container_name(a.container));
+    attached
+}
+
+/// Containers on none of `networks`
+pub fn unattached<'a>(
+    networks: &[NetworkSummary],
+    containers: &'a [ContainerSummary],
+) -> Vec<&'a ContainerSummary> {
+    containers
+        .iter()
+        .filter(|c| {
+            !networks
+                .iter()
+                .any(|n| c.networks.contains(&n.name) || n.connected_containers.contains(&c.id))
+        })
+        .collect()
+}
+
+/// Ports a container publishes on the host, as `[ip:]host→container/proto`.
+/// Docker reports a binding on all addresses twice (0.0.0.0 and ::), which
+/// shows once.
+pub fn published_ports(container: &ContainerSummary) -> Vec<String> {
+    let mut ports: Vec<String> = Vec::new();
+    for port in &container.ports {
+        let Some(public) = port.public_port else {
+            continue;
+        };
+        let host = match port.ip.as_deref() {
+            None | Some("") | Some("0.0.0.0") | Some("::") => String::new(),
+            Some(ip) if ip.contains(':') => format!("[{}]:", ip),
+            Some(ip) => format!("{}:", ip),
+        };
+        let text = format!(
+            "{}{}→{}/{}",
+            host,
+            public,
+            port.private_port,
+            port.protocol.to_lowercase()
+        );
+        if !ports.contains(&text) {
+            ports.push(text);
+        }
+    }
+    ports
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{NetworkScope, PortMapping};
+    use std::collections::HashMap;
+
+    fn network(name: &str, connected: &[&str]) -> NetworkSummary {
+        NetworkSummary {
+            id: name.into(),
+            name: name.into(),
+            driver: "bridge".into(),
+            scope: NetworkScope::Local,
+            created: chrono::Utc::now(),
+            internal: false,
+            attachable: false,
+            ingress: false,
+            enable_ipv6: false,
+            subnet: None,
+            connected_containers: connected.iter().map(|c| c.to_string()).collect(),
+        }
+    }
+
+    fn container(id: &str, networks: &[(&str, &str)]) -> ContainerSummary {
+        ContainerSummary {
+            id: id.into(),
+            names: vec![id.into()],
+            networks: networks.iter().map(|(n, _)| n.to_string()).collect(),
+            network_ips: networks
+                .iter()
+                .filter(|(_, ip)| !ip.is_empty())
+                .map(|(n, ip)| (n.to_string(), ip.to_string()))
+                .collect::<HashMap<_, _>>(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn groups_containers_by_network() {
+        let networks = vec![network("backend", &[]), network("frontend", &["proxy"])];
+        let containers = vec![
+            container(
+                "web",
+                &[("frontend", "172.21.0.3"), ("backend", "172.20.0.3")],
+            ),
+            container("db", &[("backend", "172.20.0.2")]),
+            container("proxy", &[]),
+            container("batch", &[("none", "")]),
+        ];
+
+        let backend = attachments(&networks[0], &containers);
+        let names: Vec<&str> = backend.iter().map(|a| a.container.id.as_str()).collect();
+        assert_eq!(names, vec!["db", "web"]);
+        assert_eq!(backend[1].ip, Some("172.20.0.3"));
+        assert_eq!(backend[1].also_on, vec!["frontend"]);
+
+        let frontend = attachments(&networks[1], &containers);
+        let names: Vec<&str> = frontend.iter().map(|a| a.container.id.as_str()).collect();
+        assert_eq!(names, vec!["proxy", "web"]);
+        assert_eq!(frontend[0].ip, None);
+
+        let loose: Vec<&str> = unattached(&networks, &containers)
+            .iter()
+            .map(|c| c.id.as_str())
+            .collect();
+        assert_eq!(loose, vec!["batch"]);
+    }
+
+    #[test]
+    fn lists_published_ports_once() {
+        let port = |ip: Option<&str>, public: Option<u16>, private: u16| PortMapping {
+            ip: ip.map(str::to_string),
+            private_port: private,
+            public_port: public,
+            protocol: "TCP".into(),
+        };
+        let web = ContainerSummary {
+            ports: vec![
+                port(Some("0.0.0.0"), Some(8080), 80),
+                port(Some("::"), Some(8080), 80),
+                port(Some("127.0.0.1"), Some(5432), 5432),
+                port(Some("::1"), Some(9000), 9000),
+                port(None, None, 443),
+            ],
+            ..Default::default()
+        };
+        assert_eq!(
+            published_ports(&web),
+            vec![
+                "8080→80/tcp",
+                "127.0.0.1:5432→5432/tcp",
+                "[::1]:9000→9000/tcp"
+            ]
+        );
+    }
+}
@@ -0,0 +1,208 @@
+//! Restart tracking across refreshes for crash-loop detection
+
+use std::collections::{HashMap, VecDeque};
+
+use chrono::{DateTime, Duration, Utc};
+
+use crate::core::{ContainerState, ContainerSummary};
+use crate::docker::ExitInfo;
+
+/// Restarts within this window count towards a crash loop
+pub const CRASH_LOOP_WINDOW_SECS: i64 = 300;
+
+/// Restarts within the window that flag a container as crash-looping
+const CRASH_LOOP_RESTARTS: usize = 3;
+
+/// What we have seen of one container's restarts
+#[derive(Debug, Clone)]
+pub struct RestartHistory {
+    /// Latest inspect result
+    pub info: ExitInfo,
+    /// When each observed restart was noticed, oldest first
+    pub restarts: VecDeque<DateTime<Utc>>,
+}
+
+impl RestartHistory {
+    /// Restarts noticed within the crash-loop window ending at `now`
+    pub fn recent_restarts(&self, now: DateTime<Utc>) -> usize {
+        let cutoff = now - Duration::seconds(CRASH_LOOP_WINDOW_SECS);
+        self.restarts.iter().filter(|t| **t >= cutoff).count()
+    }
+
+    /// Whether the container keeps dying and being restarted
+    pub fn is_crash_looping(&self, now: DateTime<Utc>) -> bool {
+        (self.info.restarting && self.info.exit_code != 0)
+            || self.recent_restarts(now) >= CRASH_LOOP_RESTARTS
+    }
+}
+
+/// State and status text of tracked containers as of the last refresh,
+/// keyed by container ID
+pub type ExitCheckBaseline = HashMap<String, (ContainerState, String)>;
+
+/// Whether a container needs inspecting for its exit information: it is
+/// new, its state or status changed since `baseline`, or it is restarting.
+/// Containers that didn't change keep what we have.
+pub fn needs_exit_check(container: &ContainerSummary, baseline: &ExitCheckBaseline) -> bool {
+    container.state == ContainerState::Restarting
+        || baseline.get(&container.id).map_or(true, |(state, status)| {
+            *state != container.state || *status != container.status
+        })
+}
+
+/// Restart histories keyed by container ID
+#[derive(Debug, Clone, Default)]
+pub struct RestartTracker {
+    histories: HashMap<String, RestartHistory>,
+}
+
+impl RestartTracker {
+    /// Record a refresh worth of inspect results. Containers missing from
+    /// `infos` are forgotten.
+    pub fn observe(&mut self, infos: Vec<ExitInfo>, now: DateTime<Utc>) {
+        let cutoff = now - Duration::seconds(CRASH_LOOP_WINDOW_SECS);
+        let mut histories = HashMap::with_capacity(infos.len());
+
+        for info in infos {
+            let mut history = match self.histories.remove(&info.container_id) {
+                Some(mut previous) => {
+                    // Only count increases; the first sighting is the baseline
+                    let new_restarts = info.restart_count - previous.info.restart_count;
+                    for _ in 0..new_restarts.clamp(0, CRASH_LOOP_RESTARTS as i64) {
+                        previous.restarts.push_back(now);
+                    }
+                    previous.info = info;
+                    previous
+                }
+                None => RestartHistory {
+                    info,
+                    restarts: VecDeque::new(),
+                },
+            };
+            while history.restarts.front().is_some_and(|t| *t < cutoff) {
+                history.restarts.pop_front();
+            }
+            histories.insert(history.info.container_id.clone(), history);
+        }
+
+        self.histories = histories;
+    }
+
+    /// History for a container
+    pub fn get(&self, container_id: &str) -> Option<&RestartHistory> {
+        self.histories.get(container_id)
+    }
+
+    /// Restart counts of crash-looping containers, keyed by container ID
+    pub fn crash_looping(&self, now: DateTime<Utc>) -> HashMap<String, i64> {
+        self.histories
+            .iter()
+            .filter(|(_, h)| h.is_crash_looping(now))
+            .map(|(id, h)| (id.clone(), h.info.restart_count))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn info(id: &str, restart_count: i64) -> ExitInfo {
+        ExitInfo {
+            container_id: id.to_string(),
+            restart_count,
+            restarting: false,
+            exit_code: 1,
+            oom_killed: false,
+            error: String::new(),
+            finished_at: None,
+        }
+    }
+
+    #[test]
+    fn counts_restarts_between_refreshes() {
+        let mut tracker = RestartTracker::default();
+        let start = Utc::now();
+
+        // A container that already restarted a lot before we started watching
+        tracker.observe(vec![info("a", 40)], start);
+        assert!(tracker.crash_looping(start).is_empty());
+
+        tracker.observe(vec![info("a", 41)], start + Duration::seconds(10));
+        tracker.observe(vec![info("a", 43)], start + Duration::seconds(20));
+        let looping = tracker.crash_looping(start + Duration::seconds(20));
+        assert_eq!(looping.get("a"), Some(&43));
+    }
+
+    #[test]
+    fn old_restarts_fall_out_of_the_window() {
+        let mut tracker = RestartTracker::default();
+        let start = Utc::now();
+        tracker.observe(vec![info("a", 0)], start);
+        tracker.observe(vec![info("a", 3)], start + Duration::seconds(1));
+        assert!(tracker
+            .get("a")
+            .unwrap()
+            .is_crash_looping(start + Duration::seconds(1)));
+
+        let later = start + Duration::seconds(CRASH_LOOP_WINDOW_SECS + 10);
+        tracker.observe(vec![info("a", 3)], later);
+        assert_eq!(tracker.get("a").unwrap().recent_restarts(later), 0);
+        assert!(tracker.crash_looping(later).is_empty());
+    }
+
+    #[test]
+    fn restarting_after_failure_is_a_crash_loop() {
+        let mut tracker = RestartTracker::default();
+        let now = Utc::now();
+        let mut restarting = info("a", 1);
+        restarting.restarting = true;
+        tracker.observe(vec![restarting, info("b", 0)], now);
+
+        let looping = tracker.crash_looping(now);
+        assert!(looping.contains_key("a"));
+        assert!(!looping.contains_key("b"));
+    }
+
+    #[test]
+    fn only_changed_or_stopped_containers_are_inspected() {
+        let container = |id: &str, state, status: &str| ContainerSummary {
+            id: id.into(),
+            state,
+            status: status.into(),
+            ..Default::default()
+        };
+        let baseline: ExitCheckBaseline = [
+            (
+                "up".to_string(),
+                (ContainerState::Running, "Up 5 minutes".to_string()),
+            ),
+            (
+                "down".to_string(),
+                (ContainerState::Exited, "Exited (1)".to_string()),
+            ),
+        ]
+        .into();
+
+        let up = container("up", ContainerState::Running, "Up 5 minutes");
+        assert!(!needs_exit_check(&up, &baseline));
+        let bounced = container("up", ContainerState::Running, "Up 2 seconds");
+        assert!(needs_exit_check(&bounced, &baseline));
+        let down = container("down", ContainerState::Exited, "Exited (1)");
+        assert!(!needs_exit_check(&down, &baseline));
+        let restarting = container("down", ContainerState::Restarting, "Restarting (1)");
+        assert!(needs_exit_check(&restarting, &baseline));
+        let new = container("new", ContainerState::Running, "Up 5 minutes");
+        assert!(needs_exit_check(&new, &baseline));
+    }
+
+    #[test]
+    fn forgets_removed_containers() {
+        let mut tracker = RestartTracker::default();
+        let now = Utc::now();
+        tracker.observe(vec![info("a", 0), info("b", 0)], now);
+        tracker.observe(vec![info("b", 0)], now);
+        assert!(tracker.get("a").is_none());
+        assert!(tracker.get("b").is_some());
+    }
+}
@@ -11,9 +11,9 @@ use ratatui::Frame;
 use tracing::{debug, info};
 
 use crate::core::{ConfirmAction, ContainerState, Tab, UiAction};
-use crate::docker::format_bytes_size;
+use crate::docker::{format_bytes_size, ReconstructedDockerfile};
 use crate::exec::input::encode_key_event;
-use crate::state::AppState;
+use crate::state::{AppState, FormDialogState, FormKind, RegistryLevel};
 use crate::ui::components::ContainerListWidget;
 
 /// UI Application controller
@@ -63,33 +63,112 @@ impl UiApp {
             return UiAction::None;
         }
 
+        // If a form dialog is active, handle form keys (modal, blocks everything)
+        if self.state.form_dialog.is_some() {
+            return self.handle_form_dialog_key(key);
+        }
+
         // If log view is active, handle log view keys (log view is modal, blocks everything)
         if self.state.log_view.is_some() {
             return self.handle_log_view_key(key);
         }
 
+        // If the build pane is open, handle its keys (modal, blocks everything)
+        if self.state.build_view.is_some() {
+            return self.handle_build_view_key(key);
+        }
+
+        // If the pull dialog is open, handle its keys (modal, blocks everything)
+        if self.state.transfer_view.is_some() {
+            return self.handle_transfer_view_key(key);
+        }
+
+        // If the run spec viewer is open over the detail view, it takes the keys
+        if self.state.run_spec_view.is_some() {
+            return self.handle_run_spec_view_key(key);
+        }
+
         // If detail view is active, handle detail view keys (modal, blocks everything)
         if self.state.detail_view.is_some() {
             return self.handle_detail_view_key(key);
         }
 
+        // If the registry browser is active, handle its keys (modal)
+        if self.state.registry_browser.is_some() {
+            return self.handle_registry_browser_key(key);
+        }
+
+        // If the layer explorer is open over the image detail view, it takes the keys
+        if self.state.layer_explorer.is_some() {
+            return self.handle_layer_explorer_key(key);
+        }
+
+        // If the cleanup planner is open, handle its keys (modal, blocks everything)
+        if self.state.cleanup_view.is_some() {
+            return self.handle_cleanup_view_key(key);
+        }
+
+        // If the volume browser is open, handle its keys (modal, blocks everything)
+        if self.state.volume_browser.is_some() {
+            return self.handle_volume_browser_key(key);
+        }
+
+        // If the reconstructed Dockerfile is shown, handle its keys (modal, blocks everything)
+        if self.state.dockerfile_view.is_some() {
+            return self.handle_dockerfile_view_key(key);
+        }
+
+        // If the image comparison is open, handle its keys (modal, blocks everything)
+        if self.state.image_diff_view.is_some() {
+            return self.handle_image_diff_key(key);
+        }
+
         // If image detail view is active, handle image detail view keys (modal, blocks everything)
         if self.state.image_detail_view.is_some() {
             return self.handle_image_detail_view_key(key);
         }
 
+        // If a volume or network detail view is active, handle its keys (modal, blocks everything)
+        if self.state.volume_detail_view.is_some() {
+            return self.handle_volume_detail_view_key(key);
+        }
+        if self.state.network_detail_view.is_some() {
+            return self.handle_network_detail_view_key(key);
+        }
+
+        // If the death report is open, handle its keys (modal, blocks everything)
+        if self.state.death_report.is_some() {
+            return self.handle_death_report_key(key);
+        }
+
         // If prune dialog is active, handle prune dialog keys (modal, blocks everything)
         if self.state.prune_dialog.is_some() {
             return self.handle_prune_dialog_key(key);
         }
 
-        // If exec view is focused, route keys to exec (except Ctrl+E)
+        // If exec copy mode is active, it owns the keyboard until closed
+        if self
+            .state
+            .exec_view
+            .as_ref()
+            .is_some_and(|v| v.copy_mode.is_some())
+        {
+            return self.handle_exec_copy_mode_key(key);
+        }
+
+        // If exec view is focused, route keys to exec (except Ctrl+E and Shift+PgUp)
         if let Some(exec_view) = &self.state.exec_view {
+            if exec_view.focus && exec_view.playback {
+                return self.handle_exec_playback_key(key);
+            }
             if exec_view.focus {
                 if key.code == KeyCode::Char('e') && key.modifiers.contains(KeyModifiers::CONTROL) {
                     self.state.toggle_exec_focus();
                     return UiAction::None;
                 }
+                if key.code == KeyCode::PageUp && key.modifiers.contains(KeyModifiers::SHIFT) {
+                    return UiAction::EnterExecCopyMode;
+                }
                 if let Some(bytes) = encode_key_event(key) {
                     return UiAction::ExecInput(bytes);
                 }
@@ -189,6 +268,15 @@ impl UiApp {
             KeyCode::Char('x') if self.state.current_tab == Tab::Containers => {
                 self.handle_exec_action()
             }
+            KeyCode::Char('X') if self.state.current_tab == Tab::Containers => {
+                self.handle_debug_action()
+            }
+            KeyCode::Char('w') if self.state.current_tab == Tab::Containers => {
+                self.handle_death_report_action()
+            }
+            KeyCode::Char('u') if self.state.current_tab == Tab::Containers => {
+                self.handle_recreate_action()
+            }
             // Toggle stats follow when stats panel is visible
             KeyCode::Char('f') if self.state.stats_view.is_some() => {
                 self.state.toggle_stats_follow();
@@ -202,6 +290,26 @@ impl UiApp {
                 self.state.toggle_exec_focus();
                 UiAction::None
             }
+            // Exec pane keys; the pane is drawn on every tab, but other tabs
+            // use `c` and `R` for their own actions
+            KeyCode::Char('c')
+                if self.state.current_tab == Tab::Containers
+                    && key.modifiers.is_empty()
+                    && self.state.exec_view.is_some() =>
+            {
+                UiAction::EnterExecCopyMode
+            }
+            // Toggle recording of the live exec session
+            KeyCode::Char('R')
+                if self.state.current_tab == Tab::Containers
+                    && self.state.exec_view.as_ref().is_some_and(|v| !v.playback) =>
+            {
+                UiAction::ToggleExecRecording
+            }
+            // Replay a recorded exec session
+            KeyCode::Char('P') if self.state.current_tab == Tab::Containers => {
+                UiAction::OpenPlayRecordingDialog
+            }
 
             // Image actions (when on Images tab)
             KeyCode::Char('d') if self.state.current_tab == Tab::Images => {
@@ -213,6 +321,91 @@ impl UiApp {
             KeyCode::Char('i') if self.state.current_tab == Tab::Images => {
                 self.handle_image_inspect_action()
             }
+            KeyCode::Char('b') if self.state.current_tab == Tab::Images => {
+                self.state.open_form_dialog(
+                    FormDialogState::new(FormKind::BuildImage, "Build Image")
+                        .with_text("context", "Context", ".".to_string(), "")
+                        .with_text("dockerfile", "Dockerfile", "Dockerfile".to_string(), "")
+                        .with_text("tags", "Tags", String::new(), "app:latest, app:1.0")
+                        .with_text("build_args", "Build args", String::new(), "KEY=VALUE, ...")
+                        .with_text("target", "Target", String::new(), "final stage"),
+                );
+                UiAction::None
+            }
+            KeyCode::Char('t') if self.state.current_tab == Tab::Images => {
+                self.handle_image_tag_action(false)
+            }
+            KeyCode::Char('T') if self.state.current_tab == Tab::Images => {
+                self.handle_image_tag_action(true)
+            }
+            KeyCode::Char('U') if self.state.current_tab == Tab::Images => {
+                self.handle_image_push_action()
+            }
+            KeyCode::Char('P') if self.state.current_tab == Tab::Images => {
+                self.state.open_form_dialog(
+                    FormDialogState::new(FormKind::PullImage, "Pull Image").with_text(
+                        "image",
+                        "Image",
+                        String::new(),
+                        "nginx:latest",
+                    ),
+                );
+                UiAction::None
+            }
+            KeyCode::Char(' ') if self.state.current_tab == Tab::Images => {
+                self.state.toggle_image_mark();
+                UiAction::None
+            }
+            KeyCode::Char('R') if self.state.current_tab == Tab::Images => {
+                UiAction::OpenRegistryBrowser
+            }
+            KeyCode::Char('S') if self.state.current_tab == Tab::Images => {
+                self.handle_image_save_action()
+            }
+            KeyCode::Char('X') if self.state.current_tab == Tab::Images => {
+                UiAction::OpenCleanupPlanner
+            }
+            KeyCode::Char('V') if self.state.current_tab == Tab::Images => {
+                self.state.open_form_dialog(
+                    FormDialogState::new(FormKind::ImportScanReports, "Import scan reports")
+                        .with_text("path", "Path", String::new(), "trivy.json or a directory"),
+                );
+                UiAction::None
+            }
+            KeyCode::Char('C') if self.state.current_tab == Tab::Images => {
+                self.handle_image_compare_action()
+            }
+            KeyCode::Char('v') if self.state.current_tab == Tab::Images => {
+                self.state.toggle_image_tree();
+                UiAction::None
+            }
+            KeyCode::Char('c') if self.state.current_tab == Tab::Images => {
+                self.handle_image_containers_action()
+            }
+            KeyCode::Esc
+                if self.state.current_tab == Tab::Containers
+                    && self.state.container_filter.is_some() =>
+            {
+                self.state.set_container_filter(None);
+                UiAction::None
+            }
+            KeyCode::Char('o') if self.state.current_tab == Tab::Images => {
+                UiAction::CheckImageUpdates
+            }
+            KeyCode::Char('A') if self.state.current_tab == Tab::Images => {
+                self.handle_pull_outdated_action()
+            }
+            KeyCode::Char('L') if self.state.current_tab == Tab::Images => {
+                self.state.open_form_dialog(
+                    FormDialogState::new(FormKind::LoadImages, "Load Images").with_text(
+                        "path",
+                        "Archive",
+                        String::new(),
+                        "images.tar",
+                    ),
+                );
+                UiAction::None
+            }
 
             // Volume actions (when on Volumes tab)
             KeyCode::Char('d') if self.state.current_tab == Tab::Volumes => {
@@ -221,8 +414,149 @@ impl UiApp {
             KeyCode::Char('p') if self.state.current_tab == Tab::Volumes => {
                 self.handle_volume_prune_action()
             }
+            KeyCode::Char('i') if self.state.current_tab == Tab::Volumes => {
+                match self.state.volumes.get(self.state.volume_list_selected) {
+                    Some(volume) => UiAction::ShowVolumeDetails(volume.name.clone()),
+                    None => UiAction::None,
+                }
+            }
+            KeyCode::Char(' ') if self.state.current_tab == Tab::Volumes => {
+                self.state.toggle_volume_mark();
+                UiAction::None
+            }
+            KeyCode::Char('O') if self.state.current_tab == Tab::Volumes => {
+                if self.state.mark_orphan_volumes() == 0 {
+                    self.state.add_notification(
+                        "No orphaned volumes",
+                        crate::core::NotificationLevel::Info,
+                    );
+                }
+                UiAction::None
+            }
+            KeyCode::Char('s') if self.state.current_tab == Tab::Volumes => {
+                self.state.cycle_volume_sort();
+                UiAction::None
+            }
+            KeyCode::Char('R') if self.state.current_tab == Tab::Volumes => {
+                self.state.reverse_volume_sort();
+                UiAction::None
+            }
+            KeyCode::Char('b') if self.state.current_tab == Tab::Volumes => {
+                match self.state.volumes.get(self.state.volume_list_selected) {
+                    Some(volume) => UiAction::BrowseVolume(volume.name.clone()),
+                    None => UiAction::None,
+                }
+            }
+            KeyCode::Char('S') if self.state.current_tab == Tab::Volumes => {
+                if let Some(volume) = self.state.volumes.get(self.state.volume_list_selected) {
+                    let path = format!(
+                        "{}-{}.tar.gz",
+                        volume.name,
+                        chrono::Local::now().format("%Y-%m-%d_%H-%M-%S")
+                    );
+                    self.state.open_form_dialog(
+                        FormDialogState::new(
+                            FormKind::BackupVolume(volume.name.clone()),
+                            format!("Back up {}", volume.name),
+                        )
+                        .with_text(
+                            "path",
+                            "Archive",
+                            path,
+                            "backup.tar.gz",
+                        ),
+                    );
+                }
+                UiAction::None
+            }
+            KeyCode::Char('L') if self.state.current_tab == Tab::Volumes => {
+                self.state.open_form_dialog(
+                    FormDialogState::new(FormKind::RestoreVolume, "Restore Volume")
+                        .with_text("path", "Archive", String::new(), "backup.tar.gz")
+                        .with_text(
+                            "volume",
+                            "Into volume",
+                            String::new(),
+                            "name from the backup",
+                        ),
+                );
+                UiAction::None
+            }
+            KeyCode::Char('c') if self.state.current_tab == Tab::Volumes => {
+                self.state.open_form_dialog(
+                    FormDialogState::new(FormKind::CreateVolume, "Create Volume")
+                        .with_text("name", "Name", String::new(), "generated if empty")
+                        .with_text("driver", "Driver", "local".to_string(), "")
+                        .with_text(
+                            "driver_opts",
+                            "Driver options",
+                            String::new(),
+                            "type=nfs o=addr=10.0.0.2,rw device=:/export",
+                        )
+                        .with_text("labels", "Labels", String::new(), "KEY=VALUE ..."),
+                );
+                UiAction::None
+            }
 
             // Network actions (when on Networks tab)
+            KeyCode::Char('i') if self.state.current_tab == Tab::Networks => {
+                match self.state.networks.get(self.state.network_list_selected) {
+                    Some(network) => {
+                        UiAction::ShowNetworkDetails(network.id.clone(), network.name.clone())
+                    }
+                    None => UiAction::None,
+                }
+            }
+            KeyCode::Char('v') if self.state.current_tab == Tab::Networks => {
+                self.state.network_topology_view = !self.state.network_topology_view;
+                UiAction::None
+            }
+            KeyCode::Char('c') if self.state.current_tab == Tab::Networks => {
+                self.state.open_form_dialog(
+                    FormDialogState::new(FormKind::CreateNetwork, "Create Network")
+                        .with_text("name", "Name", String::new(), "my-network")
+                        .with_text("driver", "Driver", "bridge".to_string(), "")
+                        .with_text(
+                            "subnet",
+                            "Subnet",
+                            String::new(),
+                            "172.28.0.0/16, automatic if empty",
+                        )
+                        .with_text("gateway", "Gateway", String::new(), "172.28.0.1")
+                        .with_text("ip_range", "IP range", String::new(), "172.28.5.0/24")
+                        .with_toggle("internal", "Internal (no external access)", false)
+                        .with_toggle("attachable", "Attachable", false)
+                        .with_toggle("ipv6", "Enable IPv6", false),
+                );
+                UiAction::None
+            }
+            KeyCode::Char('C') if self.state.current_tab == Tab::Networks => {
+                if let Some(network) = self.state.networks.get(self.state.network_list_selected) {
+                    let network = network.name.clone();
+                    self.open_network_connect_form(network, String::new());
+                }
+                UiAction::None
+            }
+            KeyCode::Char('D') if self.state.current_tab == Tab::Networks => {
+                if let Some(network) = self.state.networks.get(self.state.network_list_selected) {
+                    // Offer the first connected container
+                    let container = network
+                        .connected_containers
+                        .first()
+                        .map(|id| {
+                            self.state
+                                .all_containers
+                                .iter()
+                                .find(|c| &c.id == id)
+                                .and_then(|c| c.names.first().cloned())
+                                .unwrap_or_else(|| id.chars().take(12).collect())
+                        })
+                        .unwrap_or_default();
+                    let network = network.name.clone();
+                    self.open_network_disconnect_form(network, container);
+                }
+                UiAction::None
+            }
             KeyCode::Char('d') if self.state.current_tab == Tab::Networks => {
                 self.handle_network_remove_action()
             }
@@ -466,10 +800,246 @@ impl UiApp {
                 self.state.scroll_detail_view_down(9999);
                 UiAction::None
             }
+            // Generate docker run / compose
+            KeyCode::Char('g') => match &self.state.detail_view {
+                Some(view) => UiAction::GenerateRunSpec(view.container_id.clone()),
+                None => UiAction::None,
+            },
+            // Connect to / disconnect from a network
+            KeyCode::Char('C') => {
+                if let Some(view) = &self.state.detail_view {
+                    let container = view.container_name.clone();
+                    self.open_network_connect_form(String::new(), container);
+                }
+                UiAction::None
+            }
+            KeyCode::Char('D') => {
+                if let Some(view) = &self.state.detail_view {
+                    let container = view.container_name.clone();
+                    let network = view
+                        .details
+                        .as_ref()
+                        .and_then(|d| d.networks.first())
+                        .map(|n| n.name.clone())
+                        .unwrap_or_default();
+                    self.open_network_disconnect_form(network, container);
+                }
+                UiAction::None
+            }
             _ => UiAction::None,
         }
     }
 
+    /// Open the form to connect a container to a network; either may be
+    /// prefilled
+    fn open_network_connect_form(&mut self, network: String, container: String) {
+        self.state.open_form_dialog(
+            FormDialogState::new(FormKind::ConnectNetwork, "Connect to Network")
+                .with_text("network", "Network", network, "my-network")
+                .with_text("container", "Container", container, "name or ID")
+                .with_text("aliases", "Aliases", String::new(), "db database")
+                .with_text("ip", "Static IP", String::new(), "automatic if empty"),
+        );
+    }
+
+    /// Open the form to disconnect a container from a network
+    fn open_network_disconnect_form(&mut self, network: String, container: String) {
+        self.state.open_form_dialog(
+            FormDialogState::new(FormKind::DisconnectNetwork, "Disconnect from Network")
+                .with_text("network", "Network", network, "my-network")
+                .with_text("container", "Container", container, "name or ID")
+                .with_toggle("force", "Force", false),
+        );
+    }
+
+    /// Handle volume detail view keys
+    fn handle_volume_detail_view_key(&mut self, key: KeyEvent) -> UiAction {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.state.close_volume_detail_view(),
+            KeyCode::Up => self.state.scroll_volume_detail_view_up(3),
+            KeyCode::PageUp => self.state.scroll_volume_detail_view_up(10),
+            KeyCode::Down => self.state.scroll_volume_detail_view_down(3),
+            KeyCode::PageDown => self.state.scroll_volume_detail_view_down(10),
+            KeyCode::Home => self.state.scroll_volume_detail_view_up(9999),
+            KeyCode::End => self.state.scroll_volume_detail_view_down(9999),
+            _ => {}
+        }
+        UiAction::None
+    }
+
+    /// Handle network detail view keys
+    fn handle_network_detail_view_key(&mut self, key: KeyEvent) -> UiAction {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.state.close_network_detail_view(),
+            KeyCode::Up => self.state.scroll_network_detail_view_up(3),
+            KeyCode::PageUp => self.state.scroll_network_detail_view_up(10),
+            KeyCode::Down => self.state.scroll_network_detail_view_down(3),
+            KeyCode::PageDown => self.state.scroll_network_detail_view_down(10),
+            KeyCode::Home => self.state.scroll_network_detail_view_up(9999),
+            KeyCode::End => self.state.scroll_network_detail_view_down(9999),
+            _ => {}
+        }
+        UiAction::None
+    }
+
+    /// Handle build pane keys; closing a running build cancels it
+    fn handle_build_view_key(&mut self, key: KeyEvent) -> UiAction {
+        let done = self.state.build_view.as_ref().is_some_and(|v| v.done);
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc if done => self.state.close_build_view(),
+            KeyCode::Char('q') | KeyCode::Esc => return UiAction::CancelBuild,
+            KeyCode::Up | KeyCode::Char('k') => self.state.scroll_build_up(1),
+            KeyCode::Down | KeyCode::Char('j') => self.state.scroll_build_down(1),
+            KeyCode::PageUp => self.state.scroll_build_up(10),
+            KeyCode::PageDown => self.state.scroll_build_down(10),
+            KeyCode::Home => self.state.scroll_build_up(usize::MAX),
+            KeyCode::End => self.state.scroll_build_down(usize::MAX),
+            _ => {}
+        }
+        UiAction::None
+    }
+
+    /// Handle pull dialog keys; closing a running pull cancels it
+    fn handle_transfer_view_key(&mut self, key: KeyEvent) -> UiAction {
+        let done = self.state.transfer_view.as_ref().is_some_and(|v| v.done);
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc | KeyCode::Enter if done => {
+                self.state.close_transfer_view();
+                UiAction::None
+            }
+            KeyCode::Char('q') | KeyCode::Esc | KeyCode::Char('c') if !done => {
+                UiAction::CancelTransfer
+            }
+            _ => UiAction::None,
+        }
+    }
+
+    /// Handle generated `docker run` / compose viewer keys
+    fn handle_run_spec_view_key(&mut self, key: KeyEvent) -> UiAction {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.state.close_run_spec_view(),
+            KeyCode::Tab => self.state.toggle_run_spec_format(),
+            KeyCode::Char('y') => {
+                if let Some(view) = &self.state.run_spec_view {
+                    return UiAction::CopyToClipboard(view.current_text().to_string());
+                }
+            }
+            KeyCode::Char('s') => {
+                if let Some(view) = &self.state.run_spec_view {
+                    let form = FormDialogState::new(FormKind::SaveRunSpec, "Save to file")
+                        .with_text("path", "Path", view.default_file_name(), "");
+                    self.state.open_form_dialog(form);
+                }
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.state.scroll_run_spec_up(1),
+            KeyCode::Down | KeyCode::Char('j') => self.state.scroll_run_spec_down(1),
+            KeyCode::PageUp => self.state.scroll_run_spec_up(10),
+            KeyCode::PageDown => self.state.scroll_run_spec_down(10),
+            KeyCode::Home => self.state.scroll_run_spec_up(usize::MAX),
+            _ => {}
+        }
+        UiAction::None
+    }
+
+    /// Handle "why did it die" panel keys
+    fn handle_death_report_key(&mut self, key: KeyEvent) -> UiAction {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Char('w') | KeyCode::Esc => {
+                self.state.close_death_report();
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.state.scroll_death_report_up(1),
+            KeyCode::Down | KeyCode::Char('j') => self.state.scroll_death_report_down(1),
+            KeyCode::PageUp => self.state.scroll_death_report_up(10),
+            KeyCode::PageDown => self.state.scroll_death_report_down(10),
+            KeyCode::Home => self.state.scroll_death_report_up(usize::MAX),
+            KeyCode::End => self.state.scroll_death_report_down(9999),
+            _ => {}
+        }
+        UiAction::None
+    }
+
+    /// Handle exec copy mode keys (vi-style movement, search, selection)
+    fn handle_exec_copy_mode_key(&mut self, key: KeyEvent) -> UiAction {
+        let view_rows = crate::ui::components::exec_viewer::exec_view_rows();
+        let Some(copy) = self
+            .state
+            .exec_view
+            .as_mut()
+            .and_then(|v| v.copy_mode.as_mut())
+        else {
+            return UiAction::None;
+        };
+
+        // Typing a search pattern
+        if copy.search_input.is_some() {
+            let mut not_found = false;
+            match key.code {
+                KeyCode::Esc => copy.search_input = None,
+                KeyCode::Enter => {
+                    not_found = !copy.commit_search() && copy.search_pattern.is_some()
+                }
+                KeyCode::Backspace => {
+                    if let Some(input) = &mut copy.search_input {
+                        input.pop();
+                    }
+                }
+                KeyCode::Char(c) => {
+                    if let Some(input) = &mut copy.search_input {
+                        input.push(c);
+                    }
+                }
+                _ => {}
+            }
+            copy.scroll_to_cursor(view_rows);
+            if not_found {
+                self.state
+                    .add_notification("Pattern not found", crate::core::NotificationLevel::Warning);
+            }
+            return UiAction::None;
+        }
+
+        let half_page = (view_rows / 2).max(1);
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => {
+                self.state.exit_exec_copy_mode();
+                return UiAction::None;
+            }
+            KeyCode::Char('y') | KeyCode::Enter => {
+                let text = copy.selected_text();
+                self.state.exit_exec_copy_mode();
+                return UiAction::CopyToClipboard(text);
+            }
+            KeyCode::Char('u') if ctrl => copy.move_up(half_page),
+            KeyCode::Char('d') if ctrl => copy.move_down(half_page),
+            KeyCode::Char('b') if ctrl => copy.move_up(view_rows),
+            KeyCode::Char('f') if ctrl => copy.move_down(view_rows),
+            KeyCode::Char('h') | KeyCode::Left => copy.move_left(),
+            KeyCode::Char('l') | KeyCode::Right => copy.move_right(),
+            KeyCode::Char('k') | KeyCode::Up => copy.move_up(1),
+            KeyCode::Char('j') | KeyCode::Down => copy.move_down(1),
+            KeyCode::PageUp => copy.move_up(view_rows),
+            KeyCode::PageDown => copy.move_down(view_rows),
+            KeyCode::Char('w') => copy.word_forward(),
+            KeyCode::Char('b') => copy.word_backward(),
+            KeyCode::Char('0') | KeyCode::Home => copy.line_start(),
+            KeyCode::Char('$') | KeyCode::End => copy.line_end(),
+            KeyCode::Char('g') => copy.top(),
+            KeyCode::Char('G') => copy.bottom(),
+            KeyCode::Char('v') | KeyCode::Char(' ') => copy.toggle_selection(),
+            KeyCode::Char('/') => copy.start_search(),
+            KeyCode::Char('n') => {
+                copy.search_next();
+            }
+            KeyCode::Char('N') => {
+                copy.search_previous();
+            }
+            _ => {}
+        }
+        copy.scroll_to_cursor(view_rows);
+        UiAction::None
+    }
+
     /// Handle image detail view keys
     fn handle_image_detail_view_key(&mut self, key: KeyEvent) -> UiAction {
         match key.code {
@@ -500,6 +1070,297 @@ impl UiApp {
                 self.state.scroll_image_detail_view_down(9999);
                 UiAction::None
             }
+            // Explore layer contents
+            KeyCode::Char('l') => match &self.state.image_detail_view {
+                Some(view) => UiAction::ExploreLayers(view.image_id.clone()),
+                None => UiAction::None,
+            },
+            // Filter imported vulnerability findings
+            KeyCode::Char('v') => {
+                self.state.cycle_finding_severity();
+                UiAction::None
+            }
+            KeyCode::Char('/') => {
+                let current = match &self.state.image_detail_view {
+                    Some(view) if view.scan.is_some() => view.finding_package.clone(),
+                    _ => return UiAction::None,
+                };
+                self.state.open_form_dialog(
+                    FormDialogState::new(FormKind::FilterFindings, "Filter findings")
+                        .with_text("package", "Package", current, "openssl"),
+                );
+                UiAction::None
+            }
+            // Reconstruct a Dockerfile from the history
+            KeyCode::Char('f') => {
+                let dockerfile = self.state.image_detail_view.as_ref().and_then(|view| {
+                    let details = view.details.as_ref()?;
+                    let dockerfile =
+                        ReconstructedDockerfile::from_history(&view.image_name, &details.layers);
+                    Some((view.image_name.clone(), dockerfile.to_text()))
+                });
+                if let Some((name, text)) = dockerfile {
+                    self.state.open_dockerfile_view(name, text);
+                }
+                UiAction::None
+            }
+            _ => UiAction::None,
+        }
+    }
+
+    /// Handle cleanup planner keys; deleting asks for confirmation first
+    fn handle_cleanup_view_key(&mut self, key: KeyEvent) -> UiAction {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.state.close_cleanup_view(),
+            KeyCode::Tab | KeyCode::Right | KeyCode::Char('l') => {
+                self.state.cycle_cleanup_policy(true)
+            }
+            KeyCode::BackTab | KeyCode::Left | KeyCode::Char('h') => {
+                self.state.cycle_cleanup_policy(false)
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.state.scroll_cleanup_up(1),
+            KeyCode::Down | KeyCode::Char('j') => self.state.scroll_cleanup_down(1),
+            KeyCode::PageUp => self.state.scroll_cleanup_up(10),
+            KeyCode::PageDown => self.state.scroll_cleanup_down(10),
+            KeyCode::Char('r') => return UiAction::OpenCleanupPlanner,
+            KeyCode::Enter | KeyCode::Char('x') => {
+                let Some(view) = &self.state.cleanup_view else {
+                    return UiAction::None;
+                };
+                let Some(plan) = view.current() else {
+                    return UiAction::None;
+                };
+                if plan.candidates.is_empty() {
+                    self.state.add_notification(
+                        format!("Policy '{}' selects no images", plan.policy),
+                        crate::core::NotificationLevel::Info,
+                    );
+                    return UiAction::None;
+                }
+                self.state.confirm_dialog = Some(ConfirmAction {
+                    message: format!(
+                        "Delete {} image(s) selected by '{}' and reclaim {}?",
+                        plan.candidates.len(),
+                        plan.policy,
+                        format_bytes_size(plan.reclaimable())
+                    ),
+                    action: UiAction::RunCleanup(view.selected),
+                });
+            }
+            _ => {}
+        }
+        UiAction::None
+    }
+
+    /// Handle volume browser keys; closing removes the helper container
+    fn handle_volume_browser_key(&mut self, key: KeyEvent) -> UiAction {
+        let Some(browser) = &self.state.volume_browser else {
+            return UiAction::None;
+        };
+        if browser.entries.is_none() {
+            return match key.code {
+                KeyCode::Char('q') | KeyCode::Esc => UiAction::CloseVolumeBrowser,
+                _ => UiAction::None,
+            };
+        }
+
+        if let Some(preview) = &browser.preview {
+            match key.code {
+                KeyCode::Char('q')
+                | KeyCode::Esc
+                | KeyCode::Backspace
+                | KeyCode::Left
+                | KeyCode::Char('h') => self.state.close_volume_preview(),
+                KeyCode::Char('s') => {
+                    let path = preview.path.clone();
+                    self.open_volume_download_form(path);
+                }
+                KeyCode::Up | KeyCode::Char('k') => self.state.scroll_volume_preview_up(1),
+                KeyCode::Down | KeyCode::Char('j') => self.state.scroll_volume_preview_down(1),
+                KeyCode::PageUp => self.state.scroll_volume_preview_up(10),
+                KeyCode::PageDown => self.state.scroll_volume_preview_down(10),
+                KeyCode::Home => self.state.scroll_volume_preview_up(usize::MAX),
+                KeyCode::End => self.state.scroll_volume_preview_down(9999),
+                _ => {}
+            }
+            return UiAction::None;
+        }
+
+        let selected = browser.selected_entry().cloned();
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => return UiAction::CloseVolumeBrowser,
+            KeyCode::Up | KeyCode::Char('k') => self.state.previous_volume_entry(),
+            KeyCode::Down | KeyCode::Char('j') => self.state.next_volume_entry(),
+            KeyCode::Backspace | KeyCode::Left | KeyCode::Char('h') => {
+                self.state.leave_volume_dir()
+            }
+            KeyCode::Enter | KeyCode::Right | KeyCode::Char('l') => match selected {
+                Some(entry) if entry.is_dir() => self.state.enter_volume_dir(),
+                Some(entry) if entry.kind == crate::docker::VolumeEntryKind::File => {
+                    return UiAction::PreviewVolumeFile(entry.path);
+                }
+                Some(_) => self.state.add_notification(
+                    "Only regular files can be previewed",
+                    crate::core::NotificationLevel::Info,
+                ),
+                None => {}
+            },
+            KeyCode::Char('s') => {
+                if let Some(entry) = selected {
+                    self.open_volume_download_form(entry.path);
+                }
+            }
+            _ => {}
+        }
+        UiAction::None
+    }
+
+    /// Ask where to copy a volume path to
+    fn open_volume_download_form(&mut self, path: String) {
+        let title = format!("Download {}", path);
+        self.state.open_form_dialog(
+            FormDialogState::new(FormKind::DownloadVolumeEntry(path), title).with_text(
+                "directory",
+                "To directory",
+                ".".to_string(),
+                "",
+            ),
+        );
+    }
+
+    /// Handle reconstructed Dockerfile viewer keys
+    fn handle_dockerfile_view_key(&mut self, key: KeyEvent) -> UiAction {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.state.close_dockerfile_view(),
+            KeyCode::Char('y') => {
+                if let Some(view) = &self.state.dockerfile_view {
+                    return UiAction::CopyToClipboard(view.text.clone());
+                }
+            }
+            KeyCode::Char('s') => {
+                if let Some(view) = &self.state.dockerfile_view {
+                    let form = FormDialogState::new(FormKind::SaveDockerfile, "Save to file")
+                        .with_text("path", "Path", view.default_file_name(), "");
+                    self.state.open_form_dialog(form);
+                }
+            }
+            KeyCode::Up | KeyCode::Char('k') => self.state.scroll_dockerfile_up(1),
+            KeyCode::Down | KeyCode::Char('j') => self.state.scroll_dockerfile_down(1),
+            KeyCode::PageUp => self.state.scroll_dockerfile_up(10),
+            KeyCode::PageDown => self.state.scroll_dockerfile_down(10),
+            KeyCode::Home => self.state.scroll_dockerfile_up(usize::MAX),
+            KeyCode::End => self.state.scroll_dockerfile_down(9999),
+            _ => {}
+        }
+        UiAction::None
+    }
+
+    /// Handle image comparison keys
+    fn handle_image_diff_key(&mut self, key: KeyEvent) -> UiAction {
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc => self.state.close_image_diff(),
+            KeyCode::Up | KeyCode::Char('k') => self.state.scroll_image_diff_up(1),
+            KeyCode::Down | KeyCode::Char('j') => self.state.scroll_image_diff_down(1),
+            KeyCode::PageUp => self.state.scroll_image_diff_up(10),
+            KeyCode::PageDown => self.state.scroll_image_diff_down(10),
+            KeyCode::Home => self.state.scroll_image_diff_up(usize::MAX),
+            KeyCode::End => self.state.scroll_image_diff_down(9999),
+            _ => {}
+        }
+        UiAction::None
+    }
+
+    /// Handle registry browser keys
+    fn handle_registry_browser_key(&mut self, key: KeyEvent) -> UiAction {
+        let Some(browser) = &self.state.registry_browser else {
+            return UiAction::None;
+        };
+        let registry = browser.registry_selected;
+        let level = browser.level;
+        let selected = browser.selected_item().map(str::to_string);
+        let repository = browser.repository.clone().unwrap_or_default();
+        match key.code {
+            KeyCode::Char('q') => {
+                self.state.close_registry_browser();
+                UiAction::None
+            }
+            KeyCode::Esc | KeyCode::Backspace | KeyCode::Left | KeyCode::Char('h') => {
+                if !self.state.registry_browser_back() {
+                    self.state.close_registry_browser();
+                }
+                UiAction::None
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.state.registry_browser_move(false);
+                UiAction::None
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.state.registry_browser_move(true);
+                UiAction::None
+            }
+            KeyCode::Enter | KeyCode::Right | KeyCode::Char('l') => match (level, selected) {
+                (RegistryLevel::Registries, Some(_)) => UiAction::BrowseRegistry(registry),
+                (RegistryLevel::Repositories, Some(repo)) => {
+                    UiAction::BrowseRepository(registry, repo)
+                }
+                (RegistryLevel::Tags, Some(tag)) => {
+                    UiAction::InspectRegistryTag(registry, repository, tag)
+                }
+                _ => UiAction::None,
+            },
+            // Registries without a catalog (e.g. Docker Hub) are browsed by name
+            KeyCode::Char('/') if level != RegistryLevel::Tags => {
+                self.state.open_form_dialog(
+                    FormDialogState::new(FormKind::OpenRepository(registry), "Open Repository")
+                        .with_text("repository", "Repository", String::new(), "library/nginx"),
+                );
+                UiAction::None
+            }
+            KeyCode::Char('p') if level == RegistryLevel::Tags => match selected {
+                Some(tag) => UiAction::PullRegistryTag(registry, repository, tag),
+                None => UiAction::None,
+            },
+            _ => UiAction::None,
+        }
+    }
+
+    /// Handle layer explorer keys
+    fn handle_layer_explorer_key(&mut self, key: KeyEvent) -> UiAction {
+        let loading = self
+            .state
+            .layer_explorer
+            .as_ref()
+            .is_some_and(|e| e.analysis.is_none());
+        match key.code {
+            KeyCode::Char('q') | KeyCode::Esc if loading => UiAction::CancelLayerAnalysis,
+            KeyCode::Char('q') | KeyCode::Esc => {
+                self.state.close_layer_explorer();
+                UiAction::None
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.state.previous_layer();
+                UiAction::None
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.state.next_layer();
+                UiAction::None
+            }
+            KeyCode::PageUp => {
+                self.state.scroll_layer_explorer_up(10);
+                UiAction::None
+            }
+            KeyCode::PageDown => {
+                self.state.scroll_layer_explorer_down(10);
+                UiAction::None
+            }
+            KeyCode::Home => {
+                self.state.scroll_layer_explorer_up(usize::MAX);
+                UiAction::None
+            }
+            KeyCode::Tab => {
+                self.state.toggle_layer_wasted();
+                UiAction::None
+            }
             _ => UiAction::None,
         }
     }
@@ -548,6 +1409,51 @@ impl UiApp {
         }
     }
 
+    /// Handle form dialog keys
+    fn handle_form_dialog_key(&mut self, key: KeyEvent) -> UiAction {
+        let Some(form) = &mut self.state.form_dialog else {
+            return UiAction::None;
+        };
+        match key.code {
+            KeyCode::Esc => {
+                self.state.close_form_dialog();
+                UiAction::None
+            }
+            KeyCode::Enter => UiAction::SubmitForm,
+            KeyCode::Tab | KeyCode::Down => {
+                form.next_field();
+                UiAction::None
+            }
+            KeyCode::BackTab | KeyCode::Up => {
+                form.previous_field();
+                UiAction::None
+            }
+            KeyCode::Backspace => {
+                form.backspace();
+                UiAction::None
+            }
+            KeyCode::Char(c) => {
+                form.input_char(c);
+                UiAction::None
+            }
+            _ => UiAction::None,
+        }
+    }
+
+    /// Handle keys while a recording is replayed in the exec pane
+    fn handle_exec_playback_key(&mut self, key: KeyEvent) -> UiAction {
+        match key.code {
+            KeyCode::Char('e') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                self.state.toggle_exec_focus();
+                UiAction::None
+            }
+            KeyCode::Char(' ') | KeyCode::Char('p') => UiAction::TogglePlaybackPause,
+            KeyCode::Char('c') => UiAction::EnterExecCopyMode,
+            KeyCode::Char('q') | KeyCode::Esc => UiAction::StopPlayback,
+            _ => UiAction::None,
+        }
+    }
+
     /// Handle keys when in log search input mode
     fn handle_log_search_key(&mut self, key: KeyEvent) -> UiAction {
         use ratatui::crossterm::event::KeyCode;
@@ -761,6 +1667,28 @@ impl UiApp {
             UiAction::None
         }
     }
+
+    /// Handle debug sidecar action
+    fn handle_debug_action(&mut self) -> UiAction {
+        if let Some(container) = self
+            .state
+            .containers
+            .get(self.state.container_list_selected)
+        {
+            if container.state == ContainerState::Running {
+                UiAction::DebugContainer(container.id.clone())
+            } else {
+                self.state.add_notification(
+                    "Debug sidecar needs a running container",
+                    crate::core::NotificationLevel::Warning,
+                );
+                UiAction::None
+            }
+        } else {
+            UiAction::None
+        }
+    }
+
     /// Handle inspect action
     fn handle_inspect_action(&mut self) -> UiAction {
         if let Some(container) = self
@@ -777,9 +1705,57 @@ impl UiApp {
         }
     }
 
+    /// Handle "why did it die" action
+    fn handle_death_report_action(&mut self) -> UiAction {
+        if let Some(container) = self
+            .state
+            .containers
+            .get(self.state.container_list_selected)
+        {
+            UiAction::ShowDeathReport(container.id.clone())
+        } else {
+            UiAction::None
+        }
+    }
+
+    /// Handle recreate action (the form is opened once the container is
+    /// inspected)
+    fn handle_recreate_action(&mut self) -> UiAction {
+        if let Some(container) = self
+            .state
+            .containers
+            .get(self.state.container_list_selected)
+        {
+            UiAction::OpenRecreateForm(container.id.clone())
+        } else {
+            UiAction::None
+        }
+    }
+
     /// Handle image remove action (with confirmation)
     fn handle_image_remove_action(&mut self) -> UiAction {
         if let Some(image) = self.state.images.get(self.state.image_list_selected) {
+            // The daemon refuses to remove images that are still in use
+            let (containers, children) = self.state.image_dependents(&image.id);
+            if !containers.is_empty() || !children.is_empty() {
+                let mut uses = Vec::new();
+                if !containers.is_empty() {
+                    uses.push(format!("containers {}", containers.join(", ")));
+                }
+                if !children.is_empty() {
+                    uses.push(format!("child images {}", children.join(", ")));
+                }
+                self.state.add_notification(
+                    format!(
+                        "'{}' is used by {} ([c] shows its containers)",
+                        crate::state::image_tree::image_name(image),
+                        uses.join(" and ")
+                    ),
+                    crate::core::NotificationLevel::Warning,
+                );
+                return UiAction::None;
+            }
+
             let name = if image.dangling {
                 "<dangling>".to_string()
             } else {
@@ -808,6 +1784,52 @@ impl UiApp {
         UiAction::None
     }
 
+    /// Compare the two marked images, older first
+    fn handle_image_compare_action(&mut self) -> UiAction {
+        let mut marked: Vec<&crate::core::ImageSummary> = self
+            .state
+            .images
+            .iter()
+            .filter(|i| self.state.marked_images.contains(&i.id))
+            .collect();
+        if marked.len() != 2 {
+            self.state.add_notification(
+                "Mark two images with Space to compare them",
+                crate::core::NotificationLevel::Info,
+            );
+            return UiAction::None;
+        }
+        marked.sort_by_key(|image| image.created);
+        UiAction::CompareImages(marked[0].id.clone(), marked[1].id.clone())
+    }
+
+    /// Show the Containers tab with only the containers of the selected
+    /// image and of images built on it
+    fn handle_image_containers_action(&mut self) -> UiAction {
+        let Some(image) = self.state.images.get(self.state.image_list_selected) else {
+            return UiAction::None;
+        };
+        let filter = crate::state::ContainerFilter::ancestor(&self.state.images, &image.id);
+        self.state.set_container_filter(Some(filter));
+        self.switch_tab(Tab::Containers);
+        UiAction::None
+    }
+
+    /// Confirm pulling every outdated tag
+    fn handle_pull_outdated_action(&mut self) -> UiAction {
+        let tags = self.state.outdated_tags();
+        if tags.is_empty() {
+            self.state
+                .add_notification("No outdated images", crate::core::NotificationLevel::Info);
+            return UiAction::None;
+        }
+        self.state.confirm_dialog = Some(ConfirmAction {
+            message: format!("Pull {} outdated image(s)?", tags.len()),
+            action: UiAction::PullOutdated(tags),
+        });
+        UiAction::None
+    }
+
     /// Handle image inspect action
     fn handle_image_inspect_action(&mut self) -> UiAction {
         if let Some(image) = self.state.images.get(self.state.image_list_selected) {
@@ -818,9 +1840,113 @@ impl UiApp {
         }
     }
 
-    /// Handle volume remove action (with confirmation)
+    /// Open the form to add (or with `untag`, remove) a tag on the selected image
+    fn handle_image_tag_action(&mut self, untag: bool) -> UiAction {
+        let Some(image) = self.state.images.get(self.state.image_list_selected) else {
+            return UiAction::None;
+        };
+        let tags: Vec<String> = image
+            .repo_tags
+            .iter()
+            .filter(|t| !t.contains("<none>"))
+            .cloned()
+            .collect();
+        let form = if untag {
+            if tags.is_empty() {
+                self.state.add_notification(
+                    "Image has no tags to remove",
+                    crate::core::NotificationLevel::Warning,
+                );
+                return UiAction::None;
+            }
+            FormDialogState::new(
+                FormKind::UntagImage(image.id.clone()),
+                format!("Remove tag ({})", tags.join(", ")),
+            )
+            .with_text("tag", "Tag", tags[0].clone(), "")
+        } else {
+            FormDialogState::new(
+                FormKind::TagImage(image.id.clone()),
+                format!("Tag {}", tags.first().unwrap_or(&image.short_id)),
+            )
+            .with_text("tag", "New tag", String::new(), "registry/repo:tag")
+        };
+        self.state.open_form_dialog(form);
+        UiAction::None
+    }
+
+    /// Open the push form for the selected image, prefilled with its first tag
+    fn handle_image_push_action(&mut self) -> UiAction {
+        let Some(image) = self.state.images.get(self.state.image_list_selected) else {
+            return UiAction::None;
+        };
+        let tag = image
+            .repo_tags
+            .iter()
+            .find(|t| !t.contains("<none>"))
+            .cloned()
+            .unwrap_or_default();
+        self.state.open_form_dialog(
+            FormDialogState::new(FormKind::PushImage, "Push Image").with_text(
+                "tag",
+                "Tag",
+                tag,
+                "localhost:5000/repo:tag",
+            ),
+        );
+        UiAction::None
+    }
+
+    /// Open the save dialog for the marked images, or the selected one
+    fn handle_image_save_action(&mut self) -> UiAction {
+        let names: Vec<String> = self
+            .state
+            .images
+            .iter()
+            .enumerate()
+            .filter(|(index, image)| {
+                if self.state.marked_images.is_empty() {
+                    *index == self.state.image_list_selected
+                } else {
+                    self.state.marked_images.contains(&image.id)
+                }
+            })
+            // Saving by tag keeps the tag in the archive
+            .map(|(_, image)| {
+                image
+                    .repo_tags
+                    .iter()
+                    .find(|t| !t.contains("<none>"))
+                    .cloned()
+                    .unwrap_or_else(|| image.id.clone())
+            })
+            .collect();
+        if names.is_empty() {
+            return UiAction::None;
+        }
+        let title = match names.as_slice() {
+            [name] => format!("Save {}", name),
+            _ => format!("Save {} images", names.len()),
+        };
+        self.state.open_form_dialog(
+            FormDialogState::new(FormKind::SaveImages(names), title)
+                .with_text("path", "Archive", "images.tar", "images.tar")
+                .with_toggle("gzip", "Gzip compress", false),
+        );
+        UiAction::None
+    }
+
+    /// Handle volume remove action (with confirmation), for the marked
+    /// volumes if there are any
     fn handle_volume_remove_action(&mut self) -> UiAction {
-        if let Some(volume) = self.state.volumes.get(self.state.volume_list_selected) {
+        if !self.state.marked_volumes.is_empty() {
+            let mut names: Vec<String> = self.state.marked_volumes.iter().cloned().collect();
+            names.sort();
+            self.state.confirm_dialog = Some(ConfirmAction {
+                message: format!("Remove {} marked volumes", names.len()),
+                action: UiAction::RemoveVolumes(names),
+            });
+        } else if let Some(volume) = self.state.volumes.get(self.state.volume_list_selected) {
             let name = volume.name.clone();
 
             self.state.confirm_dialog = Some(ConfirmAction {
@@ -917,45 +2043,143 @@ impl UiApp {
         // Full screen clear first to prevent any ghost content
         frame.render_widget(Clear, area);
 
-        // Create the main layout
-        let main_layout = Layout::default()
-            .direction(Direction::Vertical)
-            .constraints([
-                Constraint::Length(1), // Header
-                Constraint::Min(3),    // Main content (at least 3 lines)
-                Constraint::Length(1), // Footer
-            ])
-            .split(area);
+        // Create the main layout
+        let main_layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(1), // Header
+                Constraint::Min(3),    // Main content (at least 3 lines)
+                Constraint::Length(1), // Footer
+            ])
+            .split(area);
+
+        // Render components
+        self.render_header(frame, main_layout[0]);
+        self.render_main_content(frame, main_layout[1]);
+        self.render_footer(frame, main_layout[2]);
+
+        // Render notification overlay (if any)
+        if let Some(notif) = self.state.notifications.last() {
+            self.render_notification(frame, area, notif);
+        }
+
+        // Render log viewer if active (on top of everything)
+        if let Some(ref log_view) = self.state.log_view {
+            crate::ui::components::log_viewer::render_log_viewer(frame, area, log_view);
+        }
+
+        // Render detail viewer if active (on top of everything)
+        if let Some(ref detail_view) = self.state.detail_view {
+            crate::ui::components::detail_viewer::render_detail_viewer(frame, area, detail_view);
+        }
+
+        // Render image detail viewer if active (on top of everything)
+        if let Some(ref image_detail_view) = self.state.image_detail_view {
+            crate::ui::components::image_detail_viewer::render_image_detail_viewer(
+                frame,
+                area,
+                image_detail_view,
+            );
+        }
+
+        // Render the volume and network detail viewers
+        if let Some(ref volume_detail_view) = self.state.volume_detail_view {
+            crate::ui::components::volume_detail_viewer::render_volume_detail_viewer(
+                frame,
+                area,
+                volume_detail_view,
+            );
+        }
+        if let Some(ref network_detail_view) = self.state.network_detail_view {
+            crate::ui::components::network_detail_viewer::render_network_detail_viewer(
+                frame,
+                area,
+                network_detail_view,
+            );
+        }
+
+        // Render the image comparison
+        if let Some(ref image_diff_view) = self.state.image_diff_view {
+            crate::ui::components::image_diff_viewer::render_image_diff_viewer(
+                frame,
+                area,
+                image_diff_view,
+            );
+        }
+
+        // Render the reconstructed Dockerfile over the image detail viewer
+        if let Some(ref dockerfile_view) = self.state.dockerfile_view {
+            crate::ui::components::dockerfile_viewer::render_dockerfile_viewer(
+                frame,
+                area,
+                dockerfile_view,
+            );
+        }
+
+        // Render the layer explorer over the image detail viewer
+        if let Some(ref layer_explorer) = self.state.layer_explorer {
+            crate::ui::components::layer_explorer::render_layer_explorer(
+                frame,
+                area,
+                layer_explorer,
+            );
+        }
+
+        // Render generated docker run / compose over the detail viewer
+        if let Some(ref run_spec_view) = self.state.run_spec_view {
+            crate::ui::components::run_spec_viewer::render_run_spec_viewer(
+                frame,
+                area,
+                run_spec_view,
+            );
+        }
 
-        // Render components
-        self.render_header(frame, main_layout[0]);
-        self.render_main_content(frame, main_layout[1]);
-        self.render_footer(frame, main_layout[2]);
+        // Render the image cleanup planner
+        if let Some(ref cleanup_view) = self.state.cleanup_view {
+            crate::ui::components::cleanup_planner::render_cleanup_planner(
+                frame,
+                area,
+                cleanup_view,
+            );
+        }
 
-        // Render notification overlay (if any)
-        if let Some(notif) = self.state.notifications.last() {
-            self.render_notification(frame, area, notif);
+        // Render the volume browser
+        if let Some(ref volume_browser) = self.state.volume_browser {
+            crate::ui::components::volume_browser::render_volume_browser(
+                frame,
+                area,
+                volume_browser,
+            );
         }
 
-        // Render log viewer if active (on top of everything)
-        if let Some(ref log_view) = self.state.log_view {
-            crate::ui::components::log_viewer::render_log_viewer(frame, area, log_view);
+        // Render the registry browser if active
+        if let Some(ref registry_browser) = self.state.registry_browser {
+            crate::ui::components::registry_browser::render_registry_browser(
+                frame,
+                area,
+                registry_browser,
+            );
         }
 
-        // Render detail viewer if active (on top of everything)
-        if let Some(ref detail_view) = self.state.detail_view {
-            crate::ui::components::detail_viewer::render_detail_viewer(frame, area, detail_view);
+        // Render image build output if active
+        if let Some(ref build_view) = self.state.build_view {
+            crate::ui::components::build_viewer::render_build_viewer(frame, area, build_view);
         }
 
-        // Render image detail viewer if active (on top of everything)
-        if let Some(ref image_detail_view) = self.state.image_detail_view {
-            crate::ui::components::image_detail_viewer::render_image_detail_viewer(
+        // Render image pull progress if active
+        if let Some(ref transfer_view) = self.state.transfer_view {
+            crate::ui::components::transfer_dialog::render_transfer_dialog(
                 frame,
                 area,
-                image_detail_view,
+                transfer_view,
             );
         }
 
+        // Render "why did it die" panel if active
+        if let Some(ref death_report) = self.state.death_report {
+            crate::ui::components::death_report::render_death_report(frame, area, death_report);
+        }
+
         // Render confirmation dialog if active
         if let Some(ref confirm) = self.state.confirm_dialog {
             self.render_confirmation_dialog(frame, area, confirm);
@@ -966,6 +2190,11 @@ impl UiApp {
             self.render_prune_dialog(frame, area);
         }
 
+        // Render form dialog if active
+        if let Some(ref form) = self.state.form_dialog {
+            crate::ui::components::form_dialog::render_form_dialog(frame, area, form);
+        }
+
         // Render help overlay if active (on top of everything except notifications)
         if self.state.show_help {
             self.render_help_overlay(frame, area);
@@ -1178,7 +2407,7 @@ impl UiApp {
             ("○", Color::Red)
         };
 
-        let header_spans = vec![
+        let mut header_spans = vec![
             Span::styled(
                 " 🐳 Contui ",
                 Style::default()
@@ -1208,6 +2437,21 @@ impl UiApp {
             ),
         ];
 
+        // Running background jobs
+        for job in &self.state.jobs {
+            let progress = match job.percent() {
+                Some(percent) => {
+                    format!("{}% {}", percent, format_bytes_size(job.done_bytes as i64))
+                }
+                None => format_bytes_size(job.done_bytes as i64),
+            };
+            header_spans.push(Span::raw("| "));
+            header_spans.push(Span::styled(
+                format!("⟳ {} {} ", job.label, progress),
+                Style::default().fg(Color::Cyan),
+            ));
+        }
+
         let header = Line::from(header_spans);
         frame.render_widget(
             Paragraph::new(header).style(Style::default().bg(Color::Black)),
@@ -1257,7 +2501,9 @@ impl UiApp {
         let bg = Block::default().style(Style::default().bg(Color::Black));
         frame.render_widget(bg, area);
 
-        let mut widget = crate::ui::components::VolumeListWidget::new(self.state.volumes.clone());
+        let mut widget = crate::ui::components::VolumeListWidget::new(self.state.volumes.clone())
+            .with_marked(self.state.marked_volumes.clone())
+            .with_sort(self.state.volume_sort, self.state.volume_sort_direction);
         if !self.state.volumes.is_empty() {
             widget.set_selected(Some(self.state.volume_list_selected));
         }
@@ -1273,6 +2519,17 @@ impl UiApp {
         let bg = Block::default().style(Style::default().bg(Color::Black));
         frame.render_widget(bg, area);
 
+        if self.state.network_topology_view {
+            crate::ui::components::render_network_topology(
+                frame,
+                area,
+                &self.state.networks,
+                &self.state.all_containers,
+                self.state.network_list_selected,
+            );
+            return;
+        }
+
         let mut widget = crate::ui::components::NetworkListWidget::new(self.state.networks.clone());
         if !self.state.networks.is_empty() {
             widget.set_selected(Some(self.state.network_list_selected));
@@ -1289,8 +2546,22 @@ impl UiApp {
         let bg = Block::default().style(Style::default().bg(Color::Black));
         frame.render_widget(bg, area);
 
+        if self.state.image_tree_view {
+            crate::ui::components::render_image_tree(
+                frame,
+                area,
+                &self.state.images,
+                &self.state.all_containers,
+                self.state.image_list_selected,
+            );
+            return;
+        }
+
         // Create image list widget
-        let mut widget = crate::ui::components::ImageListWidget::new(self.state.images.clone());
+        let mut widget = crate::ui::components::ImageListWidget::new(self.state.images.clone())
+            .with_marked(self.state.marked_images.clone())
+            .with_outdated(self.state.outdated_images())
+            .with_vulnerabilities(self.state.image_scan_counts());
         if !self.state.images.is_empty() {
             widget.set_selected(Some(self.state.image_list_selected));
         }
@@ -1339,7 +2610,15 @@ impl UiApp {
         };
 
         // Create container list
-        let mut widget = ContainerListWidget::new(self.state.containers.clone());
+        let mut widget = ContainerListWidget::new(self.state.containers.clone())
+            .with_crash_loops(self.state.restart_tracker.crash_looping(chrono::Utc::now()))
+            .with_outdated(self.state.outdated_containers())
+            .with_filter(
+                self.state
+                    .container_filter
+                    .as_ref()
+                    .map(|filter| filter.label.clone()),
+            );
         if !self.state.containers.is_empty() {
             widget.set_selected(Some(self.state.container_list_selected));
         }
@@ -1587,22 +2866,50 @@ impl UiApp {
     fn render_footer(&self, frame: &mut Frame, area: Rect) {
         let base_text = if self.state.log_view.is_some() {
             Cow::Borrowed(" [↑/↓]Scroll [r]Refresh [f]Follow [/]Search [s]Save [q]Close ")
-        } else if self.state.detail_view.is_some() {
-            Cow::Borrowed(" [↑/↓]Scroll [q]Close ")
-        } else if self.state.image_detail_view.is_some() {
+        } else if self.state.dockerfile_view.is_some() {
+            Cow::Borrowed(" [↑/↓]Scroll [y]Copy [s]Save [q]Close ")
+        } else if self.state.detail_view.is_some()
+            || self.state.image_detail_view.is_some()
+            || self.state.volume_detail_view.is_some()
+            || self.state.network_detail_view.is_some()
+            || self.state.image_diff_view.is_some()
+        {
             Cow::Borrowed(" [↑/↓]Scroll [q]Close ")
         } else if self.state.confirm_dialog.is_some() {
             Cow::Borrowed(" [y]Yes [n]No ")
+        } else if self.state.form_dialog.is_some() {
+            Cow::Borrowed(" [Enter]Submit [Tab]Next field [Esc]Cancel ")
         } else if self.state.show_help {
             Cow::Borrowed(" Press any key to close help ")
+        } else if self
+            .state
+            .exec_view
+            .as_ref()
+            .is_some_and(|v| v.copy_mode.is_some())
+        {
+            Cow::Borrowed(" [hjkl/wb]Move [v]Select [y]Copy [/]Search [n/N]Next/Prev [q]Exit ")
+        } else if self
+            .state
+            .exec_view
+            .as_ref()
+            .is_some_and(|v| v.playback && v.focus)
+        {
+            Cow::Borrowed(" [Space]Pause/Resume [c]Copy mode [q]Stop replay ")
+        } else if self.state.current_tab == Tab::Containers && self.state.container_filter.is_some()
+        {
+            Cow::Borrowed(" [↑/↓]Select [l]Logs [i]Inspect [x]Exec [d]Delete [Esc]Clear filter [?]Help [q]Quit ")
         } else if self.state.current_tab == Tab::Containers && !self.state.containers.is_empty() {
-            Cow::Borrowed(" [↑/↓]Select [s]Start [p]Pause [r]Restart [k]Kill [d]Delete [l]Logs [m]Stats [i]Inspect [x]Exec [?]Help [q]Quit ")
+            Cow::Borrowed(" [↑/↓]Select [s]Start [p]Pause [r]Restart [k]Kill [d]Delete [l]Logs [m]Stats [i]Inspect [x]Exec [X]Debug [w]Why died [u]Recreate [?]Help [q]Quit ")
         } else if self.state.current_tab == Tab::Images && !self.state.images.is_empty() {
-            Cow::Borrowed(" [↑/↓]Select [d]Delete [p]Prune [i]Inspect [?]Help [q]Quit ")
-        } else if self.state.current_tab == Tab::Volumes && !self.state.volumes.is_empty() {
-            Cow::Borrowed(" [↑/↓]Select [d]Delete [p]Prune [?]Help [q]Quit ")
+            Cow::Borrowed(
+                " [↑/↓]Select [d]Delete [p/X]Prune/Cleanup [i]Inspect [t/T]Tag/Untag [U]Push [P]Pull [b]Build [Space]Mark [C]Compare [V]Scans [v]Tree [c]Containers [S/L]Save/Load [R]Registries [o/A]Updates/Pull outdated [?]Help [q]Quit ",
+            )
+        } else if self.state.current_tab == Tab::Volumes {
+            Cow::Borrowed(
+                " [↑/↓]Select [i]Inspect [b]Browse [c]Create [d]Delete [Space]Mark [O]Mark orphans [p]Prune [s/R]Sort/Reverse [S/L]Backup/Restore [?]Help [q]Quit ",
+            )
         } else if self.state.current_tab == Tab::Networks && !self.state.networks.is_empty() {
-            Cow::Borrowed(" [↑/↓]Select [d]Delete [p]Prune [?]Help [q]Quit ")
+            Cow::Borrowed(" [↑/↓]Select [i]Inspect [c]Create [C/D]Connect/Disconnect [v]Topology [d]Delete [p]Prune [?]Help [q]Quit ")
         } else {
             Cow::Borrowed(" [←/→ or 1-6]:Switch Tabs | [?]:Help | [q]:Quit ")
         };
@@ -1611,6 +2918,8 @@ impl UiApp {
             && self.state.log_view.is_none()
             && self.state.detail_view.is_none()
             && self.state.image_detail_view.is_none()
+            && self.state.volume_detail_view.is_none()
+            && self.state.network_detail_view.is_none()
             && self.state.confirm_dialog.is_none()
             && !self.state.show_help
         {
@@ -1656,20 +2965,61 @@ Containers Tab:
   m                Toggle stats panel
   i                Inspect container (detailed info)
   x                Exec into container
+  X                Debug shell in a toolbox sidecar
+  w                Why did it die (exit code, OOM, last logs)
+  u                Recreate container (optionally pull newer image)
+  P                Replay exec recording
+  Esc              Clear the image filter (set with c on Images)
 
 Images Tab:
   ↑/↓ or j/k       Select image
   d                Delete image
   p                Prune dangling images
   i                Inspect image (detailed info)
+  P                Pull image (Esc cancels a running pull)
+  b                Build image from a context directory
+  t / T            Add / remove a tag
+  U                Push a tag to its registry
+  Space            Mark / unmark image
+  C                Compare the two marked images
+  V                Import Trivy/Grype scan reports
+  X                Plan cleanup with the configured policies
+  v                Toggle tree view (parent/child images, containers)
+  c                Show containers using the image
+  S                Save marked (or selected) images to a tar
+  L                Load images from a tar archive
+  R                Browse registries (repositories and tags)
+  o                Check tags for newer registry digests (↑ = outdated)
+  A                Pull all outdated images
 
 Volumes Tab:
   ↑/↓ or j/k       Select volume
-  d                Delete volume
+  i                Inspect volume (options, labels, containers)
+  b                Browse files (through a read-only helper container)
+  c                Create volume (driver, options, labels)
+  S                Back up volume to a .tar.gz (with a .json manifest)
+  L                Restore a backup into a new or existing volume
+  Space            Mark volume for batch delete
+  O                Mark all orphaned volumes (anonymous, no container)
+  d                Delete marked (or selected) volumes
+  s                Sort by name, size, ref count or creation time
+  R                Reverse sort order
   p                Prune unused volumes
 
+Volume Browser:
+  ↑/↓ or j/k       Select entry
+  Enter or →/l     Open directory or preview file
+  Backspace or ←/h Parent directory (or close the preview)
+  s                Download file or directory
+  q or Esc         Close and remove the helper container
+
 Networks Tab:
   ↑/↓ or j/k       Select network
+  i                Inspect network (IPAM, options, container addresses)
+  c                Create network (driver, subnet, gateway, IP range)
+  C                Connect a container (aliases, static IP)
+  D                Disconnect a container
+  v                Toggle topology graph (containers, IPs, host ports)
   d                Delete network
   p                Prune unused networks
 
@@ -1693,17 +3043,76 @@ Stats Panel (toggle with 'm'):
 
 Exec Pane:
   Ctrl+E           Toggle focus between UI and exec
+  Shift+PgUp       Enter copy mode (scrollback)
+  c                Enter copy mode (Containers tab)
+  R                Start/stop recording, asciicast .cast (Containers tab)
+  Space / q        Pause / stop replay
+
+Exec Copy Mode:
+  h/j/k/l or ←↓↑→  Move cursor
+  w/b  0/$  g/G    Word, line start/end, top/bottom
+  Ctrl+U/Ctrl+D    Half page up/down
+  v or Space       Start/clear selection
+  y or Enter       Copy selection (or line) to clipboard
+  / then n/N       Search, next/previous match
+  q or Esc         Leave copy mode
 
 Detail View (inspect container):
   ↑/↓ or PgUp/PgDn Scroll
   Home/End         Jump to top/bottom
+  g                Generate docker run / compose service
+  C                Connect the container to a network
+  D                Disconnect it from a network
   q or Esc         Close detail view
 
+Run Command View:
+  Tab              Switch docker run / compose
+  y                Copy to clipboard
+  s                Save to file
+  q or Esc         Close
+
 Image Detail View (inspect image):
   ↑/↓ or PgUp/PgDn Scroll
   Home/End         Jump to top/bottom
+  l                Explore layer contents
+  f                Reconstruct an approximate Dockerfile
+  v                Cycle the minimum finding severity
+  /                Filter findings by package
   q or Esc         Close detail view
 
+Image Cleanup:
+  Tab or ←/→       Switch policy
+  ↑/↓ or j/k       Scroll
+  Enter or x       Delete the listed images (asks first)
+  r                Re-plan
+  q or Esc         Close
+
+Dockerfile View:
+  ↑/↓ or j/k       Scroll
+  y                Copy to clipboard
+  s                Save to file
+  q or Esc         Close
+
+Registry Browser:
+  ↑/↓ or j/k       Select
+  Enter            Open registry / repository / tag details
+  Esc or Backspace Go back
+  /                Open a repository by name
+  p                Pull the selected tag
+  q                Close
+
+Image Comparison:
+  ↑/↓ or j/k       Scroll
+  PgUp/PgDn        Page
+  Home/End         Jump to top/bottom
+  q or Esc         Close
+
+Layer Explorer:
+  ↑/↓ or j/k       Select layer
+  PgUp/PgDn        Scroll file tree
+  Tab              Switch file tree / wasted space
+  q or Esc         Close (cancels a running analysis)
+
 Global:
   q or Ctrl+C      Quit
   ? or h           Toggle help
@@ -1883,6 +3292,9 @@ mod tests {
             status: "Starting".into(),
             screen_lines: vec![],
             cursor: None,
+            copy_mode: None,
+            recording: false,
+            playback: false,
         });
 
         let app = UiApp::new(state);
@@ -1905,4 +3317,777 @@ mod tests {
             "expected footer to include exec focus hint"
         );
     }
+
+    #[test]
+    fn recreate_key_requests_the_form() {
+        let state = AppState {
+            current_tab: Tab::Containers,
+            containers: vec![crate::core::ContainerSummary {
+                id: "abc".into(),
+                names: vec!["web".into()],
+                image: "sha256:0123456789ab".into(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        // The image comes from the inspect, not the list's (maybe ID) image
+        let mut app = UiApp::new(state);
+        let action = app.handle_key_event(KeyEvent::from(KeyCode::Char('u')));
+        assert!(matches!(action, UiAction::OpenRecreateForm(id) if id == "abc"));
+        assert!(app.state.form_dialog.is_none());
+    }
+
+    #[test]
+    fn image_tag_and_push_forms() {
+        let state = AppState {
+            current_tab: Tab::Images,
+            images: vec![crate::core::ImageSummary {
+                id: "sha256:abc".into(),
+                short_id: "abc".into(),
+                repo_tags: vec!["app:1".into(), "app:latest".into()],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let mut app = UiApp::new(state);
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('t')));
+        let form = app.state.form_dialog.take().unwrap();
+        assert_eq!(form.kind, FormKind::TagImage("sha256:abc".into()));
+        assert_eq!(form.value("tag"), "");
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('T')));
+        let form = app.state.form_dialog.take().unwrap();
+        assert_eq!(form.kind, FormKind::UntagImage("sha256:abc".into()));
+        assert_eq!(form.title, "Remove tag (app:1, app:latest)");
+        assert_eq!(form.value("tag"), "app:1");
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('U')));
+        let form = app.state.form_dialog.take().unwrap();
+        assert_eq!(form.kind, FormKind::PushImage);
+        assert_eq!(form.value("tag"), "app:1");
+    }
+
+    #[test]
+    fn image_save_uses_marked_images() {
+        let image = |id: &str, tag: &str| crate::core::ImageSummary {
+            id: id.into(),
+            repo_tags: vec![tag.into()],
+            ..Default::default()
+        };
+        let state = AppState {
+            current_tab: Tab::Images,
+            images: vec![
+                image("sha256:a", "app:1"),
+                image("sha256:b", "<none>:<none>"),
+                image("sha256:c", "db:2"),
+            ],
+            ..Default::default()
+        };
+        let mut app = UiApp::new(state);
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('S')));
+        let form = app.state.form_dialog.take().unwrap();
+        assert_eq!(form.kind, FormKind::SaveImages(vec!["app:1".into()]));
+        assert_eq!(form.title, "Save app:1");
+
+        app.state.marked_images.insert("sha256:b".into());
+        app.state.marked_images.insert("sha256:c".into());
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('S')));
+        let form = app.state.form_dialog.take().unwrap();
+        assert_eq!(
+            form.kind,
+            FormKind::SaveImages(vec!["sha256:b".into(), "db:2".into()])
+        );
+        assert_eq!(form.value("path"), "images.tar");
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('L')));
+        let form = app.state.form_dialog.take().unwrap();
+        assert_eq!(form.kind, FormKind::LoadImages);
+    }
+
+    #[test]
+    fn transfer_dialog_keys() {
+        let state = AppState {
+            current_tab: Tab::Images,
+            ..Default::default()
+        };
+        let mut app = UiApp::new(state);
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('P')));
+        assert_eq!(
+            app.state.form_dialog.as_ref().unwrap().kind,
+            FormKind::PullImage
+        );
+        app.state.close_form_dialog();
+
+        // Esc cancels while running and closes once done
+        app.state.open_transfer_view("nginx".into(), false);
+        let action = app.handle_key_event(KeyEvent::from(KeyCode::Esc));
+        assert!(matches!(action, UiAction::CancelTransfer));
+        app.state.finish_transfer(None);
+        let action = app.handle_key_event(KeyEvent::from(KeyCode::Esc));
+        assert!(matches!(action, UiAction::None));
+        assert!(app.state.transfer_view.is_none());
+    }
+
+    #[test]
+    fn image_dependents_block_removal_and_filter_containers() {
+        let mut state = AppState {
+            current_tab: Tab::Images,
+            ..Default::default()
+        };
+        state.update_images(vec![crate::core::ImageSummary {
+            id: "sha256:app".into(),
+            repo_tags: vec!["app:1".into()],
+            ..Default::default()
+        }]);
+        state.update_containers(vec![
+            crate::core::ContainerSummary {
+                id: "c1".into(),
+                names: vec!["web".into()],
+                image_id: "sha256:app".into(),
+                ..Default::default()
+            },
+            crate::core::ContainerSummary {
+                id: "c2".into(),
+                image_id: "sha256:other".into(),
+                ..Default::default()
+            },
+        ]);
+        let mut app = UiApp::new(state);
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('d')));
+        assert!(app.state.confirm_dialog.is_none());
+        assert!(app.state.notifications[0]
+            .message
+            .contains("'app:1' is used by containers web"));
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('c')));
+        assert_eq!(app.state.current_tab, Tab::Containers);
+        assert_eq!(app.state.containers.len(), 1);
+        app.handle_key_event(KeyEvent::from(KeyCode::Esc));
+        assert!(app.state.container_filter.is_none());
+        assert_eq!(app.state.containers.len(), 2);
+    }
+
+    #[test]
+    fn pull_outdated_asks_for_confirmation() {
+        let state = AppState {
+            current_tab: Tab::Images,
+            images: vec![crate::core::ImageSummary {
+                id: "sha256:a".into(),
+                repo_tags: vec!["app:1".into()],
+                repo_digests: vec!["app@sha256:old".into()],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let mut app = UiApp::new(state);
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('A')));
+        assert!(app.state.confirm_dialog.is_none());
+        assert_eq!(app.state.notifications[0].message, "No outdated images");
+
+        let action = app.handle_key_event(KeyEvent::from(KeyCode::Char('o')));
+        assert!(matches!(action, UiAction::CheckImageUpdates));
+
+        app.state
+            .set_remote_digest("app:1", Ok("sha256:new".into()), std::time::Instant::now());
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('A')));
+        let action = app.handle_key_event(KeyEvent::from(KeyCode::Char('y')));
+        assert!(matches!(action, UiAction::PullOutdated(tags) if tags == ["app:1"]));
+    }
+
+    #[test]
+    fn compare_needs_two_marked_images() {
+        let image = |id: &str, created: i64| crate::core::ImageSummary {
+            id: id.into(),
+            created: chrono::DateTime::from_timestamp(created, 0).unwrap(),
+            ..Default::default()
+        };
+        let state = AppState {
+            current_tab: Tab::Images,
+            images: vec![image("new", 200), image("old", 100), image("other", 50)],
+            ..Default::default()
+        };
+        let mut app = UiApp::new(state);
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('C')));
+        assert_eq!(
+            app.state.notifications[0].message,
+            "Mark two images with Space to compare them"
+        );
+
+        app.state.marked_images.insert("new".into());
+        app.state.marked_images.insert("old".into());
+        let action = app.handle_key_event(KeyEvent::from(KeyCode::Char('C')));
+        assert!(matches!(action, UiAction::CompareImages(a, b) if a == "old" && b == "new"));
+
+        app.state.open_image_diff("old".into(), "new".into());
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('j')));
+        assert_eq!(app.state.image_diff_view.as_ref().unwrap().scroll_offset, 1);
+        app.handle_key_event(KeyEvent::from(KeyCode::Esc));
+        assert!(app.state.image_diff_view.is_none());
+    }
+
+    #[test]
+    fn exec_pane_keys_leave_other_tabs_alone() {
+        let mut state = AppState {
+            current_tab: Tab::Volumes,
+            ..Default::default()
+        };
+        state.open_exec_view("id".into(), "web".into());
+        // Unfocused, so keys reach the tabs
+        state.toggle_exec_focus();
+        let mut app = UiApp::new(state);
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('c')));
+        let form = app.state.form_dialog.take().unwrap();
+        assert_eq!(form.kind, FormKind::CreateVolume);
+        let action = app.handle_key_event(KeyEvent::from(KeyCode::Char('R')));
+        assert!(!matches!(action, UiAction::ToggleExecRecording));
+        assert_eq!(
+            app.state.volume_sort_direction,
+            crate::core::SortDirection::Descending
+        );
+
+        app.state.current_tab = Tab::Networks;
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('c')));
+        let form = app.state.form_dialog.take().unwrap();
+        assert_eq!(form.kind, FormKind::CreateNetwork);
+
+        app.state.current_tab = Tab::Images;
+        let action = app.handle_key_event(KeyEvent::from(KeyCode::Char('R')));
+        assert!(matches!(action, UiAction::OpenRegistryBrowser));
+        let action = app.handle_key_event(KeyEvent::from(KeyCode::Char('c')));
+        assert!(!matches!(action, UiAction::EnterExecCopyMode));
+
+        app.state.current_tab = Tab::Containers;
+        let action = app.handle_key_event(KeyEvent::from(KeyCode::Char('c')));
+        assert!(matches!(action, UiAction::EnterExecCopyMode));
+        let action = app.handle_key_event(KeyEvent::from(KeyCode::Char('R')));
+        assert!(matches!(action, UiAction::ToggleExecRecording));
+    }
+
+    #[test]
+    fn registry_browser_keys() {
+        let state = AppState {
+            current_tab: Tab::Images,
+            ..Default::default()
+        };
+        let mut app = UiApp::new(state);
+        let action = app.handle_key_event(KeyEvent::from(KeyCode::Char('R')));
+        assert!(matches!(action, UiAction::OpenRegistryBrowser));
+
+        app.state
+            .open_registry_browser(vec!["Local".into(), "Docker Hub".into()]);
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('j')));
+        let action = app.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert!(matches!(action, UiAction::BrowseRegistry(1)));
+
+        // Repositories can be opened by name
+        app.state
+            .registry_browser_enter(RegistryLevel::Repositories, None);
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('/')));
+        let form = app.state.form_dialog.take().unwrap();
+        assert_eq!(form.kind, FormKind::OpenRepository(1));
+
+        app.state
+            .registry_browser_enter(RegistryLevel::Tags, Some("nginx".into()));
+        app.state.set_registry_tags(vec!["1.25".into()]);
+        let action = app.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert!(matches!(
+            action,
+            UiAction::InspectRegistryTag(1, repo, tag) if repo == "nginx" && tag == "1.25"
+        ));
+        let action = app.handle_key_event(KeyEvent::from(KeyCode::Char('p')));
+        assert!(matches!(action, UiAction::PullRegistryTag(1, _, _)));
+
+        // Esc walks back up, then closes
+        for _ in 0..3 {
+            app.handle_key_event(KeyEvent::from(KeyCode::Esc));
+        }
+        assert!(app.state.registry_browser.is_none());
+    }
+
+    #[test]
+    fn layer_explorer_keys() {
+        let mut state = AppState::default();
+        state.open_image_detail_view("sha256:abc".into(), "app:1".into());
+        let mut app = UiApp::new(state);
+        let action = app.handle_key_event(KeyEvent::from(KeyCode::Char('l')));
+        assert!(matches!(action, UiAction::ExploreLayers(id) if id == "sha256:abc"));
+
+        // q cancels while analyzing, then navigates and closes once loaded
+        app.state.open_layer_explorer("app:1".into());
+        let action = app.handle_key_event(KeyEvent::from(KeyCode::Char('q')));
+        assert!(matches!(action, UiAction::CancelLayerAnalysis));
+
+        let layer = |digest: &str| (digest.to_string(), String::new(), Vec::new());
+        app.state
+            .set_layer_analysis(crate::docker::layers::analyze(vec![layer("a"), layer("b")]));
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('j')));
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('j')));
+        app.handle_key_event(KeyEvent::from(KeyCode::Tab));
+        let explorer = app.state.layer_explorer.as_ref().unwrap();
+        assert_eq!(explorer.selected_layer, 1);
+        assert!(explorer.show_wasted);
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Esc));
+        assert!(app.state.layer_explorer.is_none());
+        assert!(app.state.image_detail_view.is_some());
+    }
+
+    #[test]
+    fn dockerfile_view_keys() {
+        let mut state = AppState::default();
+        state.open_image_detail_view("sha256:a".into(), "team/app:1".into());
+        state.set_image_detail_view_content(crate::docker::ImageDetails {
+            id: "sha256:a".into(),
+            repo_tags: vec!["team/app:1".into()],
+            size: 0,
+            created: String::new(),
+            author: String::new(),
+            os: "linux".into(),
+            architecture: "amd64".into(),
+            exposed_ports: vec![],
+            env: vec![],
+            entrypoint: None,
+            cmd: None,
+            labels: vec![],
+            layers: vec![crate::docker::ImageLayer {
+                id: "<missing>".into(),
+                created: "0".into(),
+                created_by: "/bin/sh -c #(nop)  ENV MODE prod".into(),
+                size: 0,
+                comment: String::new(),
+            }],
+        });
+        let mut app = UiApp::new(state);
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('f')));
+        let view = app.state.dockerfile_view.as_ref().unwrap();
+        assert!(view.text.contains("FROM scratch\nENV MODE=prod\n"));
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('s')));
+        let form = app.state.form_dialog.as_ref().unwrap();
+        assert_eq!(form.kind, FormKind::SaveDockerfile);
+        assert_eq!(form.value("path"), "team_app_1.Dockerfile");
+        app.handle_key_event(KeyEvent::from(KeyCode::Esc));
+
+        let action = app.handle_key_event(KeyEvent::from(KeyCode::Char('y')));
+        assert!(
+            matches!(action, UiAction::CopyToClipboard(text) if text.contains("ENV MODE=prod"))
+        );
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('q')));
+        assert!(app.state.dockerfile_view.is_none());
+        assert!(app.state.image_detail_view.is_some());
+    }
+
+    #[test]
+    fn scan_reports_attach_to_images() {
+        use crate::scan::{Finding, ScanReport, Scanner, Severity};
+
+        let state = AppState {
+            current_tab: Tab::Images,
+            images: vec![crate::core::ImageSummary {
+                id: "sha256:a".into(),
+                repo_tags: vec!["app:1".into()],
+                repo_digests: vec!["app@sha256:b".into()],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let mut app = UiApp::new(state);
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('V')));
+        let form = app.state.form_dialog.take().unwrap();
+        assert_eq!(form.kind, FormKind::ImportScanReports);
+
+        let matched = app.state.add_scan_reports(vec![ScanReport {
+            scanner: Scanner::Trivy,
+            path: "app.json".into(),
+            image: "app:1".into(),
+            digests: vec!["sha256:b".into()],
+            findings: vec![Finding {
+                id: "CVE-2024-1".into(),
+                package: "openssl".into(),
+                installed_version: "3.0.1".into(),
+                fixed_version: None,
+                severity: Severity::High,
+                title: String::new(),
+            }],
+        }]);
+        assert_eq!(matched, 1);
+        assert_eq!(app.state.image_scan_counts()["sha256:a"].short(), "H1");
+
+        app.state
+            .open_image_detail_view("sha256:a".into(), "app:1".into());
+        assert!(app.state.image_detail_view.as_ref().unwrap().scan.is_some());
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('v')));
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('v')));
+        assert_eq!(
+            app.state
+                .image_detail_view
+                .as_ref()
+                .unwrap()
+                .finding_severity,
+            Some(Severity::High)
+        );
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('/')));
+        let form = app.state.form_dialog.as_ref().unwrap();
+        assert_eq!(form.kind, FormKind::FilterFindings);
+    }
+
+    #[test]
+    fn create_volume_form() {
+        let state = AppState {
+            current_tab: Tab::Volumes,
+            ..Default::default()
+        };
+        let mut app = UiApp::new(state);
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('c')));
+        let form = app.state.form_dialog.as_ref().unwrap();
+        assert_eq!(form.kind, FormKind::CreateVolume);
+        assert_eq!(form.value("driver"), "local");
+        let keys: Vec<&str> = form.fields.iter().map(|f| f.key).collect();
+        assert_eq!(keys, vec!["name", "driver", "driver_opts", "labels"]);
+    }
+
+    #[test]
+    fn volume_backup_and_restore_forms() {
+        let mut app = UiApp::new(AppState {
+            current_tab: Tab::Volumes,
+            volumes: vec![crate::core::VolumeSummary {
+                name: "pgdata".into(),
+                driver: "local".into(),
+                mountpoint: String::new(),
+                created_at: chrono::Utc::now(),
+                status: Default::default(),
+                labels: Default::default(),
+                scope: crate::core::VolumeScope::Local,
+                options: Default::default(),
+                in_use: vec![],
+                size: None,
+                ref_count: None,
+            }],
+            ..Default::default()
+        });
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('S')));
+        let form = app.state.form_dialog.take().unwrap();
+        assert_eq!(form.kind, FormKind::BackupVolume("pgdata".into()));
+        let path = form.value("path");
+        assert!(path.starts_with("pgdata-") && path.ends_with(".tar.gz"));
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('L')));
+        let form = app.state.form_dialog.take().unwrap();
+        assert_eq!(form.kind, FormKind::RestoreVolume);
+        assert_eq!(form.value("volume"), "");
+    }
+
+    #[test]
+    fn orphaned_volumes_are_removed_together() {
+        let volume = |name: String, ref_count: i64| crate::core::VolumeSummary {
+            name,
+            driver: "local".into(),
+            mountpoint: String::new(),
+            created_at: chrono::Utc::now(),
+            status: Default::default(),
+            labels: Default::default(),
+            scope: crate::core::VolumeScope::Local,
+            options: Default::default(),
+            in_use: vec![],
+            size: Some(1024),
+            ref_count: Some(ref_count),
+        };
+        let mut state = AppState {
+            current_tab: Tab::Volumes,
+            ..Default::default()
+        };
+        state.update_volumes(vec![
+            volume("0".repeat(64), 0),
+            volume("1".repeat(64), 1),
+            volume("2".repeat(64), 0),
+            volume("pgdata".into(), 0),
+        ]);
+        let mut app = UiApp::new(state);
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('O')));
+        assert_eq!(app.state.marked_volumes.len(), 2);
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('d')));
+        let confirm = app.state.confirm_dialog.take().unwrap();
+        assert!(matches!(
+            confirm.action,
+            UiAction::RemoveVolumes(names) if names == vec!["0".repeat(64), "2".repeat(64)]
+        ));
+
+        // Pressing it again clears the marks
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('O')));
+        assert!(app.state.marked_volumes.is_empty());
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('d')));
+        let confirm = app.state.confirm_dialog.take().unwrap();
+        assert!(matches!(confirm.action, UiAction::RemoveVolume(_)));
+    }
+
+    #[test]
+    fn volume_browser_keys() {
+        use crate::docker::{FilePreview, VolumeEntry, VolumeEntryKind};
+
+        let mut app = UiApp::new(AppState {
+            current_tab: Tab::Volumes,
+            ..Default::default()
+        });
+        app.state.open_volume_browser("data".into());
+        // Only closing works while the volume is read
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('s')));
+        assert!(app.state.form_dialog.is_none());
+
+        let entry = |path: &str, kind: VolumeEntryKind| VolumeEntry {
+            path: path.into(),
+            kind,
+            size: 1,
+            mode: 0o644,
+            modified: None,
+        };
+        app.state.set_volume_entries(vec![
+            entry("conf", VolumeEntryKind::File),
+            entry("pg", VolumeEntryKind::Directory),
+        ]);
+        app.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert_eq!(app.state.volume_browser.as_ref().unwrap().cwd, "pg");
+        app.handle_key_event(KeyEvent::from(KeyCode::Backspace));
+        app.handle_key_event(KeyEvent::from(KeyCode::Down));
+        let action = app.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert!(matches!(action, UiAction::PreviewVolumeFile(path) if path == "conf"));
+
+        app.state.set_volume_preview(FilePreview {
+            path: "conf".into(),
+            lines: vec!["x".into()],
+            truncated: false,
+        });
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('s')));
+        let form = app.state.form_dialog.take().unwrap();
+        assert_eq!(form.kind, FormKind::DownloadVolumeEntry("conf".into()));
+        // q leaves the preview first, then closes the browser
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('q')));
+        assert!(app.state.volume_browser.as_ref().unwrap().preview.is_none());
+        let action = app.handle_key_event(KeyEvent::from(KeyCode::Char('q')));
+        assert!(matches!(action, UiAction::CloseVolumeBrowser));
+    }
+
+    #[test]
+    fn cleanup_planner_keys() {
+        use crate::docker::{CleanupCandidate, CleanupPlan};
+
+        let state = AppState {
+            current_tab: Tab::Images,
+            ..Default::default()
+        };
+        let mut app = UiApp::new(state);
+        let action = app.handle_key_event(KeyEvent::from(KeyCode::Char('X')));
+        assert!(matches!(action, UiAction::OpenCleanupPlanner));
+
+        let plan = |policy: &str, candidates: Vec<CleanupCandidate>| CleanupPlan {
+            policy: policy.into(),
+            candidates,
+            in_use: 0,
+            excluded: 0,
+            problem: None,
+        };
+        app.state.open_cleanup_view(vec![
+            plan("stale", vec![]),
+            plan(
+                "history",
+                vec![CleanupCandidate {
+                    id: "sha256:a".into(),
+                    short_id: "a".into(),
+                    tags: vec!["app:1".into()],
+                    size: 1024,
+                    created: chrono::Utc::now(),
+                    reason: "older than the newest 3".into(),
+                }],
+            ),
+        ]);
+
+        // Nothing to delete under the first policy
+        app.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert!(app.state.confirm_dialog.is_none());
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Tab));
+        app.handle_key_event(KeyEvent::from(KeyCode::Enter));
+        assert!(app.state.confirm_dialog.is_some());
+        let action = app.handle_key_event(KeyEvent::from(KeyCode::Char('y')));
+        assert!(matches!(action, UiAction::RunCleanup(1)));
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('q')));
+        assert!(app.state.cleanup_view.is_none());
+    }
+
+    #[test]
+    fn run_spec_view_keys() {
+        let mut state = AppState::default();
+        state.open_detail_view("abc".into(), "web".into());
+        let mut app = UiApp::new(state);
+        let action = app.handle_key_event(KeyEvent::from(KeyCode::Char('g')));
+        assert!(matches!(action, UiAction::GenerateRunSpec(id) if id == "abc"));
+
+        app.state
+            .open_run_spec_view("web".into(), "docker run".into(), "services:".into());
+        app.handle_key_event(KeyEvent::from(KeyCode::Tab));
+        let action = app.handle_key_event(KeyEvent::from(KeyCode::Char('y')));
+        assert!(matches!(action, UiAction::CopyToClipboard(text) if text == "services:"));
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('s')));
+        let form = app.state.form_dialog.as_ref().unwrap();
+        assert_eq!(form.kind, FormKind::SaveRunSpec);
+        assert_eq!(form.value("path"), "web.compose.yml");
+
+        // The save form takes keys while the viewer stays open underneath
+        app.handle_key_event(KeyEvent::from(KeyCode::Esc));
+        assert!(app.state.form_dialog.is_none());
+        assert!(app.state.run_spec_view.is_some());
+        app.handle_key_event(KeyEvent::from(KeyCode::Esc));
+        assert!(app.state.run_spec_view.is_none());
+        assert!(app.state.detail_view.is_some());
+    }
+
+    #[test]
+    fn network_detail_view_keys() {
+        let mut app = UiApp::new(AppState {
+            current_tab: Tab::Networks,
+            networks: vec![crate::core::NetworkSummary {
+                id: "f00d".into(),
+                name: "backend".into(),
+                driver: "bridge".into(),
+                scope: crate::core::NetworkScope::Local,
+                created: chrono::Utc::now(),
+                internal: false,
+                attachable: false,
+                ingress: false,
+                enable_ipv6: false,
+                subnet: None,
+                connected_containers: vec![],
+            }],
+            ..Default::default()
+        });
+        let action = app.handle_key_event(KeyEvent::from(KeyCode::Char('i')));
+        assert!(matches!(
+            action,
+            UiAction::ShowNetworkDetails(id, name) if id == "f00d" && name == "backend"
+        ));
+
+        app.state
+            .open_network_detail_view("f00d".into(), "backend".into());
+        app.handle_key_event(KeyEvent::from(KeyCode::PageDown));
+        app.handle_key_event(KeyEvent::from(KeyCode::Up));
+        assert_eq!(
+            app.state
+                .network_detail_view
+                .as_ref()
+                .unwrap()
+                .scroll_offset,
+            7
+        );
+        // Tab keys don't reach the list while the viewer is open
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('d')));
+        assert!(app.state.confirm_dialog.is_none());
+        app.handle_key_event(KeyEvent::from(KeyCode::Esc));
+        assert!(app.state.network_detail_view.is_none());
+    }
+
+    #[test]
+    fn network_forms() {
+        let mut state = AppState {
+            current_tab: Tab::Networks,
+            all_containers: vec![crate::core::ContainerSummary {
+                id: "c0ffee".into(),
+                names: vec!["web".into()],
+                ..Default::default()
+            }],
+            networks: vec![crate::core::NetworkSummary {
+                id: "f00d".into(),
+                name: "backend".into(),
+                driver: "bridge".into(),
+                scope: crate::core::NetworkScope::Local,
+                created: chrono::Utc::now(),
+                internal: false,
+                attachable: false,
+                ingress: false,
+                enable_ipv6: false,
+                subnet: None,
+                connected_containers: vec!["c0ffee".into()],
+            }],
+            ..Default::default()
+        };
+        state.open_detail_view("c0ffee".into(), "web".into());
+        let mut app = UiApp::new(state);
+
+        // From the container detail view, the container is filled in
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('C')));
+        let form = app.state.form_dialog.take().unwrap();
+        assert_eq!(form.kind, FormKind::ConnectNetwork);
+        assert_eq!(form.value("container"), "web");
+        assert_eq!(form.value("network"), "");
+        app.state.close_detail_view();
+
+        // From the network list, the network (and a connected container)
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('C')));
+        let form = app.state.form_dialog.take().unwrap();
+        assert_eq!(form.value("network"), "backend");
+        assert_eq!(form.value("container"), "");
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('D')));
+        let form = app.state.form_dialog.take().unwrap();
+        assert_eq!(form.kind, FormKind::DisconnectNetwork);
+        assert_eq!(form.value("network"), "backend");
+        assert_eq!(form.value("container"), "web");
+
+        // `v` flips between the list and the topology graph
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('v')));
+        assert!(app.state.network_topology_view);
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('v')));
+        assert!(!app.state.network_topology_view);
+
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('c')));
+        let form = app.state.form_dialog.take().unwrap();
+        assert_eq!(form.kind, FormKind::CreateNetwork);
+        assert_eq!(form.value("driver"), "bridge");
+        assert!(!form.toggle("ipv6"));
+        let keys: Vec<&str> = form.fields.iter().map(|f| f.key).collect();
+        assert_eq!(
+            keys,
+            vec![
+                "name",
+                "driver",
+                "subnet",
+                "gateway",
+                "ip_range",
+                "internal",
+                "attachable",
+                "ipv6"
+            ]
+        );
+    }
+
+    #[test]
+    fn exec_copy_mode_keys_yank_selection() {
+        let mut state = AppState::default();
+        state.open_exec_view("id".into(), "web".into());
+        state.toggle_exec_focus();
+
+        let mut app = UiApp::new(state);
+        let action = app.handle_key_event(KeyEvent::from(KeyCode::Char('c')));
+        assert!(matches!(action, UiAction::EnterExecCopyMode));
+
+        app.state
+            .enter_exec_copy_mode(vec!["hello world".into(), "bye".into()], 8);
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('k')));
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('v')));
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('w')));
+        app.handle_key_event(KeyEvent::from(KeyCode::Char('h')));
+        let action = app.handle_key_event(KeyEvent::from(KeyCode::Char('y')));
+
+        match action {
+            UiAction::CopyToClipboard(text) => assert_eq!(text, "hello"),
+            other => panic!("expected copy action, got {other:?}"),
+        }
+        assert!(app.state.exec_view.as_ref().unwrap().copy_mode.is_none());
+    }
 }
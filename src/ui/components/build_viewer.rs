@@ -0,0 +1,166 @@
+//! Build output pane
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::docker::BuildLineKind;
+use crate::state::BuildViewState;
+
+/// Render the build output overlay
+pub fn render_build_viewer(frame: &mut Frame, area: Rect, state: &BuildViewState) {
+    // Same footprint as the log viewer
+    let popup_area = centered_rect(90, 90, area);
+    frame.render_widget(Clear, popup_area);
+
+    let (status, border_color) = match (&state.error, state.done) {
+        (Some(_), _) => ("[FAILED]", Color::Red),
+        (None, true) => ("[DONE]", Color::Green),
+        (None, false) if state.scroll_back == 0 => ("[FOLLOW]", Color::Cyan),
+        (None, false) => ("[BUILDING]", Color::Cyan),
+    };
+    let block = Block::default()
+        .title(format!(" Build: {} {} ", state.title, status))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(border_color))
+        .style(Style::default().bg(Color::Black));
+
+    let inner_area = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(inner_area);
+
+    let visible_lines = chunks[0].height as usize;
+    let total_lines = state.lines.len();
+    let back = state
+        .scroll_back
+        .min(total_lines.saturating_sub(visible_lines));
+    let end_idx = total_lines - back;
+    let start_idx = end_idx.saturating_sub(visible_lines);
+
+    let lines: Vec<Line> = state.lines[start_idx..end_idx]
+        .iter()
+        .map(|line| {
+            let style = match line.kind {
+                BuildLineKind::Step => Style::default()
+                    .fg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD),
+                BuildLineKind::Error => Style::default().fg(Color::Red),
+                BuildLineKind::Output => Style::default().fg(Color::White),
+            };
+            Line::from(Span::styled(line.text.clone(), style))
+        })
+        .collect();
+    frame.render_widget(
+        Paragraph::new(lines).style(Style::default().bg(Color::Black)),
+        chunks[0],
+    );
+
+    let footer = match &state.error {
+        Some(error) => Line::from(Span::styled(
+            format!(" {}", error),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )),
+        None if state.done => Line::from(Span::styled(
+            " [↑/↓]Scroll [q]Close",
+            Style::default().fg(Color::DarkGray),
+        )),
+        None => Line::from(Span::styled(
+            " [↑/↓]Scroll [End]Follow [q]Cancel build",
+            Style::default().fg(Color::DarkGray),
+        )),
+    };
+    frame.render_widget(
+        Paragraph::new(footer).style(Style::default().bg(Color::Black)),
+        chunks[1],
+    );
+
+    // Render scroll position indicator when not following
+    if back > 0 && total_lines > visible_lines {
+        let scroll_pct = (start_idx as f64 / (total_lines - visible_lines) as f64 * 100.0) as u16;
+        let scroll_area = Rect::new(popup_area.right().saturating_sub(7), popup_area.y + 1, 5, 1);
+        if scroll_area.x > popup_area.x + 1 {
+            frame.render_widget(
+                Paragraph::new(format!("{}%", scroll_pct))
+                    .style(Style::default().fg(Color::DarkGray).bg(Color::Black)),
+                scroll_area,
+            );
+        }
+    }
+}
+
+/// Calculate centered rectangle for popup
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let width = ((r.width as u32) * (percent_x as u32) / 100) as u16;
+    let height = ((r.height as u32) * (percent_y as u32) / 100) as u16;
+    let width = width.clamp(3, r.width);
+    let height = height.clamp(3, r.height);
+    let x = r.x + (r.width.saturating_sub(width)) / 2;
+    let y = r.y + (r.height.saturating_sub(height)) / 2;
+    Rect::new(x, y, width, height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::docker::BuildLine;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    fn line(text: &str, kind: BuildLineKind) -> BuildLine {
+        BuildLine {
+            text: text.into(),
+            kind,
+        }
+    }
+
+    #[test]
+    fn follows_the_tail_and_styles_steps() {
+        let mut terminal = Terminal::new(TestBackend::new(80, 12)).unwrap();
+        let mut lines: Vec<BuildLine> = (0..30)
+            .map(|i| line(&format!("output {}", i), BuildLineKind::Output))
+            .collect();
+        lines.push(line("Step 3/3 : CMD run", BuildLineKind::Step));
+        let state = BuildViewState {
+            title: "app:latest".into(),
+            lines,
+            scroll_back: 0,
+            done: false,
+            error: None,
+        };
+
+        terminal
+            .draw(|f| render_build_viewer(f, f.area(), &state))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let text: String = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .filter_map(|x| buffer.cell((x, y)).map(|c| c.symbol().to_string()))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(text.contains("Build: app:latest [FOLLOW]"));
+        assert!(text.contains("Step 3/3 : CMD run"));
+        assert!(text.contains("output 29"));
+        assert!(!text.contains("output 0\n"));
+
+        let step_cell = (0..buffer.area.height)
+            .flat_map(|y| (0..buffer.area.width).map(move |x| (x, y)))
+            .find(|&(x, y)| {
+                buffer.cell((x, y)).map(|c| c.symbol()) == Some("S")
+                    && buffer.cell((x + 1, y)).map(|c| c.symbol()) == Some("t")
+            })
+            .unwrap();
+        assert_eq!(buffer.cell(step_cell).unwrap().fg, Color::Cyan);
+    }
+}
@@ -0,0 +1,195 @@
+//! Image cleanup planner: what a policy would delete, before it runs
+
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use crate::docker::format_bytes_size;
+use crate::state::CleanupViewState;
+
+/// Render the cleanup planner overlay
+pub fn render_cleanup_planner(frame: &mut Frame, area: Rect, state: &CleanupViewState) {
+    let popup_area = centered_rect(80, 80, area);
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(" Image Cleanup ")
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan).bg(Color::Black))
+        .style(Style::default().bg(Color::Black));
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Length(2),
+            Constraint::Min(1),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    // Policy tabs
+    let tabs: Vec<Span> = state
+        .plans
+        .iter()
+        .enumerate()
+        .flat_map(|(index, plan)| {
+            let style = if index == state.selected {
+                Style::default()
+                    .fg(Color::Black)
+                    .bg(Color::Cyan)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Gray)
+            };
+            [
+                Span::styled(format!(" {} ", plan.policy), style),
+                Span::raw(" "),
+            ]
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(Line::from(tabs)), chunks[0]);
+
+    let Some(plan) = state.current() else {
+        return;
+    };
+
+    let summary = if let Some(problem) = &plan.problem {
+        Line::from(Span::styled(
+            format!("Policy selects nothing: {}", problem),
+            Style::default().fg(Color::Yellow),
+        ))
+    } else {
+        let mut skipped = Vec::new();
+        if plan.in_use > 0 {
+            skipped.push(format!("{} in use", plan.in_use));
+        }
+        if plan.excluded > 0 {
+            skipped.push(format!("{} excluded", plan.excluded));
+        }
+        let mut spans = vec![
+            Span::raw(format!("{} image(s) to delete, ", plan.candidates.len())),
+            Span::styled(
+                format!("{} reclaimed", format_bytes_size(plan.reclaimable())),
+                Style::default().fg(Color::Green),
+            ),
+        ];
+        if !skipped.is_empty() {
+            spans.push(Span::styled(
+                format!("  (skipped: {})", skipped.join(", ")),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        Line::from(spans)
+    };
+    frame.render_widget(Paragraph::new(summary), chunks[1]);
+
+    let lines: Vec<Line> = if plan.candidates.is_empty() {
+        vec![Line::from(Span::styled(
+            "Nothing to delete",
+            Style::default().fg(Color::DarkGray),
+        ))]
+    } else {
+        plan.candidates
+            .iter()
+            .map(|candidate| {
+                Line::from(vec![
+                    Span::styled(
+                        format!("{:<40} ", candidate.name()),
+                        Style::default().fg(Color::White),
+                    ),
+                    Span::styled(
+                        format!("{:<14}", candidate.short_id),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::styled(
+                        format!("{:>10}  ", format_bytes_size(candidate.size)),
+                        Style::default().fg(Color::Cyan),
+                    ),
+                    Span::styled(candidate.reason.clone(), Style::default().fg(Color::Gray)),
+                ])
+            })
+            .collect()
+    };
+    let max_scroll = lines.len().saturating_sub(chunks[2].height as usize);
+    let scroll = state.scroll_offset.min(max_scroll);
+    frame.render_widget(
+        Paragraph::new(lines).scroll((u16::try_from(scroll).unwrap_or(u16::MAX), 0)),
+        chunks[2],
+    );
+
+    let hints = Paragraph::new(" [Tab/←/→]Policy [↑/↓]Scroll [Enter]Delete [r]Re-plan [q]Close")
+        .style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(hints, chunks[3]);
+}
+
+/// Calculate centered rectangle for popup
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let width = ((r.width as u32) * (percent_x as u32) / 100) as u16;
+    let height = ((r.height as u32) * (percent_y as u32) / 100) as u16;
+    let width = width.clamp(3, r.width);
+    let height = height.clamp(3, r.height);
+    let x = r.x + (r.width.saturating_sub(width)) / 2;
+    let y = r.y + (r.height.saturating_sub(height)) / 2;
+    Rect::new(x, y, width, height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::docker::{CleanupCandidate, CleanupPlan};
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+    use std::sync::Arc;
+
+    #[test]
+    fn shows_the_selected_plan() {
+        let plan = |policy: &str, candidates: Vec<CleanupCandidate>| CleanupPlan {
+            policy: policy.into(),
+            candidates,
+            in_use: 1,
+            excluded: 0,
+            problem: None,
+        };
+        let state = CleanupViewState {
+            plans: Arc::new(vec![
+                plan("stale", vec![]),
+                plan(
+                    "history",
+                    vec![CleanupCandidate {
+                        id: "sha256:a".into(),
+                        short_id: "a1b2c3".into(),
+                        tags: vec!["app:1".into()],
+                        size: 2048,
+                        created: chrono::Utc::now(),
+                        reason: "older than the newest 3".into(),
+                    }],
+                ),
+            ]),
+            selected: 1,
+            scroll_offset: 0,
+        };
+
+        let mut terminal = Terminal::new(TestBackend::new(120, 20)).unwrap();
+        terminal
+            .draw(|f| render_cleanup_planner(f, f.area(), &state))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let text: String = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .filter_map(|x| buffer.cell((x, y)).map(|c| c.symbol().to_string()))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(text.contains(" stale   history "));
+        assert!(text.contains("1 image(s) to delete, 2.0 KB reclaimed  (skipped: 1 in use)"));
+        assert!(text.contains("app:1"));
+        assert!(text.contains("older than the newest 3"));
+    }
+}
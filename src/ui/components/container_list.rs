@@ -1,5 +1,7 @@
 //! Container list widget
 
+use std::collections::{HashMap, HashSet};
+
 use ratatui::{
     layout::Constraint,
     style::{Color, Modifier, Style},
@@ -13,6 +15,12 @@ use crate::core::{ContainerState, ContainerSummary};
 pub struct ContainerListWidget {
     containers: Vec<ContainerSummary>,
     state: TableState,
+    /// Restart counts of crash-looping containers, keyed by ID
+    crash_loops: HashMap<String, i64>,
+    /// IDs of containers running an outdated image
+    outdated: HashSet<String>,
+    /// Active filter, shown in the title
+    filter: Option<String>,
 }
 
 impl ContainerListWidget {
@@ -22,7 +30,31 @@ impl ContainerListWidget {
         if !containers.is_empty() {
             state.select(Some(0));
         }
-        Self { containers, state }
+        Self {
+            containers,
+            state,
+            crash_loops: HashMap::new(),
+            outdated: HashSet::new(),
+            filter: None,
+        }
+    }
+
+    /// Flag crash-looping containers with a restart badge
+    pub fn with_crash_loops(mut self, crash_loops: HashMap<String, i64>) -> Self {
+        self.crash_loops = crash_loops;
+        self
+    }
+
+    /// Flag containers whose image is behind its registry
+    pub fn with_outdated(mut self, outdated: HashSet<String>) -> Self {
+        self.outdated = outdated;
+        self
+    }
+
+    /// Name the filter the list was narrowed down with
+    pub fn with_filter(mut self, filter: Option<String>) -> Self {
+        self.filter = filter;
+        self
     }
 
     /// Update the container list
@@ -140,11 +172,31 @@ impl ContainerListWidget {
                     _ => Style::default().fg(Color::Gray),
                 };
 
+                let mut status = Vec::new();
+                if let Some(restarts) = self.crash_loops.get(&c.id) {
+                    status.push(Span::styled(
+                        format!("↻{} ", restarts),
+                        Style::default()
+                            .fg(Color::Black)
+                            .bg(Color::Red)
+                            .add_modifier(Modifier::BOLD),
+                    ));
+                    status.push(Span::raw(" "));
+                }
+                status.push(Span::styled(c.status.clone(), status_style));
+
                 Row::new(vec![
                     Line::from(c.short_id.clone()),
                     Line::from(c.names.first().cloned().unwrap_or_else(|| "-".to_string())),
-                    Line::from(c.image.clone()),
-                    Line::from(Span::styled(c.status.clone(), status_style)),
+                    if self.outdated.contains(&c.id) {
+                        Line::from(vec![
+                            Span::raw(c.image.clone()),
+                            Span::styled(" ↑", Style::default().fg(Color::Yellow)),
+                        ])
+                    } else {
+                        Line::from(c.image.clone())
+                    },
+                    Line::from(status),
                     Line::from(ports),
                 ])
             })
@@ -163,7 +215,21 @@ impl ContainerListWidget {
         .header(header)
         .block(
             Block::default()
-                .title(format!(" Containers ({}) ", self.containers.len()))
+                .title({
+                    let mut counts = vec![self.containers.len().to_string()];
+                    if !self.crash_loops.is_empty() {
+                        counts.push(format!("{} crash-looping", self.crash_loops.len()));
+                    }
+                    if !self.outdated.is_empty() {
+                        counts.push(format!("{} outdated", self.outdated.len()));
+                    }
+                    match &self.filter {
+                        Some(filter) => {
+                            format!(" Containers ({}) [{}] ", counts.join(", "), filter)
+                        }
+                        None => format!(" Containers ({}) ", counts.join(", ")),
+                    }
+                })
                 .borders(Borders::ALL),
         )
         .row_highlight_style(
@@ -265,6 +331,32 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_crash_loop_badge() {
+        let mut crash_loops = HashMap::new();
+        crash_loops.insert("def789ghi012".to_string(), 7);
+        let widget = ContainerListWidget::new(create_test_containers())
+            .with_crash_loops(crash_loops)
+            .with_outdated(HashSet::from(["abc123def456".to_string()]))
+            .with_filter(Some("ancestor=nginx".into()));
+
+        let backend = ratatui::backend::TestBackend::new(100, 6);
+        let mut terminal = ratatui::Terminal::new(backend).unwrap();
+        terminal
+            .draw(|f| f.render_widget(widget.build_table(), f.area()))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let row = |y: u16| -> String {
+            (0..buffer.area.width)
+                .filter_map(|x| buffer.cell((x, y)).map(|c| c.symbol().to_string()))
+                .collect()
+        };
+        assert!(row(0).contains("1 crash-looping, 1 outdated) [ancestor=nginx]"));
+        assert!(!row(2).contains('↻'));
+        assert!(row(3).contains("↻7"));
+    }
+
     #[test]
     fn test_update_preserves_selection() {
         let containers = create_test_containers();
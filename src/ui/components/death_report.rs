@@ -0,0 +1,219 @@
+//! "Why did it die" panel - last exit, OOM flag, error and the logs before it
+
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph, Wrap};
+use ratatui::Frame;
+
+use crate::docker::exit_code_meaning;
+use crate::state::restart_tracker::CRASH_LOOP_WINDOW_SECS;
+use crate::state::DeathReportState;
+
+/// Render the "why did it die" overlay
+pub fn render_death_report(frame: &mut Frame, area: Rect, state: &DeathReportState) {
+    let popup_area = centered_rect(80, 85, area);
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!(" Why did it die: {} ", state.container_name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Red).bg(Color::Black))
+        .style(Style::default().bg(Color::Black));
+
+    let Some(info) = &state.info else {
+        let loading = Paragraph::new("Loading exit information...")
+            .style(Style::default().fg(Color::Yellow).bg(Color::Black))
+            .alignment(Alignment::Center)
+            .block(block);
+        frame.render_widget(loading, popup_area);
+        return;
+    };
+
+    let label_style = Style::default().fg(Color::Gray);
+    let value_style = Style::default().fg(Color::White);
+    let alert_style = Style::default().fg(Color::Red).add_modifier(Modifier::BOLD);
+
+    let mut lines = vec![];
+
+    let never_exited = info.finished_at.is_none() && !info.restarting;
+    if never_exited {
+        lines.push(Line::from(Span::styled(
+            "This container has not exited yet.",
+            Style::default().fg(Color::Green),
+        )));
+        lines.push(Line::from(""));
+    }
+
+    let code_style = if info.exit_code == 0 {
+        value_style
+    } else {
+        alert_style
+    };
+    lines.push(Line::from(vec![
+        Span::styled("Exit code:  ", label_style),
+        Span::styled(info.exit_code.to_string(), code_style),
+        Span::styled(
+            format!("  {}", exit_code_meaning(info.exit_code)),
+            value_style,
+        ),
+    ]));
+    lines.push(Line::from(vec![
+        Span::styled("OOM killed: ", label_style),
+        if info.oom_killed {
+            Span::styled("yes - the container exceeded its memory limit", alert_style)
+        } else {
+            Span::styled("no", value_style)
+        },
+    ]));
+    if let Some(finished_at) = info.finished_at {
+        let ago = chrono::Utc::now() - finished_at;
+        lines.push(Line::from(vec![
+            Span::styled("Finished:   ", label_style),
+            Span::styled(
+                format!(
+                    "{} ({} ago)",
+                    finished_at
+                        .with_timezone(&chrono::Local)
+                        .format("%Y-%m-%d %H:%M:%S"),
+                    format_ago(ago)
+                ),
+                value_style,
+            ),
+        ]));
+    }
+    lines.push(Line::from(vec![
+        Span::styled("Restarts:   ", label_style),
+        Span::styled(
+            format!(
+                "{} total, {} in the last {} minutes{}",
+                info.restart_count,
+                state.recent_restarts,
+                CRASH_LOOP_WINDOW_SECS / 60,
+                if info.restarting {
+                    " (restarting now)"
+                } else {
+                    ""
+                }
+            ),
+            value_style,
+        ),
+    ]));
+    if !info.error.is_empty() {
+        lines.push(Line::from(vec![
+            Span::styled("Error:      ", label_style),
+            Span::styled(info.error.clone(), alert_style),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    if state.logs.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No log output before the exit.",
+            label_style,
+        )));
+    } else {
+        lines.push(Line::from(Span::styled(
+            format!("Last {} log lines before the exit:", state.logs.len()),
+            label_style,
+        )));
+        for entry in &state.logs {
+            let style = if entry.is_stderr {
+                Style::default().fg(Color::LightRed)
+            } else {
+                value_style
+            };
+            lines.push(Line::from(Span::styled(
+                format!("  {}", entry.message),
+                style,
+            )));
+        }
+    }
+
+    let inner_height = block.inner(popup_area).height as usize;
+    let max_scroll = lines.len().saturating_sub(inner_height);
+    let scroll = state.scroll_offset.min(max_scroll);
+
+    let paragraph = Paragraph::new(lines)
+        .wrap(Wrap { trim: false })
+        .scroll((u16::try_from(scroll).unwrap_or(u16::MAX), 0))
+        .style(Style::default().bg(Color::Black))
+        .block(block);
+    frame.render_widget(paragraph, popup_area);
+}
+
+fn format_ago(duration: chrono::Duration) -> String {
+    if duration.num_days() > 0 {
+        format!("{}d", duration.num_days())
+    } else if duration.num_hours() > 0 {
+        format!("{}h", duration.num_hours())
+    } else if duration.num_minutes() > 0 {
+        format!("{}m", duration.num_minutes())
+    } else {
+        format!("{}s", duration.num_seconds().max(0))
+    }
+}
+
+/// Calculate centered rectangle for popup
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let width = ((r.width as u32) * (percent_x as u32) / 100) as u16;
+    let height = ((r.height as u32) * (percent_y as u32) / 100) as u16;
+    let width = width.clamp(3, r.width);
+    let height = height.clamp(3, r.height);
+    let x = r.x + (r.width.saturating_sub(width)) / 2;
+    let y = r.y + (r.height.saturating_sub(height)) / 2;
+    Rect::new(x, y, width, height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::docker::{ExitInfo, LogEntry};
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    #[test]
+    fn renders_exit_oom_and_logs() {
+        let backend = TestBackend::new(100, 24);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let state = DeathReportState {
+            container_id: "abc".into(),
+            container_name: "worker".into(),
+            info: Some(ExitInfo {
+                container_id: "abc".into(),
+                restart_count: 12,
+                restarting: true,
+                exit_code: 137,
+                oom_killed: true,
+                error: String::new(),
+                finished_at: Some(chrono::Utc::now()),
+            }),
+            recent_restarts: 4,
+            logs: vec![LogEntry {
+                timestamp: None,
+                message: "allocating buffer".into(),
+                is_stderr: false,
+            }],
+            scroll_offset: 0,
+        };
+
+        terminal
+            .draw(|f| render_death_report(f, f.area(), &state))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let text: String = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .filter_map(|x| buffer.cell((x, y)).map(|c| c.symbol().to_string()))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(text.contains("Why did it die: worker"));
+        assert!(text.contains("SIGKILL"));
+        assert!(text.contains("exceeded its memory limit"));
+        assert!(text.contains("12 total, 4 in the last 5 minutes"));
+        assert!(text.contains("allocating buffer"));
+    }
+}
@@ -218,6 +218,7 @@ mod tests {
             health: None,
             mounts: vec![],
             networks: vec!["bridge".to_string()],
+            network_ips: std::collections::HashMap::new(),
             compose_project: Some("myapp".to_string()),
             compose_service: Some("web".to_string()),
         }
@@ -18,7 +18,10 @@ pub fn render_detail_viewer(frame: &mut Frame, area: Rect, state: &DetailViewSta
     frame.render_widget(Clear, popup_area);
 
     // Build title
-    let title = format!(" Container: {} ", state.container_name);
+    let title = format!(
+        " Container: {}  [g]Generate run [C/D]Connect/Disconnect network ",
+        state.container_name
+    );
 
     // Create block with explicit background for the popup border + area
     let block = Block::default()
@@ -185,7 +188,7 @@ pub fn render_detail_viewer(frame: &mut Frame, area: Rect, state: &DetailViewSta
     frame.render_widget(paragraph, popup_area);
 }
 
-fn wrap_lines_to_width(lines: &[Line], width: u16) -> Vec<Line<'static>> {
+pub(crate) fn wrap_lines_to_width(lines: &[Line], width: u16) -> Vec<Line<'static>> {
     let width = width as usize;
     if width == 0 {
         return vec![];
@@ -0,0 +1,105 @@
+//! Viewer for a Dockerfile reconstructed from image history
+
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use crate::state::DockerfileViewState;
+
+/// Render the reconstructed Dockerfile overlay
+pub fn render_dockerfile_viewer(frame: &mut Frame, area: Rect, state: &DockerfileViewState) {
+    let popup_area = centered_rect(80, 85, area);
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!(" Dockerfile: {} (approximate) ", state.image_name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan).bg(Color::Black))
+        .style(Style::default().bg(Color::Black));
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(inner);
+
+    // Comments (including the inherited base image steps) are dimmed
+    let lines: Vec<Line> = state
+        .text
+        .lines()
+        .map(|line| {
+            let color = if line.starts_with('#') {
+                Color::DarkGray
+            } else {
+                Color::White
+            };
+            Line::styled(line, Style::default().fg(color))
+        })
+        .collect();
+    let max_scroll = lines.len().saturating_sub(chunks[0].height as usize);
+    let scroll = state.scroll_offset.min(max_scroll);
+    let text = Paragraph::new(lines)
+        .scroll((u16::try_from(scroll).unwrap_or(u16::MAX), 0))
+        .style(Style::default().bg(Color::Black));
+    frame.render_widget(text, chunks[0]);
+
+    let hints = Paragraph::new(" [y]Copy [s]Save [↑/↓]Scroll [q]Close")
+        .style(Style::default().fg(Color::DarkGray).bg(Color::Black));
+    frame.render_widget(hints, chunks[1]);
+}
+
+/// Calculate centered rectangle for popup
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let width = ((r.width as u32) * (percent_x as u32) / 100) as u16;
+    let height = ((r.height as u32) * (percent_y as u32) / 100) as u16;
+    let width = width.clamp(3, r.width);
+    let height = height.clamp(3, r.height);
+    let x = r.x + (r.width.saturating_sub(width)) / 2;
+    let y = r.y + (r.height.saturating_sub(height)) / 2;
+    Rect::new(x, y, width, height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    #[test]
+    fn dims_inherited_steps() {
+        let state = DockerfileViewState {
+            image_name: "app:1".into(),
+            text: "#   CMD [\"bash\"]\nFROM <base image>\nCOPY . .\n".into(),
+            scroll_offset: 0,
+        };
+        let mut terminal = Terminal::new(TestBackend::new(80, 20)).unwrap();
+        terminal
+            .draw(|f| render_dockerfile_viewer(f, f.area(), &state))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let row = |y: u16| -> String {
+            (0..buffer.area.width)
+                .filter_map(|x| buffer.cell((x, y)).map(|c| c.symbol().to_string()))
+                .collect()
+        };
+        let text: Vec<String> = (0..buffer.area.height).map(row).collect();
+        assert!(text
+            .iter()
+            .any(|l| l.contains("Dockerfile: app:1 (approximate)")));
+        let comment = text
+            .iter()
+            .position(|l| l.contains("CMD [\"bash\"]"))
+            .unwrap();
+        assert!(text[comment + 1].contains("FROM <base image>"));
+        assert!(text[comment + 2].contains("COPY . ."));
+
+        let x = text[comment].chars().position(|c| c == '#').unwrap() as u16;
+        assert_eq!(
+            buffer.cell((x, comment as u16)).unwrap().fg,
+            Color::DarkGray
+        );
+    }
+}
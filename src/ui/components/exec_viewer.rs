@@ -1,24 +1,40 @@
 //! Exec viewer component - bottom panel style
 
 use ratatui::layout::Rect;
-use ratatui::style::{Color, Style};
-use ratatui::text::Line;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
 use ratatui::widgets::{Block, Borders, Paragraph};
 use ratatui::Frame;
 
+use crate::exec::copy_mode::CopyModeState;
 use crate::state::ExecViewState;
 
 /// Height of the exec panel
 pub const EXEC_PANEL_HEIGHT: u16 = 10;
 
+/// Number of text rows visible inside the exec panel
+pub fn exec_view_rows() -> usize {
+    EXEC_PANEL_HEIGHT.saturating_sub(2) as usize
+}
+
 /// Render the exec viewer as a bottom panel
 pub fn render_exec_panel(frame: &mut Frame, area: Rect, state: &ExecViewState) {
-    let title = format!(
-        " Exec: {} [{}] {} ",
-        state.container_name,
-        if state.focus { "FOCUS" } else { "UI" },
-        state.status
-    );
+    if let Some(copy_mode) = &state.copy_mode {
+        render_copy_mode(frame, area, state, copy_mode);
+        return;
+    }
+
+    let title = if state.playback {
+        format!(" Replay: {} {} ", state.container_name, state.status)
+    } else {
+        format!(
+            " Exec: {} [{}] {}{} ",
+            state.container_name,
+            if state.focus { "FOCUS" } else { "UI" },
+            state.status,
+            if state.recording { " ● REC" } else { "" }
+        )
+    };
 
     let border_color = if state.focus {
         Color::Magenta
@@ -48,7 +64,7 @@ pub fn render_exec_panel(frame: &mut Frame, area: Rect, state: &ExecViewState) {
     let para = Paragraph::new(lines);
     frame.render_widget(para, inner_area);
 
-    if state.focus {
+    if state.focus && !state.playback {
         if let Some((row, col)) = state.cursor {
             if row < inner_area.height && col < inner_area.width {
                 frame.set_cursor_position((
@@ -60,6 +76,77 @@ pub fn render_exec_panel(frame: &mut Frame, area: Rect, state: &ExecViewState) {
     }
 }
 
+/// Render the frozen scrollback snapshot with cursor and selection
+fn render_copy_mode(frame: &mut Frame, area: Rect, state: &ExecViewState, copy: &CopyModeState) {
+    let position = if let Some(input) = &copy.search_input {
+        format!("/{}", input)
+    } else {
+        format!("{}/{}", copy.cursor.0 + 1, copy.lines.len().max(1))
+    };
+    let title = format!(
+        " Exec: {} [COPY{}] {} ",
+        state.container_name,
+        if copy.anchor.is_some() { " VISUAL" } else { "" },
+        position
+    );
+
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow));
+
+    let inner_area = block.inner(area);
+    frame.render_widget(block, area);
+
+    let height = inner_area.height as usize;
+    // Keep the cursor visible even if the pane is smaller than expected
+    let mut top = copy.scroll_offset;
+    if copy.cursor.0 >= top + height.max(1) {
+        top = copy.cursor.0 + 1 - height.max(1);
+    }
+
+    let cursor_style = Style::default().add_modifier(Modifier::REVERSED);
+    let selected_style = Style::default().bg(Color::Blue).fg(Color::White);
+
+    let lines: Vec<Line> = copy
+        .lines
+        .iter()
+        .enumerate()
+        .skip(top)
+        .take(height)
+        .map(|(idx, text)| {
+            let mut spans: Vec<Span> = Vec::new();
+            let mut run = String::new();
+            let mut run_style = Style::default();
+            let mut chars: Vec<char> = text.chars().collect();
+            if idx == copy.cursor.0 && copy.cursor.1 >= chars.len() {
+                chars.resize(copy.cursor.1 + 1, ' ');
+            }
+
+            for (col, ch) in chars.into_iter().enumerate() {
+                let style = if (idx, col) == copy.cursor {
+                    cursor_style
+                } else if copy.is_selected(idx, col) {
+                    selected_style
+                } else {
+                    Style::default()
+                };
+                if style != run_style && !run.is_empty() {
+                    spans.push(Span::styled(std::mem::take(&mut run), run_style));
+                }
+                run_style = style;
+                run.push(ch);
+            }
+            if !run.is_empty() {
+                spans.push(Span::styled(run, run_style));
+            }
+            Line::from(spans)
+        })
+        .collect();
+
+    frame.render_widget(Paragraph::new(lines), inner_area);
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -78,6 +165,9 @@ mod tests {
             status: "Running".into(),
             screen_lines: vec!["hello".into()],
             cursor: None,
+            copy_mode: None,
+            recording: false,
+            playback: false,
         };
 
         terminal
@@ -100,6 +190,9 @@ mod tests {
             status: "Starting |".into(),
             screen_lines: vec![],
             cursor: None,
+            copy_mode: None,
+            recording: false,
+            playback: false,
         };
 
         terminal
@@ -132,6 +225,9 @@ mod tests {
             status: "Running".into(),
             screen_lines: vec!["hello".into()],
             cursor: Some((1, 2)),
+            copy_mode: None,
+            recording: false,
+            playback: false,
         };
 
         terminal
@@ -142,4 +238,76 @@ mod tests {
             .backend_mut()
             .assert_cursor_position(Position::new(3, 2));
     }
+
+    #[test]
+    fn renders_recording_and_replay_titles() {
+        let backend = TestBackend::new(60, 6);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let mut state = ExecViewState {
+            container_id: "id".into(),
+            container_name: "web".into(),
+            focus: true,
+            status: "Running".into(),
+            screen_lines: vec![],
+            cursor: None,
+            copy_mode: None,
+            recording: true,
+            playback: false,
+        };
+
+        let title = |terminal: &Terminal<TestBackend>| -> String {
+            let buffer = terminal.backend().buffer();
+            (0..buffer.area.width)
+                .filter_map(|x| buffer.cell((x, 0)).map(|c| c.symbol().to_string()))
+                .collect()
+        };
+
+        terminal
+            .draw(|f| render_exec_panel(f, f.area(), &state))
+            .unwrap();
+        assert!(title(&terminal).contains("● REC"));
+
+        state.recording = false;
+        state.playback = true;
+        state.status = "Playing 1.0s/2.0s".into();
+        terminal
+            .draw(|f| render_exec_panel(f, f.area(), &state))
+            .unwrap();
+        assert!(title(&terminal).contains("Replay: web Playing"));
+    }
+
+    #[test]
+    fn renders_copy_mode_snapshot() {
+        let backend = TestBackend::new(40, 6);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let lines: Vec<String> = (0..10).map(|i| format!("line {i}")).collect();
+        let state = ExecViewState {
+            container_id: "id".into(),
+            container_name: "web".into(),
+            focus: true,
+            status: "Running".into(),
+            screen_lines: vec!["live".into()],
+            cursor: None,
+            copy_mode: Some(CopyModeState::new(lines, 4)),
+            recording: false,
+            playback: false,
+        };
+
+        terminal
+            .draw(|f| render_exec_panel(f, f.area(), &state))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let text: String = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .filter_map(|x| buffer.cell((x, y)).map(|c| c.symbol().to_string()))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(text.contains("[COPY]"));
+        assert!(text.contains("line 9"));
+        assert!(!text.contains("live"));
+    }
 }
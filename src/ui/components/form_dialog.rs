@@ -0,0 +1,143 @@
+//! Form dialog component - text fields and toggles in a centered popup
+
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use crate::state::{FormDialogState, FormFieldKind};
+
+/// Render a form dialog
+pub fn render_form_dialog(frame: &mut Frame, area: Rect, form: &FormDialogState) {
+    let label_width = form
+        .fields
+        .iter()
+        .map(|f| f.label.chars().count())
+        .max()
+        .unwrap_or(0);
+
+    // One line per field, plus spacer and hint, plus borders and margin
+    let height = form.fields.len() as u16 + 6;
+    let popup_area = centered_rect(70, height, area);
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!(" {} ", form.title))
+        .title_style(
+            Style::default()
+                .fg(Color::Yellow)
+                .add_modifier(Modifier::BOLD),
+        )
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Yellow))
+        .style(Style::default().bg(Color::Black));
+
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let mut lines: Vec<Line> = vec![Line::from("")];
+    for (idx, field) in form.fields.iter().enumerate() {
+        let focused = idx == form.selected_index;
+        let label_style = if focused {
+            Style::default()
+                .fg(Color::Cyan)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(Color::Gray)
+        };
+        let label = format!(" {:width$}  ", field.label, width = label_width);
+
+        let value = match field.kind {
+            FormFieldKind::Toggle => {
+                let checked = field.value == "true";
+                Span::styled(
+                    if checked { "[✓]" } else { "[ ]" },
+                    Style::default().fg(Color::White),
+                )
+            }
+            FormFieldKind::Text if field.value.is_empty() && !field.placeholder.is_empty() => {
+                Span::styled(
+                    field.placeholder.clone(),
+                    Style::default().fg(Color::DarkGray),
+                )
+            }
+            FormFieldKind::Text => {
+                Span::styled(field.value.clone(), Style::default().fg(Color::White))
+            }
+        };
+
+        let mut spans = vec![Span::styled(label, label_style), value];
+        if focused && field.kind == FormFieldKind::Text {
+            spans.push(Span::styled("_", Style::default().fg(Color::Cyan)));
+        }
+        lines.push(Line::from(spans));
+    }
+    lines.push(Line::from(""));
+    lines.push(
+        Line::from(vec![
+            Span::styled("[", Style::default().fg(Color::Gray)),
+            Span::styled(
+                "Enter",
+                Style::default()
+                    .fg(Color::Green)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                "]Submit [Tab/↑↓]Field [Space]Toggle [",
+                Style::default().fg(Color::Gray),
+            ),
+            Span::styled(
+                "Esc",
+                Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("]Cancel", Style::default().fg(Color::Gray)),
+        ])
+        .alignment(Alignment::Center),
+    );
+
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+fn centered_rect(percent_x: u16, height: u16, r: Rect) -> Rect {
+    let width = ((r.width as u32) * (percent_x as u32) / 100) as u16;
+    let width = width.clamp(3, r.width);
+    let height = height.clamp(3, r.height);
+    let x = r.x + (r.width.saturating_sub(width)) / 2;
+    let y = r.y + (r.height.saturating_sub(height)) / 2;
+    Rect::new(x, y, width, height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::FormKind;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    #[test]
+    fn renders_fields_and_placeholder() {
+        let backend = TestBackend::new(80, 20);
+        let mut terminal = Terminal::new(backend).unwrap();
+        let form = FormDialogState::new(FormKind::PlayRecording, "Play Recording")
+            .with_text("path", "File", "", "session.cast")
+            .with_toggle("loop", "Loop", true);
+
+        terminal
+            .draw(|f| render_form_dialog(f, f.area(), &form))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let text: String = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .filter_map(|x| buffer.cell((x, y)).map(|c| c.symbol().to_string()))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(text.contains("Play Recording"));
+        assert!(text.contains("session.cast"));
+        assert!(text.contains("[✓]"));
+    }
+}
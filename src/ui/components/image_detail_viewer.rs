@@ -8,6 +8,7 @@ use ratatui::Frame;
 use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
 use crate::docker::{format_signed_size, format_size};
+use crate::scan::{ScanReport, Severity};
 use crate::state::ImageDetailViewState;
 
 /// Render the image detail viewer overlay
@@ -19,7 +20,7 @@ pub fn render_image_detail_viewer(frame: &mut Frame, area: Rect, state: &ImageDe
     frame.render_widget(Clear, popup_area);
 
     // Build title
-    let title = format!(" Image: {} ", state.image_name);
+    let title = format!(" Image: {}  [l]Layers [f]Dockerfile ", state.image_name);
 
     // Create block with explicit background for the popup border + area
     let block = Block::default()
@@ -168,6 +169,12 @@ pub fn render_image_detail_viewer(frame: &mut Frame, area: Rect, state: &ImageDe
                 Span::styled(value.as_str(), value_style),
             ]));
         }
+        lines.push(Line::from(""));
+    }
+
+    // Imported vulnerability report
+    if let Some(ref scan) = state.scan {
+        push_findings(&mut lines, state, scan);
     }
 
     let wrapped_lines = wrap_lines_to_width(&lines, inner_area.width);
@@ -185,6 +192,76 @@ pub fn render_image_detail_viewer(frame: &mut Frame, area: Rect, state: &ImageDe
     frame.render_widget(paragraph, popup_area);
 }
 
+/// Findings section, filtered by severity and package
+fn push_findings<'a>(
+    lines: &mut Vec<Line<'a>>,
+    state: &'a ImageDetailViewState,
+    scan: &'a ScanReport,
+) {
+    let label_style = Style::default().fg(Color::Gray).bg(Color::Black);
+    let value_style = Style::default().fg(Color::White).bg(Color::Black);
+
+    lines.push(Line::from(vec![
+        Span::styled(
+            format!(
+                "Vulnerabilities ({}, {}): ",
+                scan.scanner,
+                scan.path.file_name().unwrap_or_default().to_string_lossy()
+            ),
+            label_style,
+        ),
+        Span::styled(scan.counts().short(), value_style),
+    ]));
+
+    let mut filters = vec![match state.finding_severity {
+        Some(severity) => format!("severity ≥ {}", severity),
+        None => "all severities".to_string(),
+    }];
+    if !state.finding_package.is_empty() {
+        filters.push(format!("package ~ {}", state.finding_package));
+    }
+    lines.push(Line::from(Span::styled(
+        format!("  Showing {}  [v]Severity [/]Package", filters.join(", ")),
+        Style::default().fg(Color::DarkGray).bg(Color::Black),
+    )));
+
+    let mut shown = 0;
+    for finding in scan.filtered(state.finding_severity, &state.finding_package) {
+        shown += 1;
+        let color = match finding.severity {
+            Severity::Critical => Color::Red,
+            Severity::High => Color::LightRed,
+            Severity::Medium => Color::Yellow,
+            Severity::Low | Severity::Unknown => Color::Gray,
+        };
+        let fix = match &finding.fixed_version {
+            Some(fixed) => format!(" → {}", fixed),
+            None => " (no fix)".to_string(),
+        };
+        let mut spans = vec![
+            Span::styled(
+                format!("  {:<9} ", finding.severity.to_string()),
+                Style::default().fg(color).bg(Color::Black),
+            ),
+            Span::styled(format!("{} ", finding.id), value_style),
+            Span::styled(
+                format!("{} {}{}", finding.package, finding.installed_version, fix),
+                Style::default().fg(Color::Cyan).bg(Color::Black),
+            ),
+        ];
+        if !finding.title.is_empty() {
+            spans.push(Span::styled(format!("  {}", finding.title), label_style));
+        }
+        lines.push(Line::from(spans));
+    }
+    if shown == 0 {
+        lines.push(Line::from(Span::styled(
+            "  No matching findings",
+            Style::default().fg(Color::Green).bg(Color::Black),
+        )));
+    }
+}
+
 fn wrap_lines_to_width(lines: &[Line], width: u16) -> Vec<Line<'static>> {
     let width = width as usize;
     if width == 0 {
@@ -328,4 +405,81 @@ mod tests {
             assert!(line_width <= 10);
         }
     }
+
+    #[test]
+    fn filters_findings() {
+        use crate::scan::{Finding, ScanReport, Scanner};
+        use ratatui::{backend::TestBackend, Terminal};
+        use std::sync::Arc;
+
+        let finding = |id: &str, package: &str, severity| Finding {
+            id: id.into(),
+            package: package.into(),
+            installed_version: "1.0".into(),
+            fixed_version: Some("1.1".into()),
+            severity,
+            title: String::new(),
+        };
+        let mut state = ImageDetailViewState {
+            image_id: "sha256:a".into(),
+            image_name: "app:1".into(),
+            details: Some(crate::docker::ImageDetails {
+                id: "sha256:a".into(),
+                repo_tags: vec![],
+                size: 0,
+                created: String::new(),
+                author: String::new(),
+                os: "linux".into(),
+                architecture: "amd64".into(),
+                exposed_ports: vec![],
+                env: vec![],
+                entrypoint: None,
+                cmd: None,
+                labels: vec![],
+                layers: vec![],
+            }),
+            scroll_offset: 0,
+            scan: Some(Arc::new(ScanReport {
+                scanner: Scanner::Grype,
+                path: "reports/app.json".into(),
+                image: "app:1".into(),
+                digests: vec!["sha256:a".into()],
+                findings: vec![
+                    finding("CVE-1", "openssl", Severity::Critical),
+                    finding("CVE-2", "zlib", Severity::Low),
+                ],
+            })),
+            finding_severity: None,
+            finding_package: String::new(),
+        };
+
+        let render = |state: &ImageDetailViewState| {
+            let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+            terminal
+                .draw(|f| render_image_detail_viewer(f, f.area(), state))
+                .unwrap();
+            let buffer = terminal.backend().buffer().clone();
+            (0..buffer.area.height)
+                .map(|y| {
+                    (0..buffer.area.width)
+                        .filter_map(|x| buffer.cell((x, y)).map(|c| c.symbol().to_string()))
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        let text = render(&state);
+        assert!(text.contains("Vulnerabilities (Grype, app.json): C1 L1"));
+        assert!(text.contains("CRITICAL  CVE-1 openssl 1.0 → 1.1"));
+        assert!(text.contains("LOW       CVE-2 zlib"));
+
+        state.finding_severity = Some(Severity::High);
+        let text = render(&state);
+        assert!(text.contains("Showing severity ≥ HIGH"));
+        assert!(!text.contains("CVE-2"));
+
+        state.finding_package = "zlib".into();
+        assert!(render(&state).contains("No matching findings"));
+    }
 }
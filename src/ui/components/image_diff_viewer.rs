@@ -0,0 +1,287 @@
+//! Side-by-side image comparison overlay
+
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::docker::{format_signed_size, format_size, DiffEntry, DiffKind, ImageDiff, ImageLayer};
+use crate::state::ImageDiffViewState;
+
+/// Render the image comparison overlay
+pub fn render_image_diff_viewer(frame: &mut Frame, area: Rect, state: &ImageDiffViewState) {
+    let popup_area = centered_rect(90, 90, area);
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!(
+            " Compare: {} → {} ",
+            state.left_name, state.right_name
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+
+    let Some(diff) = &state.diff else {
+        let loading = Paragraph::new("Inspecting both images...")
+            .style(Style::default().fg(Color::Yellow).bg(Color::Black))
+            .alignment(Alignment::Center)
+            .block(block);
+        frame.render_widget(loading, popup_area);
+        return;
+    };
+
+    let inner_area = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(inner_area);
+
+    let lines = diff_lines(diff);
+    let max_scroll = lines.len().saturating_sub(rows[0].height as usize);
+    frame.render_widget(
+        Paragraph::new(lines).scroll((state.scroll_offset.min(max_scroll) as u16, 0)),
+        rows[0],
+    );
+    frame.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            " [↑/↓]Scroll [PgUp/PgDn]Page [Home/End]Top/Bottom [q]Close",
+            Style::default().fg(Color::DarkGray),
+        ))),
+        rows[1],
+    );
+}
+
+/// Content lines of a comparison
+fn diff_lines(diff: &ImageDiff) -> Vec<Line<'static>> {
+    let heading = Style::default()
+        .fg(Color::Cyan)
+        .add_modifier(Modifier::BOLD);
+    let label = Style::default().fg(Color::Gray);
+    let mut lines = Vec::new();
+
+    let delta = diff.size_delta();
+    lines.push(Line::from(vec![
+        Span::styled("Size: ", label),
+        Span::raw(format!(
+            "{} → {}  ",
+            format_size(diff.left_size),
+            format_size(diff.right_size)
+        )),
+        Span::styled(
+            format!(
+                "({}{})",
+                if delta > 0 { "+" } else { "" },
+                format_signed_size(delta)
+            ),
+            Style::default().fg(delta_color(delta)),
+        ),
+    ]));
+    lines.push(Line::from(""));
+
+    if diff.same_config() {
+        lines.push(Line::from(Span::styled(
+            "Configuration is identical",
+            Style::default().fg(Color::Green),
+        )));
+        lines.push(Line::from(""));
+    }
+    let commands: Vec<DiffEntry> = diff
+        .entrypoint
+        .iter()
+        .chain(diff.cmd.iter())
+        .cloned()
+        .collect();
+    for (title, entries) in [
+        ("Entrypoint / Cmd", &commands),
+        ("Environment", &diff.env),
+        ("Labels", &diff.labels),
+        ("Exposed ports", &diff.ports),
+    ] {
+        if entries.is_empty() {
+            continue;
+        }
+        lines.push(Line::from(Span::styled(title, heading)));
+        lines.extend(entries.iter().map(entry_line));
+        lines.push(Line::from(""));
+    }
+
+    let layer_total = |layers: &[ImageLayer]| layers.iter().map(|l| l.size).sum::<i64>();
+    let sections = [
+        (
+            format!("Shared layers ({})", diff.shared_layers.len()),
+            &diff.shared_layers,
+            "  ",
+            Color::Gray,
+        ),
+        (
+            format!(
+                "Only in {} ({}, {})",
+                diff.left,
+                diff.left_layers.len(),
+                format_signed_size(layer_total(&diff.left_layers))
+            ),
+            &diff.left_layers,
+            "- ",
+            Color::Red,
+        ),
+        (
+            format!(
+                "Only in {} ({}, {})",
+                diff.right,
+                diff.right_layers.len(),
+                format_signed_size(layer_total(&diff.right_layers))
+            ),
+            &diff.right_layers,
+            "+ ",
+            Color::Green,
+        ),
+    ];
+    for (title, layers, marker, color) in sections {
+        lines.push(Line::from(Span::styled(title, heading)));
+        for layer in layers.iter() {
+            lines.push(Line::from(vec![
+                Span::styled(marker, Style::default().fg(color)),
+                Span::styled(format!("{:>10}  ", format_signed_size(layer.size)), label),
+                Span::styled(layer.created_by.clone(), Style::default().fg(color)),
+            ]));
+        }
+        lines.push(Line::from(""));
+    }
+    lines
+}
+
+/// One added, removed or changed setting
+fn entry_line(entry: &DiffEntry) -> Line<'static> {
+    let old = entry.old.clone().unwrap_or_default();
+    let new = entry.new.clone().unwrap_or_default();
+    let value = |key: &str, value: &str| {
+        if value.is_empty() {
+            key.to_string()
+        } else {
+            format!("{}={}", key, value)
+        }
+    };
+    match entry.kind {
+        DiffKind::Added => Line::from(Span::styled(
+            format!("+ {}", value(&entry.key, &new)),
+            Style::default().fg(Color::Green),
+        )),
+        DiffKind::Removed => Line::from(Span::styled(
+            format!("- {}", value(&entry.key, &old)),
+            Style::default().fg(Color::Red),
+        )),
+        DiffKind::Changed => Line::from(vec![
+            Span::styled(
+                format!("~ {}: ", entry.key),
+                Style::default().fg(Color::Yellow),
+            ),
+            Span::styled(old, Style::default().fg(Color::Red)),
+            Span::raw(" → "),
+            Span::styled(new, Style::default().fg(Color::Green)),
+        ]),
+    }
+}
+
+fn delta_color(delta: i64) -> Color {
+    match delta {
+        d if d > 0 => Color::Red,
+        d if d < 0 => Color::Green,
+        _ => Color::Gray,
+    }
+}
+
+/// Calculate centered rectangle for popup
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let width = ((r.width as u32) * (percent_x as u32) / 100) as u16;
+    let height = ((r.height as u32) * (percent_y as u32) / 100) as u16;
+    let width = width.clamp(3, r.width);
+    let height = height.clamp(3, r.height);
+    let x = r.x + (r.width.saturating_sub(width)) / 2;
+    let y = r.y + (r.height.saturating_sub(height)) / 2;
+    Rect::new(x, y, width, height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::docker::{diff_images, ImageDetails};
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+    use std::sync::Arc;
+
+    fn details(tag: &str, env: &str, layers: &[(&str, i64)]) -> ImageDetails {
+        ImageDetails {
+            id: format!("sha256:{}", tag),
+            repo_tags: vec![tag.into()],
+            size: layers.iter().map(|(_, size)| *size as u64).sum(),
+            created: String::new(),
+            author: String::new(),
+            os: "linux".into(),
+            architecture: "amd64".into(),
+            exposed_ports: vec![],
+            env: vec![env.into()],
+            entrypoint: None,
+            cmd: Some(vec!["serve".into()]),
+            labels: vec![],
+            layers: layers
+                .iter()
+                .rev()
+                .map(|(created_by, size)| ImageLayer {
+                    id: "<missing>".into(),
+                    created: "0".into(),
+                    created_by: created_by.to_string(),
+                    size: *size,
+                    comment: String::new(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn shows_changes_and_layers() {
+        let diff = diff_images(
+            &details(
+                "app:1.4",
+                "MODE=prod",
+                &[("ADD rootfs", 4096), ("COPY a", 1024)],
+            ),
+            &details(
+                "app:1.5",
+                "MODE=debug",
+                &[("ADD rootfs", 4096), ("COPY b", 3072)],
+            ),
+        );
+        let state = ImageDiffViewState {
+            left_name: "app:1.4".into(),
+            right_name: "app:1.5".into(),
+            diff: Some(Arc::new(diff)),
+            scroll_offset: 0,
+        };
+
+        let mut terminal = Terminal::new(TestBackend::new(90, 30)).unwrap();
+        terminal
+            .draw(|f| render_image_diff_viewer(f, f.area(), &state))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let text: String = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .filter_map(|x| buffer.cell((x, y)).map(|c| c.symbol().to_string()))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(text.contains("Compare: app:1.4 → app:1.5"));
+        assert!(text.contains("(+2.0 KB)"));
+        assert!(text.contains("~ MODE: prod → debug"));
+        assert!(text.contains("Shared layers (1)"));
+        assert!(text.contains("Only in app:1.5 (1, 3.0 KB)"));
+        assert!(text.contains("+     3.0 KB  COPY b"));
+    }
+}
@@ -1,5 +1,7 @@
 //! Image list widget
 
+use std::collections::{HashMap, HashSet};
+
 use ratatui::{
     layout::Constraint,
     style::{Color, Modifier, Style},
@@ -8,11 +10,18 @@ use ratatui::{
 };
 
 use crate::core::ImageSummary;
+use crate::scan::{Severity, SeverityCounts};
 
 /// Widget for displaying a list of Docker images
 pub struct ImageListWidget {
     images: Vec<ImageSummary>,
     state: TableState,
+    /// IDs of images marked for multi-image actions
+    marked: HashSet<String>,
+    /// IDs of images with a tag that is behind its registry
+    outdated: HashSet<String>,
+    /// Vulnerability counts of images with an imported scan report
+    vulnerabilities: HashMap<String, SeverityCounts>,
 }
 
 impl ImageListWidget {
@@ -22,7 +31,34 @@ impl ImageListWidget {
         if !images.is_empty() {
             state.select(Some(0));
         }
-        Self { images, state }
+        Self {
+            images,
+            state,
+            marked: HashSet::new(),
+            outdated: HashSet::new(),
+            vulnerabilities: HashMap::new(),
+        }
+    }
+
+    /// Show a marker next to these image IDs
+    pub fn with_marked(mut self, marked: HashSet<String>) -> Self {
+        self.marked = marked;
+        self
+    }
+
+    /// Flag these image IDs as outdated
+    pub fn with_outdated(mut self, outdated: HashSet<String>) -> Self {
+        self.outdated = outdated;
+        self
+    }
+
+    /// Show a vulnerability summary column for images with a scan report
+    pub fn with_vulnerabilities(
+        mut self,
+        vulnerabilities: HashMap<String, SeverityCounts>,
+    ) -> Self {
+        self.vulnerabilities = vulnerabilities;
+        self
     }
 
     /// Update the image list
@@ -120,9 +156,30 @@ impl ImageListWidget {
         format!("{:.1} {}", size, UNITS[exp])
     }
 
+    /// Vulnerability summary cell, colored by the worst severity
+    fn vulnerability_cell(&self, id: &str) -> Line<'static> {
+        let Some(counts) = self.vulnerabilities.get(id) else {
+            return Line::from(Span::styled("-", Style::default().fg(Color::DarkGray)));
+        };
+        let color = match counts.worst() {
+            Some(Severity::Critical) => Color::Red,
+            Some(Severity::High) => Color::LightRed,
+            Some(Severity::Medium) => Color::Yellow,
+            Some(_) => Color::Gray,
+            None => Color::Green,
+        };
+        Line::from(Span::styled(counts.short(), Style::default().fg(color)))
+    }
+
     /// Build the table widget
     pub fn build_table(&self) -> Table<'_> {
-        let header = Row::new(vec!["REPOSITORY", "TAG", "ID", "SIZE", "CREATED"])
+        // The vulnerability column only appears once reports are imported
+        let show_vulnerabilities = !self.vulnerabilities.is_empty();
+        let mut columns = vec!["REPOSITORY", "TAG", "ID", "SIZE", "CREATED"];
+        if show_vulnerabilities {
+            columns.push("VULNS");
+        }
+        let header = Row::new(columns)
             .style(Style::default().add_modifier(Modifier::BOLD))
             .bottom_margin(0);
 
@@ -153,38 +210,71 @@ impl ImageListWidget {
 
                 let created = format_relative_time(i.created);
 
-                Row::new(vec![
-                    Line::from(Span::styled(repo, style)),
-                    Line::from(Span::styled(tag, style)),
+                let repo = if self.marked.contains(&i.id) {
+                    Line::from(vec![
+                        Span::styled("● ", Style::default().fg(Color::Green)),
+                        Span::styled(repo, style),
+                    ])
+                } else {
+                    Line::from(Span::styled(repo, style))
+                };
+
+                let tag = if self.outdated.contains(&i.id) {
+                    Line::from(vec![
+                        Span::styled(tag, style),
+                        Span::styled(" ↑", Style::default().fg(Color::Yellow)),
+                    ])
+                } else {
+                    Line::from(Span::styled(tag, style))
+                };
+
+                let mut cells = vec![
+                    repo,
+                    tag,
                     Line::from(i.short_id.clone()),
                     Line::from(Self::format_size(i.size)),
                     Line::from(created),
-                ])
+                ];
+                if show_vulnerabilities {
+                    cells.push(self.vulnerability_cell(&i.id));
+                }
+                Row::new(cells)
             })
             .collect();
 
-        Table::new(
-            rows,
-            [
-                Constraint::Min(20),    // Repository
-                Constraint::Length(15), // Tag
-                Constraint::Length(12), // ID
-                Constraint::Length(10), // Size
-                Constraint::Length(12), // Created
-            ],
-        )
-        .header(header)
-        .block(
-            Block::default()
-                .title(format!(" Images ({}) ", self.images.len()))
-                .borders(Borders::ALL),
-        )
-        .row_highlight_style(
-            Style::default()
-                .bg(Color::DarkGray)
-                .add_modifier(Modifier::BOLD),
-        )
-        .highlight_symbol("▶ ")
+        let mut widths = vec![
+            Constraint::Min(20),    // Repository
+            Constraint::Length(15), // Tag
+            Constraint::Length(12), // ID
+            Constraint::Length(10), // Size
+            Constraint::Length(12), // Created
+        ];
+        if show_vulnerabilities {
+            widths.push(Constraint::Length(16)); // Vulnerabilities
+        }
+
+        Table::new(rows, widths)
+            .header(header)
+            .block(
+                Block::default()
+                    .title({
+                        let mut counts = vec![self.images.len().to_string()];
+                        if !self.marked.is_empty() {
+                            counts.push(format!("{} marked", self.marked.len()));
+                        }
+                        if !self.outdated.is_empty() {
+                            counts.push(format!("{} outdated", self.outdated.len()));
+                        }
+                        format!(" Images ({}) ", counts.join(", "))
+                    })
+                    .borders(Borders::ALL),
+            )
+            .row_highlight_style(
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD),
+            )
+            .highlight_symbol("▶ ")
     }
 
     /// Get the table state for rendering
@@ -288,4 +378,65 @@ mod tests {
         widget.previous();
         assert_eq!(widget.state.selected(), Some(1)); // Wrap around
     }
+
+    #[test]
+    fn test_marked_and_outdated_images() {
+        use ratatui::{backend::TestBackend, Terminal};
+
+        let widget = ImageListWidget::new(create_test_images())
+            .with_marked(HashSet::from(["abc123def456".to_string()]))
+            .with_outdated(HashSet::from(["abc123def456".to_string()]));
+        let mut terminal = Terminal::new(TestBackend::new(80, 6)).unwrap();
+        terminal
+            .draw(|f| f.render_widget(widget.build_table(), f.area()))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let text: String = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .filter_map(|x| buffer.cell((x, y)).map(|c| c.symbol().to_string()))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(text.contains("Images (2, 1 marked, 1 outdated)"));
+        assert!(text.contains("latest ↑"));
+        assert!(text.contains("● nginx"));
+        assert!(!text.contains("● <none>"));
+    }
+
+    #[test]
+    fn shows_vulnerability_column_once_reports_are_loaded() {
+        use ratatui::{backend::TestBackend, Terminal};
+
+        let render = |widget: ImageListWidget| {
+            let mut terminal = Terminal::new(TestBackend::new(100, 5)).unwrap();
+            terminal
+                .draw(|f| f.render_widget(widget.build_table(), f.area()))
+                .unwrap();
+            let buffer = terminal.backend().buffer().clone();
+            (0..buffer.area.height)
+                .map(|y| {
+                    (0..buffer.area.width)
+                        .filter_map(|x| buffer.cell((x, y)).map(|c| c.symbol().to_string()))
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+        };
+
+        let rows = render(ImageListWidget::new(create_test_images()));
+        assert!(!rows[1].contains("VULNS"));
+
+        let counts = [Severity::Critical, Severity::High, Severity::High]
+            .into_iter()
+            .collect();
+        let rows = render(
+            ImageListWidget::new(create_test_images())
+                .with_vulnerabilities(HashMap::from([("abc123def456".to_string(), counts)])),
+        );
+        assert!(rows[1].contains("VULNS"));
+        assert!(rows[2].contains("C1 H2"));
+        assert!(rows[3].trim_end_matches('│').trim_end().ends_with('-'));
+    }
 }
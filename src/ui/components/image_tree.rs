@@ -0,0 +1,119 @@
+//! Image dependency tree (parent/child images and their containers)
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+
+use crate::core::{ContainerState, ContainerSummary, ImageSummary};
+use crate::docker::format_bytes_size;
+use crate::state::image_tree::{container_name, image_name, tree_order};
+
+/// Render images as a tree, each followed by the containers created from
+/// it. `images` is expected in tree order (see `AppState::toggle_image_tree`).
+pub fn render_image_tree(
+    frame: &mut Frame,
+    area: Rect,
+    images: &[ImageSummary],
+    containers: &[ContainerSummary],
+    selected: usize,
+) {
+    let mut lines = Vec::new();
+    let mut selected_line = 0;
+    for (index, depth) in tree_order(images) {
+        let image = &images[index];
+        let indent = "  ".repeat(depth);
+        let branch = if depth > 0 { "└─ " } else { "" };
+        let style = if index == selected {
+            selected_line = lines.len();
+            Style::default()
+                .bg(Color::DarkGray)
+                .add_modifier(Modifier::BOLD)
+        } else if image.dangling {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default()
+        };
+        lines.push(Line::from(vec![
+            Span::styled(format!("{}{}{}", indent, branch, image_name(image)), style),
+            Span::styled(
+                format!("  {}  {}", image.short_id, format_bytes_size(image.size)),
+                Style::default().fg(Color::Gray),
+            ),
+        ]));
+
+        for container in containers.iter().filter(|c| c.image_id == image.id) {
+            let color = match container.state {
+                ContainerState::Running => Color::Green,
+                ContainerState::Paused => Color::Yellow,
+                ContainerState::Exited | ContainerState::Dead => Color::Red,
+                _ => Color::Gray,
+            };
+            lines.push(Line::from(vec![
+                Span::raw(format!("{}   ", indent)),
+                Span::styled("◦ ", Style::default().fg(color)),
+                Span::styled(container_name(container), Style::default().fg(Color::Cyan)),
+                Span::styled(
+                    format!("  {}", container.status),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ]));
+        }
+    }
+
+    // Scroll to keep the selected image visible
+    let visible = area.height.saturating_sub(2) as usize;
+    let first = (selected_line + 1).saturating_sub(visible);
+    frame.render_widget(
+        Paragraph::new(lines).scroll((first as u16, 0)).block(
+            Block::default()
+                .title(format!(" Images ({}) [tree] ", images.len()))
+                .borders(Borders::ALL),
+        ),
+        area,
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    #[test]
+    fn shows_children_and_containers() {
+        let image = |id: &str, tag: &str, parent: &str| ImageSummary {
+            id: id.into(),
+            short_id: id.into(),
+            repo_tags: vec![tag.into()],
+            parent_id: parent.into(),
+            ..Default::default()
+        };
+        let images = vec![image("base", "base:1", ""), image("app", "app:1", "base")];
+        let containers = vec![ContainerSummary {
+            names: vec!["web".into()],
+            image_id: "app".into(),
+            state: ContainerState::Running,
+            status: "Up 2 hours".into(),
+            ..Default::default()
+        }];
+
+        let mut terminal = Terminal::new(TestBackend::new(60, 8)).unwrap();
+        terminal
+            .draw(|f| render_image_tree(f, f.area(), &images, &containers, 1))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let row = |y: u16| -> String {
+            (0..buffer.area.width)
+                .filter_map(|x| buffer.cell((x, y)).map(|c| c.symbol().to_string()))
+                .collect()
+        };
+        assert!(row(0).contains("Images (2) [tree]"));
+        assert!(row(1).contains("│base:1  base"));
+        assert!(row(2).contains("│  └─ app:1  app"));
+        assert!(row(3).contains("◦ web  Up 2 hours"));
+    }
+}
@@ -0,0 +1,258 @@
+//! Image layer explorer (per-layer file changes and wasted space)
+
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::docker::{format_size, FileChange};
+use crate::state::LayerExplorerState;
+
+/// Render the layer explorer overlay
+pub fn render_layer_explorer(frame: &mut Frame, area: Rect, state: &LayerExplorerState) {
+    let popup_area = centered_rect(90, 90, area);
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!(" Layers: {} ", state.image_name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+
+    let Some(analysis) = &state.analysis else {
+        let loading = Paragraph::new("Exporting and analyzing image layers... [q]Cancel")
+            .style(Style::default().fg(Color::Yellow).bg(Color::Black))
+            .alignment(Alignment::Center)
+            .block(block);
+        frame.render_widget(loading, popup_area);
+        return;
+    };
+
+    let inner_area = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Min(1),
+            Constraint::Length(1),
+        ])
+        .split(inner_area);
+
+    // Summary
+    let efficiency_color = if analysis.efficiency() >= 0.95 {
+        Color::Green
+    } else {
+        Color::Yellow
+    };
+    let summary = Line::from(vec![
+        Span::styled(" Total: ", Style::default().fg(Color::Gray)),
+        Span::raw(format_size(analysis.total_size())),
+        Span::styled("  Wasted: ", Style::default().fg(Color::Gray)),
+        Span::styled(
+            format_size(analysis.wasted_bytes()),
+            Style::default().fg(Color::Red),
+        ),
+        Span::styled("  Efficiency: ", Style::default().fg(Color::Gray)),
+        Span::styled(
+            format!("{:.1}%", analysis.efficiency() * 100.0),
+            Style::default().fg(efficiency_color),
+        ),
+    ]);
+    frame.render_widget(Paragraph::new(summary), rows[0]);
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+        .split(rows[1]);
+
+    // Layer list, scrolled to keep the selection visible
+    let visible = columns[0].height.saturating_sub(2) as usize;
+    let first = (state.selected_layer + 1).saturating_sub(visible);
+    let layer_lines: Vec<Line> = analysis
+        .layers
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(visible)
+        .map(|(index, layer)| {
+            let style = if index == state.selected_layer {
+                Style::default()
+                    .bg(Color::DarkGray)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            Line::from(vec![
+                Span::styled(format!("{:>9} ", format_size(layer.size)), style),
+                Span::styled(
+                    if layer.created_by.is_empty() {
+                        layer.digest.clone()
+                    } else {
+                        layer.created_by.clone()
+                    },
+                    style,
+                ),
+            ])
+        })
+        .collect();
+    frame.render_widget(
+        Paragraph::new(layer_lines).block(
+            Block::default()
+                .title(format!(" Layers ({}) ", analysis.layers.len()))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        ),
+        columns[0],
+    );
+
+    // File tree of the selected layer, or the wasted-space list
+    let (title, lines): (String, Vec<Line>) = if state.show_wasted {
+        let lines = analysis
+            .wasted
+            .iter()
+            .skip(state.scroll_offset)
+            .map(|wasted| {
+                Line::from(vec![
+                    Span::styled(
+                        format!("{:>9} ", format_size(wasted.bytes)),
+                        Style::default().fg(Color::Red),
+                    ),
+                    Span::styled(
+                        format!("{:>3}x ", wasted.copies),
+                        Style::default().fg(Color::Gray),
+                    ),
+                    Span::raw(format!("/{}", wasted.path)),
+                ])
+            })
+            .collect();
+        (format!(" Wasted space ({}) ", analysis.wasted.len()), lines)
+    } else {
+        let layer = analysis.layers.get(state.selected_layer);
+        let lines = layer
+            .map(|layer| layer.tree.as_slice())
+            .unwrap_or_default()
+            .iter()
+            .skip(state.scroll_offset)
+            .map(|line| {
+                let (marker, color) = match line.change {
+                    Some(FileChange::Added) => ("+ ", Color::Green),
+                    Some(FileChange::Modified) => ("~ ", Color::Yellow),
+                    Some(FileChange::Removed) => ("- ", Color::Red),
+                    None => ("  ", Color::Gray),
+                };
+                let name = if line.is_dir {
+                    format!("{}{}/", "  ".repeat(line.depth), line.name)
+                } else {
+                    format!("{}{}", "  ".repeat(line.depth), line.name)
+                };
+                Line::from(vec![
+                    Span::styled(
+                        format!("{:>9} ", format_size(line.size)),
+                        Style::default().fg(Color::Gray),
+                    ),
+                    Span::styled(marker, Style::default().fg(color)),
+                    Span::styled(name, Style::default().fg(color)),
+                ])
+            })
+            .collect();
+        let title = layer
+            .map(|l| format!(" {} ({} changes) ", l.digest, l.changes.len()))
+            .unwrap_or_default();
+        (title, lines)
+    };
+    frame.render_widget(
+        Paragraph::new(lines).block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        ),
+        columns[1],
+    );
+
+    let footer = Line::from(Span::styled(
+        " [↑/↓]Layer [PgUp/PgDn]Scroll [Tab]Files/Wasted [q]Close",
+        Style::default().fg(Color::DarkGray),
+    ));
+    frame.render_widget(Paragraph::new(footer), rows[2]);
+}
+
+/// Calculate centered rectangle for popup
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let width = ((r.width as u32) * (percent_x as u32) / 100) as u16;
+    let height = ((r.height as u32) * (percent_y as u32) / 100) as u16;
+    let width = width.clamp(3, r.width);
+    let height = height.clamp(3, r.height);
+    let x = r.x + (r.width.saturating_sub(width)) / 2;
+    let y = r.y + (r.height.saturating_sub(height)) / 2;
+    Rect::new(x, y, width, height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::docker::layers::{analyze, RawEntry};
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+    use std::sync::Arc;
+
+    #[test]
+    fn shows_layer_tree_and_waste() {
+        let entry = |path: &str, size| RawEntry {
+            path: path.into(),
+            size,
+            is_dir: false,
+        };
+        let analysis = analyze(vec![
+            (
+                "aaa".into(),
+                "ADD rootfs".into(),
+                vec![entry("etc/passwd", 2048)],
+            ),
+            (
+                "bbb".into(),
+                "RUN useradd app".into(),
+                vec![entry("etc/passwd", 4096)],
+            ),
+        ]);
+        let mut state = LayerExplorerState {
+            image_name: "app:1".into(),
+            analysis: Some(Arc::new(analysis)),
+            selected_layer: 1,
+            scroll_offset: 0,
+            show_wasted: false,
+        };
+
+        let render = |state: &LayerExplorerState| {
+            let mut terminal = Terminal::new(TestBackend::new(100, 16)).unwrap();
+            terminal
+                .draw(|f| render_layer_explorer(f, f.area(), state))
+                .unwrap();
+            let buffer = terminal.backend().buffer().clone();
+            (0..buffer.area.height)
+                .map(|y| {
+                    (0..buffer.area.width)
+                        .filter_map(|x| buffer.cell((x, y)).map(|c| c.symbol().to_string()))
+                        .collect::<String>()
+                })
+                .collect::<Vec<_>>()
+                .join("\n")
+        };
+
+        let text = render(&state);
+        assert!(text.contains("Layers: app:1"));
+        assert!(text.contains("Wasted: 2.0 KB"));
+        assert!(text.contains("RUN useradd app"));
+        assert!(text.contains("bbb (1 changes)"));
+        assert!(text.contains("~   passwd"));
+
+        state.show_wasted = true;
+        let text = render(&state);
+        assert!(text.contains("1x /etc/passwd"));
+    }
+}
@@ -66,8 +66,8 @@ pub fn render_log_viewer(frame: &mut Frame, area: Rect, state: &LogViewState) {
             }
         })
         .unwrap_or_default();
-    let search_indicator = if state.search_pattern.is_some() {
-        format!(" [SEARCH: {}]", state.search_pattern.as_ref().unwrap())
+    let search_indicator = if let Some(pattern) = &state.search_pattern {
+        format!(" [SEARCH: {}]", pattern)
     } else {
         String::new()
     };
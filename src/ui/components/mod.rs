@@ -1,22 +1,52 @@
 //! UI components
 
+pub mod build_viewer;
+pub mod cleanup_planner;
 pub mod container_list;
+pub mod death_report;
 pub mod detail_panel;
 pub mod detail_viewer;
+pub mod dockerfile_viewer;
 pub mod exec_viewer;
+pub mod form_dialog;
 pub mod image_detail_viewer;
+pub mod image_diff_viewer;
 pub mod image_list;
+pub mod image_tree;
+pub mod layer_explorer;
 pub mod log_viewer;
+pub mod network_detail_viewer;
 pub mod network_list;
+pub mod network_topology;
+pub mod registry_browser;
+pub mod run_spec_viewer;
 pub mod stats_viewer;
+pub mod transfer_dialog;
+pub mod volume_browser;
+pub mod volume_detail_viewer;
 pub mod volume_list;
 
+pub use build_viewer::render_build_viewer;
+pub use cleanup_planner::render_cleanup_planner;
 pub use container_list::ContainerListWidget;
+pub use death_report::render_death_report;
 pub use detail_panel::{ContainerDetailPanel, SplitLayout};
 pub use detail_viewer::render_detail_viewer;
+pub use dockerfile_viewer::render_dockerfile_viewer;
 pub use exec_viewer::{render_exec_panel, EXEC_PANEL_HEIGHT};
+pub use form_dialog::render_form_dialog;
 pub use image_detail_viewer::render_image_detail_viewer;
+pub use image_diff_viewer::render_image_diff_viewer;
 pub use image_list::ImageListWidget;
+pub use image_tree::render_image_tree;
+pub use layer_explorer::render_layer_explorer;
+pub use network_detail_viewer::render_network_detail_viewer;
 pub use network_list::NetworkListWidget;
+pub use network_topology::render_network_topology;
+pub use registry_browser::render_registry_browser;
+pub use run_spec_viewer::render_run_spec_viewer;
 pub use stats_viewer::{render_stats_panel, STATS_PANEL_HEIGHT};
+pub use transfer_dialog::render_transfer_dialog;
+pub use volume_browser::render_volume_browser;
+pub use volume_detail_viewer::render_volume_detail_viewer;
 pub use volume_list::VolumeListWidget;
@@ -0,0 +1,265 @@
+//! Network detail viewer component
+
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use super::detail_viewer::wrap_lines_to_width;
+use crate::state::NetworkDetailViewState;
+
+/// Render the network detail viewer overlay
+pub fn render_network_detail_viewer(frame: &mut Frame, area: Rect, state: &NetworkDetailViewState) {
+    // Use 80% of screen for detail viewer
+    let popup_area = centered_rect(80, 85, area);
+
+    // Clear background first
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!(" Network: {} ", state.network_name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan).bg(Color::Black))
+        .style(Style::default().bg(Color::Black));
+
+    let inner_area = block.inner(popup_area);
+
+    let details = match state.details {
+        Some(ref d) => d,
+        None => {
+            let loading = Paragraph::new("Loading network details...")
+                .style(Style::default().fg(Color::Yellow).bg(Color::Black))
+                .alignment(Alignment::Center)
+                .block(block);
+            frame.render_widget(loading, popup_area);
+            return;
+        }
+    };
+
+    let mut lines = vec![];
+
+    let label_style = Style::default().fg(Color::Gray).bg(Color::Black);
+    let value_style = Style::default().fg(Color::White).bg(Color::Black);
+    let accent_style = Style::default().fg(Color::Cyan).bg(Color::Black);
+    let muted_style = Style::default().fg(Color::DarkGray).bg(Color::Black);
+
+    // Basic Info
+    let id: String = details.id.chars().take(12).collect();
+    let mut flags = Vec::new();
+    if details.internal {
+        flags.push("internal");
+    }
+    if details.attachable {
+        flags.push("attachable");
+    }
+    if details.enable_ipv6 {
+        flags.push("IPv6");
+    }
+    let flags = if flags.is_empty() {
+        "-".to_string()
+    } else {
+        flags.join(", ")
+    };
+    for (label, value) in [
+        ("ID:         ", &id),
+        ("Name:       ", &details.name),
+        ("Driver:     ", &details.driver),
+        ("Scope:      ", &details.scope),
+        ("Created:    ", &details.created),
+        ("Flags:      ", &flags),
+    ] {
+        lines.push(Line::from(vec![
+            Span::styled(label, label_style),
+            Span::styled(value.as_str(), value_style),
+        ]));
+    }
+    lines.push(Line::from(""));
+
+    // IPAM
+    lines.push(Line::from(vec![Span::styled(
+        format!("IPAM ({}):", details.ipam_driver),
+        label_style,
+    )]));
+    if details.subnets.is_empty() {
+        lines.push(Line::from(vec![Span::styled(
+            "  No subnets configured",
+            muted_style,
+        )]));
+    }
+    for subnet in &details.subnets {
+        let mut text = String::new();
+        if !subnet.gateway.is_empty() {
+            text.push_str(&format!("gateway {}", subnet.gateway));
+        }
+        if !subnet.ip_range.is_empty() {
+            if !text.is_empty() {
+                text.push_str("  ");
+            }
+            text.push_str(&format!("range {}", subnet.ip_range));
+        }
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {}  ", subnet.subnet), accent_style),
+            Span::styled(text, value_style),
+        ]));
+    }
+    lines.push(Line::from(""));
+
+    // Driver options
+    if !details.options.is_empty() {
+        lines.push(Line::from(vec![Span::styled("Options:", label_style)]));
+        for (key, value) in &details.options {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {}=", key), accent_style),
+                Span::styled(value.as_str(), value_style),
+            ]));
+        }
+        lines.push(Line::from(""));
+    }
+
+    // Labels
+    if !details.labels.is_empty() {
+        lines.push(Line::from(vec![Span::styled("Labels:", label_style)]));
+        for (key, value) in &details.labels {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {}=", key), accent_style),
+                Span::styled(value.as_str(), value_style),
+            ]));
+        }
+        lines.push(Line::from(""));
+    }
+
+    // Connected containers
+    lines.push(Line::from(vec![Span::styled(
+        format!("Containers ({}):", details.containers.len()),
+        label_style,
+    )]));
+    if details.containers.is_empty() {
+        lines.push(Line::from(vec![Span::styled(
+            "  No containers connected",
+            muted_style,
+        )]));
+    }
+    let or_dash = |s: &str| {
+        if s.is_empty() {
+            "-".to_string()
+        } else {
+            s.to_string()
+        }
+    };
+    for container in &details.containers {
+        lines.push(Line::from(vec![Span::styled(
+            format!("  {}", container.name),
+            accent_style,
+        )]));
+        lines.push(Line::from(vec![
+            Span::styled("     ", label_style),
+            Span::styled(
+                format!(
+                    "IPv4: {}  IPv6: {}  MAC: {}",
+                    or_dash(&container.ipv4_address),
+                    or_dash(&container.ipv6_address),
+                    or_dash(&container.mac_address)
+                ),
+                value_style,
+            ),
+        ]));
+    }
+
+    let wrapped_lines = wrap_lines_to_width(&lines, inner_area.width);
+
+    // Clamp scroll offset to the wrapped content height
+    let max_scroll = wrapped_lines
+        .len()
+        .saturating_sub(inner_area.height as usize);
+    let scroll = state.scroll_offset.min(max_scroll);
+
+    let paragraph = Paragraph::new(wrapped_lines)
+        .scroll((u16::try_from(scroll).unwrap_or(u16::MAX), 0))
+        .style(Style::default().bg(Color::Black))
+        .block(block);
+
+    frame.render_widget(paragraph, popup_area);
+}
+
+/// Calculate centered rectangle for popup
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let width = ((r.width as u32) * (percent_x as u32) / 100) as u16;
+    let height = ((r.height as u32) * (percent_y as u32) / 100) as u16;
+    let width = width.clamp(3, r.width);
+    let height = height.clamp(3, r.height);
+    let x = r.x + (r.width.saturating_sub(width)) / 2;
+    let y = r.y + (r.height.saturating_sub(height)) / 2;
+    Rect::new(x, y, width, height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::docker::{IpamSubnet, NetworkDetails, NetworkEndpoint};
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    fn render(state: &NetworkDetailViewState, height: u16) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(100, height)).unwrap();
+        terminal
+            .draw(|f| render_network_detail_viewer(f, f.area(), state))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .filter_map(|x| buffer.cell((x, y)).map(|c| c.symbol().to_string()))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn shows_ipam_and_endpoints() {
+        let mut state = NetworkDetailViewState {
+            network_id: "f00d".into(),
+            network_name: "backend".into(),
+            details: Some(NetworkDetails {
+                id: "f00dcafe0123456789".into(),
+                name: "backend".into(),
+                driver: "bridge".into(),
+                scope: "local".into(),
+                created: "2026-01-02T03:04:05Z".into(),
+                internal: false,
+                attachable: true,
+                enable_ipv6: true,
+                ipam_driver: "default".into(),
+                subnets: vec![IpamSubnet {
+                    subnet: "172.20.0.0/16".into(),
+                    gateway: "172.20.0.1".into(),
+                    ip_range: String::new(),
+                }],
+                options: vec![("com.docker.network.bridge.name".into(), "br0".into())],
+                labels: vec![],
+                containers: vec![NetworkEndpoint {
+                    container_id: "a".repeat(64),
+                    name: "db".into(),
+                    ipv4_address: "172.20.0.2/16".into(),
+                    ipv6_address: String::new(),
+                    mac_address: "02:42:ac:14:00:02".into(),
+                }],
+            }),
+            scroll_offset: 0,
+        };
+
+        let text = render(&state, 30);
+        assert!(text.contains("ID:         f00dcafe0123 "));
+        assert!(text.contains("Flags:      attachable, IPv6"));
+        assert!(text.contains("172.20.0.0/16  gateway 172.20.0.1"));
+        assert!(text.contains("com.docker.network.bridge.name=br0"));
+        assert!(text.contains("IPv4: 172.20.0.2/16  IPv6: -  MAC: 02:42:ac:14:00:02"));
+
+        // Scrolling past the end stops at the last line
+        state.scroll_offset = 9999;
+        let text = render(&state, 12);
+        assert!(text.contains("IPv4: 172.20.0.2/16"));
+        assert!(!text.contains("Name:       backend"));
+    }
+}
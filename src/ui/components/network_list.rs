@@ -186,6 +186,7 @@ mod tests {
                 attachable: false,
                 ingress: false,
                 enable_ipv6: false,
+                subnet: Some("172.17.0.0/16".to_string()),
                 connected_containers: vec!["c1".to_string(), "c2".to_string()],
             },
             NetworkSummary {
@@ -198,6 +199,7 @@ mod tests {
                 attachable: true,
                 ingress: false,
                 enable_ipv6: false,
+                subnet: None,
                 connected_containers: vec![],
             },
         ]
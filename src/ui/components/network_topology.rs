@@ -0,0 +1,249 @@
+//! Network topology graph (networks, their containers and published ports)
+
+use ratatui::{
+    layout::Rect,
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Paragraph},
+    Frame,
+};
+use unicode_width::UnicodeWidthStr;
+
+use crate::core::{ContainerState, ContainerSummary, NetworkSummary};
+use crate::state::image_tree::container_name;
+use crate::state::network_topology::{attachments, published_ports, unattached};
+
+/// Render each network as a box with its containers branching off it.
+/// Every container shows its address on that network, the ports it
+/// publishes on the host and the other networks it is on.
+pub fn render_network_topology(
+    frame: &mut Frame,
+    area: Rect,
+    networks: &[NetworkSummary],
+    containers: &[ContainerSummary],
+    selected: usize,
+) {
+    let mut lines = Vec::new();
+    let mut selected_lines = (0, 0);
+    for (index, network) in networks.iter().enumerate() {
+        let start = lines.len();
+        push_network(&mut lines, network, containers, index == selected);
+        if index == selected {
+            selected_lines = (start, lines.len());
+        }
+        lines.push(Line::from(""));
+    }
+
+    let loose = unattached(networks, containers);
+    if !loose.is_empty() {
+        let names: Vec<String> = loose.iter().map(|c| container_name(c)).collect();
+        lines.push(Line::from(vec![
+            Span::styled("Not on any network: ", Style::default().fg(Color::Gray)),
+            Span::styled(names.join(", "), Style::default().fg(Color::Yellow)),
+        ]));
+    }
+
+    // Scroll to keep the selected network visible, its box first
+    let visible = area.height.saturating_sub(2) as usize;
+    let (start, end) = selected_lines;
+    let first = start.min(end.saturating_sub(visible));
+    frame.render_widget(
+        Paragraph::new(lines)
+            .scroll((u16::try_from(first).unwrap_or(u16::MAX), 0))
+            .block(
+                Block::default()
+                    .title(format!(
+                        " Networks ({}) [topology]  ● running ○ stopped  ⇄ published on host ",
+                        networks.len()
+                    ))
+                    .borders(Borders::ALL),
+            ),
+        area,
+    );
+}
+
+fn push_network(
+    lines: &mut Vec<Line<'static>>,
+    network: &NetworkSummary,
+    containers: &[ContainerSummary],
+    selected: bool,
+) {
+    let border = if selected {
+        Style::default()
+            .fg(Color::Cyan)
+            .add_modifier(Modifier::BOLD)
+    } else {
+        Style::default().fg(Color::DarkGray)
+    };
+
+    let mut details = vec![network.driver.clone()];
+    if let Some(subnet) = &network.subnet {
+        details.push(subnet.clone());
+    }
+    if network.internal {
+        details.push("internal, no host access".to_string());
+    }
+    let details = format!("  {}", details.join("  "));
+    let width = network.name.width() + details.width() + 2;
+
+    let attached = attachments(network, containers);
+    lines.push(Line::from(Span::styled(
+        format!("┌{}┐", "─".repeat(width)),
+        border,
+    )));
+    lines.push(Line::from(vec![
+        Span::styled("│ ", border),
+        Span::styled(
+            network.name.clone(),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            details,
+            Style::default().fg(if network.internal {
+                Color::Yellow
+            } else {
+                Color::Gray
+            }),
+        ),
+        Span::styled(" │", border),
+    ]));
+    if attached.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!("└{}┘", "─".repeat(width)),
+            border,
+        )));
+        lines.push(Line::from(Span::styled(
+            "  (no containers)",
+            Style::default().fg(Color::DarkGray),
+        )));
+        return;
+    }
+    lines.push(Line::from(Span::styled(
+        format!("└─┬{}┘", "─".repeat(width.saturating_sub(2))),
+        border,
+    )));
+
+    let name_width = attached
+        .iter()
+        .map(|a| container_name(a.container).width())
+        .max()
+        .unwrap_or(0);
+    for (i, attachment) in attached.iter().enumerate() {
+        let branch = if i + 1 == attached.len() {
+            "  └── "
+        } else {
+            "  ├── "
+        };
+        let container = attachment.container;
+        let (dot, color) = match container.state {
+            ContainerState::Running => ("● ", Color::Green),
+            ContainerState::Paused => ("● ", Color::Yellow),
+            ContainerState::Exited | ContainerState::Dead => ("○ ", Color::Red),
+            _ => ("○ ", Color::Gray),
+        };
+        let mut spans = vec![
+            Span::styled(branch, border),
+            Span::styled(dot, Style::default().fg(color)),
+            Span::styled(
+                format!("{:<width$}", container_name(container), width = name_width),
+                Style::default().fg(Color::Cyan),
+            ),
+            Span::styled(
+                format!("  {:<18}", attachment.ip.unwrap_or("-")),
+                Style::default().fg(Color::White),
+            ),
+        ];
+        let ports = published_ports(container);
+        if !ports.is_empty() {
+            spans.push(Span::styled(
+                format!("  ⇄ {}", ports.join(" ")),
+                Style::default().fg(Color::Magenta),
+            ));
+        }
+        if !attachment.also_on.is_empty() {
+            spans.push(Span::styled(
+                format!("  also on: {}", attachment.also_on.join(", ")),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{NetworkScope, PortMapping};
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    #[test]
+    fn draws_networks_containers_and_ports() {
+        let network = |name: &str, subnet: Option<&str>, internal: bool| NetworkSummary {
+            id: name.into(),
+            name: name.into(),
+            driver: "bridge".into(),
+            scope: NetworkScope::Local,
+            created: chrono::Utc::now(),
+            internal,
+            attachable: false,
+            ingress: false,
+            enable_ipv6: false,
+            subnet: subnet.map(str::to_string),
+            connected_containers: vec![],
+        };
+        let networks = vec![
+            network("backend", Some("172.20.0.0/16"), true),
+            network("frontend", None, false),
+        ];
+        let containers = vec![
+            ContainerSummary {
+                id: "w".into(),
+                names: vec!["web".into()],
+                state: ContainerState::Running,
+                networks: vec!["frontend".into(), "backend".into()],
+                network_ips: [
+                    ("frontend".to_string(), "172.21.0.3".to_string()),
+                    ("backend".to_string(), "172.20.0.3".to_string()),
+                ]
+                .into(),
+                ports: vec![PortMapping {
+                    ip: Some("0.0.0.0".into()),
+                    private_port: 80,
+                    public_port: Some(8080),
+                    protocol: "tcp".into(),
+                }],
+                ..Default::default()
+            },
+            ContainerSummary {
+                id: "d".into(),
+                names: vec!["db".into()],
+                state: ContainerState::Exited,
+                networks: vec!["backend".into()],
+                ..Default::default()
+            },
+        ];
+
+        let mut terminal = Terminal::new(TestBackend::new(120, 16)).unwrap();
+        terminal
+            .draw(|f| render_network_topology(f, f.area(), &networks, &containers, 1))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let text: String = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .filter_map(|x| buffer.cell((x, y)).map(|c| c.symbol().to_string()))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert!(text.contains("│ backend  bridge  172.20.0.0/16  internal, no host access │"));
+        assert!(text.contains("├── ○ db   -"));
+        assert!(text.contains("└── ● web  172.20.0.3          ⇄ 8080→80/tcp  also on: frontend"));
+        assert!(text.contains("│ frontend  bridge │"));
+        assert!(text.contains("└── ● web  172.21.0.3"));
+    }
+}
@@ -0,0 +1,222 @@
+//! Registry browser (registries, repositories, tags and manifests)
+
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::docker::format_size;
+use crate::state::{RegistryBrowserState, RegistryLevel};
+
+/// Render the registry browser overlay
+pub fn render_registry_browser(frame: &mut Frame, area: Rect, state: &RegistryBrowserState) {
+    let popup_area = centered_rect(80, 80, area);
+    frame.render_widget(Clear, popup_area);
+
+    let mut path = vec!["Registries".to_string()];
+    if state.level != RegistryLevel::Registries {
+        path.push(
+            state
+                .registries
+                .get(state.registry_selected)
+                .cloned()
+                .unwrap_or_default(),
+        );
+    }
+    if let (RegistryLevel::Tags, Some(repository)) = (state.level, &state.repository) {
+        path.push(repository.clone());
+    }
+    let block = Block::default()
+        .title(format!(" {} ", path.join(" / ")))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+    let inner_area = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(inner_area);
+
+    let (list_area, detail_area) = if state.level == RegistryLevel::Tags {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(40), Constraint::Percentage(60)])
+            .split(rows[0]);
+        (columns[0], Some(columns[1]))
+    } else {
+        (rows[0], None)
+    };
+
+    // Items of the current level, scrolled to keep the selection visible
+    let (items, selected) = state.current_list();
+    let visible = list_area.height as usize;
+    let first = (selected + 1).saturating_sub(visible);
+    let mut lines: Vec<Line> = items
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(visible)
+        .map(|(index, item)| {
+            if index == selected {
+                Line::from(Span::styled(
+                    format!("▶ {}", item),
+                    Style::default()
+                        .bg(Color::DarkGray)
+                        .add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(format!("  {}", item))
+            }
+        })
+        .collect();
+    if state.loading {
+        lines.push(Line::from(Span::styled(
+            "  Loading...",
+            Style::default().fg(Color::Yellow),
+        )));
+    } else if let Some(error) = &state.error {
+        lines.push(Line::from(Span::styled(
+            format!("  {}", error),
+            Style::default().fg(Color::Red),
+        )));
+    } else if items.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  Nothing here",
+            Style::default().fg(Color::DarkGray),
+        )));
+    }
+    frame.render_widget(Paragraph::new(lines), list_area);
+
+    if let Some(detail_area) = detail_area {
+        render_manifest(frame, detail_area, state);
+    }
+
+    let hint = match state.level {
+        RegistryLevel::Registries => " [Enter]Repositories [/]Open repository [q]Close",
+        RegistryLevel::Repositories => " [Enter]Tags [/]Open repository [Esc]Back [q]Close",
+        RegistryLevel::Tags => " [Enter]Details [p]Pull [Esc]Back [q]Close",
+    };
+    frame.render_widget(
+        Paragraph::new(Line::from(Span::styled(
+            hint,
+            Style::default().fg(Color::DarkGray),
+        ))),
+        rows[1],
+    );
+}
+
+/// Digest and platforms of the selected tag
+fn render_manifest(frame: &mut Frame, area: Rect, state: &RegistryBrowserState) {
+    let label = Style::default().fg(Color::Gray);
+    let lines = match state.selected_manifest() {
+        Some(manifest) => {
+            let mut lines = vec![
+                Line::from(vec![
+                    Span::styled("Digest: ", label),
+                    Span::raw(manifest.digest.clone()),
+                ]),
+                Line::from(vec![
+                    Span::styled("Type:   ", label),
+                    Span::raw(if manifest.is_index() {
+                        "multi-arch index"
+                    } else {
+                        "image"
+                    }),
+                ]),
+                Line::from(""),
+            ];
+            for platform in &manifest.platforms {
+                lines.push(Line::from(vec![
+                    Span::styled(
+                        format!("{:<16}", platform.platform),
+                        Style::default().fg(Color::Cyan),
+                    ),
+                    Span::raw(format!("{:>10}  ", format_size(platform.size))),
+                    Span::styled(platform.digest.chars().take(19).collect::<String>(), label),
+                ]));
+            }
+            lines
+        }
+        None => vec![Line::from(Span::styled(
+            "Press Enter to resolve the tag",
+            Style::default().fg(Color::DarkGray),
+        ))],
+    };
+    frame.render_widget(
+        Paragraph::new(lines).block(
+            Block::default()
+                .title(" Manifest ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::DarkGray)),
+        ),
+        area,
+    );
+}
+
+/// Calculate centered rectangle for popup
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let width = ((r.width as u32) * (percent_x as u32) / 100) as u16;
+    let height = ((r.height as u32) * (percent_y as u32) / 100) as u16;
+    let width = width.clamp(3, r.width);
+    let height = height.clamp(3, r.height);
+    let x = r.x + (r.width.saturating_sub(width)) / 2;
+    let y = r.y + (r.height.saturating_sub(height)) / 2;
+    Rect::new(x, y, width, height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::registry::{ManifestInfo, PlatformManifest};
+    use crate::state::AppState;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    #[test]
+    fn shows_tags_and_platforms() {
+        let mut state = AppState::default();
+        state.open_registry_browser(vec!["localhost:5000".into()]);
+        state.registry_browser_enter(RegistryLevel::Repositories, None);
+        state.set_registry_repositories(vec!["team/app".into()]);
+        state.registry_browser_enter(RegistryLevel::Tags, Some("team/app".into()));
+        state.set_registry_tags(vec!["1.0".into(), "latest".into()]);
+        state.set_registry_manifest(
+            "1.0".into(),
+            ManifestInfo {
+                digest: "sha256:0123456789abcdef".into(),
+                media_type: "application/vnd.oci.image.index.v1+json".into(),
+                platforms: vec![PlatformManifest {
+                    platform: "linux/arm64/v8".into(),
+                    digest: "sha256:fedcba".into(),
+                    size: 3 * 1024 * 1024,
+                }],
+            },
+        );
+
+        let mut terminal = Terminal::new(TestBackend::new(120, 20)).unwrap();
+        terminal
+            .draw(|f| {
+                render_registry_browser(f, f.area(), state.registry_browser.as_ref().unwrap())
+            })
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let text: String = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .filter_map(|x| buffer.cell((x, y)).map(|c| c.symbol().to_string()))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(text.contains("Registries / localhost:5000 / team/app"));
+        assert!(text.contains("▶ 1.0"));
+        assert!(text.contains("multi-arch index"));
+        assert!(text.contains("linux/arm64/v8"));
+        assert!(text.contains("3.0 MB"));
+    }
+}
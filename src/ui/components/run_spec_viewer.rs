@@ -0,0 +1,103 @@
+//! Viewer for a generated `docker run` command or compose service
+
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Style};
+use ratatui::text::Line;
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use crate::state::RunSpecViewState;
+
+/// Render the generated run command / compose overlay
+pub fn render_run_spec_viewer(frame: &mut Frame, area: Rect, state: &RunSpecViewState) {
+    let popup_area = centered_rect(80, 85, area);
+    frame.render_widget(Clear, popup_area);
+
+    let title = if state.show_compose {
+        format!(" Compose: {} ", state.container_name)
+    } else {
+        format!(" docker run: {} ", state.container_name)
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan).bg(Color::Black))
+        .style(Style::default().bg(Color::Black));
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(inner);
+
+    // Shell and YAML are copied verbatim, so lines are never wrapped
+    let lines: Vec<Line> = state.current_text().lines().map(Line::from).collect();
+    let max_scroll = lines.len().saturating_sub(chunks[0].height as usize);
+    let scroll = state.scroll_offset.min(max_scroll);
+    let text = Paragraph::new(lines)
+        .scroll((u16::try_from(scroll).unwrap_or(u16::MAX), 0))
+        .style(Style::default().fg(Color::White).bg(Color::Black));
+    frame.render_widget(text, chunks[0]);
+
+    let hints = Paragraph::new(" [Tab]Run/Compose [y]Copy [s]Save [↑/↓]Scroll [q]Close")
+        .style(Style::default().fg(Color::DarkGray).bg(Color::Black));
+    frame.render_widget(hints, chunks[1]);
+}
+
+/// Calculate centered rectangle for popup
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let width = ((r.width as u32) * (percent_x as u32) / 100) as u16;
+    let height = ((r.height as u32) * (percent_y as u32) / 100) as u16;
+    let width = width.clamp(3, r.width);
+    let height = height.clamp(3, r.height);
+    let x = r.x + (r.width.saturating_sub(width)) / 2;
+    let y = r.y + (r.height.saturating_sub(height)) / 2;
+    Rect::new(x, y, width, height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    fn screen_text(terminal: &Terminal<TestBackend>) -> String {
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .filter_map(|x| buffer.cell((x, y)).map(|c| c.symbol().to_string()))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn shows_the_selected_format() {
+        let mut terminal = Terminal::new(TestBackend::new(100, 24)).unwrap();
+        let mut state = RunSpecViewState {
+            container_name: "web".into(),
+            docker_run: "docker run -d \\\n  --name web \\\n  nginx:1.25".into(),
+            compose: "services:\n  web:\n    image: \"nginx:1.25\"".into(),
+            show_compose: false,
+            scroll_offset: 0,
+        };
+
+        terminal
+            .draw(|f| render_run_spec_viewer(f, f.area(), &state))
+            .unwrap();
+        let text = screen_text(&terminal);
+        assert!(text.contains("docker run: web"));
+        assert!(text.contains("--name web"));
+
+        state.show_compose = true;
+        terminal
+            .draw(|f| render_run_spec_viewer(f, f.area(), &state))
+            .unwrap();
+        let text = screen_text(&terminal);
+        assert!(text.contains("Compose: web"));
+        assert!(text.contains("services:"));
+    }
+}
@@ -0,0 +1,212 @@
+//! Image pull/push dialog with per-layer progress bars
+
+use ratatui::layout::{Constraint, Direction, Layout, Rect};
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Gauge, Paragraph};
+use ratatui::Frame;
+
+use crate::docker::{format_bytes, LayerProgress};
+use crate::state::TransferViewState;
+
+/// Width of the per-layer progress bar in cells
+const BAR_WIDTH: usize = 24;
+
+/// Render the pull/push progress overlay
+pub fn render_transfer_dialog(frame: &mut Frame, area: Rect, state: &TransferViewState) {
+    let popup_area = centered_rect(70, 70, area);
+    frame.render_widget(Clear, popup_area);
+
+    let border_color = match (&state.error, state.done) {
+        (Some(_), _) => Color::Red,
+        (None, true) => Color::Green,
+        (None, false) => Color::Cyan,
+    };
+    let block = Block::default()
+        .title(format!(
+            " {}: {} ",
+            if state.push { "Push" } else { "Pull" },
+            state.image
+        ))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(border_color).bg(Color::Black))
+        .style(Style::default().bg(Color::Black));
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Min(1),
+            Constraint::Length(2),
+            Constraint::Length(1),
+        ])
+        .split(inner);
+
+    let progress = &state.progress;
+    let status = if state.push && progress.layers_pushed + progress.layers_existing > 0 {
+        format!(
+            "{} ({} pushed, {} already in registry)",
+            progress.status, progress.layers_pushed, progress.layers_existing
+        )
+    } else {
+        progress.status.clone()
+    };
+    frame.render_widget(
+        Paragraph::new(status).style(Style::default().fg(Color::Gray)),
+        chunks[0],
+    );
+
+    let transferred = progress.transferred_bytes();
+    let total = progress.total_bytes();
+    let ratio = if state.done && state.error.is_none() {
+        1.0
+    } else if total == 0 {
+        0.0
+    } else {
+        (transferred as f64 / total as f64).min(1.0)
+    };
+    let gauge = Gauge::default()
+        .gauge_style(Style::default().fg(border_color).bg(Color::DarkGray))
+        .ratio(ratio)
+        .label(format!(
+            "{} / {} ({} layers)",
+            format_bytes(transferred),
+            format_bytes(total),
+            progress.layers.len()
+        ));
+    frame.render_widget(gauge, chunks[1]);
+
+    // Keep the most recent layers visible when there are more than fit
+    let rows = chunks[2].height as usize;
+    let skip = progress.layers.len().saturating_sub(rows);
+    let lines: Vec<Line> = progress.layers.iter().skip(skip).map(layer_line).collect();
+    frame.render_widget(Paragraph::new(lines), chunks[2]);
+
+    let footer = if let Some(error) = &state.error {
+        Line::from(Span::styled(
+            error.as_str(),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        ))
+    } else if let Some(digest) = &progress.digest {
+        Line::from(vec![
+            Span::styled("Digest: ", Style::default().fg(Color::Gray)),
+            Span::styled(digest.as_str(), Style::default().fg(Color::Green)),
+        ])
+    } else {
+        Line::from("")
+    };
+    frame.render_widget(Paragraph::new(footer), chunks[3]);
+
+    let hints = match (state.done, state.push) {
+        (true, _) => " [Enter/Esc]Close",
+        (false, false) => " [Esc]Cancel pull",
+        (false, true) => " [Esc]Cancel push",
+    };
+    frame.render_widget(
+        Paragraph::new(hints).style(Style::default().fg(Color::DarkGray)),
+        chunks[4],
+    );
+}
+
+/// One line per layer: ID, status, text progress bar and byte counts
+fn layer_line(layer: &LayerProgress) -> Line<'static> {
+    let filled = (layer.ratio() * BAR_WIDTH as f64).round() as usize;
+    let bar = format!("{}{}", "█".repeat(filled), "░".repeat(BAR_WIDTH - filled));
+    let bar_color = if layer.is_complete() {
+        Color::Green
+    } else {
+        Color::Cyan
+    };
+    let bytes = if layer.size > 0 {
+        format!(
+            "{} / {}",
+            format_bytes(layer.transferred.min(layer.size)),
+            format_bytes(layer.size)
+        )
+    } else {
+        String::new()
+    };
+    Line::from(vec![
+        Span::styled(
+            format!("{:<12} ", layer.id),
+            Style::default().fg(Color::White),
+        ),
+        Span::styled(
+            format!("{:<18} ", layer.status),
+            Style::default().fg(Color::Gray),
+        ),
+        Span::styled(bar, Style::default().fg(bar_color)),
+        Span::styled(format!(" {}", bytes), Style::default().fg(Color::Gray)),
+    ])
+}
+
+/// Calculate centered rectangle for popup
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let width = ((r.width as u32) * (percent_x as u32) / 100) as u16;
+    let height = ((r.height as u32) * (percent_y as u32) / 100) as u16;
+    let width = width.clamp(3, r.width);
+    let height = height.clamp(3, r.height);
+    let x = r.x + (r.width.saturating_sub(width)) / 2;
+    let y = r.y + (r.height.saturating_sub(height)) / 2;
+    Rect::new(x, y, width, height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::docker::TransferProgress;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    #[test]
+    fn renders_layers_and_digest() {
+        let mut terminal = Terminal::new(TestBackend::new(120, 30)).unwrap();
+        let state = TransferViewState {
+            image: "nginx:1.25".into(),
+            push: false,
+            progress: TransferProgress {
+                layers: vec![
+                    LayerProgress {
+                        id: "aaa".into(),
+                        status: "Already exists".into(),
+                        transferred: 0,
+                        size: 0,
+                    },
+                    LayerProgress {
+                        id: "bbb".into(),
+                        status: "Downloading".into(),
+                        transferred: 1024,
+                        size: 4096,
+                    },
+                ],
+                status: "Pulling from library/nginx".into(),
+                digest: Some("sha256:abc".into()),
+                ..Default::default()
+            },
+            done: false,
+            error: None,
+        };
+
+        terminal
+            .draw(|f| render_transfer_dialog(f, f.area(), &state))
+            .unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let text: String = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .filter_map(|x| buffer.cell((x, y)).map(|c| c.symbol().to_string()))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert!(text.contains("Pull: nginx:1.25"));
+        assert!(text.contains("Already exists"));
+        assert!(text.contains("1.0 KB / 4.0 KB"));
+        assert!(text.contains("Digest: sha256:abc"));
+        assert!(text.contains("Cancel pull"));
+    }
+}
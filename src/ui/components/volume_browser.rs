@@ -0,0 +1,228 @@
+//! Volume file browser (listing and file preview)
+
+use ratatui::{
+    layout::{Alignment, Constraint, Direction, Layout, Rect},
+    style::{Color, Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, Borders, Clear, Paragraph},
+    Frame,
+};
+
+use crate::docker::{format_size, FilePreview, VolumeEntry, VolumeEntryKind};
+use crate::state::VolumeBrowserState;
+
+/// `ls -l` style permissions
+fn mode_string(entry: &VolumeEntry) -> String {
+    let kind = match entry.kind {
+        VolumeEntryKind::Directory => 'd',
+        VolumeEntryKind::Symlink(_) => 'l',
+        VolumeEntryKind::File => '-',
+        VolumeEntryKind::Other => '?',
+    };
+    let bits: String = (0..9)
+        .map(|i| {
+            if entry.mode & (0o400 >> i) == 0 {
+                '-'
+            } else {
+                ['r', 'w', 'x'][i % 3]
+            }
+        })
+        .collect();
+    format!("{}{}", kind, bits)
+}
+
+/// Render the volume browser overlay
+pub fn render_volume_browser(frame: &mut Frame, area: Rect, state: &VolumeBrowserState) {
+    let popup_area = centered_rect(85, 85, area);
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!(" Volume: {}  /{} ", state.volume, state.cwd))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan))
+        .style(Style::default().bg(Color::Black));
+
+    if state.entries.is_none() {
+        let loading = Paragraph::new("Reading volume through a helper container... [q]Cancel")
+            .style(Style::default().fg(Color::Yellow).bg(Color::Black))
+            .alignment(Alignment::Center)
+            .block(block);
+        frame.render_widget(loading, popup_area);
+        return;
+    }
+
+    let inner_area = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(1), Constraint::Length(1)])
+        .split(inner_area);
+
+    if let Some(preview) = &state.preview {
+        render_preview(frame, rows[0], preview, state.preview_scroll);
+        let hints = Paragraph::new(" [↑/↓]Scroll [s]Download [q]Back")
+            .style(Style::default().fg(Color::DarkGray));
+        frame.render_widget(hints, rows[1]);
+        return;
+    }
+
+    let children = state.children();
+    let lines: Vec<Line> = if children.is_empty() {
+        vec![Line::from(Span::styled(
+            "Empty directory",
+            Style::default().fg(Color::DarkGray),
+        ))]
+    } else {
+        // Scroll to keep the selection visible
+        let visible = rows[0].height as usize;
+        let first = (state.selected + 1).saturating_sub(visible);
+        children
+            .iter()
+            .enumerate()
+            .skip(first)
+            .take(visible)
+            .map(|(index, entry)| {
+                let mut style = match entry.kind {
+                    VolumeEntryKind::Directory => Style::default().fg(Color::Blue),
+                    VolumeEntryKind::Symlink(_) => Style::default().fg(Color::Cyan),
+                    _ => Style::default().fg(Color::White),
+                };
+                if index == state.selected {
+                    style = style.bg(Color::DarkGray).add_modifier(Modifier::BOLD);
+                }
+                let name = match &entry.kind {
+                    VolumeEntryKind::Directory => format!("{}/", entry.name()),
+                    VolumeEntryKind::Symlink(target) => {
+                        format!("{} -> {}", entry.name(), target)
+                    }
+                    _ => entry.name().to_string(),
+                };
+                let size = if entry.is_dir() {
+                    String::new()
+                } else {
+                    format_size(entry.size)
+                };
+                let modified = entry
+                    .modified
+                    .map(|t| t.format("%Y-%m-%d %H:%M").to_string())
+                    .unwrap_or_default();
+                Line::from(vec![
+                    Span::styled(
+                        format!("{} {:>10}  {:<16}  ", mode_string(entry), size, modified),
+                        Style::default().fg(Color::DarkGray),
+                    ),
+                    Span::styled(name, style),
+                ])
+            })
+            .collect()
+    };
+    frame.render_widget(Paragraph::new(lines), rows[0]);
+
+    let hints =
+        Paragraph::new(" [↑/↓]Select [Enter]Open/Preview [Backspace/h]Up [s]Download [q]Close")
+            .style(Style::default().fg(Color::DarkGray));
+    frame.render_widget(hints, rows[1]);
+}
+
+fn render_preview(frame: &mut Frame, area: Rect, preview: &FilePreview, scroll: usize) {
+    let mut title = format!(" {} ", preview.path);
+    if preview.truncated {
+        title.push_str("(first 64 KB) ");
+    }
+    let lines: Vec<Line> = preview
+        .lines
+        .iter()
+        .map(|l| Line::raw(l.as_str()))
+        .collect();
+    let max_scroll = lines
+        .len()
+        .saturating_sub(area.height.saturating_sub(2) as usize);
+    let scroll = scroll.min(max_scroll);
+    frame.render_widget(
+        Paragraph::new(lines)
+            .block(
+                Block::default()
+                    .title(title)
+                    .borders(Borders::ALL)
+                    .border_style(Style::default().fg(Color::DarkGray)),
+            )
+            .scroll((u16::try_from(scroll).unwrap_or(u16::MAX), 0)),
+        area,
+    );
+}
+
+/// Calculate centered rectangle for popup
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let width = ((r.width as u32) * (percent_x as u32) / 100) as u16;
+    let height = ((r.height as u32) * (percent_y as u32) / 100) as u16;
+    let width = width.clamp(3, r.width);
+    let height = height.clamp(3, r.height);
+    let x = r.x + (r.width.saturating_sub(width)) / 2;
+    let y = r.y + (r.height.saturating_sub(height)) / 2;
+    Rect::new(x, y, width, height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+    use std::sync::Arc;
+
+    fn render(state: &VolumeBrowserState) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(120, 20)).unwrap();
+        terminal
+            .draw(|f| render_volume_browser(f, f.area(), state))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .filter_map(|x| buffer.cell((x, y)).map(|c| c.symbol().to_string()))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn lists_the_current_directory() {
+        let entry = |path: &str, kind: VolumeEntryKind, mode: u32| VolumeEntry {
+            path: path.into(),
+            kind,
+            size: 2048,
+            mode,
+            modified: None,
+        };
+        let mut state = VolumeBrowserState {
+            volume: "data".into(),
+            entries: Some(Arc::new(vec![
+                entry("conf", VolumeEntryKind::File, 0o640),
+                entry("current", VolumeEntryKind::Symlink("v2".into()), 0o777),
+                entry("pg", VolumeEntryKind::Directory, 0o755),
+                entry("pg/base", VolumeEntryKind::Directory, 0o700),
+            ])),
+            cwd: String::new(),
+            selected: 0,
+            preview: None,
+            preview_scroll: 0,
+        };
+
+        let text = render(&state);
+        assert!(text.contains("Volume: data  / "));
+        assert!(text.contains("drwxr-xr-x"));
+        assert!(text.contains("pg/"));
+        assert!(text.contains("-rw-r-----     2.0 KB"));
+        assert!(text.contains("current -> v2"));
+        assert!(!text.contains("base/"));
+
+        state.preview = Some(FilePreview {
+            path: "conf".into(),
+            lines: vec!["listen_addresses = '*'".into()],
+            truncated: true,
+        });
+        let text = render(&state);
+        assert!(text.contains(" conf (first 64 KB) "));
+        assert!(text.contains("listen_addresses = '*'"));
+    }
+}
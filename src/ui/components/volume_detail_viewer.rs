@@ -0,0 +1,203 @@
+//! Volume detail viewer component
+
+use ratatui::layout::{Alignment, Rect};
+use ratatui::style::{Color, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Borders, Clear, Paragraph};
+use ratatui::Frame;
+
+use super::detail_viewer::wrap_lines_to_width;
+use crate::state::VolumeDetailViewState;
+
+/// Render the volume detail viewer overlay
+pub fn render_volume_detail_viewer(frame: &mut Frame, area: Rect, state: &VolumeDetailViewState) {
+    // Use 80% of screen for detail viewer
+    let popup_area = centered_rect(80, 85, area);
+
+    // Clear background first
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!(" Volume: {} ", state.volume_name))
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Cyan).bg(Color::Black))
+        .style(Style::default().bg(Color::Black));
+
+    let inner_area = block.inner(popup_area);
+
+    let details = match state.details {
+        Some(ref d) => d,
+        None => {
+            let loading = Paragraph::new("Loading volume details...")
+                .style(Style::default().fg(Color::Yellow).bg(Color::Black))
+                .alignment(Alignment::Center)
+                .block(block);
+            frame.render_widget(loading, popup_area);
+            return;
+        }
+    };
+
+    let mut lines = vec![];
+
+    let label_style = Style::default().fg(Color::Gray).bg(Color::Black);
+    let value_style = Style::default().fg(Color::White).bg(Color::Black);
+    let accent_style = Style::default().fg(Color::Cyan).bg(Color::Black);
+    let muted_style = Style::default().fg(Color::DarkGray).bg(Color::Black);
+
+    // Basic Info
+    for (label, value) in [
+        ("Name:       ", &details.name),
+        ("Driver:     ", &details.driver),
+        ("Scope:      ", &details.scope),
+        ("Mountpoint: ", &details.mountpoint),
+        ("Created:    ", &details.created),
+    ] {
+        lines.push(Line::from(vec![
+            Span::styled(label, label_style),
+            Span::styled(value.as_str(), value_style),
+        ]));
+    }
+    let status = if details.status.is_empty() {
+        "-".to_string()
+    } else {
+        details.status.join(", ")
+    };
+    lines.push(Line::from(vec![
+        Span::styled("Status:     ", label_style),
+        Span::styled(status, value_style),
+    ]));
+    lines.push(Line::from(""));
+
+    // Driver options
+    if !details.options.is_empty() {
+        lines.push(Line::from(vec![Span::styled("Options:", label_style)]));
+        for (key, value) in &details.options {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {}=", key), accent_style),
+                Span::styled(value.as_str(), value_style),
+            ]));
+        }
+        lines.push(Line::from(""));
+    }
+
+    // Labels
+    if !details.labels.is_empty() {
+        lines.push(Line::from(vec![Span::styled("Labels:", label_style)]));
+        for (key, value) in &details.labels {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {}=", key), accent_style),
+                Span::styled(value.as_str(), value_style),
+            ]));
+        }
+        lines.push(Line::from(""));
+    }
+
+    // Containers
+    lines.push(Line::from(vec![Span::styled(
+        format!("Containers ({}):", details.containers.len()),
+        label_style,
+    )]));
+    if details.containers.is_empty() {
+        lines.push(Line::from(vec![Span::styled(
+            "  Not used by any container",
+            muted_style,
+        )]));
+    }
+    for container in &details.containers {
+        lines.push(Line::from(vec![
+            Span::styled(format!("  {}  ", container.name), accent_style),
+            Span::styled(
+                format!(
+                    "{} ({})",
+                    container.destination,
+                    if container.read_write { "rw" } else { "ro" }
+                ),
+                value_style,
+            ),
+            Span::styled(format!("  {}", container.state), muted_style),
+        ]));
+    }
+
+    let wrapped_lines = wrap_lines_to_width(&lines, inner_area.width);
+
+    // Clamp scroll offset to the wrapped content height
+    let max_scroll = wrapped_lines
+        .len()
+        .saturating_sub(inner_area.height as usize);
+    let scroll = state.scroll_offset.min(max_scroll);
+
+    let paragraph = Paragraph::new(wrapped_lines)
+        .scroll((u16::try_from(scroll).unwrap_or(u16::MAX), 0))
+        .style(Style::default().bg(Color::Black))
+        .block(block);
+
+    frame.render_widget(paragraph, popup_area);
+}
+
+/// Calculate centered rectangle for popup
+fn centered_rect(percent_x: u16, percent_y: u16, r: Rect) -> Rect {
+    let width = ((r.width as u32) * (percent_x as u32) / 100) as u16;
+    let height = ((r.height as u32) * (percent_y as u32) / 100) as u16;
+    let width = width.clamp(3, r.width);
+    let height = height.clamp(3, r.height);
+    let x = r.x + (r.width.saturating_sub(width)) / 2;
+    let y = r.y + (r.height.saturating_sub(height)) / 2;
+    Rect::new(x, y, width, height)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::docker::{VolumeContainer, VolumeDetails};
+    use ratatui::backend::TestBackend;
+    use ratatui::Terminal;
+
+    fn render(state: &VolumeDetailViewState) -> String {
+        let mut terminal = Terminal::new(TestBackend::new(100, 30)).unwrap();
+        terminal
+            .draw(|f| render_volume_detail_viewer(f, f.area(), state))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .filter_map(|x| buffer.cell((x, y)).map(|c| c.symbol().to_string()))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn shows_options_labels_and_containers() {
+        let mut state = VolumeDetailViewState {
+            volume_name: "pgdata".into(),
+            details: None,
+            scroll_offset: 0,
+        };
+        assert!(render(&state).contains("Loading volume details..."));
+
+        state.details = Some(VolumeDetails {
+            name: "pgdata".into(),
+            driver: "local".into(),
+            mountpoint: "/var/lib/docker/volumes/pgdata/_data".into(),
+            scope: "local".into(),
+            created: "2026-01-02T03:04:05Z".into(),
+            options: vec![("type".into(), "nfs".into())],
+            labels: vec![("team".into(), "db".into())],
+            status: vec![],
+            containers: vec![VolumeContainer {
+                name: "pg".into(),
+                state: "running".into(),
+                destination: "/var/lib/postgresql/data".into(),
+                read_write: false,
+            }],
+        });
+        let text = render(&state);
+        assert!(text.contains("Mountpoint: /var/lib/docker/volumes/pgdata/_data"));
+        assert!(text.contains("type=nfs"));
+        assert!(text.contains("team=db"));
+        assert!(text.contains("Containers (1):"));
+        assert!(text.contains("pg  /var/lib/postgresql/data (ro)  running"));
+    }
+}
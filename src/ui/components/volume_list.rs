@@ -1,5 +1,7 @@
 //! Volume list widget
 
+use std::collections::HashSet;
+
 use ratatui::{
     layout::Constraint,
     style::{Color, Modifier, Style},
@@ -7,12 +9,18 @@ use ratatui::{
     widgets::{Block, Borders, Row, Table, TableState},
 };
 
-use crate::core::VolumeSummary;
+use crate::core::{SortDirection, VolumeSummary};
+use crate::docker::format_bytes_size;
+use crate::state::VolumeSortKey;
 
 /// Widget for displaying a list of Docker volumes
 pub struct VolumeListWidget {
     volumes: Vec<VolumeSummary>,
     state: TableState,
+    /// Names of volumes marked for batch removal
+    marked: HashSet<String>,
+    /// Column and direction shown as sorted in the header
+    sort: Option<(VolumeSortKey, SortDirection)>,
 }
 
 impl VolumeListWidget {
@@ -22,7 +30,24 @@ impl VolumeListWidget {
         if !volumes.is_empty() {
             state.select(Some(0));
         }
-        Self { volumes, state }
+        Self {
+            volumes,
+            state,
+            marked: HashSet::new(),
+            sort: None,
+        }
+    }
+
+    /// Show these volumes as marked
+    pub fn with_marked(mut self, marked: HashSet<String>) -> Self {
+        self.marked = marked;
+        self
+    }
+
+    /// Show the column the volumes are sorted by
+    pub fn with_sort(mut self, key: VolumeSortKey, direction: SortDirection) -> Self {
+        self.sort = Some((key, direction));
+        self
     }
 
     /// Update the volume list
@@ -103,22 +128,51 @@ impl VolumeListWidget {
 
     /// Build the table widget
     pub fn build_table(&self) -> Table<'_> {
-        let header = Row::new(vec!["NAME", "DRIVER", "SCOPE", "MOUNTPOINT"])
-            .style(Style::default().add_modifier(Modifier::BOLD))
-            .bottom_margin(0);
+        let title = |name: &str, key: VolumeSortKey| match self.sort {
+            Some((sorted, SortDirection::Ascending)) if sorted == key => format!("{} ▲", name),
+            Some((sorted, SortDirection::Descending)) if sorted == key => format!("{} ▼", name),
+            _ => name.to_string(),
+        };
+        let header = Row::new(vec![
+            title("NAME", VolumeSortKey::Name),
+            "DRIVER".to_string(),
+            title("SIZE", VolumeSortKey::Size),
+            title("REFS", VolumeSortKey::RefCount),
+            title("CREATED", VolumeSortKey::Created),
+            "MOUNTPOINT".to_string(),
+        ])
+        .style(Style::default().add_modifier(Modifier::BOLD))
+        .bottom_margin(0);
 
         let rows: Vec<Row> = self
             .volumes
             .iter()
             .map(|v| {
-                // Style for unused volumes
-                let style = if v.in_use.is_empty() {
+                // Style for unused volumes (the ref count is exact once known)
+                let unused = v.ref_count.map_or(v.in_use.is_empty(), |count| count == 0);
+                let style = if unused {
                     Style::default().fg(Color::Yellow)
                 } else {
                     Style::default()
                 };
 
-                let scope = format!("{:?}", v.scope);
+                let name = if self.marked.contains(&v.name) {
+                    Line::from(vec![
+                        Span::styled("● ", Style::default().fg(Color::Green)),
+                        Span::styled(v.name.clone(), style),
+                    ])
+                } else {
+                    Line::from(Span::styled(v.name.clone(), style))
+                };
+
+                let size = v.size.map(format_bytes_size).unwrap_or_else(|| "-".into());
+                let refs = match v.ref_count {
+                    Some(_) if v.is_orphan() => {
+                        Line::from(Span::styled("0 orphan", Style::default().fg(Color::Red)))
+                    }
+                    Some(count) => Line::from(count.to_string()),
+                    None => Line::from("-"),
+                };
 
                 // Truncate mountpoint if too long
                 let mountpoint = if v.mountpoint.len() > 40 {
@@ -128,27 +182,40 @@ impl VolumeListWidget {
                 };
 
                 Row::new(vec![
-                    Line::from(Span::styled(v.name.clone(), style)),
+                    name,
                     Line::from(v.driver.clone()),
-                    Line::from(scope.to_lowercase()),
+                    Line::from(size),
+                    refs,
+                    Line::from(v.created_at.format("%Y-%m-%d %H:%M").to_string()),
                     Line::from(mountpoint),
                 ])
             })
             .collect();
 
+        let mut counts = vec![self.volumes.len().to_string()];
+        if !self.marked.is_empty() {
+            counts.push(format!("{} marked", self.marked.len()));
+        }
+        let orphans = self.volumes.iter().filter(|v| v.is_orphan()).count();
+        if orphans > 0 {
+            counts.push(format!("{} orphaned", orphans));
+        }
+
         Table::new(
             rows,
             [
                 Constraint::Min(20),    // Name
-                Constraint::Length(12), // Driver
-                Constraint::Length(8),  // Scope
+                Constraint::Length(10), // Driver
+                Constraint::Length(10), // Size
+                Constraint::Length(8),  // Refs
+                Constraint::Length(16), // Created
                 Constraint::Min(20),    // Mountpoint
             ],
         )
         .header(header)
         .block(
             Block::default()
-                .title(format!(" Volumes ({}) ", self.volumes.len()))
+                .title(format!(" Volumes ({}) ", counts.join(", ")))
                 .borders(Borders::ALL),
         )
         .row_highlight_style(
@@ -188,6 +255,8 @@ mod tests {
                 scope: VolumeScope::Local,
                 options: Default::default(),
                 in_use: vec!["container1".to_string()],
+                size: None,
+                ref_count: None,
             },
             VolumeSummary {
                 name: "unused-volume".to_string(),
@@ -199,10 +268,33 @@ mod tests {
                 scope: VolumeScope::Local,
                 options: Default::default(),
                 in_use: vec![], // Unused
+                size: None,
+                ref_count: None,
             },
         ]
     }
 
+    /// The test volumes with disk usage loaded, plus an orphaned anonymous one
+    fn create_usage_test_volumes() -> Vec<VolumeSummary> {
+        let mut volumes = create_test_volumes();
+        volumes[0].size = Some(3 * 1024 * 1024);
+        volumes[0].ref_count = Some(1);
+        volumes.push(VolumeSummary {
+            name: "a3f1c0de".repeat(8),
+            driver: "local".to_string(),
+            mountpoint: String::new(),
+            created_at: Utc::now(),
+            status: Default::default(),
+            labels: Default::default(),
+            scope: VolumeScope::Local,
+            options: Default::default(),
+            in_use: vec![],
+            size: Some(512),
+            ref_count: Some(0),
+        });
+        volumes
+    }
+
     #[test]
     fn test_volume_list_creation() {
         let volumes = create_test_volumes();
@@ -224,4 +316,38 @@ mod tests {
         widget.next();
         assert_eq!(widget.state.selected(), Some(0)); // Wrap around
     }
+
+    #[test]
+    fn test_usage_columns_and_orphans() {
+        use ratatui::{backend::TestBackend, Terminal};
+
+        let volumes = create_usage_test_volumes();
+        assert!(!volumes[0].is_orphan());
+        assert!(!volumes[1].is_orphan());
+        assert!(volumes[2].is_orphan());
+
+        let orphan = volumes[2].name.clone();
+        let widget = VolumeListWidget::new(volumes)
+            .with_marked(HashSet::from([orphan]))
+            .with_sort(VolumeSortKey::Size, SortDirection::Descending);
+        let mut terminal = Terminal::new(TestBackend::new(160, 7)).unwrap();
+        terminal
+            .draw(|f| f.render_widget(widget.build_table(), f.area()))
+            .unwrap();
+        let buffer = terminal.backend().buffer();
+        let text: String = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .filter_map(|x| buffer.cell((x, y)).map(|c| c.symbol().to_string()))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        assert!(text.contains("Volumes (3, 1 marked, 1 orphaned)"));
+        assert!(text.contains("SIZE ▼"));
+        assert!(text.contains("3.0 MB"));
+        assert!(text.contains("0 orphan"));
+        assert!(text.contains("● a3f1c0de"));
+    }
 }
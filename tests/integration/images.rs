@@ -0,0 +1,111 @@
+//! Image tag/push (against a throwaway local registry) and save/load
+//! integration tests
+
+use std::collections::HashMap;
+
+use bollard::container::{Config, CreateContainerOptions};
+use bollard::models::{HostConfig, PortBinding};
+use contui::docker::{DockerClient, TransferProgress};
+use futures::StreamExt;
+
+const REGISTRY_PORT: &str = "5055";
+
+/// Start a `registry:2` container published on a fixed localhost port.
+/// Returns the container ID.
+pub async fn start_local_registry(client: &DockerClient, port: &str) -> String {
+    client.ensure_image("registry:2").await.unwrap();
+    let name = format!("contui-test-registry-{}-{}", port, std::process::id());
+    let registry = client
+        .inner()
+        .create_container(
+            Some(CreateContainerOptions {
+                name: name.as_str(),
+                platform: None,
+            }),
+            Config {
+                image: Some("registry:2".to_string()),
+                host_config: Some(HostConfig {
+                    port_bindings: Some(HashMap::from([(
+                        "5000/tcp".to_string(),
+                        Some(vec![PortBinding {
+                            host_ip: Some("127.0.0.1".to_string()),
+                            host_port: Some(port.to_string()),
+                        }]),
+                    )])),
+                    ..Default::default()
+                }),
+                ..Default::default()
+            },
+        )
+        .await
+        .unwrap();
+    client.start_container(&registry.id).await.unwrap();
+    tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+    registry.id
+}
+
+/// Push a tag, returning the progress seen
+pub async fn push(
+    client: &DockerClient,
+    reference: &str,
+) -> (TransferProgress, Result<(), contui::core::ContuiError>) {
+    let mut progress = TransferProgress::default();
+    let mut stream = std::pin::pin!(client.push_image_progress(reference, None));
+    while let Some(item) = stream.next().await {
+        match item {
+            Ok(info) => progress.apply_push(&info),
+            Err(e) => return (progress, Err(e)),
+        }
+    }
+    (progress, Ok(()))
+}
+
+#[tokio::test]
+#[ignore = "requires Docker daemon and network access"]
+async fn test_tag_push_untag_with_local_registry() {
+    let client = DockerClient::from_env().await.unwrap();
+    client.ensure_image("busybox:latest").await.unwrap();
+    let registry = start_local_registry(&client, REGISTRY_PORT).await;
+
+    let reference = format!("localhost:{}/contui-test:1", REGISTRY_PORT);
+    client
+        .tag_image("busybox:latest", &reference)
+        .await
+        .unwrap();
+
+    let (progress, result) = push(&client, &reference).await;
+
+    // Untagging leaves busybox:latest in place
+    let untagged = client.untag_image(&reference).await;
+    let _ = client.remove_container(&registry, true, true).await;
+
+    result.unwrap();
+    untagged.unwrap();
+    assert!(progress
+        .digest
+        .as_deref()
+        .is_some_and(|d| d.starts_with("sha256:")));
+    assert!(client.inner().inspect_image("busybox:latest").await.is_ok());
+}
+
+#[tokio::test]
+#[ignore = "requires Docker daemon and network access"]
+async fn test_save_and_load_round_trip() {
+    let client = DockerClient::from_env().await.unwrap();
+    client.ensure_image("busybox:latest").await.unwrap();
+
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("busybox.tar.gz");
+    let mut reported = 0;
+    let written = client
+        .save_images(&["busybox:latest".to_string()], &path, true, |n| {
+            reported = n
+        })
+        .await
+        .unwrap();
+    assert!(written > 0);
+    assert_eq!(reported, written);
+
+    let loaded = client.load_images(&path, |_| {}).await.unwrap();
+    assert_eq!(loaded, vec!["busybox:latest"]);
+}
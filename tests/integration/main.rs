@@ -2,3 +2,5 @@
 
 mod containers;
 mod docker_client;
+mod images;
+mod registry;
@@ -0,0 +1,45 @@
+//! Registry HTTP API v2 client tests against a throwaway `registry:2`
+
+use contui::docker::DockerClient;
+use contui::registry::RegistryClient;
+
+use crate::images::{push, start_local_registry};
+
+const REGISTRY_PORT: &str = "5056";
+
+#[tokio::test]
+#[ignore = "requires Docker daemon and network access"]
+async fn test_browse_local_registry() {
+    let client = DockerClient::from_env().await.unwrap();
+    client.ensure_image("busybox:latest").await.unwrap();
+    let registry = start_local_registry(&client, REGISTRY_PORT).await;
+
+    let reference = format!("localhost:{}/contui/browse:1", REGISTRY_PORT);
+    client
+        .tag_image("busybox:latest", &reference)
+        .await
+        .unwrap();
+    let (_, pushed) = push(&client, &reference).await;
+
+    let registry_client =
+        RegistryClient::with_base_url(format!("http://127.0.0.1:{}", REGISTRY_PORT), None);
+    let catalog = registry_client.catalog().await;
+    let tags = registry_client.tags("contui/browse").await;
+    let manifest = registry_client.manifest("contui/browse", "1").await;
+    let digest = registry_client.tag_digest("contui/browse", "1").await;
+
+    let _ = client.untag_image(&reference).await;
+    let _ = client.remove_container(&registry, true, true).await;
+
+    pushed.unwrap();
+    assert!(catalog.unwrap().contains(&"contui/browse".to_string()));
+    assert_eq!(tags.unwrap(), vec!["1"]);
+    let manifest = manifest.unwrap();
+    assert!(manifest.digest.starts_with("sha256:"));
+    assert_eq!(digest.unwrap(), manifest.digest);
+    assert!(manifest.platforms.iter().all(|p| p.size > 0));
+    assert!(manifest
+        .platforms
+        .iter()
+        .any(|p| p.platform.starts_with("linux/")));
+}